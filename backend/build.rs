@@ -0,0 +1,40 @@
+//! Generates the gRPC service in `src/grpc`. Messages are hand-written prost
+//! types, so the build doesn't need `protoc`; `proto/scoreboard.proto`
+//! describes the same service for clients.
+
+use tonic_build::manual::{Builder, Method, Service};
+
+fn method(name: &str, route: &str, input: &str, output: &str) -> Method {
+    Method::builder()
+        .name(name)
+        .route_name(route)
+        .input_type(format!("super::{input}"))
+        .output_type(format!("super::{output}"))
+        .codec_path("tonic_prost::ProstCodec")
+        .build()
+}
+
+fn main() {
+    let watch_game = Method::builder()
+        .name("watch_game")
+        .route_name("WatchGame")
+        .input_type("super::WatchGameRequest")
+        .output_type("super::FootballGame")
+        .codec_path("tonic_prost::ProstCodec")
+        .server_streaming()
+        .build();
+
+    let service = Service::builder()
+        .name("Scoreboard")
+        .package("scoreboard.v1")
+        .method(method("list_football_games", "ListFootballGames", "ListGamesRequest", "FootballGames"))
+        .method(method("get_football_game", "GetFootballGame", "GetGameRequest", "FootballGame"))
+        .method(method("list_basketball_games", "ListBasketballGames", "ListGamesRequest", "BasketballGames"))
+        .method(method("get_basketball_game", "GetBasketballGame", "GetGameRequest", "BasketballGame"))
+        .method(method("list_mock_games", "ListMockGames", "ListMockGamesRequest", "FootballGames"))
+        .method(watch_game)
+        .build();
+
+    Builder::new().build_client(false).compile(&[service]);
+    println!("cargo::rerun-if-changed=build.rs");
+}
@@ -0,0 +1,329 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::AppState;
+use crate::auth::AdminKey;
+use crate::clock::uptime;
+use crate::error::{AppError, ErrorResponse};
+use crate::health::CacheHealth;
+use crate::reload;
+
+use super::types::{
+    ApiKeyInfo, CacheFlushResponse, ConfigReloadResponse, CreateApiKeyRequest, CreatedApiKey,
+    KeySource, LogLevelResponse, SetLogLevelRequest, StatsResponse,
+};
+
+/// GET /api/admin/keys
+/// List the labeled API keys accepted alongside the admin key
+#[utoipa::path(
+    get,
+    path = "/api/admin/keys",
+    responses(
+        (status = 200, description = "Labeled API keys", body = Vec<ApiKeyInfo>),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_api_keys(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<Vec<ApiKeyInfo>> {
+    Json(state.api_keys.list())
+}
+
+/// POST /api/admin/keys
+/// Add a labeled API key, e.g. for a new device. Lasts until restart.
+#[utoipa::path(
+    post,
+    path = "/api/admin/keys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "Key added. The key is not shown again", body = CreatedApiKey),
+        (status = 400, description = "Empty label or scopes, or key too short or already in use", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+        (status = 409, description = "Label already in use", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn create_api_key(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> Result<(StatusCode, Json<CreatedApiKey>), AppError> {
+    let (key, key_hash) = state.api_keys.add(
+        &request.label,
+        request.key,
+        request.scopes.clone(),
+        KeySource::Runtime,
+        state.config.reserved_keys(),
+    )?;
+    tracing::info!(label = request.label.trim(), scopes = ?request.scopes, "API key added");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedApiKey {
+            label: request.label.trim().to_string(),
+            key,
+            key_hash: key_hash.to_string(),
+            scopes: request.scopes,
+        }),
+    ))
+}
+
+/// DELETE /api/admin/keys/{label}
+/// Revoke a labeled API key, unregistering the device if it was a device's.
+/// Keys from config come back on restart unless also removed there.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/keys/{label}",
+    params(
+        ("label" = String, Path, description = "Label of the key to revoke"),
+    ),
+    responses(
+        (status = 204, description = "Key revoked"),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+        (status = 404, description = "No key with this label", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn revoke_api_key(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+    Path(label): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.api_keys.revoke(&label)?;
+    // A registered device without its key is gone too
+    state.devices.remove(&state.api_keys, &label).await;
+    tracing::info!(label = %label, "API key revoked");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/admin/cache/flush
+/// Drop every cached logo and rendered frame, e.g. after a team's artwork changes
+#[utoipa::path(
+    post,
+    path = "/api/admin/cache/flush",
+    responses(
+        (status = 200, description = "Caches flushed", body = CacheFlushResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn flush_caches(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<CacheFlushResponse> {
+    let flushed = CacheFlushResponse {
+        logos: state.logo_cache.clear().await,
+        frames: state.frame_cache.clear(),
+    };
+    tracing::info!(logos = flushed.logos, frames = flushed.frames, "Caches flushed");
+    Json(flushed)
+}
+
+/// POST /api/admin/config/reload
+/// Re-read the config files and environment and apply the settings that don't
+/// need a restart: log level, rate limits, mock listings, and display profiles.
+/// Same as sending the server SIGHUP.
+#[utoipa::path(
+    post,
+    path = "/api/admin/config/reload",
+    responses(
+        (status = 200, description = "Configuration reloaded", body = ConfigReloadResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+        (status = 500, description = "Configuration is invalid; current settings kept", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn reload_config(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<ConfigReloadResponse>, AppError> {
+    let changed = reload::reload(&state)?;
+    Ok(Json(ConfigReloadResponse {
+        changed: changed.into_iter().map(String::from).collect(),
+    }))
+}
+
+/// GET /api/admin/config
+/// The configuration the server is running with, after layering config files,
+/// environment variables, command-line flags, and any reload. Secrets are
+/// redacted.
+#[utoipa::path(
+    get,
+    path = "/api/admin/config",
+    responses(
+        (status = 200, description = "Effective configuration, secrets shown as \"[redacted]\"", body = Object),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_config(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<serde_json::Value> {
+    let mut config = serde_json::to_value(&state.config).unwrap();
+    // Reloaded settings replace the ones read at startup
+    let live = serde_json::to_value(&*state.live.read().unwrap()).unwrap();
+    if let (Some(config), serde_json::Value::Object(live)) = (config.as_object_mut(), live) {
+        config.extend(live);
+    }
+    Json(config)
+}
+
+/// GET /api/admin/stats
+/// ESPN request counts, cache state, and each key's requests, bytes, and poll
+/// rate, for checking polling behavior without reading the logs.
+#[utoipa::path(
+    get,
+    path = "/api/admin/stats",
+    responses(
+        (status = 200, description = "Upstream, cache, and poll statistics", body = StatsResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_stats(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<StatsResponse> {
+    Json(StatsResponse {
+        uptime_secs: uptime().as_secs(),
+        espn: state.espn_client.stats(),
+        caches: CacheHealth::new(&state),
+        keys: state.api_keys.usage(),
+    })
+}
+
+fn log_level_response(state: &AppState) -> LogLevelResponse {
+    let active = state.log_level.override_level();
+    LogLevelResponse {
+        level: state.log_level.current(),
+        configured: state.live.read().unwrap().log_level.clone(),
+        overridden: active.is_some(),
+        expires_at: active.and_then(|o| o.expires_at),
+    }
+}
+
+/// GET /api/admin/log-level
+/// The log filter in effect, and whether it's a temporary override
+#[utoipa::path(
+    get,
+    path = "/api/admin/log-level",
+    responses(
+        (status = 200, description = "Log level in effect", body = LogLevelResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_log_level(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<LogLevelResponse> {
+    Json(log_level_response(&state))
+}
+
+/// PUT /api/admin/log-level
+/// Override the configured log level, e.g. "info,espn::deserialize=debug" to
+/// log the raw JSON of ESPN responses that fail to parse. With `duration_secs`,
+/// the configured level comes back on its own.
+#[utoipa::path(
+    put,
+    path = "/api/admin/log-level",
+    request_body = SetLogLevelRequest,
+    responses(
+        (status = 200, description = "Log level changed", body = LogLevelResponse),
+        (status = 400, description = "Invalid log filter", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn set_log_level(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SetLogLevelRequest>,
+) -> Result<Json<LogLevelResponse>, AppError> {
+    let duration = request.duration_secs.map(Duration::from_secs);
+    let (applied, generation) = state.log_level.set_override(&request.level, duration)?;
+    tracing::info!(level = %applied.level, expires_at = ?applied.expires_at, "Log level overridden");
+
+    if let Some(duration) = duration {
+        let state = state.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(duration).await;
+            let configured = state.live.read().unwrap().log_level.clone();
+            match state.log_level.expire(generation, configured.as_deref()) {
+                Ok(true) => tracing::info!("Log level override expired"),
+                Ok(false) => {}
+                Err(e) => tracing::error!(error = ?e, "Couldn't restore the configured log level"),
+            }
+        });
+    }
+    Ok(Json(log_level_response(&state)))
+}
+
+/// DELETE /api/admin/log-level
+/// End any override and go back to the configured log level
+#[utoipa::path(
+    delete,
+    path = "/api/admin/log-level",
+    responses(
+        (status = 200, description = "Configured log level restored", body = LogLevelResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn reset_log_level(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<LogLevelResponse>, AppError> {
+    let configured = state.live.read().unwrap().log_level.clone();
+    state.log_level.configure(configured.as_deref())?;
+    tracing::info!("Log level reset to configured");
+    Ok(Json(log_level_response(&state)))
+}
@@ -0,0 +1,7 @@
+pub mod handler;
+pub mod types;
+
+pub use handler::{
+    create_api_key, flush_caches, get_config, get_log_level, get_stats, list_api_keys,
+    reload_config, reset_log_level, revoke_api_key, set_log_level,
+};
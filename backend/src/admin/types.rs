@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::espn::stats::EspnStats;
+use crate::health::CacheHealth;
+
+/// What an API key may do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum Scope {
+    /// Read games, logos, and rendered images, including mock games
+    #[serde(rename = "games:read")]
+    GamesRead,
+    /// Create, change, and delete mock games
+    #[serde(rename = "mock:write")]
+    MockWrite,
+    /// Use the admin routes, when no separate `admin_key` is configured
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 3] = [Scope::GamesRead, Scope::MockWrite, Scope::Admin];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scope::GamesRead => "games:read",
+            Scope::MockWrite => "mock:write",
+            Scope::Admin => "admin",
+        }
+    }
+}
+
+/// Scopes for keys that don't list their own
+pub fn default_scopes() -> Vec<Scope> {
+    vec![Scope::GamesRead]
+}
+
+/// Where an API key came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum KeySource {
+    /// Listed in `api_keys` in config; comes back on restart even if revoked
+    Config,
+    /// Added through the admin endpoint; lost on restart
+    Runtime,
+    /// Issued to a device at registration
+    Device,
+}
+
+/// An API key, without its value
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiKeyInfo {
+    pub label: String,
+    pub scopes: Vec<Scope>,
+    pub source: KeySource,
+    /// When the key was added (Unix seconds). Config keys date from server start.
+    pub created_at: i64,
+    /// Last 4 characters of the key, to tell keys apart. Unknown for keys
+    /// configured by hash.
+    pub key_suffix: Option<String>,
+    /// Last request made with the key (Unix seconds), since server start
+    pub last_used: Option<i64>,
+    /// Requests made with the key since server start
+    pub request_count: u64,
+    /// Response body bytes sent to the key since server start
+    pub bytes_sent: u64,
+}
+
+/// What a key (and so usually one device) has been doing since server start
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KeyUsage {
+    /// Key label; a registered device's ID for device keys
+    pub label: String,
+    pub source: KeySource,
+    pub request_count: u64,
+    /// Response body bytes sent to the key
+    pub bytes_sent: u64,
+    /// Requests per minute over the last 5 minutes
+    pub requests_per_minute: f64,
+    /// Mean seconds between those requests. Null with fewer than two.
+    pub mean_interval_secs: Option<f64>,
+    /// Last request made with the key (Unix seconds)
+    pub last_used: Option<i64>,
+}
+
+/// Request body for adding an API key
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// Unique name for the key (e.g., "kitchen-pico")
+    pub label: String,
+    /// Key to use, at least 16 characters. Generated if not given.
+    pub key: Option<String>,
+    /// What the key may do (default: `games:read` only)
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<Scope>,
+}
+
+/// A newly added API key. The key is only ever returned here.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiKey {
+    pub label: String,
+    pub key: String,
+    /// Salted hash of the key, for `key_hash` in config to keep the key across
+    /// restarts without storing it in plain text
+    pub key_hash: String,
+    pub scopes: Vec<Scope>,
+}
+
+/// Response from flushing the caches
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CacheFlushResponse {
+    /// Processed logos dropped from memory (disk entries are removed too)
+    pub logos: usize,
+    /// Rendered frames dropped, so the next poll of each gets a full frame
+    pub frames: usize,
+}
+
+/// Response from reloading the configuration
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigReloadResponse {
+    /// Settings that changed: any of "log_level", "rate_limit", "mock", "devices"
+    pub changed: Vec<String>,
+}
+
+/// Response from the admin stats endpoint
+#[derive(Serialize, ToSchema)]
+pub struct StatsResponse {
+    /// Seconds since the server started
+    pub uptime_secs: u64,
+    pub espn: EspnStats,
+    pub caches: CacheHealth,
+    /// Usage and poll rates per labeled key (and so per device)
+    pub keys: Vec<KeyUsage>,
+}
+
+/// Request body for overriding the log level
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetLogLevelRequest {
+    /// Log filter in RUST_LOG syntax (e.g., "info,espn::deserialize=debug")
+    pub level: String,
+    /// Go back to the configured level after this many seconds. Without it,
+    /// the override lasts until reset, a config reload that changes
+    /// `log_level`, or restart.
+    pub duration_secs: Option<u64>,
+}
+
+/// The log level in effect
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LogLevelResponse {
+    /// The filter in effect, in RUST_LOG syntax
+    pub level: String,
+    /// `log_level` from config. Null when RUST_LOG (or "info") applies.
+    pub configured: Option<String>,
+    /// Whether `level` was set through the admin API
+    pub overridden: bool,
+    /// When the configured level comes back (Unix seconds). Null if it doesn't
+    /// on its own.
+    pub expires_at: Option<i64>,
+}
@@ -0,0 +1,143 @@
+//! Games from every configured league in one list (`all_games.leagues`), so a
+//! single display can rotate across sports on busy weekends. Built from the
+//! same (cached) scoreboards as the per-league lists.
+
+use axum::extract::State;
+use axum::Json;
+use futures_util::future::join_all;
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use crate::auth::ApiKey;
+use crate::basketball::transform::transform_from_scoreboard;
+use crate::basketball::types::BasketballGameResponse;
+use crate::error::{AppError, ErrorResponse};
+use crate::football::handler::{fetch_scoreboard, include_mock};
+use crate::football::types::{FootballGameResponse, GamesQuery};
+use crate::shared::transform::skip_malformed;
+use crate::sport::League;
+use crate::timing::{self, Timed};
+use crate::AppState;
+
+/// A game from any league, in that league's response shape
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum AnyGame {
+    Football(Box<FootballGameResponse>),
+    Basketball(Box<BasketballGameResponse>),
+}
+
+/// A game and the league it's from
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeagueGame {
+    /// League the game is from: nfl, ncaaf, nba, or ncaab
+    #[schema(example = "nfl")]
+    pub league: String,
+    #[serde(flatten)]
+    pub game: AnyGame,
+}
+
+/// The league's current scoreboard, mock games included where its own list
+/// would include them.
+async fn league_games(state: &AppState, league: League) -> Result<Vec<AnyGame>, AppError> {
+    match league {
+        League::Football(l) => {
+            let include_mock = include_mock(state, l, &GamesQuery::default());
+            let games = fetch_scoreboard(state, l, include_mock).await?;
+            Ok(games.into_iter().map(|game| AnyGame::Football(Box::new(game))).collect())
+        }
+        League::Basketball(l) => {
+            let scoreboard = timing::espn(state.provider.fetch_scoreboard(league, None)).await?;
+            Ok(timing::transform(|| {
+                scoreboard
+                    .events
+                    .iter()
+                    .filter_map(|e| skip_malformed(&e.id, transform_from_scoreboard(e, l)))
+                    .map(|game| AnyGame::Basketball(Box::new(game)))
+                    .collect()
+            }))
+        }
+    }
+}
+
+/// Merge each league's games in order, tagging them with their league. A
+/// league that couldn't be fetched is left out, unless every one failed.
+fn merge(
+    leagues: &[League],
+    results: Vec<Result<Vec<AnyGame>, AppError>>,
+) -> Result<Vec<LeagueGame>, AppError> {
+    let mut merged = Vec::new();
+    let mut last_error = None;
+    let mut fetched = 0;
+    for (&league, result) in leagues.iter().zip(results) {
+        match result {
+            Ok(games) => {
+                fetched += 1;
+                merged.extend(games.into_iter().map(|game| LeagueGame {
+                    league: league.name().to_string(),
+                    game,
+                }));
+            }
+            Err(e) => {
+                tracing::warn!(league = league.name(), error = ?e, "Leaving league out of all games");
+                last_error = Some(e);
+            }
+        }
+    }
+    match last_error {
+        Some(e) if fetched == 0 => Err(e),
+        _ => Ok(merged),
+    }
+}
+
+/// GET /api/all/games
+/// Games from every league in `all_games.leagues`, each with a league field
+#[utoipa::path(
+    get,
+    path = "/api/all/games",
+    responses(
+        (status = 200, description = "Games from each configured league in turn, in the shape of that league's own list plus a `league` field. A league that can't be fetched is left out.", body = Vec<LeagueGame>),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN API for every league", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "all"
+)]
+pub async fn get_all_games(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+) -> Result<Timed<Json<Vec<LeagueGame>>>, AppError> {
+    let leagues = &state.config.all_games.leagues;
+    let results = join_all(leagues.iter().map(|&league| league_games(&state, league))).await;
+    Ok(Timed(Json(merge(leagues, results)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sport::{BasketballLeague, FootballLeague};
+
+    const LEAGUES: [League; 2] = [
+        League::Football(FootballLeague::Nfl),
+        League::Basketball(BasketballLeague::Nba),
+    ];
+
+    #[test]
+    fn test_merge_leaves_out_failed_leagues_unless_all_failed() {
+        let down = || AppError::EspnRateLimited { retry_after_secs: 30 };
+        let partial = merge(&LEAGUES, vec![Ok(Vec::new()), Err(down())]);
+        assert!(partial.unwrap().is_empty());
+
+        let failed = merge(
+            &LEAGUES,
+            vec![Err(down()), Err(AppError::MissingCompetition("401".to_string()))],
+        );
+        assert!(matches!(failed, Err(AppError::MissingCompetition(_))));
+
+        assert!(merge(&[], Vec::new()).unwrap().is_empty());
+    }
+}
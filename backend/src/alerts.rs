@@ -0,0 +1,200 @@
+//! Alerts POSTed to a webhook (ntfy, Slack, Discord, or plain JSON), so a
+//! broken setup shows up on a phone before game day instead of in the logs.
+//!
+//! A monitor checks every minute for:
+//! - ESPN responses failing to parse `failure_threshold` times in a row
+//! - ESPN requests failing `failure_threshold` times in a row
+//! - a labeled key (usually one per device) going `device_offline_mins`
+//!   without a request
+//!
+//! Each alert is sent once when its condition starts, and again only after the
+//! condition has cleared and come back.
+
+use chrono::Utc;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::AppState;
+use crate::config::AlertsConfig;
+
+/// How often the monitor checks its conditions
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Longest to wait for the webhook
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Payload shape for the webhook
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertFormat {
+    /// `{"kind", "title", "message", "at"}`
+    #[default]
+    Json,
+    /// Plain-text body with a `Title` header, for ntfy.sh topics
+    Ntfy,
+    /// `{"text"}` for Slack incoming webhooks
+    Slack,
+    /// `{"content"}` for Discord webhooks
+    Discord,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Alert {
+    /// Identifies the condition, e.g. "device_offline:kitchen"
+    key: String,
+    kind: &'static str,
+    title: String,
+    message: String,
+}
+
+/// The alert conditions that hold right now.
+fn conditions(state: &AppState, config: &AlertsConfig) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    let espn = state.espn_client.stats();
+    let threshold = config.failure_threshold;
+
+    if threshold > 0 && espn.consecutive_parse_failures >= threshold {
+        alerts.push(Alert {
+            key: "espn_deserialize".to_string(),
+            kind: "espn_deserialize",
+            title: "ESPN responses can't be parsed".to_string(),
+            message: format!(
+                "The last {} ESPN responses failed to parse: {}",
+                espn.consecutive_parse_failures,
+                espn.last_error.as_deref().unwrap_or("unknown error")
+            ),
+        });
+    } else if threshold > 0 && espn.consecutive_failures >= threshold {
+        alerts.push(Alert {
+            key: "espn_unreachable".to_string(),
+            kind: "espn_unreachable",
+            title: "ESPN requests are failing".to_string(),
+            message: format!(
+                "The last {} ESPN requests failed: {}",
+                espn.consecutive_failures,
+                espn.last_error.as_deref().unwrap_or("unknown error")
+            ),
+        });
+    }
+
+    if config.device_offline_mins > 0 {
+        let cutoff = Utc::now().timestamp() - config.device_offline_mins as i64 * 60;
+        for key in state.api_keys.list() {
+            // Keys never used since startup have nothing to go quiet from
+            let Some(last_used) = key.last_used.filter(|&at| at < cutoff) else {
+                continue;
+            };
+            alerts.push(Alert {
+                key: format!("device_offline:{}", key.label),
+                kind: "device_offline",
+                title: format!("{} stopped checking in", key.label),
+                message: format!(
+                    "No requests from '{}' for {} minutes",
+                    key.label,
+                    (Utc::now().timestamp() - last_used) / 60
+                ),
+            });
+        }
+    }
+    alerts
+}
+
+/// The alerts in `current` that weren't active on the last check, updating
+/// `active` to the keys of `current`.
+fn newly_active(active: &mut HashSet<String>, current: Vec<Alert>) -> Vec<Alert> {
+    let keys: HashSet<String> = current.iter().map(|a| a.key.clone()).collect();
+    let new = current
+        .into_iter()
+        .filter(|a| !active.contains(&a.key))
+        .collect();
+    *active = keys;
+    new
+}
+
+async fn send(
+    client: &Client,
+    url: &str,
+    format: AlertFormat,
+    alert: &Alert,
+) -> Result<(), reqwest::Error> {
+    let request = client.post(url);
+    let request = match format {
+        AlertFormat::Json => request.json(&serde_json::json!({
+            "kind": alert.kind,
+            "title": alert.title,
+            "message": alert.message,
+            "at": Utc::now().timestamp(),
+        })),
+        AlertFormat::Ntfy => request
+            .header("Title", &alert.title)
+            .header("Tags", "warning")
+            .body(alert.message.clone()),
+        AlertFormat::Slack => request.json(&serde_json::json!({
+            "text": format!("*{}*\n{}", alert.title, alert.message),
+        })),
+        AlertFormat::Discord => request.json(&serde_json::json!({
+            "content": format!("**{}**\n{}", alert.title, alert.message),
+        })),
+    };
+    request.send().await?.error_for_status()?;
+    Ok(())
+}
+
+/// Check the alert conditions every minute, sending new alerts to the webhook.
+pub async fn monitor(state: Arc<AppState>, url: String) {
+    let client = Client::builder()
+        .timeout(SEND_TIMEOUT)
+        .build()
+        .expect("Failed to create HTTP client");
+    let mut active = HashSet::new();
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+
+    loop {
+        interval.tick().await;
+        let current = conditions(&state, &state.config.alerts);
+        for alert in newly_active(&mut active, current) {
+            let format = state.config.alerts.format;
+            match send(&client, &url, format, &alert).await {
+                Ok(()) => tracing::info!(kind = alert.kind, title = %alert.title, "Alert sent"),
+                Err(e) => {
+                    tracing::warn!(kind = alert.kind, error = %e, "Alert webhook failed — retrying next check");
+                    active.remove(&alert.key);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alert(key: &str) -> Alert {
+        Alert {
+            key: key.to_string(),
+            kind: "device_offline",
+            title: String::new(),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_alerts_fire_once_per_occurrence() {
+        let mut active = HashSet::new();
+        let fired = newly_active(&mut active, vec![alert("a"), alert("b")]);
+        assert_eq!(fired.len(), 2);
+
+        // Still failing: nothing new
+        assert!(newly_active(&mut active, vec![alert("a"), alert("b")]).is_empty());
+
+        // "a" clears, then comes back
+        assert!(newly_active(&mut active, vec![alert("b")]).is_empty());
+        assert_eq!(
+            newly_active(&mut active, vec![alert("a"), alert("b")]),
+            vec![alert("a")]
+        );
+    }
+}
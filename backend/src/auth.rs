@@ -1,23 +1,66 @@
-use axum::extract::{FromRef, FromRequestParts};
+use axum::extract::{FromRef, FromRequestParts, OriginalUri};
+use axum::http::{header, Extensions};
 use axum::http::request::Parts;
 use chrono::Utc;
 use hmac::{Hmac, Mac};
-use sha2::Sha256;
-use std::sync::Arc;
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use subtle::ConstantTimeEq;
 
+use crate::admin::types::{ApiKeyInfo, KeySource, KeyUsage, Scope};
+use crate::config::AppConfig;
 use crate::error::AppError;
+use crate::jwt;
+use crate::request_log::record_identity;
 use crate::AppState;
 
 type HmacSha256 = Hmac<Sha256>;
 
-/// API key extractor that validates via X-Api-Key header or HMAC-signed URL.
+/// Scope ids for `ScopedApiKey`, which can't take an enum as a const parameter.
+pub const GAMES_READ: u8 = 0;
+pub const MOCK_WRITE: u8 = 1;
+pub const ADMIN: u8 = 2;
+
+impl Scope {
+    const fn from_id(id: u8) -> Self {
+        match id {
+            GAMES_READ => Scope::GamesRead,
+            MOCK_WRITE => Scope::MockWrite,
+            _ => Scope::Admin,
+        }
+    }
+}
+
+/// API key extractor that validates via X-Api-Key header, bearer token, or
+/// HMAC-signed URL, and requires the credential to hold scope `SCOPE`.
 ///
 /// Authentication methods (tried in order):
 /// 1. `X-Api-Key` header — direct API key match
-/// 2. `expires` + `sig` query params — HMAC-SHA256 signed URL
-pub struct ApiKey;
+/// 2. `Authorization: Bearer` — HS256 JWT, when `jwt.secret` is configured.
+///    Scopes come from its `scope` claim.
+/// 3. `expires` + `sig` query params — HMAC-SHA256 signed URL, signed with
+///    `api_key`. Signed URLs only grant `games:read`.
+pub struct ScopedApiKey<const SCOPE: u8>;
+
+/// Read access to games, logos, and rendered images (including mock games)
+pub type ApiKey = ScopedApiKey<GAMES_READ>;
+
+/// Creating, changing, and deleting mock games
+pub type MockWriteKey = ScopedApiKey<MOCK_WRITE>;
+
+/// Admin credential for `/api/admin/*`: key and device management, cache flushes,
+/// and simulation control.
+///
+/// With `admin_key` configured, only the `X-Admin-Key` header opens these routes,
+/// so no device key — not even `api_key` — can perform destructive actions.
+/// Without it, they fall back to keys holding the `admin` scope.
+pub struct AdminKey;
 
-impl<S> FromRequestParts<S> for ApiKey
+impl<S> FromRequestParts<S> for AdminKey
 where
     S: Send + Sync,
     Arc<AppState>: FromRef<S>,
@@ -26,23 +69,86 @@ where
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         let app_state = Arc::<AppState>::from_ref(state);
-
-        // If no API key is configured, skip authentication entirely
-        let expected_key = match &app_state.config.api_key {
-            Some(key) => key,
-            None => return Ok(ApiKey),
+        let Some(admin_key) = app_state.config.admin_key_hash.as_ref() else {
+            ScopedApiKey::<ADMIN>::from_request_parts(parts, state).await?;
+            return Ok(AdminKey);
         };
 
-        // Method 1: X-Api-Key header
+        match parts.headers.get("x-admin-key").and_then(|v| v.to_str().ok()) {
+            Some(provided) if admin_key.matches(provided) => {
+                tracing::debug!(path = parts.uri.path(), "Authenticated admin key");
+                record_identity(parts, "admin_key", None);
+                Ok(AdminKey)
+            }
+            Some(_) => Err(AppError::Unauthorized),
+            None => Err(AppError::MissingAdminKey),
+        }
+    }
+}
+
+impl<S, const SCOPE: u8> FromRequestParts<S> for ScopedApiKey<SCOPE>
+where
+    S: Send + Sync,
+    Arc<AppState>: FromRef<S>,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let app_state = Arc::<AppState>::from_ref(state);
+        let required = Scope::from_id(SCOPE);
+
+        // If no credential is configured, skip authentication entirely. Admin routes
+        // stay closed, since anyone could otherwise add keys and lock everyone out.
+        if app_state.config.auth_disabled() {
+            return match required {
+                Scope::Admin => Err(AppError::AdminKeyRequired),
+                _ => Ok(ScopedApiKey),
+            };
+        }
+
+        // Method 1: X-Api-Key header. `api_key` holds every scope; labeled
+        // keys hold their own.
+        let api_key = app_state.config.api_key.as_deref();
         if let Some(provided_key) = parts.headers.get("x-api-key").and_then(|v| v.to_str().ok()) {
-            return if provided_key == expected_key {
-                Ok(ApiKey)
+            let scopes = if api_key.is_some_and(|key| keys_equal(key, provided_key)) {
+                record_identity(parts, "api_key", None);
+                Scope::ALL.to_vec()
+            } else {
+                let (label, scopes) =
+                    match_key(&mut parts.extensions, &app_state.api_keys, provided_key)
+                        .ok_or(AppError::Unauthorized)?;
+                app_state.api_keys.record_request(&label);
+                tracing::debug!(key = %label, path = parts.uri.path(), "Authenticated");
+                let device = app_state.devices.contains(&label).then_some(label.as_str());
+                record_identity(parts, label.as_str(), device);
+                scopes
+            };
+            return if scopes.contains(&required) {
+                Ok(ScopedApiKey)
+            } else {
+                Err(AppError::InsufficientScope(required))
+            };
+        }
+
+        // Method 2: Authorization: Bearer <HS256 JWT>, when a secret is configured
+        let bearer = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        if let Some(token) = bearer {
+            let claims = jwt::validate(token.trim(), &app_state.config.jwt, Utc::now().timestamp())?;
+            tracing::debug!(sub = ?claims.sub, path = parts.uri.path(), "Authenticated bearer token");
+            let subject = claims.sub.as_deref().unwrap_or("unknown");
+            record_identity(parts, format!("bearer:{}", subject), None);
+            return if claims.scopes().contains(&required) {
+                Ok(ScopedApiKey)
             } else {
-                Err(AppError::Unauthorized)
+                Err(AppError::InsufficientScope(required))
             };
         }
 
-        // Method 2: HMAC signature via query params (?expires=...&sig=...)
+        // Method 3: HMAC signature via query params (?expires=...&sig=...)
         if let Some(query) = parts.uri.query() {
             let mut expires_val = None;
             let mut sig_val = None;
@@ -64,18 +170,26 @@ where
                 }
 
                 // Compute expected HMAC: sign("{path}|{expires}")
-                let path = parts.uri.path();
+                // Routes are nested under /api, /api/v1, and /api/v2, which
+                // strips the prefix from `parts.uri`; sign the full path
+                let path = parts
+                    .extensions
+                    .get::<OriginalUri>()
+                    .map_or(parts.uri.path(), |uri| uri.path());
                 let message = format!("{}|{}", path, expires_str);
 
-                let mut mac = HmacSha256::new_from_slice(expected_key.as_bytes())
-                    .map_err(|_| AppError::InvalidSignature)?;
-                mac.update(message.as_bytes());
-                let expected_sig = hex::encode(mac.finalize().into_bytes());
+                // Only `api_key` signs URLs, since labeled keys are kept as hashes
+                let signed = api_key.is_some_and(|key| {
+                    sign(key, &message).is_some_and(|expected| keys_equal(&expected, &sig))
+                });
 
-                return if sig == expected_sig {
-                    Ok(ApiKey)
-                } else {
-                    Err(AppError::InvalidSignature)
+                if signed {
+                    record_identity(parts, "signed_url", None);
+                }
+                return match (signed, required) {
+                    (false, _) => Err(AppError::InvalidSignature),
+                    (true, Scope::GamesRead) => Ok(ScopedApiKey),
+                    (true, _) => Err(AppError::InsufficientScope(required)),
                 };
             }
         }
@@ -84,3 +198,471 @@ where
         Err(AppError::MissingApiKey)
     }
 }
+
+fn sign(key: &str, message: &str) -> Option<String> {
+    let mut mac = HmacSha256::new_from_slice(key.as_bytes()).ok()?;
+    mac.update(message.as_bytes());
+    Some(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Shortest key accepted when a client supplies its own
+const MIN_KEY_LENGTH: usize = 16;
+
+/// Salted SHA-256 of an API key, written as `sha256:<salt hex>:<digest hex>`.
+/// Keys are long random strings, so a fast hash is enough to keep them out of
+/// config files and memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHash {
+    salt: [u8; 16],
+    digest: [u8; 32],
+}
+
+impl KeyHash {
+    /// Hash a key with a fresh random salt.
+    pub fn new(key: &str) -> Self {
+        let salt = rand::random();
+        Self {
+            salt,
+            digest: Self::digest(&salt, key),
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().split(':');
+        if parts.next()? != "sha256" {
+            return None;
+        }
+        let salt = hex::decode(parts.next()?).ok()?.try_into().ok()?;
+        let digest = hex::decode(parts.next()?).ok()?.try_into().ok()?;
+        parts.next().is_none().then_some(Self { salt, digest })
+    }
+
+    /// Whether `key` hashes to this, compared in constant time.
+    pub fn matches(&self, key: &str) -> bool {
+        Self::digest(&self.salt, key).ct_eq(&self.digest).into()
+    }
+
+    fn digest(salt: &[u8; 16], key: &str) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(salt);
+        hasher.update(key.as_bytes());
+        hasher.finalize().into()
+    }
+}
+
+impl fmt::Display for KeyHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sha256:{}:{}", hex::encode(self.salt), hex::encode(self.digest))
+    }
+}
+
+impl Serialize for KeyHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Constant-time string comparison, so response timing doesn't reveal how much
+/// of a guessed key was right. Compares digests, since comparing the strings
+/// themselves would stop early on a length mismatch.
+pub fn keys_equal(a: &str, b: &str) -> bool {
+    Sha256::digest(a).ct_eq(&Sha256::digest(b)).into()
+}
+
+/// Keys no labeled key may reuse: `api_key` and `admin_key`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReservedKeys<'a> {
+    pub api_key: Option<&'a str>,
+    pub admin_key_hash: Option<&'a KeyHash>,
+}
+
+impl ReservedKeys<'_> {
+    pub fn contains(&self, key: &str) -> bool {
+        self.api_key.is_some_and(|api_key| keys_equal(api_key, key))
+            || self.admin_key_hash.is_some_and(|hash| hash.matches(key))
+    }
+}
+
+/// The labeled key an `X-Api-Key` matched, if any, kept in the request
+/// extensions so the rate limiter and the extractor share one lookup.
+#[derive(Debug, Clone)]
+struct KeyMatch(Option<(String, Vec<Scope>)>);
+
+/// Label and scopes of the stored key matching `key`, looked up at most once
+/// per request.
+pub fn match_key(
+    extensions: &mut Extensions,
+    store: &KeyStore,
+    key: &str,
+) -> Option<(String, Vec<Scope>)> {
+    if let Some(KeyMatch(matched)) = extensions.get::<KeyMatch>() {
+        return matched.clone();
+    }
+    let matched = store.find(key);
+    extensions.insert(KeyMatch(matched.clone()));
+    matched
+}
+
+/// A labeled device key. Only its hash is kept.
+#[derive(Debug)]
+struct StoredKey {
+    label: String,
+    hash: KeyHash,
+    /// Last 4 characters, when the key was seen in plain text
+    suffix: Option<String>,
+    scopes: Vec<Scope>,
+    source: KeySource,
+    created_at: i64,
+    /// Behind its own lock, so counting a request only needs the store's read lock
+    counters: Mutex<KeyCounters>,
+}
+
+/// How much a key has been used since startup.
+#[derive(Debug, Default)]
+struct KeyCounters {
+    /// Last successful authentication (Unix seconds)
+    last_used: Option<i64>,
+    request_count: u64,
+    /// Response body bytes sent for requests made with the key
+    bytes_sent: u64,
+    /// Requests within the last `POLL_RATE_WINDOW`
+    recent: VecDeque<Instant>,
+}
+
+/// How far back poll rates look
+const POLL_RATE_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Most recent requests kept per key, bounding memory for runaway clients
+const POLL_RATE_MAX_REQUESTS: usize = 1000;
+
+/// Requests per minute over `POLL_RATE_WINDOW`, and the mean gap between them
+/// in seconds. Drops requests older than the window from `recent`.
+fn poll_rate(recent: &mut VecDeque<Instant>, now: Instant) -> (f64, Option<f64>) {
+    while recent
+        .front()
+        .is_some_and(|at| now.duration_since(*at) > POLL_RATE_WINDOW)
+    {
+        recent.pop_front();
+    }
+    let per_minute = recent.len() as f64 / (POLL_RATE_WINDOW.as_secs_f64() / 60.0);
+    let mean_interval = match (recent.front(), recent.back()) {
+        (Some(first), Some(last)) if recent.len() > 1 => {
+            Some(last.duration_since(*first).as_secs_f64() / (recent.len() - 1) as f64)
+        }
+        _ => None,
+    };
+    (per_minute, mean_interval)
+}
+
+fn suffix(key: &str) -> String {
+    key.chars().skip(key.chars().count().saturating_sub(4)).collect()
+}
+
+/// Labeled, scoped API keys accepted alongside the admin key. Starts with the
+/// keys from config; keys added or revoked at runtime last until restart.
+pub struct KeyStore {
+    keys: RwLock<Vec<StoredKey>>,
+}
+
+impl KeyStore {
+    pub fn new(config: &AppConfig) -> Self {
+        let now = Utc::now().timestamp();
+        let keys = config
+            .api_keys
+            .iter()
+            .filter_map(|k| {
+                let (hash, suffix) = match (&k.key_hash, &k.key) {
+                    (Some(hash), _) => (KeyHash::parse(hash), None),
+                    (None, Some(key)) if !key.is_empty() => {
+                        tracing::warn!(
+                            label = %k.label,
+                            "API key stored in plain text — use key_hash instead"
+                        );
+                        (Some(KeyHash::new(key)), Some(suffix(key)))
+                    }
+                    (None, _) => (None, None),
+                };
+                let Some(hash) = hash else {
+                    tracing::warn!(label = %k.label, "API key has no valid key or key_hash — skipped");
+                    return None;
+                };
+                Some(StoredKey {
+                    label: k.label.clone(),
+                    hash,
+                    suffix,
+                    scopes: k.scopes.clone(),
+                    source: KeySource::Config,
+                    created_at: now,
+                    counters: Mutex::default(),
+                })
+            })
+            .collect();
+        Self {
+            keys: RwLock::new(keys),
+        }
+    }
+
+    /// Label and scopes of the stored key matching `key`, if any. Hashes `key`
+    /// once per stored key, so requests should go through `match_key`.
+    fn find(&self, key: &str) -> Option<(String, Vec<Scope>)> {
+        let keys = self.keys.read().unwrap();
+        keys.iter()
+            .find(|k| k.hash.matches(key))
+            .map(|k| (k.label.clone(), k.scopes.clone()))
+    }
+
+    /// Count a request made with the key labeled `label`.
+    pub fn record_request(&self, label: &str) {
+        if let Some(stored) = self.keys.read().unwrap().iter().find(|k| k.label == label) {
+            let mut counters = stored.counters.lock().unwrap();
+            counters.last_used = Some(Utc::now().timestamp());
+            counters.request_count += 1;
+            if counters.recent.len() == POLL_RATE_MAX_REQUESTS {
+                counters.recent.pop_front();
+            }
+            counters.recent.push_back(Instant::now());
+        }
+    }
+
+    /// Count response bytes sent for a request made with the key labeled
+    /// `label`. Does nothing for other credentials (e.g., `api_key`).
+    pub fn record_bytes(&self, label: &str, bytes: u64) {
+        if let Some(stored) = self.keys.read().unwrap().iter().find(|k| k.label == label) {
+            stored.counters.lock().unwrap().bytes_sent += bytes;
+        }
+    }
+
+    /// Each key's usage, including how often it has been polling over the
+    /// last few minutes.
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        let now = Instant::now();
+        self.keys
+            .read()
+            .unwrap()
+            .iter()
+            .map(|k| {
+                let mut counters = k.counters.lock().unwrap();
+                let (requests_per_minute, mean_interval_secs) = poll_rate(&mut counters.recent, now);
+                KeyUsage {
+                    label: k.label.clone(),
+                    source: k.source,
+                    request_count: counters.request_count,
+                    bytes_sent: counters.bytes_sent,
+                    requests_per_minute,
+                    mean_interval_secs,
+                    last_used: counters.last_used,
+                }
+            })
+            .collect()
+    }
+
+    /// Every key, without its value.
+    pub fn list(&self) -> Vec<ApiKeyInfo> {
+        self.keys
+            .read()
+            .unwrap()
+            .iter()
+            .map(|k| {
+                let counters = k.counters.lock().unwrap();
+                ApiKeyInfo {
+                    label: k.label.clone(),
+                    scopes: k.scopes.clone(),
+                    source: k.source,
+                    created_at: k.created_at,
+                    key_suffix: k.suffix.clone(),
+                    last_used: counters.last_used,
+                    request_count: counters.request_count,
+                    bytes_sent: counters.bytes_sent,
+                }
+            })
+            .collect()
+    }
+
+    /// Add a key under a new label, generating the key if none is given.
+    /// Returns the key and its hash. `reserved` are keys that can't be reused.
+    pub fn add(
+        &self,
+        label: &str,
+        key: Option<String>,
+        scopes: Vec<Scope>,
+        source: KeySource,
+        reserved: ReservedKeys<'_>,
+    ) -> Result<(String, KeyHash), AppError> {
+        let label = label.trim();
+        if label.is_empty() {
+            return Err(AppError::InvalidApiKey("label is empty".to_string()));
+        }
+        let key = match key {
+            Some(key) if key.len() < MIN_KEY_LENGTH => {
+                return Err(AppError::InvalidApiKey(format!(
+                    "key is shorter than {} characters",
+                    MIN_KEY_LENGTH
+                )));
+            }
+            Some(key) => key,
+            None => hex::encode(rand::random::<[u8; 24]>()),
+        };
+
+        if scopes.is_empty() {
+            return Err(AppError::InvalidApiKey("key has no scopes".to_string()));
+        }
+
+        let mut keys = self.keys.write().unwrap();
+        if keys.iter().any(|k| k.label == label) {
+            return Err(AppError::ApiKeyExists(label.to_string()));
+        }
+        let reused = reserved.contains(&key)
+            || keys.iter().any(|k| k.hash.matches(&key));
+        if reused {
+            return Err(AppError::InvalidApiKey("key is already in use".to_string()));
+        }
+        let hash = KeyHash::new(&key);
+        keys.push(StoredKey {
+            label: label.to_string(),
+            hash: hash.clone(),
+            suffix: Some(suffix(&key)),
+            scopes,
+            source,
+            created_at: Utc::now().timestamp(),
+            counters: Mutex::default(),
+        });
+        Ok((key, hash))
+    }
+
+    /// Restore a key known only by its hash (e.g., a registered device's).
+    pub fn insert_hashed(
+        &self,
+        label: &str,
+        hash: KeyHash,
+        scopes: Vec<Scope>,
+        source: KeySource,
+        created_at: i64,
+    ) -> Result<(), AppError> {
+        let mut keys = self.keys.write().unwrap();
+        if keys.iter().any(|k| k.label == label) {
+            return Err(AppError::ApiKeyExists(label.to_string()));
+        }
+        keys.push(StoredKey {
+            label: label.to_string(),
+            hash,
+            suffix: None,
+            scopes,
+            source,
+            created_at,
+            counters: Mutex::default(),
+        });
+        Ok(())
+    }
+
+    /// Revoke the key with this label.
+    pub fn revoke(&self, label: &str) -> Result<(), AppError> {
+        let mut keys = self.keys.write().unwrap();
+        let index = keys
+            .iter()
+            .position(|k| k.label == label)
+            .ok_or_else(|| AppError::ApiKeyNotFound(label.to_string()))?;
+        keys.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> KeyStore {
+        KeyStore {
+            keys: RwLock::new(Vec::new()),
+        }
+    }
+
+    fn read() -> Vec<Scope> {
+        vec![Scope::GamesRead]
+    }
+
+    #[test]
+    fn test_key_hash_round_trip() {
+        let hash = KeyHash::new("0123456789abcdef");
+        assert!(hash.matches("0123456789abcdef"));
+        assert!(!hash.matches("0123456789abcdee"));
+
+        let parsed = KeyHash::parse(&hash.to_string()).unwrap();
+        assert_eq!(parsed, hash);
+        assert!(KeyHash::parse("sha256:00:11").is_none());
+        assert!(KeyHash::parse("md5:abc:def").is_none());
+
+        // Same key, different salt
+        assert_ne!(KeyHash::new("0123456789abcdef"), hash);
+    }
+
+    #[test]
+    fn test_add_and_revoke() {
+        let store = store();
+        let (key, hash) = store.add("kitchen", None, read(), KeySource::Runtime, ReservedKeys::default()).unwrap();
+        assert_eq!(key.len(), 48);
+        assert!(hash.matches(&key));
+        let dev_scopes = vec![Scope::GamesRead, Scope::MockWrite];
+        let laptop = Some("0123456789abcdef".to_string());
+        store.add("laptop", laptop, dev_scopes.clone(), KeySource::Runtime, ReservedKeys::default()).unwrap();
+        assert_eq!(store.find(&key), Some(("kitchen".to_string(), read())));
+        assert_eq!(store.find("0123456789abcdef"), Some(("laptop".to_string(), dev_scopes)));
+        assert_eq!(store.find("fedcba9876543210"), None);
+        assert_eq!(store.list()[1].key_suffix.as_deref(), Some("cdef"));
+        // Looking a key up doesn't count as a request
+        assert_eq!(store.list()[1].request_count, 0);
+        store.record_request("laptop");
+        assert_eq!(store.list()[1].request_count, 1);
+
+        // One lookup per request, however many times it's asked for
+        let mut extensions = Extensions::new();
+        assert_eq!(match_key(&mut extensions, &store, &key).unwrap().0, "kitchen");
+        store.revoke("kitchen").unwrap();
+        assert_eq!(match_key(&mut extensions, &store, &key).unwrap().0, "kitchen");
+        assert_eq!(store.find(&key), None);
+        assert!(matches!(store.revoke("kitchen"), Err(AppError::ApiKeyNotFound(_))));
+    }
+
+    #[test]
+    fn test_add_rejects_duplicates_and_short_keys() {
+        let store = store();
+        let admin = "admin-key-0123456789";
+        let admin_hash = KeyHash::new(admin);
+        let reserved = ReservedKeys {
+            api_key: Some("api-key-0123456789"),
+            admin_key_hash: Some(&admin_hash),
+        };
+        let key = || Some("0123456789abcdef".to_string());
+        let add = |label, key, scopes| store.add(label, key, scopes, KeySource::Runtime, reserved);
+        add("kitchen", key(), read()).unwrap();
+
+        let duplicate_label = add("kitchen", None, read());
+        assert!(matches!(duplicate_label, Err(AppError::ApiKeyExists(_))));
+        let duplicate_key = add("garage", key(), read());
+        assert!(matches!(duplicate_key, Err(AppError::InvalidApiKey(_))));
+        let admin_key = add("garage", Some(admin.to_string()), read());
+        assert!(matches!(admin_key, Err(AppError::InvalidApiKey(_))));
+        let api_key = add("garage", Some("api-key-0123456789".to_string()), read());
+        assert!(matches!(api_key, Err(AppError::InvalidApiKey(_))));
+        let short = add("garage", Some("short".to_string()), read());
+        assert!(matches!(short, Err(AppError::InvalidApiKey(_))));
+        let unscoped = add("garage", None, Vec::new());
+        assert!(matches!(unscoped, Err(AppError::InvalidApiKey(_))));
+        assert!(matches!(add(" ", None, read()), Err(AppError::InvalidApiKey(_))));
+    }
+
+    #[test]
+    fn test_poll_rate() {
+        let now = Instant::now();
+        let mut recent: VecDeque<Instant> = [400, 20, 10, 0]
+            .into_iter()
+            .map(|secs| now - Duration::from_secs(secs))
+            .collect();
+        let (per_minute, mean_interval) = poll_rate(&mut recent, now);
+        // The request 400s ago is outside the window
+        assert_eq!(recent.len(), 3);
+        assert_eq!(per_minute, 0.6);
+        assert_eq!(mean_interval, Some(10.0));
+
+        assert_eq!(poll_rate(&mut VecDeque::new(), now), (0.0, None));
+    }
+}
@@ -1,12 +1,17 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
 };
 use std::sync::Arc;
 
 use crate::auth::ApiKey;
 use crate::error::{AppError, ErrorResponse};
+use crate::espn::types::WARNINGS_HEADER;
+use crate::json_stream::{self, StreamQuery};
+use crate::shared::transform::skip_malformed;
 use crate::sport::BasketballLeague;
+use crate::timing::{self, Timed};
 use crate::AppState;
 
 use super::transform;
@@ -19,13 +24,16 @@ use super::types::{BasketballGameDetail, BasketballGameResponse};
     path = "/api/basketball/{league}/games",
     operation_id = "get_all_basketball_games",
     params(
-        ("league" = String, Path, description = "Basketball league: nba or ncaab")
+        ("league" = String, Path, description = "Basketball league: nba or ncaab"),
+        StreamQuery
     ),
     responses(
-        (status = 200, description = "Basketball games retrieved successfully", body = Vec<BasketballGameResponse>),
+        (status = 200, description = "Basketball games retrieved successfully. ESPN events that can't be parsed are left out.", body = Vec<BasketballGameResponse>,
+            headers(("X-Warnings" = usize, description = "Number of ESPN events left out because they couldn't be parsed"))),
         (status = 400, description = "Invalid league", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
         (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
     ),
     security(
         ("api_key" = [])
@@ -36,16 +44,22 @@ pub async fn get_all_games(
     _api_key: ApiKey,
     State(state): State<Arc<AppState>>,
     Path(league): Path<String>,
-) -> Result<Json<Vec<BasketballGameResponse>>, AppError> {
+    Query(stream): Query<StreamQuery>,
+) -> Result<Response, AppError> {
     let basketball_league = BasketballLeague::from_league(&league)?;
-    let events = state.espn_client.fetch_all_games(basketball_league).await?;
+    let scoreboard = timing::espn(state.provider.fetch_scoreboard(basketball_league.into(), None)).await?;
+    let warnings = [(WARNINGS_HEADER, scoreboard.skipped.len().to_string())];
+    let responses = scoreboard.events.into_iter().filter_map(move |e| {
+        skip_malformed(&e.id, transform::transform_from_scoreboard(&e, basketball_league))
+    });
+
+    if stream.enabled() {
+        return Ok((warnings, json_stream::array(responses)).into_response());
+    }
 
-    let responses: Vec<BasketballGameResponse> = events
-        .iter()
-        .map(|e| transform::transform_from_scoreboard(e, basketball_league))
-        .collect();
+    let responses: Vec<BasketballGameResponse> = timing::transform(|| responses.collect());
 
-    Ok(Json(responses))
+    Ok((warnings, Timed(Json(responses))).into_response())
 }
 
 /// GET /api/{league}/games/{event_id}
@@ -63,6 +77,7 @@ pub async fn get_all_games(
         (status = 400, description = "Invalid league or event ID", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
         (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
     ),
     security(
         ("api_key" = [])
@@ -73,7 +88,7 @@ pub async fn get_game(
     _api_key: ApiKey,
     State(state): State<Arc<AppState>>,
     Path((league, event_id)): Path<(String, String)>,
-) -> Result<Json<BasketballGameDetail>, AppError> {
+) -> Result<Timed<Json<BasketballGameDetail>>, AppError> {
     let basketball_league = BasketballLeague::from_league(&league)?;
 
     // Validate event_id is numeric only
@@ -81,12 +96,15 @@ pub async fn get_game(
         return Err(AppError::InvalidEventId(event_id));
     }
 
-    let summary = state
-        .espn_client
-        .fetch_game_summary(basketball_league, &event_id)
-        .await?;
+    let summary = timing::espn(
+        state
+            .provider
+            .fetch_summary(basketball_league.into(), &event_id),
+    )
+    .await?;
 
-    let response = transform::transform_from_summary(&summary, basketball_league);
+    let response =
+        timing::transform(|| transform::transform_from_summary(&summary, basketball_league))?;
 
-    Ok(Json(response))
+    Ok(Timed(Json(response)))
 }
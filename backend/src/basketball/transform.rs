@@ -1,7 +1,8 @@
-use crate::espn::types::{EspnCompetitor, EspnEvent, EspnSummary};
+use crate::error::AppError;
+use crate::espn::types::{EspnCompetition, EspnCompetitor, EspnEvent, EspnSummary};
 use crate::shared::transform::{
-    determine_winner, get_broadcast, get_competitors, parse_espn_date, parse_hex_color, parse_rank,
-    to_team,
+    determine_winner, first_competition, get_broadcast, get_competitors, parse_espn_date,
+    parse_hex_color, parse_rank, secondary_color, seconds_until, to_team,
 };
 use crate::sport::{BasketballLeague, EspnLeague};
 
@@ -18,33 +19,35 @@ use crate::shared::types::FinalStatus;
 pub fn transform_from_scoreboard(
     event: &EspnEvent,
     league: BasketballLeague,
-) -> BasketballGameResponse {
-    let competition = &event.competitions[0];
-    let (home, away) = get_competitors(&competition.competitors);
+) -> Result<BasketballGameResponse, AppError> {
+    let competition = first_competition(&event.competitions, &event.id)?;
+    let (home, away) = get_competitors(&competition.competitors, &event.id)?;
     let state = event.status.status_type.state.as_str();
 
-    match state {
-        "pre" => BasketballGameResponse::Pregame(to_pregame(event, home, away, league)),
+    Ok(match state {
+        "pre" => BasketballGameResponse::Pregame(to_pregame(event, competition, home, away, league)),
         "in" => BasketballGameResponse::Live(to_live(event, home, away, league)),
         "post" => BasketballGameResponse::Final(to_final(event, home, away, league)),
-        _ => BasketballGameResponse::Pregame(to_pregame(event, home, away, league)),
-    }
+        _ => BasketballGameResponse::Pregame(to_pregame(event, competition, home, away, league)),
+    })
 }
 
 fn to_pregame(
     event: &EspnEvent,
+    competition: &EspnCompetition,
     home: &EspnCompetitor,
     away: &EspnCompetitor,
     league: BasketballLeague,
 ) -> BasketballPregame {
-    let is_college = league.is_college();
-    let venue = event.competitions[0].venue.as_ref();
+    let venue = competition.venue.as_ref();
+    let start_time = parse_espn_date(&event.date);
 
     BasketballPregame {
         event_id: event.id.clone(),
-        home: to_team(home, is_college),
-        away: to_team(away, is_college),
-        start_time: parse_espn_date(&event.date),
+        home: to_team(home, league),
+        away: to_team(away, league),
+        start_time,
+        seconds_until_start: seconds_until(start_time),
         venue: venue.map(|v| v.full_name.clone()),
         broadcast: get_broadcast(event),
     }
@@ -56,12 +59,10 @@ fn to_live(
     away: &EspnCompetitor,
     league: BasketballLeague,
 ) -> BasketballLive {
-    let is_college = league.is_college();
-
     BasketballLive {
         event_id: event.id.clone(),
-        home: to_team_score(home, is_college),
-        away: to_team_score(away, is_college),
+        home: to_team_score(home, league),
+        away: to_team_score(away, league),
         period: parse_period(event.status.period, league, &event.status.status_type.id),
         clock: event.status.display_clock.clone(),
     }
@@ -73,7 +74,6 @@ fn to_final(
     away: &EspnCompetitor,
     league: BasketballLeague,
 ) -> BasketballFinal {
-    let is_college = league.is_college();
     let home_score = parse_score_u16(&home.score);
     let away_score = parse_score_u16(&away.score);
 
@@ -81,8 +81,8 @@ fn to_final(
 
     BasketballFinal {
         event_id: event.id.clone(),
-        home: to_team_score(home, is_college),
-        away: to_team_score(away, is_college),
+        home: to_team_score(home, league),
+        away: to_team_score(away, league),
         status: if event.status.period > regulation_periods {
             FinalStatus::FinalOvertime
         } else {
@@ -92,12 +92,14 @@ fn to_final(
     }
 }
 
-fn to_team_score(competitor: &EspnCompetitor, is_college: bool) -> BasketballTeamScore {
+fn to_team_score(competitor: &EspnCompetitor, league: impl EspnLeague) -> BasketballTeamScore {
     BasketballTeamScore {
         abbreviation: competitor.team.abbreviation.clone(),
         color: parse_hex_color(competitor.team.color.as_deref().unwrap_or("000000")),
+        color_secondary: secondary_color(competitor),
         record: competitor.records.first().map(|r| r.summary.clone()),
-        rank: parse_rank(competitor, is_college),
+        rank: parse_rank(competitor, league.is_college()),
+        logo_path: league.logo_path(&competitor.team.id, &competitor.team.abbreviation),
         score: parse_score_u16(&competitor.score),
     }
 }
@@ -108,20 +110,20 @@ fn to_team_score(competitor: &EspnCompetitor, is_college: bool) -> BasketballTea
 pub fn transform_from_summary(
     summary: &EspnSummary,
     league: BasketballLeague,
-) -> BasketballGameDetail {
-    let competition = &summary.header.competitions[0];
-    let (home, away) = get_competitors(&competition.competitors);
+) -> Result<BasketballGameDetail, AppError> {
+    let competition = first_competition(&summary.header.competitions, &summary.header.id)?;
+    let (home, away) = get_competitors(&competition.competitors, &summary.header.id)?;
     let state = competition.status.status_type.state.as_str();
-    let is_college = league.is_college();
 
-    match state {
+    Ok(match state {
         "pre" => {
             let venue = competition.venue.as_ref();
             BasketballGameDetail::Pregame(BasketballPregame {
                 event_id: summary.header.id.clone(),
-                home: to_team(home, is_college),
-                away: to_team(away, is_college),
+                home: to_team(home, league),
+                away: to_team(away, league),
                 start_time: 0, // summary endpoint doesn't carry event date
+                seconds_until_start: 0,
                 venue: venue.map(|v| v.full_name.clone()),
                 broadcast: None, // summary doesn't carry broadcast info the same way
             })
@@ -132,8 +134,8 @@ pub fn transform_from_summary(
 
             BasketballGameDetail::Live(BasketballLiveDetail {
                 event_id: summary.header.id.clone(),
-                home: to_team_score_detail(home, is_college, home_fouls),
-                away: to_team_score_detail(away, is_college, away_fouls),
+                home: to_team_score_detail(home, league, home_fouls),
+                away: to_team_score_detail(away, league, away_fouls),
                 period: parse_period(competition.status.period, league, &competition.status.status_type.id),
                 clock: competition.status.display_clock.clone(),
             })
@@ -148,8 +150,8 @@ pub fn transform_from_summary(
 
             BasketballGameDetail::Final(BasketballFinalDetail {
                 event_id: summary.header.id.clone(),
-                home: to_team_score_detail(home, is_college, home_fouls),
-                away: to_team_score_detail(away, is_college, away_fouls),
+                home: to_team_score_detail(home, league, home_fouls),
+                away: to_team_score_detail(away, league, away_fouls),
                 status: if competition.status.period > regulation_periods {
                     FinalStatus::FinalOvertime
                 } else {
@@ -162,26 +164,29 @@ pub fn transform_from_summary(
             let venue = competition.venue.as_ref();
             BasketballGameDetail::Pregame(BasketballPregame {
                 event_id: summary.header.id.clone(),
-                home: to_team(home, is_college),
-                away: to_team(away, is_college),
+                home: to_team(home, league),
+                away: to_team(away, league),
                 start_time: 0, // summary endpoint doesn't carry event date
+                seconds_until_start: 0,
                 venue: venue.map(|v| v.full_name.clone()),
                 broadcast: None,
             })
         }
-    }
+    })
 }
 
 fn to_team_score_detail(
     competitor: &EspnCompetitor,
-    is_college: bool,
+    league: impl EspnLeague,
     fouls: u8,
 ) -> BasketballTeamScoreDetail {
     BasketballTeamScoreDetail {
         abbreviation: competitor.team.abbreviation.clone(),
         color: parse_hex_color(competitor.team.color.as_deref().unwrap_or("000000")),
+        color_secondary: secondary_color(competitor),
         record: competitor.records.first().map(|r| r.summary.clone()),
-        rank: parse_rank(competitor, is_college),
+        rank: parse_rank(competitor, league.is_college()),
+        logo_path: league.logo_path(&competitor.team.id, &competitor.team.abbreviation),
         score: parse_score_u16(&competitor.score),
         fouls,
     }
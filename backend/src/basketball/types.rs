@@ -1,3 +1,4 @@
+use async_graphql::{Enum, SimpleObject, Union};
 use serde::Serialize;
 use utoipa::ToSchema;
 
@@ -7,7 +8,7 @@ use crate::shared::types::{Color, FinalStatus, Team, Winner};
 
 /// Basketball game response for list endpoints (scoreboard data).
 /// No fouls -- scoreboard doesn't include them.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, Union)]
 #[serde(tag = "state", rename_all = "lowercase")]
 pub enum BasketballGameResponse {
     Pregame(BasketballPregame),
@@ -16,12 +17,15 @@ pub enum BasketballGameResponse {
 }
 
 /// Basketball pregame data. Shared by both list and detail responses.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballPregame {
     pub event_id: String,
     pub home: Team,
     pub away: Team,
     pub start_time: i64,
+    /// Seconds until the scheduled tip-off as of this response, for displays
+    /// without a clock. Negative once tip-off time has passed; 0 if unknown.
+    pub seconds_until_start: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub venue: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,19 +34,24 @@ pub struct BasketballPregame {
 }
 
 /// Team score for list endpoints (no fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballTeamScore {
     pub abbreviation: String,
     pub color: Color,
+    /// Secondary color for two-tone displays (white if ESPN lists none)
+    pub color_secondary: Color,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<u8>,
+    /// Path of the team's logo endpoint, e.g. "/api/basketball/ncaab/150/logo"
+    /// (college teams by ESPN team ID, since their abbreviations collide)
+    pub logo_path: String,
     pub score: u16,
 }
 
 /// Live basketball game from scoreboard (no fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballLive {
     pub event_id: String,
     pub home: BasketballTeamScore,
@@ -52,7 +61,7 @@ pub struct BasketballLive {
 }
 
 /// Final basketball game from scoreboard (no fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballFinal {
     pub event_id: String,
     pub home: BasketballTeamScore,
@@ -64,7 +73,7 @@ pub struct BasketballFinal {
 // ── Single-game detail response (from summary -- has fouls) ──
 
 /// Basketball game detail for single-game endpoints (summary data with fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, Union)]
 #[serde(tag = "state", rename_all = "lowercase")]
 pub enum BasketballGameDetail {
     Pregame(BasketballPregame),
@@ -73,20 +82,25 @@ pub enum BasketballGameDetail {
 }
 
 /// Team score for detail endpoints (includes fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballTeamScoreDetail {
     pub abbreviation: String,
     pub color: Color,
+    /// Secondary color for two-tone displays (white if ESPN lists none)
+    pub color_secondary: Color,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<u8>,
+    /// Path of the team's logo endpoint, e.g. "/api/basketball/ncaab/150/logo"
+    /// (college teams by ESPN team ID, since their abbreviations collide)
+    pub logo_path: String,
     pub score: u16,
     pub fouls: u8,
 }
 
 /// Live basketball game detail (with fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballLiveDetail {
     pub event_id: String,
     pub home: BasketballTeamScoreDetail,
@@ -96,7 +110,7 @@ pub struct BasketballLiveDetail {
 }
 
 /// Final basketball game detail (with fouls).
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct BasketballFinalDetail {
     pub event_id: String,
     pub home: BasketballTeamScoreDetail,
@@ -109,7 +123,7 @@ pub struct BasketballFinalDetail {
 
 /// Basketball period. NBA uses quarters (Q1-Q4), NCAAB uses halves (H1-H2).
 /// Both share overtime and halftime.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema, Enum)]
 pub enum BasketballPeriod {
     Q1,
     Q2,
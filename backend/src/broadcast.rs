@@ -0,0 +1,69 @@
+//! UDP broadcast of football scores in the packed binary format, so any number
+//! of displays on the LAN can follow games with no HTTP or TLS stack. Enabled
+//! by setting `broadcast.port`.
+//!
+//! Every `interval_secs` the league's scoreboard is encoded with
+//! `football::packed` and sent to `broadcast.group` (a multicast group, or a
+//! broadcast address). Packets don't leave the local network.
+
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+use crate::AppState;
+use crate::football::handler::{fetch_scoreboard, include_mock};
+use crate::football::packed::encode_packets;
+use crate::football::types::GamesQuery;
+use crate::sport::FootballLeague;
+
+/// Send the scoreboard to `group:port` every interval until shutdown. ESPN
+/// errors skip a round; the last good packets aren't repeated.
+pub async fn broadcast(state: Arc<AppState>, port: u16) {
+    let config = &state.config.broadcast;
+    let league = match FootballLeague::from_league(&config.league) {
+        Ok(league) => league,
+        Err(e) => {
+            tracing::error!(error = ?e, "Invalid broadcast league; broadcasts disabled");
+            return;
+        }
+    };
+    let target = SocketAddrV4::new(config.group, port);
+    let socket = match bind(config.group).await {
+        Ok(socket) => socket,
+        Err(e) => {
+            tracing::error!(error = %e, "Couldn't open broadcast socket; broadcasts disabled");
+            return;
+        }
+    };
+    tracing::info!(%target, league = %config.league, "Broadcasting scores over UDP");
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs.max(1)));
+    loop {
+        interval.tick().await;
+        let include_mock = include_mock(&state, league, &GamesQuery::default());
+        let games = match fetch_scoreboard(&state, league, include_mock).await {
+            Ok(games) => games,
+            Err(e) => {
+                tracing::warn!(error = ?e, "Couldn't fetch scoreboard to broadcast");
+                continue;
+            }
+        };
+        for packet in encode_packets(&games) {
+            if let Err(e) = socket.send_to(&packet, target).await {
+                tracing::warn!(%target, error = %e, "Broadcast send failed");
+            }
+        }
+    }
+}
+
+/// A socket that can send to `group`, limited to the local network.
+async fn bind(group: Ipv4Addr) -> std::io::Result<UdpSocket> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+    if group.is_multicast() {
+        socket.set_multicast_ttl_v4(1)?;
+    } else {
+        socket.set_broadcast(true)?;
+    }
+    Ok(socket)
+}
@@ -0,0 +1,88 @@
+//! Command-line flags, applied over the config files and APP_* environment
+//! variables. Handy for one-off local servers during firmware development:
+//!
+//! ```text
+//! backend --port 8081 --mock-only --log-level debug
+//! backend --fixtures fixtures/
+//! backend --record recordings/
+//! backend --replay recordings/espn-20261018-170000.jsonl --replay-speed 10
+//! backend --export-openapi openapi.json
+//! ```
+
+use clap::Parser;
+use config::{ConfigBuilder, ConfigError, builder::DefaultState};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Pico Scoreboard API server")]
+pub struct Cli {
+    /// Port to listen on (overrides server.port)
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// Config file to read in place of config/local.toml
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Serve only mock games from the football endpoints, never fetching games
+    /// from ESPN (overrides mock.only)
+    #[arg(long)]
+    pub mock_only: bool,
+
+    /// Serve recorded ESPN responses from DIR instead of calling ESPN
+    /// (overrides fixtures.dir)
+    #[arg(long, value_name = "DIR")]
+    pub fixtures: Option<PathBuf>,
+
+    /// Record every ESPN response to a file in DIR (overrides espn.record_dir)
+    #[arg(long, value_name = "DIR")]
+    pub record: Option<PathBuf>,
+
+    /// Replay an ESPN recording instead of calling ESPN (overrides replay.file)
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Replay this many times faster than recorded (overrides replay.speed)
+    #[arg(long, value_name = "N")]
+    pub replay_speed: Option<f64>,
+
+    /// Log filter in RUST_LOG syntax, e.g. "debug" (overrides log_level)
+    #[arg(long, value_name = "FILTER")]
+    pub log_level: Option<String>,
+
+    /// Write the OpenAPI spec as JSON to FILE ("-" for stdout) and exit
+    /// without starting the server, for generating clients in CI
+    #[arg(long, value_name = "FILE")]
+    pub export_openapi: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Apply the flags that were given over everything else in `builder`.
+    pub fn apply(
+        &self,
+        mut builder: ConfigBuilder<DefaultState>,
+    ) -> Result<ConfigBuilder<DefaultState>, ConfigError> {
+        if let Some(port) = self.port {
+            builder = builder.set_override("server.port", port)?;
+        }
+        if self.mock_only {
+            builder = builder.set_override("mock.only", true)?;
+        }
+        if let Some(dir) = &self.fixtures {
+            builder = builder.set_override("fixtures.dir", dir.to_string_lossy().as_ref())?;
+        }
+        if let Some(dir) = &self.record {
+            builder = builder.set_override("espn.record_dir", dir.to_string_lossy().as_ref())?;
+        }
+        if let Some(file) = &self.replay {
+            builder = builder.set_override("replay.file", file.to_string_lossy().as_ref())?;
+        }
+        if let Some(speed) = self.replay_speed {
+            builder = builder.set_override("replay.speed", speed)?;
+        }
+        if let Some(level) = &self.log_level {
+            builder = builder.set_override("log_level", level.as_str())?;
+        }
+        Ok(builder)
+    }
+}
@@ -1,15 +1,20 @@
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::http::HeaderMap;
 use axum::Json;
-use chrono::{Offset, Utc};
+use chrono::{DateTime, Offset, Utc};
 use chrono_tz::Tz;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
-use std::sync::Arc;
-use utoipa::ToSchema;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
+use utoipa::{IntoParams, ToSchema};
 
+use crate::error::{AppError, ErrorResponse};
 use crate::AppState;
 
+/// Reference point for the monotonic tick
+static STARTED: LazyLock<Instant> = LazyLock::new(Instant::now);
+
 /// Response from the /time endpoint
 #[derive(Serialize, ToSchema)]
 pub struct TimeResponse {
@@ -21,11 +26,37 @@ pub struct TimeResponse {
     pub utc_offset: Option<i32>,
 }
 
+/// Response from the /api/time endpoint
+#[derive(Serialize, ToSchema)]
+pub struct ClockSyncResponse {
+    /// Current Unix timestamp in seconds (UTC)
+    pub timestamp: i64,
+    /// Current Unix timestamp in milliseconds (UTC)
+    pub timestamp_ms: i64,
+    /// UTC offset in seconds for the requested or inferred timezone. Add this
+    /// to a timestamp (e.g., a pregame `start_time`) to get local time.
+    /// Null when the timezone cannot be determined.
+    pub utc_offset: Option<i32>,
+    /// IANA name of the timezone behind `utc_offset`
+    pub timezone: Option<String>,
+    /// Milliseconds on the server's monotonic clock, which never jumps. The
+    /// difference between two readings is the real time elapsed between them.
+    pub monotonic_ms: u64,
+}
+
+/// Query parameters for the /api/time endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ClockSyncQuery {
+    /// IANA timezone (e.g., "America/Chicago"). Inferred from the client's IP
+    /// address if not specified.
+    pub tz: Option<String>,
+}
+
 /// Extract the client's IP address from reverse-proxy headers.
 ///
 /// Checks Fly-Client-IP first (set by Fly.io's proxy), then falls back
 /// to the first address in X-Forwarded-For.
-fn client_ip(headers: &HeaderMap) -> Option<IpAddr> {
+pub fn client_ip(headers: &HeaderMap) -> Option<IpAddr> {
     if let Some(ip) = headers
         .get("fly-client-ip")
         .and_then(|v| v.to_str().ok())
@@ -70,13 +101,72 @@ pub async fn time(
     })
 }
 
+/// GET /api/time — clock sync for firmware without NTP
+///
+/// Everything a device needs to keep its own clock: the time to the
+/// millisecond, the local UTC offset, and a monotonic tick for measuring
+/// request latency and driving countdowns.
+#[utoipa::path(
+    get,
+    path = "/api/time",
+    operation_id = "get_clock_sync",
+    params(ClockSyncQuery),
+    responses(
+        (status = 200, description = "Current time, timezone offset, and monotonic tick", body = ClockSyncResponse),
+        (status = 400, description = "Unknown timezone", body = ErrorResponse),
+    ),
+    tag = "clock"
+)]
+pub async fn clock_sync(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ClockSyncQuery>,
+    headers: HeaderMap,
+) -> Result<Json<ClockSyncResponse>, AppError> {
+    let now = Utc::now();
+    let tz = match &query.tz {
+        Some(name) => Some(
+            name.parse::<Tz>()
+                .map_err(|_| AppError::InvalidTimezone(name.clone()))?,
+        ),
+        None => resolve_timezone(&state, &headers),
+    };
+
+    Ok(Json(ClockSyncResponse {
+        timestamp: now.timestamp(),
+        timestamp_ms: now.timestamp_millis(),
+        utc_offset: tz.map(|tz| offset_seconds(tz, &now)),
+        timezone: tz.map(|tz| tz.name().to_string()),
+        monotonic_ms: uptime().as_millis() as u64,
+    }))
+}
+
 /// Attempt to resolve the UTC offset for the client.
 /// Returns None on any failure (missing IP, DB miss, bad timezone).
-fn resolve_utc_offset(
+pub fn resolve_utc_offset(
     state: &AppState,
     headers: &HeaderMap,
-    now: &chrono::DateTime<Utc>,
+    now: &DateTime<Utc>,
 ) -> Option<i32> {
+    resolve_timezone(state, headers).map(|tz| offset_seconds(tz, now))
+}
+
+/// Start the monotonic tick reported by /api/time.
+pub fn start_monotonic_clock() {
+    LazyLock::force(&STARTED);
+}
+
+/// Time since the server started.
+pub fn uptime() -> Duration {
+    STARTED.elapsed()
+}
+
+fn offset_seconds(tz: Tz, now: &DateTime<Utc>) -> i32 {
+    now.with_timezone(&tz).offset().fix().local_minus_utc()
+}
+
+/// Attempt to resolve the client's timezone from its IP address.
+/// Returns None on any failure (missing IP, DB miss, bad timezone).
+fn resolve_timezone(state: &AppState, headers: &HeaderMap) -> Option<Tz> {
     let reader = state.geoip_reader.as_ref()?;
 
     let ip = client_ip(headers).or_else(|| {
@@ -95,11 +185,5 @@ fn resolve_utc_offset(
         tracing::warn!(timezone = tz_name, error = ?e, "Failed to parse IANA timezone");
     }).ok()?;
 
-    let offset_seconds = now
-        .with_timezone(&tz)
-        .offset()
-        .fix()
-        .local_minus_utc();
-
-    Some(offset_seconds)
+    Some(tz)
 }
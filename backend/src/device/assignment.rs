@@ -0,0 +1,176 @@
+//! Picking the game for a device's team or auto assignment from a scoreboard.
+
+use crate::football::types::{FootballGameResponse, FootballLive, FootballPeriod};
+
+/// How worth watching a live game is: close scores count for more the later
+/// it is, and a drive in the red zone adds a little.
+pub fn interest(game: &FootballLive) -> i32 {
+    let margin = (game.home.score as i32 - game.away.score as i32).abs();
+    let stage = match game.period {
+        FootballPeriod::Q1 => 1,
+        FootballPeriod::Q2 | FootballPeriod::Halftime => 2,
+        FootballPeriod::Q3 => 3,
+        FootballPeriod::Q4 => 4,
+        _ => 5,
+    };
+    let red_zone = game.situation.as_ref().is_some_and(|s| s.red_zone);
+    (30 - margin.min(30)) * stage + if red_zone { 10 } else { 0 }
+}
+
+/// The most interesting live game, else the next to kick off, else the first
+/// finished one.
+pub fn pick_auto(games: Vec<FootballGameResponse>) -> Option<FootballGameResponse> {
+    pick(games, |_| true)
+}
+
+/// The team's live game, else its next one, else its last result.
+pub fn pick_team(games: Vec<FootballGameResponse>, team: &str) -> Option<FootballGameResponse> {
+    pick(games, |game| {
+        let (away, home) = match game {
+            FootballGameResponse::Pregame(g) => (&g.away.abbreviation, &g.home.abbreviation),
+            FootballGameResponse::Live(g) => (&g.away.abbreviation, &g.home.abbreviation),
+            FootballGameResponse::Final(g) => (&g.away.abbreviation, &g.home.abbreviation),
+        };
+        away.eq_ignore_ascii_case(team) || home.eq_ignore_ascii_case(team)
+    })
+}
+
+fn pick(
+    games: Vec<FootballGameResponse>,
+    include: impl Fn(&FootballGameResponse) -> bool,
+) -> Option<FootballGameResponse> {
+    // Live first, then pregame by kickoff, then final
+    games
+        .into_iter()
+        .filter(include)
+        .min_by_key(|game| match game {
+            FootballGameResponse::Live(g) => (0, -interest(g) as i64),
+            FootballGameResponse::Pregame(g) => (1, g.start_time),
+            FootballGameResponse::Final(_) => (2, 0),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::{FootballFinal, FootballPregame, FootballTeamScore};
+    use crate::shared::types::{Color, FinalStatus, Team, Winner};
+
+    fn score(abbreviation: &str, score: u8) -> FootballTeamScore {
+        FootballTeamScore::for_test(abbreviation, score).with_timeouts(Some(3))
+    }
+
+    fn team(abbreviation: &str) -> Team {
+        Team {
+            abbreviation: abbreviation.to_string(),
+            color: Color { r: 0, g: 0, b: 0 },
+            color_secondary: Color {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+            record: None,
+            rank: None,
+            logo_path: format!("/api/football/nfl/{}/logo", abbreviation.to_lowercase()),
+        }
+    }
+
+    fn live(
+        id: &str,
+        away: (&str, u8),
+        home: (&str, u8),
+        period: FootballPeriod,
+    ) -> FootballGameResponse {
+        FootballGameResponse::Live(FootballLive {
+            event_id: id.to_string(),
+            season: None,
+            away: score(away.0, away.1),
+            home: score(home.0, home.1),
+            period,
+            clock: "5:00".to_string(),
+            clock_running: true,
+            inside_two_minutes: false,
+            is_close_game: false,
+            situation: None,
+            last_play: None,
+            weather: None,
+            leaders: None,
+            recent_plays: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        })
+    }
+
+    fn pregame(id: &str, away: &str, home: &str, start_time: i64) -> FootballGameResponse {
+        FootballGameResponse::Pregame(FootballPregame {
+            event_id: id.to_string(),
+            season: None,
+            home: team(home),
+            away: team(away),
+            start_time,
+            seconds_until_start: 0,
+            seconds_until_kickoff: None,
+            venue: None,
+            broadcast: None,
+            weather: None,
+        })
+    }
+
+    fn final_game(id: &str, away: &str, home: &str) -> FootballGameResponse {
+        FootballGameResponse::Final(FootballFinal {
+            event_id: id.to_string(),
+            season: None,
+            away: score(away, 10),
+            home: score(home, 20),
+            status: FinalStatus::Final,
+            winner: Winner::Home,
+            leaders: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        })
+    }
+
+    fn id(game: Option<FootballGameResponse>) -> String {
+        match game.unwrap() {
+            FootballGameResponse::Pregame(g) => g.event_id,
+            FootballGameResponse::Live(g) => g.event_id,
+            FootballGameResponse::Final(g) => g.event_id,
+        }
+    }
+
+    #[test]
+    fn test_auto_prefers_close_late_games() {
+        let games = || {
+            vec![
+                final_game("1", "DAL", "NYG"),
+                live("2", ("KC", 21), ("BUF", 3), FootballPeriod::Q4),
+                live("3", ("SF", 17), ("SEA", 14), FootballPeriod::Q4),
+                live("4", ("GB", 7), ("CHI", 7), FootballPeriod::Q1),
+                pregame("5", "MIA", "NE", 100),
+            ]
+        };
+        assert_eq!(id(pick_auto(games())), "3");
+
+        let no_live = vec![
+            final_game("1", "DAL", "NYG"),
+            pregame("6", "LAR", "ARI", 200),
+            pregame("5", "MIA", "NE", 100),
+        ];
+        assert_eq!(id(pick_auto(no_live)), "5");
+        assert!(pick_auto(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_team_prefers_live_then_next() {
+        let games = vec![
+            final_game("1", "KC", "DEN"),
+            pregame("2", "BUF", "KC", 100),
+            live("3", ("SF", 17), ("SEA", 14), FootballPeriod::Q4),
+        ];
+        assert_eq!(id(pick_team(games, "kc")), "2");
+
+        let games = vec![final_game("1", "KC", "DEN")];
+        assert_eq!(id(pick_team(games, "KC")), "1");
+        assert!(pick_team(vec![final_game("1", "KC", "DEN")], "SF").is_none());
+    }
+}
@@ -0,0 +1,207 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::auth::{AdminKey, ApiKey, keys_equal};
+use crate::error::{AppError, ErrorResponse};
+use crate::football::handler::{fetch_game, fetch_scoreboard, include_mock};
+use crate::football::types::{FootballGameResponse, GamesQuery};
+use crate::sport::FootballLeague;
+
+use super::assignment::{pick_auto, pick_team};
+use super::types::{Assignment, DeviceInfo, RegisterDeviceRequest, RegisteredDevice};
+
+/// POST /api/devices/register
+/// Register a device with the provisioning token, returning its own API key
+#[utoipa::path(
+    post,
+    path = "/api/devices/register",
+    request_body = RegisterDeviceRequest,
+    responses(
+        (status = 201, description = "Device registered. The API key is not shown again", body = RegisteredDevice),
+        (status = 400, description = "Unknown device profile", body = ErrorResponse),
+        (status = 401, description = "Invalid provisioning token", body = ErrorResponse),
+        (status = 403, description = "No provisioning token is configured", body = ErrorResponse),
+    ),
+    tag = "devices"
+)]
+pub async fn register_device(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<RegisterDeviceRequest>,
+) -> Result<(StatusCode, Json<RegisteredDevice>), AppError> {
+    let provisioning = &state.config.provisioning;
+    let Some(token) = &provisioning.token else {
+        return Err(AppError::ProvisioningDisabled);
+    };
+    if !keys_equal(token, &request.token) {
+        return Err(AppError::InvalidProvisioningToken);
+    }
+    if let Some(profile) = &request.profile {
+        state.live.read().unwrap().device(profile)?;
+    }
+
+    let scopes = provisioning.scopes.clone();
+    let (device_id, api_key) = state.devices.register(
+        &state.api_keys,
+        request.name.clone(),
+        request.profile.clone(),
+        scopes.clone(),
+        state.config.reserved_keys(),
+    )
+    .await?;
+    tracing::info!(device = %device_id, name = ?request.name, "Device registered");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RegisteredDevice {
+            device_id,
+            api_key,
+            scopes,
+            profile: request.profile,
+        }),
+    ))
+}
+
+/// GET /api/admin/devices
+/// List registered devices and their usage
+#[utoipa::path(
+    get,
+    path = "/api/admin/devices",
+    responses(
+        (status = 200, description = "Registered devices", body = Vec<DeviceInfo>),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "devices"
+)]
+pub async fn list_devices(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<Vec<DeviceInfo>> {
+    Json(state.devices.list(&state.api_keys))
+}
+
+/// DELETE /api/admin/devices/{device_id}
+/// Unregister a device and revoke its API key
+#[utoipa::path(
+    delete,
+    path = "/api/admin/devices/{device_id}",
+    params(
+        ("device_id" = String, Path, description = "Device ID from registration (e.g., 'dev_1a2b3c4d5e6f')"),
+    ),
+    responses(
+        (status = 204, description = "Device removed"),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+        (status = 404, description = "No device with this ID", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "devices"
+)]
+pub async fn remove_device(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+    Path(device_id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    if !state.devices.remove(&state.api_keys, &device_id).await {
+        return Err(AppError::DeviceNotFound(device_id));
+    }
+    state.api_keys.revoke(&device_id)?;
+    tracing::info!(device = %device_id, "Device removed");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PUT /api/admin/devices/{device_id}/assignment
+/// Point a device at a specific game, a team's game, or the best live game
+#[utoipa::path(
+    put,
+    path = "/api/admin/devices/{device_id}/assignment",
+    params(
+        ("device_id" = String, Path, description = "Device ID from registration"),
+    ),
+    request_body = Assignment,
+    responses(
+        (status = 200, description = "Assignment saved", body = Assignment),
+        (status = 400, description = "Invalid league", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+        (status = 404, description = "No device with this ID", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "devices"
+)]
+pub async fn assign_device(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+    Path(device_id): Path<String>,
+    Json(assignment): Json<Assignment>,
+) -> Result<Json<Assignment>, AppError> {
+    FootballLeague::from_league(assignment.league())?;
+    state.devices
+        .assign(&state.api_keys, &device_id, assignment.clone())
+        .await?;
+    tracing::info!(device = %device_id, assignment = ?assignment, "Device assigned");
+    Ok(Json(assignment))
+}
+
+/// GET /api/devices/{device_id}/game
+/// The game a device is assigned to, resolved to a specific game at request time
+#[utoipa::path(
+    get,
+    path = "/api/devices/{device_id}/game",
+    params(
+        ("device_id" = String, Path, description = "Device ID from registration"),
+    ),
+    responses(
+        (status = 200, description = "The assigned game", body = FootballGameResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "No such device, no assignment, or no game matches it", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "devices"
+)]
+pub async fn get_device_game(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(device_id): Path<String>,
+) -> Result<Json<FootballGameResponse>, AppError> {
+    let assignment = state.devices.assignment(&device_id)?.ok_or_else(|| {
+        AppError::NoAssignedGame(format!("device '{}' is not assigned", device_id))
+    })?;
+    let league = FootballLeague::from_league(assignment.league())?;
+    let query = GamesQuery::default();
+    let include_mock = include_mock(&state, league, &query);
+
+    let mut game = match assignment {
+        Assignment::Event { event_id, .. } => fetch_game(&state, league, event_id, true).await?,
+        Assignment::Team { team, .. } => {
+            let games = fetch_scoreboard(&state, league, include_mock).await?;
+            pick_team(games, &team).ok_or_else(|| {
+                AppError::NoAssignedGame(format!("{} has no game on the scoreboard", team))
+            })?
+        }
+        Assignment::Auto { .. } => {
+            let games = fetch_scoreboard(&state, league, include_mock).await?;
+            pick_auto(games)
+                .ok_or_else(|| AppError::NoAssignedGame("the scoreboard is empty".to_string()))?
+        }
+    };
+    game.retain_includes(&query);
+
+    Ok(Json(game))
+}
@@ -0,0 +1,7 @@
+pub mod assignment;
+pub mod handler;
+pub mod registry;
+pub mod types;
+
+pub use handler::{assign_device, get_device_game, list_devices, register_device, remove_device};
+pub use registry::DeviceRegistry;
@@ -0,0 +1,324 @@
+//! Registered devices, each holding its own API key.
+//!
+//! A device's key lives in the `KeyStore` under the device ID as its label, so
+//! requests are authenticated and counted like any other key's. The registry
+//! keeps the rest of the record in `storage` (with key hashes only) and, when
+//! `provisioning.registry_path` is set, in a file, to survive restarts.
+//!
+//! Each device is stored as its own field, so instances registering, assigning,
+//! or removing different devices at the same moment don't overwrite one
+//! another. With shared storage, every instance picks up the others' changes
+//! every `storage.device_sync_secs`, and before each change of its own.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::AppState;
+use crate::admin::types::{KeySource, Scope};
+use crate::auth::{KeyHash, KeyStore, ReservedKeys};
+use crate::config::ProvisioningConfig;
+use crate::error::AppError;
+use crate::storage::Storage;
+
+use super::types::{Assignment, DeviceInfo};
+
+/// Storage key for the registered devices, a hash of each by ID
+const STORAGE_KEY: &str = "devices_by_id";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Device {
+    id: String,
+    name: Option<String>,
+    profile: Option<String>,
+    scopes: Vec<Scope>,
+    registered_at: i64,
+    key_hash: String,
+    #[serde(default)]
+    assignment: Option<Assignment>,
+}
+
+pub struct DeviceRegistry {
+    devices: RwLock<Vec<Device>>,
+    path: Option<PathBuf>,
+    storage: Arc<dyn Storage>,
+    /// Held from picking up stored devices through saving a change, so this
+    /// instance's changes and syncs don't interleave
+    saving: tokio::sync::Mutex<()>,
+}
+
+impl DeviceRegistry {
+    /// Load saved devices from storage, or from `registry_path` if storage has
+    /// none, adding their keys to `keys`.
+    pub async fn load(config: &ProvisioningConfig, keys: &KeyStore, storage: Arc<dyn Storage>) -> Self {
+        let path = config.registry_path.as_ref().map(PathBuf::from);
+        let stored = read_stored(&*storage).await.filter(|devices| !devices.is_empty());
+        let from_file = stored.is_none();
+        let saved: Vec<Device> = match (stored, path.as_ref().map(std::fs::read)) {
+            (Some(devices), _) => devices,
+            (None, Some(Ok(bytes))) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Device registry unreadable — starting empty");
+                Vec::new()
+            }),
+            _ => Vec::new(),
+        };
+
+        let devices = saved
+            .into_iter()
+            .filter(|device| restore_key(keys, device))
+            .collect::<Vec<_>>();
+        if !devices.is_empty() {
+            tracing::info!(count = devices.len(), "Registered devices loaded");
+        }
+
+        let registry = Self {
+            devices: RwLock::new(devices),
+            path,
+            storage,
+            saving: tokio::sync::Mutex::new(()),
+        };
+        // Share devices saved before storage was
+        if from_file && registry.storage.is_shared() {
+            let devices = registry.devices.read().unwrap().clone();
+            for device in &devices {
+                registry.save_to_storage(&device.id, Some(device)).await;
+            }
+        }
+        registry
+    }
+
+    /// Register a device, returning its ID and API key.
+    pub async fn register(
+        &self,
+        keys: &KeyStore,
+        name: Option<String>,
+        profile: Option<String>,
+        scopes: Vec<Scope>,
+        reserved: ReservedKeys<'_>,
+    ) -> Result<(String, String), AppError> {
+        let id = format!("dev_{}", hex::encode(rand::random::<[u8; 6]>()));
+        self.change(keys, &id, |devices| {
+            let (key, hash) = keys.add(&id, None, scopes.clone(), KeySource::Device, reserved)?;
+            devices.push(Device {
+                id: id.clone(),
+                name,
+                profile,
+                scopes,
+                registered_at: Utc::now().timestamp(),
+                key_hash: hash.to_string(),
+                assignment: None,
+            });
+            Ok((id.clone(), key))
+        })
+        .await
+    }
+
+    /// Registered devices with their key usage.
+    pub fn list(&self, keys: &KeyStore) -> Vec<DeviceInfo> {
+        let usage = keys.list();
+        self.devices
+            .read()
+            .unwrap()
+            .iter()
+            .map(|device| {
+                let key = usage.iter().find(|k| k.label == device.id);
+                DeviceInfo {
+                    device_id: device.id.clone(),
+                    name: device.name.clone(),
+                    profile: device.profile.clone(),
+                    scopes: device.scopes.clone(),
+                    registered_at: device.registered_at,
+                    last_used: key.and_then(|k| k.last_used),
+                    request_count: key.map_or(0, |k| k.request_count),
+                    bytes_sent: key.map_or(0, |k| k.bytes_sent),
+                    assignment: device.assignment.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Point a device at a game.
+    pub async fn assign(
+        &self,
+        keys: &KeyStore,
+        id: &str,
+        assignment: Assignment,
+    ) -> Result<(), AppError> {
+        self.change(keys, id, |devices| {
+            let device = devices
+                .iter_mut()
+                .find(|d| d.id == id)
+                .ok_or_else(|| AppError::DeviceNotFound(id.to_string()))?;
+            device.assignment = Some(assignment);
+            Ok(())
+        })
+        .await
+    }
+
+    /// The game a device is pointed at, if it has been.
+    pub fn assignment(&self, id: &str) -> Result<Option<Assignment>, AppError> {
+        self.devices
+            .read()
+            .unwrap()
+            .iter()
+            .find(|d| d.id == id)
+            .map(|d| d.assignment.clone())
+            .ok_or_else(|| AppError::DeviceNotFound(id.to_string()))
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.devices.read().unwrap().iter().any(|d| d.id == id)
+    }
+
+    /// Forget a device. Its key must be revoked separately. Returns whether it
+    /// was registered.
+    pub async fn remove(&self, keys: &KeyStore, id: &str) -> bool {
+        self.change(keys, id, |devices| {
+            let index = devices
+                .iter()
+                .position(|d| d.id == id)
+                .ok_or_else(|| AppError::DeviceNotFound(id.to_string()))?;
+            devices.remove(index);
+            Ok(())
+        })
+        .await
+        .is_ok()
+    }
+
+    /// Pick up changes other instances saved to shared storage.
+    pub async fn sync(&self, keys: &KeyStore) {
+        let _saving = self.saving.lock().await;
+        self.refresh(keys).await;
+    }
+
+    /// Replace the devices with those in storage, unless it can't be read,
+    /// updating their keys to match.
+    async fn refresh(&self, keys: &KeyStore) {
+        let Some(stored) = read_stored(&*self.storage).await else {
+            return;
+        };
+        let mut devices = self.devices.write().unwrap();
+        *devices = reconcile(keys, &devices, stored);
+    }
+
+    /// Apply a change to the device `id` and, if it succeeds, save it. With
+    /// shared storage, other instances' changes are picked up first so this
+    /// one doesn't undo them.
+    async fn change<T>(
+        &self,
+        keys: &KeyStore,
+        id: &str,
+        change: impl FnOnce(&mut Vec<Device>) -> Result<T, AppError>,
+    ) -> Result<T, AppError> {
+        let _saving = self.saving.lock().await;
+        if self.storage.is_shared() {
+            self.refresh(keys).await;
+        }
+        let (result, devices) = {
+            let mut devices = self.devices.write().unwrap();
+            let result = change(&mut devices)?;
+            (result, devices.clone())
+        };
+        self.save_to_storage(id, devices.iter().find(|d| d.id == id)).await;
+        self.save_to_file(&devices);
+        Ok(result)
+    }
+
+    /// Save the device `id`, or remove it if it's gone.
+    async fn save_to_storage(&self, id: &str, device: Option<&Device>) {
+        let result = match device.map(serde_json::to_vec) {
+            Some(Ok(json)) => self.storage.set_field(STORAGE_KEY, id, &json).await,
+            Some(Err(e)) => {
+                tracing::warn!(device = %id, error = %e, "Failed to serialize device");
+                return;
+            }
+            None => self.storage.remove_field(STORAGE_KEY, id).await,
+        };
+        if let Err(e) = result {
+            tracing::warn!(device = %id, error = %e, "Failed to save device to storage");
+        }
+    }
+
+    fn save_to_file(&self, devices: &[Device]) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = serde_json::to_vec_pretty(devices)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(path, json));
+        if let Err(e) = result {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to save device registry");
+        }
+    }
+}
+
+/// The devices in storage, in the order they registered, or `None` if it
+/// can't be read. Unreadable devices are left out.
+async fn read_stored(storage: &dyn Storage) -> Option<Vec<Device>> {
+    let fields = match storage.get_fields(STORAGE_KEY).await {
+        Ok(fields) => fields,
+        Err(e) => {
+            tracing::warn!(error = %e, "Couldn't read device registry from storage");
+            return None;
+        }
+    };
+    let mut devices: Vec<Device> = fields
+        .into_iter()
+        .filter_map(|(id, bytes)| {
+            serde_json::from_slice(&bytes)
+                .inspect_err(|e| tracing::warn!(device = %id, error = %e, "Stored device unreadable — ignored"))
+                .ok()
+        })
+        .collect();
+    devices.sort_by(|a, b| (a.registered_at, &a.id).cmp(&(b.registered_at, &b.id)));
+    Some(devices)
+}
+
+/// Add a saved device's key to `keys`, returning whether it could be.
+fn restore_key(keys: &KeyStore, device: &Device) -> bool {
+    let Some(hash) = KeyHash::parse(&device.key_hash) else {
+        tracing::warn!(device = %device.id, "Device has an invalid key hash — skipped");
+        return false;
+    };
+    let scopes = device.scopes.clone();
+    keys.insert_hashed(&device.id, hash, scopes, KeySource::Device, device.registered_at)
+        .inspect_err(|_| {
+            tracing::warn!(device = %device.id, "Device ID already used by a key — skipped");
+        })
+        .is_ok()
+}
+
+/// The stored devices to use in place of `current`, revoking the keys of
+/// devices removed since and adding those of devices registered since.
+fn reconcile(keys: &KeyStore, current: &[Device], stored: Vec<Device>) -> Vec<Device> {
+    for device in current {
+        if !stored.iter().any(|d| d.id == device.id) && keys.revoke(&device.id).is_ok() {
+            tracing::info!(device = %device.id, "Device removed by another instance");
+        }
+    }
+    stored
+        .into_iter()
+        .filter(|device| {
+            current.iter().any(|d| d.id == device.id) || {
+                let restored = restore_key(keys, device);
+                if restored {
+                    tracing::info!(device = %device.id, "Device registered by another instance");
+                }
+                restored
+            }
+        })
+        .collect()
+}
+
+/// Pick up devices changed on other instances every `every`, while storage
+/// is shared.
+pub async fn sync_from_storage(state: Arc<AppState>, every: Duration) {
+    let mut interval = tokio::time::interval(every);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        state.devices.sync(&state.api_keys).await;
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::admin::types::Scope;
+
+/// Request body for registering a device
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegisterDeviceRequest {
+    /// Provisioning token from the server's config
+    pub token: String,
+    /// Human-readable name (e.g., "Kitchen scoreboard")
+    pub name: Option<String>,
+    /// Configured display profile for the device
+    pub profile: Option<String>,
+}
+
+/// A newly registered device and its API key. The key is only ever returned here.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RegisteredDevice {
+    pub device_id: String,
+    pub api_key: String,
+    pub scopes: Vec<Scope>,
+    pub profile: Option<String>,
+}
+
+/// A registered device with its key usage
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeviceInfo {
+    pub device_id: String,
+    pub name: Option<String>,
+    pub profile: Option<String>,
+    pub scopes: Vec<Scope>,
+    /// Unix seconds
+    pub registered_at: i64,
+    /// Last request made by the device (Unix seconds), since server start
+    pub last_used: Option<i64>,
+    /// Requests made by the device since server start
+    pub request_count: u64,
+    /// Response body bytes sent to the device since server start
+    pub bytes_sent: u64,
+    /// Game the device is pointed at
+    pub assignment: Option<Assignment>,
+}
+
+/// Which game a device shows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Assignment {
+    /// One specific game
+    Event {
+        /// ESPN event ID, or a mock game ID (e.g., 'sim_1')
+        event_id: String,
+        /// League identifier (nfl, ncaaf; default: nfl)
+        #[serde(default = "default_league")]
+        league: String,
+    },
+    /// A team's live game, else its next one, else its last result
+    Team {
+        /// Team abbreviation (e.g., 'KC')
+        team: String,
+        #[serde(default = "default_league")]
+        league: String,
+    },
+    /// The most interesting live game (closest score, latest in the game),
+    /// else the next to kick off
+    Auto {
+        #[serde(default = "default_league")]
+        league: String,
+    },
+}
+
+fn default_league() -> String {
+    "nfl".to_string()
+}
+
+impl Assignment {
+    pub fn league(&self) -> &str {
+        match self {
+            Assignment::Event { league, .. }
+            | Assignment::Team { league, .. }
+            | Assignment::Auto { league } => league,
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
+use serde_json::json;
 use utoipa::ToSchema;
 
+use crate::admin::types::Scope;
+
 /// Application error types
 #[derive(Debug)]
 pub enum AppError {
@@ -17,6 +20,14 @@ pub enum AppError {
     ImageDecode(String),
     /// Invalid hex color format
     InvalidColor(String),
+    /// Palette size outside the supported range
+    InvalidColorCount(u16),
+    /// Gamma, brightness, or saturation value out of range
+    InvalidColorAdjustment(String),
+    /// Text to render is empty or too long
+    InvalidText(String),
+    /// No device profile with this name is configured
+    UnknownDevice(String),
     /// Team logo not found (ESPN returned 404)
     TeamNotFound(String),
     /// Game not found in scoreboard
@@ -27,6 +38,16 @@ pub enum AppError {
     InvalidScenario(String),
     /// Mock game not found in repository
     MockGameNotFound(String),
+    /// Mock game snapshot could not be restored
+    InvalidSnapshot(String),
+    /// Mock game options describe a state the simulation can't run from
+    InvalidGameOptions(String),
+    /// Requested virtual time change is not allowed
+    InvalidVirtualTime(String),
+    /// Mock game cannot be moved to the requested game time
+    InvalidSeekTarget(String),
+    /// Unknown mock scenario preset
+    PresetNotFound { name: String, valid: &'static str },
     /// Missing API key header
     MissingApiKey,
     /// Invalid API key
@@ -35,65 +56,232 @@ pub enum AppError {
     ExpiredSignature,
     /// HMAC signature is invalid
     InvalidSignature,
+    /// Bearer token is malformed, badly signed, or fails a claim check
+    InvalidToken(String),
+    /// Bearer token has expired
+    ExpiredToken,
+    /// Configuration files or environment failed to load on reload
+    InvalidConfig(String),
+    /// Log filter from the admin API isn't valid RUST_LOG syntax
+    InvalidLogLevel(String),
+    /// Admin endpoint called without the X-Admin-Key header
+    MissingAdminKey,
+    /// Admin endpoints are closed because no admin key is configured
+    AdminKeyRequired,
+    /// The API key is valid but lacks the scope this endpoint needs
+    InsufficientScope(Scope),
+    /// API key label or value is not acceptable
+    InvalidApiKey(String),
+    /// An API key with this label already exists
+    ApiKeyExists(String),
+    /// No API key with this label
+    ApiKeyNotFound(String),
+    /// Device registration is disabled (no provisioning token configured)
+    ProvisioningDisabled,
+    /// Provisioning token doesn't match
+    InvalidProvisioningToken,
+    /// No registered device with this ID
+    DeviceNotFound(String),
+    /// A device's assignment doesn't resolve to a game
+    NoAssignedGame(String),
+    /// Unknown IANA timezone name
+    InvalidTimezone(String),
+    /// Client exceeded its request rate
+    RateLimited { retry_after_secs: u64 },
+    /// ESPN is throttling us (429 or 403); requests wait out the backoff
+    EspnRateLimited { retry_after_secs: u64 },
     /// ESPN API response deserialization failed
     EspnDeserialize { path: String, message: String },
+    /// ESPN event has no competition
+    MissingCompetition(String),
+    /// ESPN event's competition lacks its home or away team
+    MissingCompetitor { event_id: String, side: &'static str },
     /// Invalid league path parameter
     InvalidLeague { league: String, valid: &'static str },
 }
 
+/// Machine-readable error code, stable across releases. New codes may be
+/// added; existing ones are never renamed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    EspnError,
+    ImageFetchError,
+    ImageDecodeError,
+    InvalidColor,
+    InvalidColorCount,
+    InvalidColorAdjustment,
+    InvalidText,
+    UnknownDevice,
+    TeamNotFound,
+    GameNotFound,
+    InvalidEventId,
+    InvalidScenario,
+    MockGameNotFound,
+    InvalidSnapshot,
+    InvalidGameOptions,
+    InvalidVirtualTime,
+    InvalidSeekTarget,
+    PresetNotFound,
+    MissingApiKey,
+    Unauthorized,
+    ExpiredSignature,
+    InvalidSignature,
+    InvalidToken,
+    ExpiredToken,
+    InvalidConfig,
+    InvalidLogLevel,
+    MissingAdminKey,
+    AdminKeyRequired,
+    InsufficientScope,
+    InvalidApiKey,
+    ApiKeyExists,
+    ApiKeyNotFound,
+    ProvisioningDisabled,
+    InvalidProvisioningToken,
+    DeviceNotFound,
+    NoAssignedGame,
+    InvalidTimezone,
+    RateLimited,
+    EspnRateLimited,
+    EspnDeserializeError,
+    MissingCompetition,
+    MissingCompetitor,
+    InvalidLeague,
+}
+
 /// Error response body
 #[derive(Serialize, ToSchema)]
 pub struct ErrorResponse {
-    /// Error code (e.g., "game_not_found", "unauthorized")
-    pub error: String,
+    /// Error code to branch on
+    pub error: ErrorCode,
     /// Human-readable error message
     pub message: String,
+    /// Structured context for some codes (e.g., `retry_after_secs` for
+    /// `rate_limited`, `valid` for `invalid_league`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub details: Option<serde_json::Value>,
+}
+
+impl AppError {
+    /// Structured context for the error response, for the variants that have any.
+    fn details(&self) -> Option<serde_json::Value> {
+        let details = match self {
+            AppError::RateLimited { retry_after_secs }
+            | AppError::EspnRateLimited { retry_after_secs } => {
+                json!({ "retry_after_secs": retry_after_secs })
+            }
+            AppError::EspnDeserialize { path, .. } => json!({ "path": path }),
+            AppError::MissingCompetition(event_id) => json!({ "event_id": event_id }),
+            AppError::MissingCompetitor { event_id, side } => {
+                json!({ "event_id": event_id, "side": side })
+            }
+            AppError::InvalidLeague { league, valid } => json!({
+                "league": league,
+                "valid": valid.split(", ").collect::<Vec<_>>(),
+            }),
+            AppError::PresetNotFound { name, valid } => json!({
+                "name": name,
+                "valid": valid.split(", ").collect::<Vec<_>>(),
+            }),
+            AppError::InsufficientScope(scope) => json!({ "scope": scope.name() }),
+            AppError::InvalidColorCount(count) => json!({ "count": count, "min": 2, "max": 256 }),
+            _ => return None,
+        };
+        Some(details)
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, error, message) = match self {
+        let retry_after = match &self {
+            AppError::RateLimited { retry_after_secs }
+            | AppError::EspnRateLimited { retry_after_secs } => Some(*retry_after_secs),
+            _ => None,
+        };
+        let details = self.details();
+        let (status, error, message) = self.into_parts();
+
+        let body = ErrorResponse {
+            error,
+            message,
+            details,
+        };
+
+        let mut response = (status, Json(body)).into_response();
+        if let Some(secs) = retry_after {
+            response.headers_mut().insert(header::RETRY_AFTER, secs.into());
+        }
+        response
+    }
+}
+
+impl AppError {
+    /// HTTP status, error code, and message for the error.
+    pub fn into_parts(self) -> (StatusCode, ErrorCode, String) {
+        match self {
             AppError::EspnRequest(e) => (
                 StatusCode::BAD_GATEWAY,
-                "espn_error".to_string(),
+                ErrorCode::EspnError,
                 format!("Failed to fetch data from ESPN: {}", e),
             ),
             AppError::ImageFetch(e) => (
                 StatusCode::BAD_GATEWAY,
-                "image_fetch_error".to_string(),
+                ErrorCode::ImageFetchError,
                 format!("Failed to fetch logo from ESPN: {}", e),
             ),
             AppError::ImageDecode(msg) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "image_decode_error".to_string(),
+                ErrorCode::ImageDecodeError,
                 format!("Failed to process image: {}", msg),
             ),
             AppError::InvalidColor(c) => (
                 StatusCode::BAD_REQUEST,
-                "invalid_color".to_string(),
+                ErrorCode::InvalidColor,
                 format!(
                     "Invalid hex color '{}'. Expected 6-digit RGB hex (e.g., 'FF0000')",
                     c
                 ),
             ),
+            AppError::InvalidColorCount(count) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidColorCount,
+                format!("Invalid color count {}. Expected 2-256", count),
+            ),
+            AppError::InvalidColorAdjustment(name) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidColorAdjustment,
+                format!("Invalid {}. Expected a finite, non-negative number", name),
+            ),
+            AppError::InvalidText(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidText,
+                format!("Invalid text: {}", reason),
+            ),
+            AppError::UnknownDevice(name) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::UnknownDevice,
+                format!("No device profile named '{}' is configured", name),
+            ),
             AppError::TeamNotFound(team) => (
                 StatusCode::NOT_FOUND,
-                "team_not_found".to_string(),
+                ErrorCode::TeamNotFound,
                 format!("Team '{}' not found", team),
             ),
             AppError::GameNotFound(id) => (
                 StatusCode::NOT_FOUND,
-                "game_not_found".to_string(),
+                ErrorCode::GameNotFound,
                 format!("Game with ID '{}' not found on current scoreboard", id),
             ),
             AppError::InvalidEventId(id) => (
                 StatusCode::BAD_REQUEST,
-                "invalid_event_id".to_string(),
+                ErrorCode::InvalidEventId,
                 format!("Event ID '{}' is invalid. Must be numeric.", id),
             ),
             AppError::InvalidScenario(s) => (
                 StatusCode::BAD_REQUEST,
-                "invalid_scenario".to_string(),
+                ErrorCode::InvalidScenario,
                 format!(
                     "Invalid scenario '{}'. Valid options: pregame, live, final, mixed, redzone, overtime",
                     s
@@ -101,43 +289,162 @@ impl IntoResponse for AppError {
             ),
             AppError::MockGameNotFound(id) => (
                 StatusCode::NOT_FOUND,
-                "mock_game_not_found".to_string(),
+                ErrorCode::MockGameNotFound,
                 format!("Mock game with ID '{}' not found", id),
             ),
+            AppError::InvalidSnapshot(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidSnapshot,
+                format!("Invalid game snapshot: {}", reason),
+            ),
+            AppError::InvalidGameOptions(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidGameOptions,
+                format!("Invalid game options: {}", reason),
+            ),
+            AppError::InvalidVirtualTime(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidVirtualTime,
+                format!("Invalid virtual time: {}", reason),
+            ),
+            AppError::InvalidSeekTarget(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidSeekTarget,
+                format!("Invalid seek target: {}", reason),
+            ),
+            AppError::PresetNotFound { name, valid } => (
+                StatusCode::NOT_FOUND,
+                ErrorCode::PresetNotFound,
+                format!("Unknown preset '{}'. Valid presets: {}", name, valid),
+            ),
             AppError::MissingApiKey => (
                 StatusCode::UNAUTHORIZED,
-                "missing_api_key".to_string(),
-                "X-Api-Key header or valid signature is required".to_string(),
+                ErrorCode::MissingApiKey,
+                "X-Api-Key header, bearer token, or valid signature is required".to_string(),
             ),
             AppError::Unauthorized => (
                 StatusCode::UNAUTHORIZED,
-                "unauthorized".to_string(),
+                ErrorCode::Unauthorized,
                 "Invalid API key".to_string(),
             ),
             AppError::ExpiredSignature => (
                 StatusCode::UNAUTHORIZED,
-                "expired_signature".to_string(),
+                ErrorCode::ExpiredSignature,
                 "Signature has expired".to_string(),
             ),
             AppError::InvalidSignature => (
                 StatusCode::UNAUTHORIZED,
-                "invalid_signature".to_string(),
+                ErrorCode::InvalidSignature,
                 "Invalid request signature".to_string(),
             ),
+            AppError::InvalidToken(reason) => (
+                StatusCode::UNAUTHORIZED,
+                ErrorCode::InvalidToken,
+                format!("Invalid bearer token: {}", reason),
+            ),
+            AppError::ExpiredToken => (
+                StatusCode::UNAUTHORIZED,
+                ErrorCode::ExpiredToken,
+                "Bearer token has expired".to_string(),
+            ),
+            AppError::InvalidConfig(reason) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorCode::InvalidConfig,
+                format!("Configuration not reloaded: {}", reason),
+            ),
+            AppError::InvalidLogLevel(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidLogLevel,
+                format!("Invalid log filter: {}", reason),
+            ),
+            AppError::MissingAdminKey => (
+                StatusCode::UNAUTHORIZED,
+                ErrorCode::MissingAdminKey,
+                "X-Admin-Key header is required".to_string(),
+            ),
+            AppError::AdminKeyRequired => (
+                StatusCode::FORBIDDEN,
+                ErrorCode::AdminKeyRequired,
+                "Admin endpoints require an admin key (admin_key or api_key in config)".to_string(),
+            ),
+            AppError::InsufficientScope(scope) => (
+                StatusCode::FORBIDDEN,
+                ErrorCode::InsufficientScope,
+                format!("This API key lacks the '{}' scope", scope.name()),
+            ),
+            AppError::InvalidApiKey(reason) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidApiKey,
+                format!("Invalid API key: {}", reason),
+            ),
+            AppError::ApiKeyExists(label) => (
+                StatusCode::CONFLICT,
+                ErrorCode::ApiKeyExists,
+                format!("An API key labeled '{}' already exists", label),
+            ),
+            AppError::ApiKeyNotFound(label) => (
+                StatusCode::NOT_FOUND,
+                ErrorCode::ApiKeyNotFound,
+                format!("No API key labeled '{}'", label),
+            ),
+            AppError::ProvisioningDisabled => (
+                StatusCode::FORBIDDEN,
+                ErrorCode::ProvisioningDisabled,
+                "Device registration is disabled. Set provisioning.token to enable it".to_string(),
+            ),
+            AppError::InvalidProvisioningToken => (
+                StatusCode::UNAUTHORIZED,
+                ErrorCode::InvalidProvisioningToken,
+                "Invalid provisioning token".to_string(),
+            ),
+            AppError::DeviceNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorCode::DeviceNotFound,
+                format!("No registered device with ID '{}'", id),
+            ),
+            AppError::NoAssignedGame(reason) => (
+                StatusCode::NOT_FOUND,
+                ErrorCode::NoAssignedGame,
+                format!("No game to show: {}", reason),
+            ),
+            AppError::InvalidTimezone(name) => (
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidTimezone,
+                format!("Unknown timezone '{}'. Expected an IANA name (e.g., 'America/Chicago')", name),
+            ),
+            AppError::RateLimited { retry_after_secs } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                ErrorCode::RateLimited,
+                format!("Too many requests. Retry in {} seconds", retry_after_secs),
+            ),
+            AppError::EspnRateLimited { retry_after_secs } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ErrorCode::EspnRateLimited,
+                format!(
+                    "ESPN is rate limiting requests. Retry in {} seconds",
+                    retry_after_secs
+                ),
+            ),
             AppError::EspnDeserialize { path, message } => (
                 StatusCode::BAD_GATEWAY,
-                "espn_deserialize_error".to_string(),
+                ErrorCode::EspnDeserializeError,
                 format!("Failed to parse ESPN response at '{}': {}", path, message),
             ),
+            AppError::MissingCompetition(event_id) => (
+                StatusCode::BAD_GATEWAY,
+                ErrorCode::MissingCompetition,
+                format!("ESPN event '{}' has no competition", event_id),
+            ),
+            AppError::MissingCompetitor { event_id, side } => (
+                StatusCode::BAD_GATEWAY,
+                ErrorCode::MissingCompetitor,
+                format!("ESPN event '{}' has no {} team", event_id, side),
+            ),
             AppError::InvalidLeague { league, valid } => (
                 StatusCode::BAD_REQUEST,
-                "invalid_league".to_string(),
+                ErrorCode::InvalidLeague,
                 format!("Invalid league '{}'. Valid leagues: {}", league, valid),
             ),
-        };
-
-        let body = ErrorResponse { error, message };
-
-        (status, Json(body)).into_response()
+        }
     }
 }
@@ -1,4 +1,6 @@
 pub mod client;
+pub mod record;
+pub mod stats;
 pub mod types;
 
 pub use client::EspnClient;
@@ -0,0 +1,68 @@
+//! Recordings of raw ESPN API responses, for replaying a real game later
+//! (see `provider::replay`). Enabled by setting `espn.record_dir` (or
+//! `--record DIR`).
+//!
+//! Each server run writes one JSON Lines file, `espn-<started>.jsonl`, with
+//! a `Recorded` line per response. Logos aren't recorded.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// One ESPN response as it arrived
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Recorded {
+    /// When it arrived, in Unix milliseconds
+    pub at: i64,
+    /// Request URL relative to the API base URL, e.g.
+    /// "football/nfl/summary?event=401547417"
+    pub path: String,
+    /// Response body, exactly as sent
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub struct Recorder {
+    file: Mutex<tokio::fs::File>,
+}
+
+impl Recorder {
+    /// Start a recording in `dir`, or log why it couldn't.
+    pub fn start(dir: &str) -> Option<Self> {
+        let name = format!("espn-{}.jsonl", Utc::now().format("%Y%m%d-%H%M%S"));
+        let path = Path::new(dir).join(name);
+        let file = std::fs::create_dir_all(dir).and_then(|()| {
+            std::fs::OpenOptions::new().create(true).append(true).open(&path)
+        });
+        match file {
+            Ok(file) => {
+                tracing::info!(path = %path.display(), "Recording ESPN responses");
+                Some(Self {
+                    file: Mutex::new(tokio::fs::File::from_std(file)),
+                })
+            }
+            Err(e) => {
+                tracing::error!(path = %path.display(), error = %e, "Couldn't start recording ESPN responses");
+                None
+            }
+        }
+    }
+
+    /// Append a response. A failed write is logged and the request goes on.
+    pub async fn record(&self, path: &str, body: &str) {
+        let recorded = Recorded {
+            at: Utc::now().timestamp_millis(),
+            path: path.to_string(),
+            body: body.to_string(),
+        };
+        let Ok(mut line) = serde_json::to_vec(&recorded) else {
+            return;
+        };
+        line.push(b'\n');
+        if let Err(e) = self.file.lock().await.write_all(&line).await {
+            tracing::warn!(error = %e, "Couldn't record ESPN response");
+        }
+    }
+}
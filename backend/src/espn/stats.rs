@@ -0,0 +1,144 @@
+//! Counts of the requests made to ESPN, for checking polling behavior from the
+//! admin API instead of the logs.
+
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use utoipa::ToSchema;
+
+/// ESPN traffic since server start
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct EspnStats {
+    /// Requests sent to ESPN
+    pub requests: u64,
+    /// Requests that failed, returned an error status, or couldn't be parsed
+    pub failures: u64,
+    /// Scoreboard fetches answered by another caller's request already in flight
+    pub coalesced: u64,
+    /// Scoreboard fetches answered from storage (see `storage.scoreboard_ttl_secs`)
+    pub cached: u64,
+    /// Scoreboard requests ESPN answered 304 Not Modified, reusing the last body
+    pub not_modified: u64,
+    /// Last response we could use (Unix seconds)
+    pub last_success: Option<i64>,
+    /// Last failure (Unix seconds)
+    pub last_failure: Option<i64>,
+    /// What went wrong at `last_failure`
+    pub last_error: Option<String>,
+    /// Failures since the last success
+    pub consecutive_failures: u64,
+    /// Responses in a row that couldn't be parsed
+    pub consecutive_parse_failures: u64,
+    /// Scoreboard events left out of responses because they couldn't be parsed
+    pub skipped_events: u64,
+    /// The counts per kind of request ("scoreboard", "summary", "team_lookup", "logo")
+    pub endpoints: Vec<EndpointStats>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EndpointStats {
+    pub endpoint: String,
+    pub requests: u64,
+    pub failures: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct UpstreamStats {
+    inner: Mutex<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    /// Requests and failures per kind of request
+    endpoints: BTreeMap<&'static str, (u64, u64)>,
+    coalesced: u64,
+    cached: u64,
+    not_modified: u64,
+    last_success: Option<i64>,
+    last_failure: Option<(i64, String)>,
+    consecutive_failures: u64,
+    consecutive_parse_failures: u64,
+    skipped_events: u64,
+}
+
+impl UpstreamStats {
+    /// Count a request sent to ESPN.
+    pub fn request(&self, endpoint: &'static str) {
+        self.inner
+            .lock()
+            .unwrap()
+            .endpoints
+            .entry(endpoint)
+            .or_default()
+            .0 += 1;
+    }
+
+    /// Count a caller served by a request already in flight.
+    pub fn coalesced(&self) {
+        self.inner.lock().unwrap().coalesced += 1;
+    }
+
+    /// Count a caller served a scoreboard from storage.
+    pub fn cached(&self) {
+        self.inner.lock().unwrap().cached += 1;
+    }
+
+    /// Count a request ESPN answered with 304 Not Modified.
+    pub fn not_modified(&self) {
+        self.inner.lock().unwrap().not_modified += 1;
+    }
+
+    pub fn success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.last_success = Some(Utc::now().timestamp());
+        inner.consecutive_failures = 0;
+        inner.consecutive_parse_failures = 0;
+    }
+
+    pub fn failure(&self, endpoint: &'static str, error: impl ToString) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.endpoints.entry(endpoint).or_default().1 += 1;
+        inner.last_failure = Some((Utc::now().timestamp(), error.to_string()));
+        inner.consecutive_failures += 1;
+    }
+
+    /// Count a response that arrived but couldn't be parsed.
+    pub fn parse_failure(&self, endpoint: &'static str, error: impl ToString) {
+        self.failure(endpoint, error);
+        self.inner.lock().unwrap().consecutive_parse_failures += 1;
+    }
+
+    /// Count a scoreboard event that couldn't be parsed; the rest of the
+    /// scoreboard was still used.
+    pub fn skipped_event(&self) {
+        self.inner.lock().unwrap().skipped_events += 1;
+    }
+
+    pub fn snapshot(&self) -> EspnStats {
+        let inner = self.inner.lock().unwrap();
+        let endpoints: Vec<EndpointStats> = inner
+            .endpoints
+            .iter()
+            .map(|(endpoint, (requests, failures))| EndpointStats {
+                endpoint: endpoint.to_string(),
+                requests: *requests,
+                failures: *failures,
+            })
+            .collect();
+        EspnStats {
+            requests: endpoints.iter().map(|e| e.requests).sum(),
+            failures: endpoints.iter().map(|e| e.failures).sum(),
+            coalesced: inner.coalesced,
+            cached: inner.cached,
+            not_modified: inner.not_modified,
+            last_success: inner.last_success,
+            last_failure: inner.last_failure.as_ref().map(|(at, _)| *at),
+            last_error: inner.last_failure.as_ref().map(|(_, e)| e.clone()),
+            consecutive_failures: inner.consecutive_failures,
+            consecutive_parse_failures: inner.consecutive_parse_failures,
+            skipped_events: inner.skipped_events,
+            endpoints,
+        }
+    }
+}
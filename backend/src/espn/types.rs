@@ -1,9 +1,31 @@
 use serde::Deserialize;
 
-/// Root response from ESPN scoreboard API
+use crate::error::AppError;
+
+/// Root response from ESPN scoreboard API, with each event left as JSON so
+/// events can be parsed one at a time
 #[derive(Debug, Deserialize)]
+pub struct RawScoreboard {
+    pub events: Vec<serde_json::Value>,
+}
+
+/// A scoreboard whose events were parsed one at a time
+#[derive(Debug, Default)]
 pub struct EspnScoreboard {
     pub events: Vec<EspnEvent>,
+    /// Events left out because they couldn't be parsed
+    pub skipped: Vec<SkippedEvent>,
+}
+
+/// Game list response header counting the events skipped from the list
+pub const WARNINGS_HEADER: &str = "X-Warnings";
+
+/// An event that couldn't be parsed
+#[derive(Debug)]
+pub struct SkippedEvent {
+    /// The event's ID, if it had one
+    pub id: Option<String>,
+    pub error: AppError,
 }
 
 /// Single game/event from ESPN
@@ -18,12 +40,32 @@ pub struct EspnEvent {
     pub weather: Option<EspnWeather>,
     #[serde(default)]
     pub geo_broadcasts: Vec<EspnBroadcast>,
+    pub season: Option<EspnSeason>,
+    pub week: Option<EspnWeek>,
+}
+
+/// Season an event belongs to
+#[derive(Debug, Deserialize)]
+pub struct EspnSeason {
+    pub year: u16,
+    /// 1 = preseason, 2 = regular season, 3 = postseason, 4 = offseason
+    #[serde(rename = "type")]
+    pub season_type: u8,
+}
+
+/// Week of the season an event is played in
+#[derive(Debug, Deserialize)]
+pub struct EspnWeek {
+    pub number: u8,
 }
 
 /// Game status information
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EspnStatus {
+    /// Seconds left in the period
+    #[serde(default)]
+    pub clock: f64,
     pub period: u8,
     pub display_clock: String,
     #[serde(rename = "type")]
@@ -45,6 +87,41 @@ pub struct EspnCompetition {
     pub competitors: Vec<EspnCompetitor>,
     pub situation: Option<EspnSituation>,
     pub venue: Option<EspnVenue>,
+    /// Game stat leaders by category (football: passing, rushing, receiving)
+    #[serde(default)]
+    pub leaders: Vec<EspnLeaderCategory>,
+}
+
+/// Stat leaders in one category, e.g. "passingLeader"
+#[derive(Debug, Deserialize)]
+pub struct EspnLeaderCategory {
+    pub name: String,
+    #[serde(default)]
+    pub leaders: Vec<EspnLeader>,
+}
+
+/// A player leading a stat category
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EspnLeader {
+    /// Stat line, e.g. "24/35, 298 YDS, 3 TD"
+    pub display_value: String,
+    pub athlete: Option<EspnAthlete>,
+    pub team: Option<EspnTeamRef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EspnAthlete {
+    pub display_name: Option<String>,
+    /// Abbreviated name, e.g. "P. Mahomes"
+    pub short_name: Option<String>,
+}
+
+/// A team referenced by ID only
+#[derive(Debug, Deserialize)]
+pub struct EspnTeamRef {
+    pub id: String,
 }
 
 /// Team competitor in a game
@@ -57,6 +134,14 @@ pub struct EspnCompetitor {
     #[serde(default)]
     pub records: Vec<EspnRecord>,
     pub curated_rank: Option<EspnCuratedRank>,
+    #[serde(default)]
+    pub linescores: Vec<EspnLinescore>,
+}
+
+/// Points scored by a competitor in a single period
+#[derive(Debug, Deserialize)]
+pub struct EspnLinescore {
+    pub value: f64,
 }
 
 /// Curated rank for college sports
@@ -71,6 +156,8 @@ pub struct EspnTeam {
     pub id: String,
     pub abbreviation: String,
     pub color: Option<String>,
+    #[serde(rename = "alternateColor")]
+    pub alternate_color: Option<String>,
 }
 
 /// Team record
@@ -88,6 +175,10 @@ pub struct EspnSituation {
     pub yard_line: Option<i8>,
     pub possession: Option<String>,
     pub is_red_zone: Option<bool>,
+    /// Line of scrimmage as ESPN displays it, e.g. "KC 35"
+    pub possession_text: Option<String>,
+    /// Down and distance, e.g. "1st & Goal"
+    pub short_down_distance_text: Option<String>,
     pub home_timeouts: Option<u8>,
     pub away_timeouts: Option<u8>,
     pub last_play: Option<EspnLastPlay>,
@@ -178,6 +269,61 @@ pub struct EspnBoxscoreStat {
     pub display_value: String,
 }
 
+/// Football summary response; only the play-by-play is read
+#[derive(Debug, Deserialize)]
+pub struct EspnFootballSummary {
+    pub drives: Option<EspnDrives>,
+}
+
+/// Drives so far, completed ones first
+#[derive(Debug, Deserialize)]
+pub struct EspnDrives {
+    #[serde(default)]
+    pub previous: Vec<EspnDrive>,
+    pub current: Option<EspnDrive>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EspnDrive {
+    #[serde(default)]
+    pub plays: Vec<EspnPlay>,
+}
+
+/// A play in the play-by-play
+#[derive(Debug, Deserialize)]
+pub struct EspnPlay {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub play_type: Option<EspnPlayType>,
+    pub text: Option<String>,
+    pub period: Option<EspnPlayPeriod>,
+    pub clock: Option<EspnPlayClock>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EspnPlayPeriod {
+    pub number: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EspnPlayClock {
+    pub display_value: String,
+}
+
+impl EspnDrives {
+    /// Every play in game order. The current drive is often also the last of
+    /// `previous`, so plays are de-duplicated by ID.
+    pub fn plays(&self) -> impl Iterator<Item = &EspnPlay> {
+        let mut seen = std::collections::HashSet::new();
+        self.previous
+            .iter()
+            .chain(self.current.as_ref())
+            .flat_map(|drive| drive.plays.iter())
+            .filter(move |play| seen.insert(play.id.as_str()))
+    }
+}
+
 // ── Team lookup types (for college logo resolution) ──
 
 /// Response from ESPN teams endpoint (e.g., /sports/football/college-football/teams/228)
@@ -194,4 +340,7 @@ pub struct EspnTeamDetail {
 #[derive(Debug, Deserialize)]
 pub struct EspnLogo {
     pub href: String,
+    /// Tags describing the artwork (e.g., ["full", "dark"])
+    #[serde(default)]
+    pub rel: Vec<String>,
 }
@@ -0,0 +1,293 @@
+//! CSV exports, for logging scores to spreadsheets and analyzing simulated
+//! games. Fields are quoted only when they need it (RFC 4180).
+
+use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use futures_util::future::try_join_all;
+use serde::Deserialize;
+use std::fmt::Display;
+use std::sync::Arc;
+use utoipa::IntoParams;
+
+use crate::auth::ApiKey;
+use crate::basketball::transform::transform_from_scoreboard;
+use crate::basketball::types::BasketballGameResponse;
+use crate::error::{AppError, ErrorResponse};
+use crate::football::handler::{fetch_scoreboard, include_mock};
+use crate::football::types::{FootballGameResponse, GamesQuery};
+use crate::shared::transform::{json_name, skip_malformed};
+use crate::sport::League;
+use crate::timing;
+use crate::AppState;
+
+/// A CSV document built row by row.
+pub struct Csv {
+    filename: &'static str,
+    body: String,
+}
+
+impl Csv {
+    pub fn new(filename: &'static str, header: &[&str]) -> Self {
+        let mut csv = Self {
+            filename,
+            body: String::new(),
+        };
+        csv.row(header);
+        csv
+    }
+
+    pub fn row<T: Display>(&mut self, fields: &[T]) {
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                self.body.push(',');
+            }
+            let field = field.to_string();
+            if field.contains([',', '"', '\n', '\r']) {
+                self.body.push('"');
+                self.body.push_str(&field.replace('"', "\"\""));
+                self.body.push('"');
+            } else {
+                self.body.push_str(&field);
+            }
+        }
+        self.body.push_str("\r\n");
+    }
+}
+
+impl IntoResponse for Csv {
+    fn into_response(self) -> Response {
+        (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"{}\"", self.filename),
+                ),
+            ],
+            self.body,
+        )
+            .into_response()
+    }
+}
+
+/// Query parameters for /api/games.csv
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GamesCsvQuery {
+    /// Only games from this league: nfl, ncaaf, nba, or ncaab. All leagues if
+    /// not specified.
+    pub league: Option<String>,
+}
+
+const GAMES_HEADER: &[&str] = &[
+    "league",
+    "event_id",
+    "state",
+    "start_time",
+    "away",
+    "away_score",
+    "home",
+    "home_score",
+    "period",
+    "clock",
+    "status",
+];
+
+/// One row of /api/games.csv. Columns that don't apply to the game's state
+/// are left empty.
+#[derive(Debug, Default)]
+struct GameRow {
+    event_id: String,
+    state: &'static str,
+    start_time: Option<i64>,
+    away: String,
+    away_score: Option<u16>,
+    home: String,
+    home_score: Option<u16>,
+    period: String,
+    clock: String,
+    status: String,
+}
+
+impl From<FootballGameResponse> for GameRow {
+    fn from(game: FootballGameResponse) -> Self {
+        match game {
+            FootballGameResponse::Pregame(g) => GameRow {
+                event_id: g.event_id,
+                state: "pregame",
+                start_time: Some(g.start_time),
+                away: g.away.abbreviation,
+                home: g.home.abbreviation,
+                ..GameRow::default()
+            },
+            FootballGameResponse::Live(g) => GameRow {
+                event_id: g.event_id,
+                state: "live",
+                away: g.away.abbreviation,
+                away_score: Some(g.away.score.into()),
+                home: g.home.abbreviation,
+                home_score: Some(g.home.score.into()),
+                period: json_name(g.period),
+                clock: g.clock,
+                ..GameRow::default()
+            },
+            FootballGameResponse::Final(g) => GameRow {
+                event_id: g.event_id,
+                state: "final",
+                away: g.away.abbreviation,
+                away_score: Some(g.away.score.into()),
+                home: g.home.abbreviation,
+                home_score: Some(g.home.score.into()),
+                status: json_name(g.status),
+                ..GameRow::default()
+            },
+        }
+    }
+}
+
+impl From<BasketballGameResponse> for GameRow {
+    fn from(game: BasketballGameResponse) -> Self {
+        match game {
+            BasketballGameResponse::Pregame(g) => GameRow {
+                event_id: g.event_id,
+                state: "pregame",
+                start_time: Some(g.start_time),
+                away: g.away.abbreviation,
+                home: g.home.abbreviation,
+                ..GameRow::default()
+            },
+            BasketballGameResponse::Live(g) => GameRow {
+                event_id: g.event_id,
+                state: "live",
+                away: g.away.abbreviation,
+                away_score: Some(g.away.score),
+                home: g.home.abbreviation,
+                home_score: Some(g.home.score),
+                period: json_name(g.period),
+                clock: g.clock,
+                ..GameRow::default()
+            },
+            BasketballGameResponse::Final(g) => GameRow {
+                event_id: g.event_id,
+                state: "final",
+                away: g.away.abbreviation,
+                away_score: Some(g.away.score),
+                home: g.home.abbreviation,
+                home_score: Some(g.home.score),
+                status: json_name(g.status),
+                ..GameRow::default()
+            },
+        }
+    }
+}
+
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+impl GameRow {
+    fn fields(&self, league: League) -> [String; 11] {
+        [
+            league.name().to_string(),
+            self.event_id.clone(),
+            self.state.to_string(),
+            optional(self.start_time),
+            self.away.clone(),
+            optional(self.away_score),
+            self.home.clone(),
+            optional(self.home_score),
+            self.period.clone(),
+            self.clock.clone(),
+            self.status.clone(),
+        ]
+    }
+}
+
+/// The league's current scoreboard as rows, mock games included where the
+/// JSON list would include them.
+async fn game_rows(state: &AppState, league: League) -> Result<Vec<GameRow>, AppError> {
+    match league {
+        League::Football(l) => {
+            let include_mock = include_mock(state, l, &GamesQuery::default());
+            let games = fetch_scoreboard(state, l, include_mock).await?;
+            Ok(games.into_iter().map(GameRow::from).collect())
+        }
+        League::Basketball(l) => {
+            let scoreboard = timing::espn(state.provider.fetch_scoreboard(league, None)).await?;
+            Ok(scoreboard
+                .events
+                .iter()
+                .filter_map(|e| skip_malformed(&e.id, transform_from_scoreboard(e, l)))
+                .map(GameRow::from)
+                .collect())
+        }
+    }
+}
+
+/// GET /api/games.csv
+/// Games on the current scoreboards as CSV, one row per game
+#[utoipa::path(
+    get,
+    path = "/api/games.csv",
+    params(GamesCsvQuery),
+    responses(
+        (status = 200, description = "CSV with a header row: league, event_id, state, start_time, away, away_score, home, home_score, period, clock, status. Columns that don't apply to a game's state are empty.", content_type = "text/csv"),
+        (status = 400, description = "Invalid league", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn games_csv(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<GamesCsvQuery>,
+) -> Result<Csv, AppError> {
+    let leagues = League::one_or_all(query.league.as_deref())?;
+    let rows = try_join_all(leagues.iter().map(|&league| game_rows(&state, league))).await?;
+
+    let mut csv = Csv::new("games.csv", GAMES_HEADER);
+    for (league, rows) in leagues.into_iter().zip(rows) {
+        for row in rows {
+            csv.row(&row.fields(league));
+        }
+    }
+    Ok(csv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sport::FootballLeague;
+
+    #[test]
+    fn test_csv_quotes_only_when_needed() {
+        let mut csv = Csv::new("test.csv", &["a", "b"]);
+        csv.row(&["plain", "has, comma"]);
+        csv.row(&["say \"hi\"", "two\nlines"]);
+        assert_eq!(
+            csv.body,
+            "a,b\r\nplain,\"has, comma\"\r\n\"say \"\"hi\"\"\",\"two\nlines\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_game_row_leaves_inapplicable_columns_empty() {
+        let row = GameRow {
+            event_id: "401".to_string(),
+            state: "pregame",
+            start_time: Some(1_700_000_000),
+            away: "BUF".to_string(),
+            home: "KC".to_string(),
+            ..GameRow::default()
+        };
+        assert_eq!(
+            row.fields(League::Football(FootballLeague::Nfl)).join(","),
+            "nfl,401,pregame,1700000000,BUF,,KC,,,,"
+        );
+    }
+}
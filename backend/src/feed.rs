@@ -0,0 +1,223 @@
+//! Atom feed of final scores, for feed readers and automation that can't
+//! parse the JSON API. Built from the same (cached) ESPN scoreboards.
+
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap};
+use axum::response::IntoResponse;
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::future::join_all;
+use serde::Deserialize;
+use std::fmt::Write;
+use std::sync::Arc;
+use utoipa::IntoParams;
+
+use crate::auth::ApiKey;
+use crate::basketball::transform::transform_from_scoreboard;
+use crate::basketball::types::BasketballGameResponse;
+use crate::error::{AppError, ErrorResponse};
+use crate::football::transform::transform;
+use crate::football::types::FootballGameResponse;
+use crate::shared::transform::{parse_espn_date, skip_malformed};
+use crate::shared::types::FinalStatus;
+use crate::sport::League;
+use crate::timing;
+use crate::AppState;
+
+const FEED_PATH: &str = "/api/feeds/finals.atom";
+
+/// Query parameters for the finals feed
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct FeedQuery {
+    /// Only games from this league: nfl, ncaaf, nba, or ncaab. All leagues if
+    /// not specified.
+    pub league: Option<String>,
+}
+
+/// A completed game as one feed entry
+#[derive(Debug)]
+struct FinalScore {
+    league: League,
+    event_id: String,
+    home: (String, u16),
+    away: (String, u16),
+    status: FinalStatus,
+    /// Scheduled start, the only time ESPN gives for a game
+    date: i64,
+}
+
+impl FinalScore {
+    /// e.g. "BUF 24 at KC 27 (Final/OT)"
+    fn title(&self) -> String {
+        let status = match self.status {
+            FinalStatus::Final => "Final",
+            FinalStatus::FinalOvertime => "Final/OT",
+        };
+        format!(
+            "{} {} at {} {} ({})",
+            self.away.0, self.away.1, self.home.0, self.home.1, status
+        )
+    }
+
+    fn path(&self) -> String {
+        format!("/api/{}/games/{}", self.league.api_path(), self.event_id)
+    }
+}
+
+/// Final games on a league's scoreboard. ESPN errors are logged and give no
+/// games, so one league being down doesn't empty the feed.
+async fn finals(state: &AppState, league: League) -> Vec<FinalScore> {
+    let scoreboard = timing::espn(state.provider.fetch_scoreboard(league, None)).await;
+    let scoreboard = match scoreboard {
+        Ok(scoreboard) => scoreboard,
+        Err(e) => {
+            tracing::warn!(league = league.api_path(), error = ?e, "Couldn't fetch scoreboard for feed");
+            return Vec::new();
+        }
+    };
+
+    scoreboard
+        .events
+        .iter()
+        .filter(|e| e.status.status_type.state == "post")
+        .filter_map(|e| {
+            let date = parse_espn_date(&e.date);
+            let (home, away, status) = match league {
+                League::Football(l) => match skip_malformed(&e.id, transform(e, l))? {
+                    FootballGameResponse::Final(g) => (
+                        (g.home.abbreviation, g.home.score.into()),
+                        (g.away.abbreviation, g.away.score.into()),
+                        g.status,
+                    ),
+                    _ => return None,
+                },
+                League::Basketball(l) => {
+                    match skip_malformed(&e.id, transform_from_scoreboard(e, l))? {
+                        BasketballGameResponse::Final(g) => (
+                            (g.home.abbreviation, g.home.score),
+                            (g.away.abbreviation, g.away.score),
+                            g.status,
+                        ),
+                        _ => return None,
+                    }
+                }
+            };
+            Some(FinalScore {
+                league,
+                event_id: e.id.clone(),
+                home,
+                away,
+                status,
+                date,
+            })
+        })
+        .collect()
+}
+
+/// Escape text for an XML element or attribute.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn rfc3339(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Scheme and host the client reached us at, for absolute links.
+fn base_url(headers: &HeaderMap) -> String {
+    let header = |name| headers.get(name).and_then(|v| v.to_str().ok());
+    let scheme = header("x-forwarded-proto").unwrap_or("http");
+    let host = header("host").unwrap_or("localhost");
+    format!("{scheme}://{host}")
+}
+
+/// Render games, newest first, as an Atom feed.
+fn render(base: &str, mut games: Vec<FinalScore>, now: i64) -> String {
+    games.sort_by_key(|g| std::cmp::Reverse(g.date));
+    let updated = games.first().map_or(now, |g| g.date);
+    let base = escape(base);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str("  <title>Pico Scoreboard final scores</title>\n");
+    let _ = writeln!(xml, "  <id>{base}{FEED_PATH}</id>");
+    let _ = writeln!(xml, "  <link rel=\"self\" href=\"{base}{FEED_PATH}\"/>");
+    let _ = writeln!(xml, "  <updated>{}</updated>", rfc3339(updated));
+    xml.push_str("  <author><name>Pico Scoreboard</name></author>\n");
+    for game in &games {
+        let url = format!("{base}{}", escape(&game.path()));
+        xml.push_str("  <entry>\n");
+        let _ = writeln!(xml, "    <id>{url}</id>");
+        let _ = writeln!(xml, "    <title>{}</title>", escape(&game.title()));
+        let _ = writeln!(xml, "    <link href=\"{url}\"/>");
+        let _ = writeln!(xml, "    <updated>{}</updated>", rfc3339(game.date));
+        let _ = writeln!(xml, "    <category term=\"{}\"/>", game.league.api_path());
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// GET /api/feeds/finals.atom
+/// Completed games with final scores, as an Atom feed
+#[utoipa::path(
+    get,
+    path = "/api/feeds/finals.atom",
+    params(FeedQuery),
+    responses(
+        (status = 200, description = "Atom feed of today's final scores across leagues, newest first. Leagues ESPN fails to return are left out.", content_type = "application/atom+xml"),
+        (status = 400, description = "Invalid league", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key (feed readers can use a signed URL)", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "feeds"
+)]
+pub async fn finals_feed(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    let leagues = League::one_or_all(query.league.as_deref())?;
+    let games = join_all(leagues.into_iter().map(|league| finals(&state, league)))
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+    let xml = render(&base_url(&headers), games, Utc::now().timestamp());
+    Ok(([(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")], xml))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sport::FootballLeague;
+
+    #[test]
+    fn test_feed_lists_newest_first_and_escapes() {
+        let game = |event_id: &str, away: &str, date| FinalScore {
+            league: League::Football(FootballLeague::Ncaaf),
+            event_id: event_id.to_string(),
+            home: ("TA&M".to_string(), 31),
+            away: (away.to_string(), 28),
+            status: FinalStatus::FinalOvertime,
+            date,
+        };
+        let xml = render(
+            "https://scores.example",
+            vec![game("1", "LSU", 1_700_000_000), game("2", "BAMA", 1_700_100_000)],
+            0,
+        );
+
+        assert!(xml.contains("<updated>2023-11-16T02:00:00Z</updated>"));
+        assert!(xml.contains("<title>BAMA 28 at TA&amp;M 31 (Final/OT)</title>"));
+        assert!(xml.contains("<link href=\"https://scores.example/api/football/ncaaf/games/2\"/>"));
+        assert!(xml.find("BAMA").unwrap() < xml.find("LSU").unwrap());
+    }
+}
@@ -0,0 +1,162 @@
+//! Score and lead changes between polls, so displays can flash on a score
+//! without keeping the previous state themselves.
+//!
+//! Every change bumps one global sequence number. Responses carry the current
+//! number in the `X-Sequence` header; a client passes the last one it saw as
+//! `?since=` and each game reports whether its score or lead changed after it.
+//!
+//! The sequence is kept per instance and starts over on restart. Behind a
+//! load balancer, a `since` from one instance means nothing to another, so
+//! route each display to one instance (e.g., with sticky sessions) to rely on
+//! it.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::types::{FootballGameResponse, Possession};
+
+/// Response header carrying the sequence number to pass as `since` next time
+pub const SEQUENCE_HEADER: &str = "X-Sequence";
+
+/// Games not seen for this long are forgotten
+const FORGET_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug)]
+struct Entry {
+    home: u8,
+    away: u8,
+    /// The last team to hold the lead; ties don't clear it
+    leader: Option<Possession>,
+    /// Sequence number of the last score change
+    score_changed: u64,
+    /// Sequence number of the last lead change
+    lead_changed: u64,
+    last_seen: Instant,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    sequence: u64,
+    games: HashMap<String, Entry>,
+}
+
+#[derive(Default)]
+pub struct ScoreChanges {
+    inner: Mutex<Inner>,
+}
+
+impl ScoreChanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The latest sequence number.
+    pub fn sequence(&self) -> u64 {
+        self.inner.lock().unwrap().sequence
+    }
+
+    /// Record the game's score and mark whether it or the lead changed after
+    /// `since`. The first time a game is seen counts as no change, so a
+    /// restart doesn't flash every display.
+    pub fn observe(&self, game: &mut FootballGameResponse, since: Option<u64>) {
+        let Some((home, away)) = game.scores() else {
+            return;
+        };
+        let now = Instant::now();
+        let leader = match home.cmp(&away) {
+            std::cmp::Ordering::Greater => Some(Possession::Home),
+            std::cmp::Ordering::Less => Some(Possession::Away),
+            std::cmp::Ordering::Equal => None,
+        };
+
+        let mut inner = self.inner.lock().unwrap();
+        let Inner { sequence, games } = &mut *inner;
+        games.retain(|_, entry| now.duration_since(entry.last_seen) < FORGET_AFTER);
+
+        let entry = games.entry(game.event_id().to_string()).or_insert(Entry {
+            home,
+            away,
+            leader,
+            score_changed: 0,
+            lead_changed: 0,
+            last_seen: now,
+        });
+        entry.last_seen = now;
+
+        if (entry.home, entry.away) != (home, away) {
+            *sequence += 1;
+            entry.home = home;
+            entry.away = away;
+            entry.score_changed = *sequence;
+            if leader.is_some() && leader != entry.leader {
+                if entry.leader.is_some() {
+                    entry.lead_changed = *sequence;
+                }
+                entry.leader = leader;
+            }
+        }
+
+        if let Some(since) = since {
+            game.set_changes(entry.score_changed > since, entry.lead_changed > since);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::{FootballFinal, FootballTeamScore};
+    use crate::shared::types::{FinalStatus, Winner};
+
+    fn game(home: u8, away: u8) -> FootballGameResponse {
+        let team = |score| FootballTeamScore::for_test("KC", score);
+        FootballGameResponse::Final(FootballFinal {
+            event_id: "401".to_string(),
+            home: team(home),
+            away: team(away),
+            season: None,
+            status: FinalStatus::Final,
+            winner: Winner::Tie,
+            leaders: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        })
+    }
+
+    fn changes(game: &FootballGameResponse) -> (bool, bool) {
+        match game {
+            FootballGameResponse::Final(g) => (g.score_changed_since_last_poll, g.lead_changed),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_changes_since_cursor() {
+        let tracker = ScoreChanges::new();
+
+        let mut first = game(7, 0);
+        tracker.observe(&mut first, Some(0));
+        assert_eq!(changes(&first), (false, false));
+        let cursor = tracker.sequence();
+
+        // Tying the game changes the score but not who last led
+        let mut tied = game(7, 7);
+        tracker.observe(&mut tied, Some(cursor));
+        assert_eq!(changes(&tied), (true, false));
+        let cursor = tracker.sequence();
+
+        let mut unchanged = game(7, 7);
+        tracker.observe(&mut unchanged, Some(cursor));
+        assert_eq!(changes(&unchanged), (false, false));
+
+        let mut away_leads = game(7, 10);
+        tracker.observe(&mut away_leads, Some(cursor));
+        assert_eq!(changes(&away_leads), (true, true));
+
+        // A client that hasn't polled since still sees the change
+        let mut again = game(7, 10);
+        tracker.observe(&mut again, Some(cursor));
+        assert_eq!(changes(&again), (true, true));
+    }
+}
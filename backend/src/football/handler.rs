@@ -1,16 +1,26 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
 };
+use futures_util::future::join_all;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::auth::ApiKey;
+use crate::auth::{AdminKey, ApiKey};
 use crate::error::{AppError, ErrorResponse};
+use crate::espn::types::{EspnEvent, EspnScoreboard, WARNINGS_HEADER};
+use crate::json_stream::{self, StreamQuery};
+use crate::shared::transform::skip_malformed;
+use crate::shared::types::SeasonType;
+use crate::mock::simulation::SimulatedGame;
 use crate::sport::FootballLeague;
+use crate::timing::{self, Timed};
 use crate::AppState;
 
+use super::changes::SEQUENCE_HEADER;
 use super::transform;
-use super::types::FootballGameResponse;
+use super::types::{FootballGameResponse, GamesQuery, RecentPlay, UnknownPlayType};
 
 /// GET /api/{league}/games/{event_id}
 /// Fetches game data from ESPN and returns a minimal payload for the Pi Pico
@@ -20,14 +30,17 @@ use super::types::FootballGameResponse;
     operation_id = "get_football_game",
     params(
         ("league" = String, Path, description = "League identifier (nfl, ncaaf)"),
-        ("event_id" = String, Path, description = "ESPN event ID (numeric)"),
+        ("event_id" = String, Path, description = "ESPN event ID (numeric), or a mock game ID (e.g., 'sim_1') when mock games are included"),
+        GamesQuery
     ),
     responses(
-        (status = 200, description = "Game data retrieved successfully", body = FootballGameResponse),
+        (status = 200, description = "Game data retrieved successfully", body = FootballGameResponse,
+            headers(("X-Sequence" = u64, description = "Pass as `since` on the next poll to learn of score and lead changes"))),
         (status = 400, description = "Invalid league or event ID format", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
-        (status = 404, description = "Game not found on current scoreboard", body = ErrorResponse),
+        (status = 404, description = "Game not found on current scoreboard or in the mock repository", body = ErrorResponse),
         (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
     ),
     security(
         ("api_key" = [])
@@ -38,21 +51,24 @@ pub async fn get_game(
     _api_key: ApiKey,
     State(state): State<Arc<AppState>>,
     Path((league, event_id)): Path<(String, String)>,
-) -> Result<Json<FootballGameResponse>, AppError> {
+    Query(query): Query<GamesQuery>,
+) -> Result<Response, AppError> {
     let football_league = FootballLeague::from_league(&league)?;
-
-    // Validate event_id is numeric only
-    if !event_id.chars().all(|c| c.is_ascii_digit()) {
-        return Err(AppError::InvalidEventId(event_id));
+    let include_mock = include_mock(&state, football_league, &query);
+    let mut response = fetch_game(&state, football_league, event_id, include_mock).await?;
+    response.retain_includes(&query);
+    // Read first, so a change another request records in between is still
+    // after the cursor handed out here
+    let sequence = [(SEQUENCE_HEADER, state.score_changes.sequence().to_string())];
+    state.score_changes.observe(&mut response, query.since);
+    if let Some(count) = query.recent_plays()
+        && let FootballGameResponse::Live(game) = &mut response
+    {
+        let plays = fetch_recent_plays(&state, football_league, &game.event_id, count);
+        game.recent_plays = timing::espn(plays).await;
     }
 
-    // Fetch game from ESPN
-    let event = state.espn_client.fetch_game(football_league, &event_id).await?;
-
-    // Transform to our response format
-    let response = transform::transform(&event, football_league);
-
-    Ok(Json(response))
+    Ok((sequence, Timed(Json(response))).into_response())
 }
 
 /// GET /api/{league}/games
@@ -63,12 +79,19 @@ pub async fn get_game(
     operation_id = "get_all_football_games",
     params(
         ("league" = String, Path, description = "League identifier (nfl, ncaaf)"),
+        GamesQuery,
+        StreamQuery
     ),
     responses(
-        (status = 200, description = "All games retrieved successfully", body = Vec<FootballGameResponse>),
+        (status = 200, description = "All games retrieved successfully. ESPN events that can't be parsed are left out.", body = Vec<FootballGameResponse>,
+            headers(
+                ("X-Warnings" = usize, description = "Number of ESPN events left out because they couldn't be parsed"),
+                ("X-Sequence" = u64, description = "Pass as `since` on the next poll to learn of score and lead changes"),
+            )),
         (status = 400, description = "Invalid league", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
         (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
     ),
     security(
         ("api_key" = [])
@@ -79,17 +102,210 @@ pub async fn get_all_games(
     _api_key: ApiKey,
     State(state): State<Arc<AppState>>,
     Path(league): Path<String>,
-) -> Result<Json<Vec<FootballGameResponse>>, AppError> {
+    Query(query): Query<GamesQuery>,
+    Query(stream): Query<StreamQuery>,
+) -> Result<Response, AppError> {
     let football_league = FootballLeague::from_league(&league)?;
+    let include_mock = include_mock(&state, football_league, &query);
 
-    // Fetch all games from ESPN
-    let events = state.espn_client.fetch_all_games(football_league).await?;
+    let scoreboard =
+        scoreboard_sources(&state, football_league, include_mock, query.seasontype).await?;
+    let warnings = [(WARNINGS_HEADER, scoreboard.skipped.to_string())];
+    let mut recent_plays = match query.recent_plays() {
+        Some(count) => timing::espn(scoreboard.recent_plays(&state, football_league, count)).await,
+        None => HashMap::new(),
+    };
+
+    // Read before any game is observed (see `get_game`). A change found while
+    // listing may then be reported again on the next poll, but never missed.
+    let sequence = [(SEQUENCE_HEADER, state.score_changes.sequence().to_string())];
+    let changes = state.clone();
+    let responses = scoreboard
+        .into_responses(football_league)
+        .map(move |mut game| {
+            game.retain_includes(&query);
+            if let Some(plays) = recent_plays.remove(game.event_id()) {
+                game.set_recent_plays(plays);
+            }
+            changes.score_changes.observe(&mut game, query.since);
+            game
+        });
+
+    if stream.enabled() {
+        return Ok((warnings, sequence, json_stream::array(responses)).into_response());
+    }
+
+    let responses: Vec<FootballGameResponse> = timing::transform(|| responses.collect());
+    Ok((warnings, sequence, Timed(Json(responses))).into_response())
+}
+
+/// A single game, served from the mock repository for `sim_*` IDs when
+/// `include_mock` is set and fetched from ESPN otherwise.
+pub async fn fetch_game(
+    state: &AppState,
+    league: FootballLeague,
+    event_id: String,
+    include_mock: bool,
+) -> Result<FootballGameResponse, AppError> {
+    // Serve simulated games by their sim_* ID
+    if event_id.starts_with("sim_") && include_mock {
+        let game = state
+            .game_repository
+            .get(&event_id)
+            .await
+            .ok_or_else(|| AppError::MockGameNotFound(event_id))?;
+        return Ok(game.to_game_response());
+    }
+
+    // Validate event_id is numeric only
+    if !event_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AppError::InvalidEventId(event_id));
+    }
+    if mock_only(state) {
+        return Err(AppError::GameNotFound(event_id));
+    }
+
+    // Fetch game from ESPN
+    let event = timing::espn(state.provider.fetch_game(league.into(), &event_id)).await?;
+    state.unknown_plays.observe(&event);
+
+    // Transform to our response format
+    timing::transform(|| transform::transform(&event, league))
+}
+
+/// Every game on the league's current ESPN scoreboard, followed by the mock
+/// games when `include_mock` is set.
+pub async fn fetch_scoreboard(
+    state: &AppState,
+    league: FootballLeague,
+    include_mock: bool,
+) -> Result<Vec<FootballGameResponse>, AppError> {
+    let scoreboard = scoreboard_sources(state, league, include_mock, None).await?;
 
     // Transform each event to our response format
-    let responses: Vec<FootballGameResponse> = events
-        .iter()
-        .map(|e| transform::transform(e, football_league))
-        .collect();
+    Ok(timing::transform(|| scoreboard.into_responses(league).collect()))
+}
 
-    Ok(Json(responses))
+/// The ESPN events and mock games behind `fetch_scoreboard`, untransformed.
+struct Scoreboard {
+    events: Vec<EspnEvent>,
+    /// ESPN events left out because they couldn't be parsed
+    skipped: usize,
+    mock_games: Vec<Arc<SimulatedGame>>,
+}
+
+impl Scoreboard {
+    /// Transform the games as they're taken from the iterator.
+    fn into_responses(
+        self,
+        league: FootballLeague,
+    ) -> impl Iterator<Item = FootballGameResponse> + Send + 'static {
+        self.events
+            .into_iter()
+            .filter_map(move |e| skip_malformed(&e.id, transform::transform(&e, league)))
+            .chain(self.mock_games.into_iter().map(|g| g.to_game_response()))
+    }
+
+    /// The last `count` plays of each live game, keyed by event ID. ESPN
+    /// games are fetched concurrently; those that fail are left out.
+    async fn recent_plays(
+        &self,
+        state: &AppState,
+        league: FootballLeague,
+        count: usize,
+    ) -> HashMap<String, Vec<RecentPlay>> {
+        let live = self
+            .events
+            .iter()
+            .filter(|e| e.status.status_type.state == "in")
+            .map(|e| async move {
+                let plays = fetch_recent_plays(state, league, &e.id, count).await?;
+                Some((e.id.clone(), plays))
+            });
+        let mut plays: HashMap<_, _> = join_all(live).await.into_iter().flatten().collect();
+        plays.extend(self.mock_games.iter().map(|g| (g.id.clone(), g.recent_plays(count))));
+        plays
+    }
+}
+
+/// The last `count` plays of a live game, newest first. ESPN errors are
+/// logged and give `None`, so the game is still returned without them.
+async fn fetch_recent_plays(
+    state: &AppState,
+    league: FootballLeague,
+    event_id: &str,
+    count: usize,
+) -> Option<Vec<RecentPlay>> {
+    if event_id.starts_with("sim_") {
+        return Some(state.game_repository.get(event_id).await?.recent_plays(count));
+    }
+    match state.provider.fetch_football_summary(league.into(), event_id).await {
+        Ok(summary) => Some(timing::transform(|| transform::to_recent_plays(&summary, count))),
+        Err(e) => {
+            tracing::warn!(event_id, error = ?e, "Couldn't fetch recent plays; leaving them out");
+            None
+        }
+    }
+}
+
+async fn scoreboard_sources(
+    state: &AppState,
+    league: FootballLeague,
+    include_mock: bool,
+    season_type: Option<SeasonType>,
+) -> Result<Scoreboard, AppError> {
+    // Fetch all games from ESPN
+    let espn = if mock_only(state) {
+        EspnScoreboard::default()
+    } else {
+        timing::espn(state.provider.fetch_scoreboard(league.into(), season_type)).await?
+    };
+    espn.events.iter().for_each(|e| state.unknown_plays.observe(e));
+
+    let mock_games = if include_mock {
+        state.game_repository.list().await
+    } else {
+        Vec::new()
+    };
+
+    Ok(Scoreboard {
+        events: espn.events,
+        skipped: espn.skipped.len(),
+        mock_games,
+    })
+}
+
+/// Whether mock games are merged into this request (NFL only, since the simulator is NFL-only).
+pub fn include_mock(state: &AppState, league: FootballLeague, query: &GamesQuery) -> bool {
+    let mock = state.live.read().unwrap().mock.clone();
+    league == FootballLeague::Nfl
+        && (mock.only || query.include_mock.unwrap_or(mock.include_in_listings))
+}
+
+/// Whether ESPN is left out entirely (`--mock-only`).
+fn mock_only(state: &AppState) -> bool {
+    state.live.read().unwrap().mock.only
+}
+
+/// GET /api/admin/unknown-plays
+/// ESPN play type IDs seen since startup that have no mapping yet, with sample
+/// plays. Include these when reporting a new play type.
+#[utoipa::path(
+    get,
+    path = "/api/admin/unknown-plays",
+    responses(
+        (status = 200, description = "Unknown play type IDs, most frequent first", body = Vec<UnknownPlayType>),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "football"
+)]
+pub async fn get_unknown_plays(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+) -> Json<Vec<UnknownPlayType>> {
+    Json(state.unknown_plays.list())
 }
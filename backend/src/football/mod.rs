@@ -1,3 +1,6 @@
+pub mod changes;
 pub mod handler;
+pub mod packed;
+pub mod telemetry;
 pub mod transform;
 pub mod types;
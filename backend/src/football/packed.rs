@@ -0,0 +1,277 @@
+//! Fixed-size binary encoding of football games, for listeners too small for
+//! HTTP or JSON (see `broadcast`).
+//!
+//! A packet is a 4-byte header followed by `count` records of `RECORD_LEN`
+//! bytes. Multi-byte fields are little-endian.
+//!
+//! Header: `"PS"`, format `VERSION`, `count`.
+//!
+//! Record:
+//!
+//! | Offset | Size | Field |
+//! |-------:|-----:|-------|
+//! | 0  | 1 | state: 0 pregame, 1 live, 2 final |
+//! | 1  | 4 | ESPN event ID (0 for mock games) |
+//! | 5  | 4 | away abbreviation, ASCII padded with NULs |
+//! | 9  | 4 | home abbreviation |
+//! | 13 | 3 | away color (RGB) |
+//! | 16 | 3 | home color |
+//! | 19 | 1 | away score |
+//! | 20 | 1 | home score |
+//! | 21 | 1 | period: 0 none, 1-4 quarters, 5-8 overtimes, 9 halftime |
+//! | 22 | 2 | clock seconds remaining in the period |
+//! | 24 | 1 | flags (`FLAG_*`) |
+//! | 25 | 1 | down (0 when there's no situation) |
+//! | 26 | 1 | yards to go |
+//! | 27 | 1 | yard line, from the offense's own goal line |
+//! | 28 | 1 | away timeouts (`UNKNOWN` if not reported) |
+//! | 29 | 1 | home timeouts |
+//! | 30 | 4 | kickoff as Unix seconds (pregame only, else 0) |
+
+use super::types::{Down, FootballGameResponse, FootballPeriod, FootballTeamScore, Possession};
+use crate::shared::transform::parse_clock;
+use crate::shared::types::{Color, FinalStatus, Team};
+
+pub const MAGIC: &[u8; 2] = b"PS";
+pub const VERSION: u8 = 1;
+pub const HEADER_LEN: usize = 4;
+pub const RECORD_LEN: usize = 34;
+
+/// Games per packet, keeping packets under the 576-byte minimum IPv4
+/// datagram so nothing along the way fragments them
+pub const MAX_RECORDS: usize = 16;
+
+/// Timeouts value when ESPN doesn't report them
+pub const UNKNOWN: u8 = 0xFF;
+
+pub const FLAG_CLOCK_RUNNING: u8 = 1 << 0;
+pub const FLAG_HOME_POSSESSION: u8 = 1 << 1;
+pub const FLAG_AWAY_POSSESSION: u8 = 1 << 2;
+pub const FLAG_RED_ZONE: u8 = 1 << 3;
+pub const FLAG_GOAL_TO_GO: u8 = 1 << 4;
+pub const FLAG_INSIDE_TWO_MINUTES: u8 = 1 << 5;
+pub const FLAG_CLOSE_GAME: u8 = 1 << 6;
+/// Final games only: the game went to overtime
+pub const FLAG_FINAL_OVERTIME: u8 = 1 << 7;
+
+fn period(period: FootballPeriod) -> u8 {
+    match period {
+        FootballPeriod::Q1 => 1,
+        FootballPeriod::Q2 => 2,
+        FootballPeriod::Q3 => 3,
+        FootballPeriod::Q4 => 4,
+        FootballPeriod::OT => 5,
+        FootballPeriod::OT2 => 6,
+        FootballPeriod::OT3 => 7,
+        FootballPeriod::OT4 => 8,
+        FootballPeriod::Halftime => 9,
+    }
+}
+
+fn down(down: Down) -> u8 {
+    match down {
+        Down::First => 1,
+        Down::Second => 2,
+        Down::Third => 3,
+        Down::Fourth => 4,
+    }
+}
+
+fn abbreviation(abbreviation: &str) -> [u8; 4] {
+    let mut packed = [0; 4];
+    for (slot, byte) in packed.iter_mut().zip(abbreviation.bytes().filter(u8::is_ascii)) {
+        *slot = byte;
+    }
+    packed
+}
+
+/// The fields both teams fill in, whatever the game state.
+struct Side<'a> {
+    abbreviation: &'a str,
+    color: &'a Color,
+    score: u8,
+    timeouts: Option<u8>,
+}
+
+impl<'a> From<&'a Team> for Side<'a> {
+    fn from(team: &'a Team) -> Self {
+        Self {
+            abbreviation: &team.abbreviation,
+            color: &team.color,
+            score: 0,
+            timeouts: None,
+        }
+    }
+}
+
+impl<'a> From<&'a FootballTeamScore> for Side<'a> {
+    fn from(team: &'a FootballTeamScore) -> Self {
+        Self {
+            abbreviation: &team.abbreviation,
+            color: &team.color,
+            score: team.score,
+            timeouts: team.timeouts,
+        }
+    }
+}
+
+/// Encode one game as a `RECORD_LEN`-byte record.
+pub fn encode_game(game: &FootballGameResponse) -> [u8; RECORD_LEN] {
+    let mut record = [0; RECORD_LEN];
+    let (state, away, home): (u8, Side, Side) = match game {
+        FootballGameResponse::Pregame(g) => (0, (&g.away).into(), (&g.home).into()),
+        FootballGameResponse::Live(g) => (1, (&g.away).into(), (&g.home).into()),
+        FootballGameResponse::Final(g) => (2, (&g.away).into(), (&g.home).into()),
+    };
+
+    record[0] = state;
+    let event_id: u32 = game.event_id().parse().unwrap_or(0);
+    record[1..5].copy_from_slice(&event_id.to_le_bytes());
+    record[5..9].copy_from_slice(&abbreviation(away.abbreviation));
+    record[9..13].copy_from_slice(&abbreviation(home.abbreviation));
+    record[13..16].copy_from_slice(&[away.color.r, away.color.g, away.color.b]);
+    record[16..19].copy_from_slice(&[home.color.r, home.color.g, home.color.b]);
+    record[19] = away.score;
+    record[20] = home.score;
+    record[28] = away.timeouts.unwrap_or(UNKNOWN);
+    record[29] = home.timeouts.unwrap_or(UNKNOWN);
+
+    match game {
+        FootballGameResponse::Pregame(g) => {
+            let kickoff = u32::try_from(g.start_time).unwrap_or(0);
+            record[30..34].copy_from_slice(&kickoff.to_le_bytes());
+        }
+        FootballGameResponse::Live(g) => {
+            record[21] = period(g.period);
+            record[22..24].copy_from_slice(&parse_clock(&g.clock).unwrap_or(0).to_le_bytes());
+            let mut flags = 0;
+            if g.clock_running {
+                flags |= FLAG_CLOCK_RUNNING;
+            }
+            if g.inside_two_minutes {
+                flags |= FLAG_INSIDE_TWO_MINUTES;
+            }
+            if g.is_close_game {
+                flags |= FLAG_CLOSE_GAME;
+            }
+            if let Some(situation) = &g.situation {
+                flags |= match situation.possession {
+                    Possession::Home => FLAG_HOME_POSSESSION,
+                    Possession::Away => FLAG_AWAY_POSSESSION,
+                };
+                if situation.red_zone {
+                    flags |= FLAG_RED_ZONE;
+                }
+                if situation.goal_to_go {
+                    flags |= FLAG_GOAL_TO_GO;
+                }
+                record[25] = down(situation.down);
+                record[26] = situation.distance;
+                record[27] = situation.yard_line;
+            }
+            record[24] = flags;
+        }
+        FootballGameResponse::Final(g) => {
+            if g.status == FinalStatus::FinalOvertime {
+                record[24] = FLAG_FINAL_OVERTIME;
+            }
+        }
+    }
+    record
+}
+
+/// Encode games into packets of at most `MAX_RECORDS` games each. No games
+/// still gives one (empty) packet, so listeners can tell the server is up.
+pub fn encode_packets(games: &[FootballGameResponse]) -> Vec<Vec<u8>> {
+    let mut chunks: Vec<&[FootballGameResponse]> = games.chunks(MAX_RECORDS).collect();
+    if chunks.is_empty() {
+        chunks.push(&[]);
+    }
+    chunks
+        .into_iter()
+        .map(|chunk| {
+            let mut packet = Vec::with_capacity(HEADER_LEN + chunk.len() * RECORD_LEN);
+            packet.extend_from_slice(MAGIC);
+            packet.push(VERSION);
+            packet.push(chunk.len() as u8);
+            for game in chunk {
+                packet.extend_from_slice(&encode_game(game));
+            }
+            packet
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::{Direction, FootballLive, Situation};
+
+    fn team(abbreviation: &str, score: u8, timeouts: Option<u8>) -> FootballTeamScore {
+        FootballTeamScore::for_test(abbreviation, score)
+            .with_color(Color { r: 227, g: 24, b: 55 })
+            .with_timeouts(timeouts)
+    }
+
+    fn live() -> FootballGameResponse {
+        FootballGameResponse::Live(FootballLive {
+            event_id: "401547417".to_string(),
+            home: team("KC", 27, Some(2)),
+            away: team("BUF", 24, None),
+            season: None,
+            period: FootballPeriod::Q4,
+            clock: "1:52".to_string(),
+            clock_running: true,
+            inside_two_minutes: true,
+            is_close_game: true,
+            situation: Some(Situation {
+                down: Down::Third,
+                distance: 7,
+                yard_line: 65,
+                possession: Possession::Away,
+                red_zone: false,
+                goal_to_go: false,
+                ball_on: "KC 35".to_string(),
+                direction: Direction::Right,
+            }),
+            last_play: None,
+            weather: None,
+            leaders: None,
+            recent_plays: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        })
+    }
+
+    #[test]
+    fn test_live_game_record_layout() {
+        let record = encode_game(&live());
+        assert_eq!(record[0], 1);
+        assert_eq!(u32::from_le_bytes(record[1..5].try_into().unwrap()), 401547417);
+        assert_eq!(&record[5..9], b"BUF\0");
+        assert_eq!(&record[9..13], b"KC\0\0");
+        assert_eq!(&record[16..19], &[227, 24, 55]);
+        assert_eq!((record[19], record[20]), (24, 27));
+        assert_eq!(record[21], 4);
+        assert_eq!(u16::from_le_bytes([record[22], record[23]]), 112);
+        assert_eq!(
+            record[24],
+            FLAG_CLOCK_RUNNING | FLAG_AWAY_POSSESSION | FLAG_INSIDE_TWO_MINUTES | FLAG_CLOSE_GAME
+        );
+        assert_eq!(&record[25..28], &[3, 7, 65]);
+        assert_eq!((record[28], record[29]), (UNKNOWN, 2));
+    }
+
+    #[test]
+    fn test_packets_split_and_never_empty() {
+        let packets = encode_packets(&[]);
+        assert_eq!(packets, vec![b"PS\x01\x00".to_vec()]);
+
+        let games: Vec<_> = (0..MAX_RECORDS + 1).map(|_| live()).collect();
+        let packets = encode_packets(&games);
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0][3] as usize, MAX_RECORDS);
+        assert_eq!(packets[0].len(), HEADER_LEN + MAX_RECORDS * RECORD_LEN);
+        assert_eq!(packets[1][3], 1);
+    }
+}
@@ -0,0 +1,143 @@
+//! ESPN play type IDs that `PlayType` doesn't map yet, collected with sample
+//! plays so users can report new IDs with evidence.
+
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::espn::types::EspnEvent;
+
+use super::types::{PlayType, UnknownPlaySample, UnknownPlayType};
+
+/// Sample plays kept per unknown ID
+const MAX_SAMPLES: usize = 5;
+
+#[derive(Debug, Default)]
+struct Entry {
+    /// ESPN's own name for the play type, if it sent one
+    espn_name: Option<String>,
+    /// Distinct plays seen, by event and play ID, so repeated polls of the same
+    /// last play count once
+    plays: HashSet<(String, String)>,
+    samples: Vec<UnknownPlaySample>,
+    first_seen: i64,
+    last_seen: i64,
+}
+
+#[derive(Default)]
+pub struct UnknownPlays {
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+impl UnknownPlays {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the event's last play if ESPN gave it a type ID we don't know.
+    pub fn observe(&self, event: &EspnEvent) {
+        let Some(play) = event
+            .competitions
+            .first()
+            .and_then(|c| c.situation.as_ref())
+            .and_then(|s| s.last_play.as_ref())
+        else {
+            return;
+        };
+        if PlayType::is_known_espn_id(&play.play_type.id) {
+            return;
+        }
+
+        let now = Utc::now().timestamp();
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries
+            .entry(play.play_type.id.clone())
+            .or_insert_with(|| Entry {
+                first_seen: now,
+                ..Entry::default()
+            });
+        entry.last_seen = now;
+        if entry.espn_name.is_none() {
+            entry.espn_name = play.play_type.text.clone();
+        }
+        if entry.plays.insert((event.id.clone(), play.id.clone()))
+            && entry.samples.len() < MAX_SAMPLES
+        {
+            entry.samples.push(UnknownPlaySample {
+                event_id: event.id.clone(),
+                text: play.text.clone(),
+            });
+        }
+    }
+
+    /// Every unknown ID seen, most frequent first.
+    pub fn list(&self) -> Vec<UnknownPlayType> {
+        let mut list: Vec<UnknownPlayType> = self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, entry)| UnknownPlayType {
+                play_type_id: id.clone(),
+                espn_name: entry.espn_name.clone(),
+                count: entry.plays.len(),
+                first_seen: entry.first_seen,
+                last_seen: entry.last_seen,
+                samples: entry.samples.clone(),
+            })
+            .collect();
+        list.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.play_type_id.cmp(&b.play_type_id))
+        });
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_id: &str, play_id: &str, type_id: &str) -> EspnEvent {
+        serde_json::from_value(serde_json::json!({
+            "id": event_id,
+            "date": "2026-10-18T17:00Z",
+            "status": {
+                "period": 2,
+                "displayClock": "4:12",
+                "type": { "id": "2", "state": "in", "shortDetail": "4:12 - 2nd" }
+            },
+            "competitions": [{
+                "competitors": [],
+                "situation": {
+                    "lastPlay": {
+                        "id": play_id,
+                        "type": { "id": type_id, "text": "Mystery Play" },
+                        "text": "Something new happened"
+                    }
+                }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_counts_distinct_unknown_plays() {
+        let plays = UnknownPlays::new();
+        plays.observe(&event("401", "1", "999"));
+        // The same last play polled again
+        plays.observe(&event("401", "1", "999"));
+        plays.observe(&event("402", "7", "999"));
+        // Known: pass reception
+        plays.observe(&event("401", "2", "24"));
+
+        let list = plays.list();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].play_type_id, "999");
+        assert_eq!(list[0].espn_name.as_deref(), Some("Mystery Play"));
+        assert_eq!(list[0].count, 2);
+        assert_eq!(list[0].samples.len(), 2);
+        assert_eq!(list[0].samples[1].event_id, "402");
+    }
+}
@@ -1,27 +1,34 @@
-use crate::espn::types::{EspnCompetition, EspnCompetitor, EspnEvent, EspnLastPlay, EspnSituation};
-use crate::shared::transform::{get_broadcast, get_competitors, parse_espn_date, parse_hex_color, parse_rank};
-use crate::shared::types::Weather;
+use crate::error::AppError;
+use crate::espn::types::{
+    EspnCompetition, EspnCompetitor, EspnEvent, EspnFootballSummary, EspnLastPlay,
+    EspnLeaderCategory, EspnSituation,
+};
+use crate::shared::transform::{
+    first_competition, get_broadcast, get_competitors, parse_espn_date, parse_hex_color, parse_rank,
+    secondary_color, seconds_until, seconds_until_kickoff,
+};
+use crate::shared::types::{Season, SeasonType, Weather};
 use crate::sport::{EspnLeague, FootballLeague};
 
 use super::types::{
     Down, FootballFinal, FootballGameResponse, FootballLive, FootballPeriod, FootballPregame,
-    FootballTeamScore, LastPlay, PlayType, Possession, Situation,
+    FootballTeamScore, GameLeaders, LastPlay, Leader, PlayType, Possession, RecentPlay, Situation,
 };
 
 use crate::shared::types::{FinalStatus, Winner};
 
 /// Transform an ESPN event into our football API response format
-pub fn transform(event: &EspnEvent, league: FootballLeague) -> FootballGameResponse {
-    let competition = &event.competitions[0];
-    let state = event.status.status_type.state.as_str();
+pub fn transform(event: &EspnEvent, league: FootballLeague) -> Result<FootballGameResponse, AppError> {
     let event_id = &event.id;
+    let competition = first_competition(&event.competitions, event_id)?;
+    let state = event.status.status_type.state.as_str();
 
-    match state {
-        "pre" => FootballGameResponse::Pregame(to_pregame(event, competition, event_id, league)),
-        "in" => FootballGameResponse::Live(to_live(event, competition, event_id, league)),
-        "post" => FootballGameResponse::Final(to_final(event, competition, event_id, league)),
-        _ => FootballGameResponse::Pregame(to_pregame(event, competition, event_id, league)),
-    }
+    Ok(match state {
+        "pre" => FootballGameResponse::Pregame(to_pregame(event, competition, event_id, league)?),
+        "in" => FootballGameResponse::Live(to_live(event, competition, event_id, league)?),
+        "post" => FootballGameResponse::Final(to_final(event, competition, event_id, league)?),
+        _ => FootballGameResponse::Pregame(to_pregame(event, competition, event_id, league)?),
+    })
 }
 
 /// Transform to pregame response
@@ -30,18 +37,21 @@ fn to_pregame(
     competition: &EspnCompetition,
     event_id: &str,
     league: FootballLeague,
-) -> FootballPregame {
-    let (home_competitor, away_competitor) = get_competitors(&competition.competitors);
-    let is_college = league.is_college();
+) -> Result<FootballPregame, AppError> {
+    let (home_competitor, away_competitor) = get_competitors(&competition.competitors, event_id)?;
 
+    let start_time = parse_espn_date(&event.date);
     let venue = competition.venue.as_ref();
     let is_outdoor = venue.map(|v| !v.indoor.unwrap_or(false)).unwrap_or(true);
 
-    FootballPregame {
+    Ok(FootballPregame {
         event_id: event_id.to_string(),
-        home: crate::shared::transform::to_team(home_competitor, is_college),
-        away: crate::shared::transform::to_team(away_competitor, is_college),
-        start_time: parse_espn_date(&event.date),
+        home: crate::shared::transform::to_team(home_competitor, league),
+        away: crate::shared::transform::to_team(away_competitor, league),
+        season: to_season(event, league),
+        start_time,
+        seconds_until_start: seconds_until(start_time),
+        seconds_until_kickoff: seconds_until_kickoff(start_time),
         venue: venue.map(|v| v.full_name.clone()),
         broadcast: get_broadcast(event),
         weather: if is_outdoor {
@@ -54,7 +64,7 @@ fn to_pregame(
         } else {
             None
         },
-    }
+    })
 }
 
 /// Transform to live game response
@@ -63,14 +73,15 @@ fn to_live(
     competition: &EspnCompetition,
     event_id: &str,
     league: FootballLeague,
-) -> FootballLive {
-    let (home_competitor, away_competitor) = get_competitors(&competition.competitors);
-    let is_college = league.is_college();
+) -> Result<FootballLive, AppError> {
+    let (home_competitor, away_competitor) = get_competitors(&competition.competitors, event_id)?;
     let situation = competition.situation.as_ref();
     let last_play = situation.and_then(|s| s.last_play.as_ref()).map(to_last_play);
 
     // Compute clock_running based on game status and last play
     let clock_running = compute_clock_running(event, last_play.as_ref());
+    let period = parse_period(event.status.period, &event.status.status_type.id);
+    let clock_seconds = event.status.clock.clamp(0.0, u16::MAX as f64) as u16;
 
     // Weather is available for outdoor venues during live games
     let venue = competition.venue.as_ref();
@@ -86,17 +97,27 @@ fn to_live(
         None
     };
 
-    FootballLive {
+    Ok(FootballLive {
         event_id: event_id.to_string(),
-        home: to_team_with_score(home_competitor, situation.and_then(|s| s.home_timeouts), is_college),
-        away: to_team_with_score(away_competitor, situation.and_then(|s| s.away_timeouts), is_college),
-        period: parse_period(event.status.period, &event.status.status_type.id),
+        home: to_team_with_score(home_competitor, situation.and_then(|s| s.home_timeouts), league),
+        away: to_team_with_score(away_competitor, situation.and_then(|s| s.away_timeouts), league),
+        season: to_season(event, league),
+        period,
         clock: event.status.display_clock.clone(),
         clock_running,
+        inside_two_minutes: period.is_inside_two_minutes(clock_seconds),
+        is_close_game: period.is_close_game(
+            parse_score(&home_competitor.score),
+            parse_score(&away_competitor.score),
+        ),
         situation: situation.and_then(|s| to_situation(s, home_competitor, away_competitor)),
         last_play,
         weather,
-    }
+        leaders: to_leaders(&competition.leaders, &competition.competitors),
+        recent_plays: None,
+        score_changed_since_last_poll: false,
+        lead_changed: false,
+    })
 }
 
 /// Transform to final game response
@@ -105,41 +126,144 @@ fn to_final(
     competition: &EspnCompetition,
     event_id: &str,
     league: FootballLeague,
-) -> FootballFinal {
-    let (home_competitor, away_competitor) = get_competitors(&competition.competitors);
-    let is_college = league.is_college();
+) -> Result<FootballFinal, AppError> {
+    let (home_competitor, away_competitor) = get_competitors(&competition.competitors, event_id)?;
 
     let home_score = parse_score(&home_competitor.score);
     let away_score = parse_score(&away_competitor.score);
 
-    let situation = competition.situation.as_ref();
-
-    FootballFinal {
+    // Timeouts left over once the game ends mean nothing, so don't report them
+    Ok(FootballFinal {
         event_id: event_id.to_string(),
-        home: to_team_with_score(home_competitor, situation.and_then(|s| s.home_timeouts), is_college),
-        away: to_team_with_score(away_competitor, situation.and_then(|s| s.away_timeouts), is_college),
+        home: to_team_with_score(home_competitor, None, league),
+        away: to_team_with_score(away_competitor, None, league),
+        season: to_season(event, league),
         status: if event.status.period > 4 {
             FinalStatus::FinalOvertime
         } else {
             FinalStatus::Final
         },
         winner: determine_winner(home_score, away_score),
+        leaders: to_leaders(&competition.leaders, &competition.competitors),
+        score_changed_since_last_poll: false,
+        lead_changed: false,
+    })
+}
+
+/// The event's season and week, with a label to show for it.
+fn to_season(event: &EspnEvent, league: FootballLeague) -> Option<Season> {
+    let season = event.season.as_ref()?;
+    let season_type = SeasonType::from_espn(season.season_type)?;
+    let week = event.week.as_ref().map(|w| w.number);
+
+    Some(Season {
+        year: season.year,
+        season_type,
+        week,
+        label: season_label(season_type, week, league),
+    })
+}
+
+/// Name a week as a broadcast would, e.g. "Preseason Wk 2" or "Divisional Round".
+fn season_label(season_type: SeasonType, week: Option<u8>, league: FootballLeague) -> String {
+    match (season_type, week) {
+        (SeasonType::Preseason, Some(week)) => format!("Preseason Wk {week}"),
+        (SeasonType::Preseason, None) => "Preseason".to_string(),
+        (SeasonType::Regular, Some(week)) => format!("Week {week}"),
+        (SeasonType::Regular, None) => "Regular Season".to_string(),
+        (SeasonType::Postseason, Some(week)) if league == FootballLeague::Nfl => match week {
+            1 => "Wild Card".to_string(),
+            2 => "Divisional Round".to_string(),
+            3 => "Conference Championship".to_string(),
+            4 => "Pro Bowl".to_string(),
+            _ => "Super Bowl".to_string(),
+        },
+        (SeasonType::Postseason, _) => "Postseason".to_string(),
+        (SeasonType::Offseason, _) => "Offseason".to_string(),
     }
 }
 
+/// Passing, rushing, and receiving leaders, if ESPN listed any.
+///
+/// Scoreboard categories are named like "passingLeader"; other feeds use
+/// "passingYards", so match on the prefix.
+fn to_leaders(
+    categories: &[EspnLeaderCategory],
+    competitors: &[EspnCompetitor],
+) -> Option<GameLeaders> {
+    let leader = |prefix: &str| {
+        let category = categories.iter().find(|c| c.name.starts_with(prefix))?;
+        let leader = category.leaders.first()?;
+        let athlete = leader.athlete.as_ref()?;
+        let name = athlete.short_name.clone().or_else(|| athlete.display_name.clone())?;
+        let team = leader.team.as_ref().and_then(|team| {
+            competitors
+                .iter()
+                .find(|c| c.team.id == team.id)
+                .map(|c| c.team.abbreviation.clone())
+        });
+        Some(Leader {
+            name,
+            team,
+            stat_line: leader.display_value.clone(),
+        })
+    };
+
+    let leaders = GameLeaders {
+        passing: leader("passing"),
+        rushing: leader("rushing"),
+        receiving: leader("receiving"),
+    };
+    (leaders.passing.is_some() || leaders.rushing.is_some() || leaders.receiving.is_some())
+        .then_some(leaders)
+}
+
+/// The last `count` plays of a game's play-by-play, newest first.
+/// Plays ESPN lists without a period or clock are left out.
+pub fn to_recent_plays(summary: &EspnFootballSummary, count: usize) -> Vec<RecentPlay> {
+    let Some(drives) = &summary.drives else {
+        return Vec::new();
+    };
+    let plays: Vec<_> = drives.plays().collect();
+    plays
+        .into_iter()
+        .rev()
+        .filter_map(|play| {
+            let text = play.text.clone();
+            let play_type = play.play_type.as_ref().map_or(PlayType::Unknown, |t| {
+                PlayType::from_espn_id_with_context(&t.id, text.as_deref())
+            });
+            Some(RecentPlay {
+                play_type,
+                text,
+                period: parse_period(play.period.as_ref()?.number, ""),
+                clock: play.clock.as_ref()?.display_value.clone(),
+            })
+        })
+        .take(count)
+        .collect()
+}
+
 /// Transform ESPN competitor to our FootballTeamScore type
 fn to_team_with_score(
     competitor: &EspnCompetitor,
     timeouts: Option<u8>,
-    is_college: bool,
+    league: impl EspnLeague,
 ) -> FootballTeamScore {
     FootballTeamScore {
         abbreviation: competitor.team.abbreviation.clone(),
         color: parse_hex_color(competitor.team.color.as_deref().unwrap_or("000000")),
+        color_secondary: secondary_color(competitor),
         record: competitor.records.first().map(|r| r.summary.clone()),
-        rank: parse_rank(competitor, is_college),
+        rank: parse_rank(competitor, league.is_college()),
+        logo_path: league.logo_path(&competitor.team.id, &competitor.team.abbreviation),
         score: parse_score(&competitor.score),
-        timeouts: timeouts.unwrap_or(0),
+        timeouts,
+        linescore: competitor
+            .linescores
+            .iter()
+            .map(|l| l.value.clamp(0.0, u8::MAX as f64) as u8)
+            .collect(),
     }
 }
 
@@ -154,13 +278,37 @@ fn to_situation(
     let distance = situation.distance.filter(|&v| v >= 0).map(|v| v as u8)?;
     let yard_line = situation.yard_line.filter(|&v| v >= 0).map(|v| v as u8)?;
     let possession_id = situation.possession.as_ref()?;
+    let possession = determine_possession(possession_id, &home.team.id, &away.team.id);
+
+    let ball_on = match situation.possession_text.as_deref().map(str::trim) {
+        Some(text) if !text.is_empty() => text.to_string(),
+        _ => {
+            let (offense, defense) = match possession {
+                Possession::Home => (home, away),
+                Possession::Away => (away, home),
+            };
+            Situation::format_ball_on(
+                yard_line,
+                &offense.team.abbreviation,
+                &defense.team.abbreviation,
+            )
+        }
+    };
+
+    let goal_to_go = match &situation.short_down_distance_text {
+        Some(text) => text.contains("Goal"),
+        None => yard_line.saturating_add(distance) >= 100,
+    };
 
     Some(Situation {
         down: parse_down(down)?,
         distance,
         yard_line,
-        possession: determine_possession(possession_id, &home.team.id, &away.team.id),
+        possession,
         red_zone: situation.is_red_zone.unwrap_or(false),
+        goal_to_go,
+        ball_on,
+        direction: possession.direction(),
     })
 }
 
@@ -278,3 +426,190 @@ fn compute_clock_running(event: &EspnEvent, last_play: Option<&LastPlay>) -> boo
     // Default: assume clock is running during in-progress status
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::{Direction, GamesQuery};
+
+    #[test]
+    fn test_recent_plays_newest_first_without_duplicates() {
+        let play = |id: &str, type_id: &str, clock: &str| {
+            serde_json::json!({
+                "id": id,
+                "type": { "id": type_id },
+                "text": format!("play {id}"),
+                "period": { "number": 2 },
+                "clock": { "displayValue": clock }
+            })
+        };
+        let summary: EspnFootballSummary = serde_json::from_value(serde_json::json!({
+            "drives": {
+                "previous": [
+                    { "plays": [play("1", "5", "9:00"), play("2", "52", "8:30")] },
+                    { "plays": [play("3", "24", "8:00")] }
+                ],
+                "current": { "plays": [play("3", "24", "8:00"), play("4", "3", "7:40")] }
+            }
+        }))
+        .unwrap();
+
+        let plays = to_recent_plays(&summary, 3);
+        let texts: Vec<_> = plays.iter().map(|p| p.text.as_deref().unwrap()).collect();
+        assert_eq!(texts, ["play 4", "play 3", "play 2"]);
+        assert_eq!(plays[0].play_type, PlayType::PassIncompletion);
+        assert_eq!(plays[0].period, FootballPeriod::Q2);
+        assert_eq!(plays[0].clock, "7:40");
+    }
+
+    #[test]
+    fn test_leaders_from_scoreboard_categories() {
+        let competition: EspnCompetition = serde_json::from_value(serde_json::json!({
+            "competitors": [
+                { "team": { "id": "12", "abbreviation": "KC" }, "homeAway": "home" },
+                { "team": { "id": "2", "abbreviation": "BUF" }, "homeAway": "away" }
+            ],
+            "leaders": [
+                {
+                    "name": "passingLeader",
+                    "leaders": [{
+                        "displayValue": "24/35, 298 YDS, 3 TD",
+                        "athlete": { "displayName": "Patrick Mahomes", "shortName": "P. Mahomes" },
+                        "team": { "id": "12" }
+                    }]
+                },
+                { "name": "rushingLeader", "leaders": [] }
+            ]
+        }))
+        .unwrap();
+
+        let leaders = to_leaders(&competition.leaders, &competition.competitors).unwrap();
+        let passing = leaders.passing.unwrap();
+        assert_eq!(passing.name, "P. Mahomes");
+        assert_eq!(passing.team.as_deref(), Some("KC"));
+        assert_eq!(passing.stat_line, "24/35, 298 YDS, 3 TD");
+        assert!(leaders.rushing.is_none());
+        assert!(leaders.receiving.is_none());
+
+        assert!(to_leaders(&[], &competition.competitors).is_none());
+    }
+
+    #[test]
+    fn test_timeouts_unknown_when_espn_omits_them() {
+        let competitor: EspnCompetitor = serde_json::from_value(serde_json::json!({
+            "team": { "id": "12", "abbreviation": "KC" },
+            "homeAway": "home",
+            "score": "21"
+        }))
+        .unwrap();
+
+        let team = to_team_with_score(&competitor, None, FootballLeague::Nfl);
+        assert_eq!(team.timeouts, None);
+        assert!(serde_json::to_value(&team).unwrap().get("timeouts").is_none());
+
+        let team = to_team_with_score(&competitor, Some(2), FootballLeague::Nfl);
+        assert_eq!(team.timeouts, Some(2));
+    }
+
+    #[test]
+    fn test_ball_on_prefers_espn_text_then_computes() {
+        let competitors: Vec<EspnCompetitor> = serde_json::from_value(serde_json::json!([
+            { "team": { "id": "12", "abbreviation": "KC" }, "homeAway": "home" },
+            { "team": { "id": "2", "abbreviation": "BUF" }, "homeAway": "away" }
+        ]))
+        .unwrap();
+        let situation = |possession_text: Option<&str>, yard_line: i8| -> EspnSituation {
+            serde_json::from_value(serde_json::json!({
+                "down": 1,
+                "distance": 10,
+                "yardLine": yard_line,
+                "possession": "2",
+                "possessionText": possession_text
+            }))
+            .unwrap()
+        };
+
+        let s = to_situation(&situation(Some("BUF 35"), 35), &competitors[0], &competitors[1]).unwrap();
+        assert_eq!(s.ball_on, "BUF 35");
+        assert_eq!(s.direction, Direction::Right);
+
+        let s = to_situation(&situation(None, 70), &competitors[0], &competitors[1]).unwrap();
+        assert_eq!(s.ball_on, "KC 30");
+
+        assert_eq!(Situation::format_ball_on(50, "KC", "BUF"), "50");
+        assert_eq!(Situation::format_ball_on(1, "KC", "BUF"), "KC 1");
+    }
+
+    #[test]
+    fn test_season_labels() {
+        let event: EspnEvent = serde_json::from_value(serde_json::json!({
+            "id": "401671889",
+            "date": "2025-01-18T21:30Z",
+            "status": { "period": 0, "displayClock": "0:00", "type": { "id": "1", "state": "pre", "shortDetail": "" } },
+            "competitions": [],
+            "season": { "year": 2024, "type": 3 },
+            "week": { "number": 2 }
+        }))
+        .unwrap();
+
+        let season = to_season(&event, FootballLeague::Nfl).unwrap();
+        assert_eq!(season.year, 2024);
+        assert_eq!(season.season_type, SeasonType::Postseason);
+        assert_eq!(season.label, "Divisional Round");
+        assert_eq!(to_season(&event, FootballLeague::Ncaaf).unwrap().label, "Postseason");
+
+        assert_eq!(season_label(SeasonType::Preseason, Some(2), FootballLeague::Nfl), "Preseason Wk 2");
+        assert_eq!(season_label(SeasonType::Regular, Some(5), FootballLeague::Ncaaf), "Week 5");
+    }
+
+    #[test]
+    fn test_seasontype_query_takes_number_or_name() {
+        let parse = |query: &str| {
+            let uri = format!("/games?{query}").parse().unwrap();
+            axum::extract::Query::<GamesQuery>::try_from_uri(&uri).map(|q| q.0.seasontype)
+        };
+        assert_eq!(parse("seasontype=1").unwrap(), Some(SeasonType::Preseason));
+        assert_eq!(parse("seasontype=postseason").unwrap(), Some(SeasonType::Postseason));
+        assert_eq!(parse("").unwrap(), None);
+        assert!(parse("seasontype=7").is_err());
+    }
+
+    #[test]
+    fn test_late_game_flags() {
+        assert!(FootballPeriod::Q2.is_inside_two_minutes(120));
+        assert!(FootballPeriod::OT.is_inside_two_minutes(45));
+        assert!(!FootballPeriod::Q3.is_inside_two_minutes(30));
+        assert!(!FootballPeriod::Q4.is_inside_two_minutes(121));
+
+        assert!(FootballPeriod::Q4.is_close_game(17, 24));
+        assert!(!FootballPeriod::Q4.is_close_game(14, 23));
+        assert!(!FootballPeriod::Q3.is_close_game(21, 21));
+    }
+
+    #[test]
+    fn test_goal_to_go() {
+        let competitors: Vec<EspnCompetitor> = serde_json::from_value(serde_json::json!([
+            { "team": { "id": "12", "abbreviation": "KC" }, "homeAway": "home" },
+            { "team": { "id": "2", "abbreviation": "BUF" }, "homeAway": "away" }
+        ]))
+        .unwrap();
+        let situation = |text: Option<&str>, distance: i8, yard_line: i8| -> EspnSituation {
+            serde_json::from_value(serde_json::json!({
+                "down": 1,
+                "distance": distance,
+                "yardLine": yard_line,
+                "possession": "12",
+                "shortDownDistanceText": text
+            }))
+            .unwrap()
+        };
+        let goal_to_go = |s: &EspnSituation| {
+            to_situation(s, &competitors[0], &competitors[1]).unwrap().goal_to_go
+        };
+
+        assert!(goal_to_go(&situation(Some("1st & Goal"), 5, 95)));
+        assert!(!goal_to_go(&situation(Some("2nd & 7"), 7, 60)));
+        assert!(goal_to_go(&situation(None, 3, 97)));
+        assert!(!goal_to_go(&situation(None, 10, 85)));
+    }
+}
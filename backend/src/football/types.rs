@@ -1,11 +1,54 @@
+use async_graphql::{Enum, SimpleObject, Union};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::shared::types::{Color, FinalStatus, Season, SeasonType, Team, Weather, Winner};
+
+/// Query parameters for the football game endpoints
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct GamesQuery {
+    /// Merge simulated games from the mock repository into NFL results.
+    /// Overrides the `mock.include_in_listings` config setting.
+    pub include_mock: Option<bool>,
+    /// Optional blocks to add to live and final games, comma-separated:
+    /// `leaders` (passing, rushing, and receiving leaders) and `recent_plays`
+    /// (the last few plays of live games)
+    pub include: Option<String>,
+    /// Plays to return with `include=recent_plays` (default 5, at most 20)
+    pub count: Option<usize>,
+    /// Part of the season to list games from: 1 = preseason, 2 = regular
+    /// season, 3 = postseason. Defaults to whichever ESPN shows now.
+    #[param(value_type = Option<u8>, minimum = 1, maximum = 4)]
+    pub seasontype: Option<SeasonType>,
+    /// The `X-Sequence` header from the last poll. Live and final games then
+    /// report whether their score or lead changed since. Sequences belong to
+    /// the instance that sent them and start over when it restarts.
+    pub since: Option<u64>,
+}
+
+/// Plays returned with `include=recent_plays` when no `count` is given
+const DEFAULT_RECENT_PLAYS: usize = 5;
+/// Most plays `include=recent_plays` returns
+const MAX_RECENT_PLAYS: usize = 20;
+
+impl GamesQuery {
+    /// Whether `block` was asked for in `include`.
+    pub fn includes(&self, block: &str) -> bool {
+        self.include
+            .as_deref()
+            .is_some_and(|include| include.split(',').any(|b| b.trim() == block))
+    }
 
-use crate::shared::types::{Color, FinalStatus, Team, Weather, Winner};
+    /// How many plays to add to live games, if `recent_plays` was asked for.
+    pub fn recent_plays(&self) -> Option<usize> {
+        self.includes("recent_plays")
+            .then(|| self.count.unwrap_or(DEFAULT_RECENT_PLAYS).min(MAX_RECENT_PLAYS))
+    }
+}
 
 /// The football API response - a tagged enum that serializes with "state" discriminator.
 /// Shared by NFL and NCAAF.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, Union)]
 #[serde(tag = "state", rename_all = "lowercase")]
 pub enum FootballGameResponse {
     Pregame(FootballPregame),
@@ -14,12 +57,25 @@ pub enum FootballGameResponse {
 }
 
 /// Football pregame data
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct FootballPregame {
     pub event_id: String,
     pub home: Team,
     pub away: Team,
+    /// Season and week, for labels like "Preseason Wk 2" (ESPN games only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,
     pub start_time: i64,
+    /// Seconds until the scheduled kickoff as of this response, for displays
+    /// without a clock. Negative once kickoff time has passed (e.g., a delay);
+    /// 0 if unknown.
+    pub seconds_until_start: i64,
+    /// Deprecated: use `seconds_until_start`. The same countdown, never
+    /// negative; absent if unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(deprecated)]
+    #[graphql(deprecation = "Use secondsUntilStart")]
+    pub seconds_until_kickoff: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub venue: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -29,50 +85,213 @@ pub struct FootballPregame {
 }
 
 /// Football team with score and timeouts (for live/final games)
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct FootballTeamScore {
     pub abbreviation: String,
     pub color: Color,
+    /// Secondary color for two-tone displays (white if ESPN lists none)
+    pub color_secondary: Color,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record: Option<String>,
     /// AP/Coaches ranking (college sports only; absent for pro leagues)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<u8>,
+    /// Path of the team's logo endpoint, e.g. "/api/football/ncaaf/228/logo"
+    /// (college teams by ESPN team ID, since their abbreviations collide)
+    pub logo_path: String,
     pub score: u8,
-    pub timeouts: u8,
+    /// Timeouts remaining, absent when ESPN doesn't report them or the game is over
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeouts: Option<u8>,
+    /// Points scored in each period so far (Q1..Q4, then overtime periods)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub linescore: Vec<u8>,
+}
+
+#[cfg(test)]
+impl FootballTeamScore {
+    /// An NFL team's score for tests, with default colors and nothing else set
+    pub fn for_test(abbreviation: &str, score: u8) -> Self {
+        Self {
+            abbreviation: abbreviation.to_string(),
+            color: Color::default(),
+            color_secondary: Color::default(),
+            record: None,
+            rank: None,
+            logo_path: format!("/api/football/nfl/{}/logo", abbreviation.to_lowercase()),
+            score,
+            timeouts: None,
+            linescore: Vec::new(),
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_record(mut self, record: &str) -> Self {
+        self.record = Some(record.to_string());
+        self
+    }
+
+    pub fn with_timeouts(mut self, timeouts: Option<u8>) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    pub fn with_linescore(mut self, linescore: Vec<u8>) -> Self {
+        self.linescore = linescore;
+        self
+    }
 }
 
 /// Football live game data
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct FootballLive {
     pub event_id: String,
     pub home: FootballTeamScore,
     pub away: FootballTeamScore,
+    /// Season and week (ESPN games only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,
     pub period: FootballPeriod,
     pub clock: String,
     /// Whether the game clock is believed to be running.
     /// Computed from game status and last play type using NFL rules.
     pub clock_running: bool,
+    /// Two minutes or less left in the half or in overtime
+    pub inside_two_minutes: bool,
+    /// Fourth quarter or overtime, with the margin at 8 points or less
+    pub is_close_game: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub situation: Option<Situation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_play: Option<LastPlay>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub weather: Option<Weather>,
+    /// Stat leaders, with `?include=leaders` (ESPN games only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leaders: Option<GameLeaders>,
+    /// The last few plays, newest first, with `?include=recent_plays`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recent_plays: Option<Vec<RecentPlay>>,
+    /// Whether either score changed since the `since` sequence number
+    pub score_changed_since_last_poll: bool,
+    /// Whether the other team took the lead since the `since` sequence number
+    pub lead_changed: bool,
 }
 
 /// Football final game data
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct FootballFinal {
     pub event_id: String,
     pub home: FootballTeamScore,
     pub away: FootballTeamScore,
+    /// Season and week (ESPN games only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,
     pub status: FinalStatus,
     pub winner: Winner,
+    /// Stat leaders, with `?include=leaders` (ESPN games only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leaders: Option<GameLeaders>,
+    /// Whether either score changed since the `since` sequence number
+    pub score_changed_since_last_poll: bool,
+    /// Whether the other team took the lead since the `since` sequence number
+    pub lead_changed: bool,
+}
+
+/// The game's passing, rushing, and receiving leaders
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+pub struct GameLeaders {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passing: Option<Leader>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rushing: Option<Leader>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiving: Option<Leader>,
+}
+
+/// A player leading a stat category
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+pub struct Leader {
+    /// Short player name (e.g., "P. Mahomes")
+    pub name: String,
+    /// Team abbreviation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// Stat line (e.g., "24/35, 298 YDS, 3 TD")
+    pub stat_line: String,
+}
+
+/// A play from the play-by-play
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+pub struct RecentPlay {
+    pub play_type: PlayType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    pub period: FootballPeriod,
+    /// Game clock at the snap
+    pub clock: String,
+}
+
+impl FootballGameResponse {
+    /// The ESPN event ID, or the `sim_*` ID of a mock game.
+    pub fn event_id(&self) -> &str {
+        match self {
+            FootballGameResponse::Pregame(game) => &game.event_id,
+            FootballGameResponse::Live(game) => &game.event_id,
+            FootballGameResponse::Final(game) => &game.event_id,
+        }
+    }
+
+    /// Home and away scores, once the game has started.
+    pub fn scores(&self) -> Option<(u8, u8)> {
+        match self {
+            FootballGameResponse::Pregame(_) => None,
+            FootballGameResponse::Live(game) => Some((game.home.score, game.away.score)),
+            FootballGameResponse::Final(game) => Some((game.home.score, game.away.score)),
+        }
+    }
+
+    /// Mark whether the score and lead changed since the client's last poll.
+    pub fn set_changes(&mut self, score_changed: bool, lead_changed: bool) {
+        match self {
+            FootballGameResponse::Pregame(_) => {}
+            FootballGameResponse::Live(game) => {
+                game.score_changed_since_last_poll = score_changed;
+                game.lead_changed = lead_changed;
+            }
+            FootballGameResponse::Final(game) => {
+                game.score_changed_since_last_poll = score_changed;
+                game.lead_changed = lead_changed;
+            }
+        }
+    }
+
+    /// Attach recent plays to a live game. Other states don't carry them.
+    pub fn set_recent_plays(&mut self, plays: Vec<RecentPlay>) {
+        if let FootballGameResponse::Live(game) = self {
+            game.recent_plays = Some(plays);
+        }
+    }
+
+    /// Drop the optional blocks the query didn't ask for.
+    pub fn retain_includes(&mut self, query: &GamesQuery) {
+        if query.includes("leaders") {
+            return;
+        }
+        match self {
+            FootballGameResponse::Pregame(_) => {}
+            FootballGameResponse::Live(game) => game.leaders = None,
+            FootballGameResponse::Final(game) => game.leaders = None,
+        }
+    }
 }
 
 /// Football period (quarter / overtime / halftime)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Enum)]
 pub enum FootballPeriod {
     Q1,
     Q2,
@@ -85,18 +304,55 @@ pub enum FootballPeriod {
     Halftime,
 }
 
+/// Largest deficit one possession can erase: a touchdown and two-point conversion
+const ONE_SCORE: u8 = 8;
+
+impl FootballPeriod {
+    fn is_overtime(self) -> bool {
+        matches!(self, Self::OT | Self::OT2 | Self::OT3 | Self::OT4)
+    }
+
+    /// Two minutes or less left in the half or in overtime, where the
+    /// two-minute warning falls.
+    pub fn is_inside_two_minutes(self, clock_seconds: u16) -> bool {
+        (matches!(self, Self::Q2 | Self::Q4) || self.is_overtime()) && clock_seconds <= 120
+    }
+
+    /// Fourth quarter or overtime with the margin within one score.
+    pub fn is_close_game(self, home_score: u8, away_score: u8) -> bool {
+        (self == Self::Q4 || self.is_overtime()) && home_score.abs_diff(away_score) <= ONE_SCORE
+    }
+}
+
 /// Current play situation (only during active play)
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct Situation {
     pub down: Down,
     pub distance: u8,
     pub yard_line: u8,
     pub possession: Possession,
     pub red_zone: bool,
+    /// The goal line is the line to gain ("1st & Goal")
+    pub goal_to_go: bool,
+    /// Line of scrimmage as broadcasts show it, e.g. "KC 35" or "50"
+    pub ball_on: String,
+    /// Which way the offense is driving on a field with the away team drawn on the left
+    pub direction: Direction,
+}
+
+impl Situation {
+    /// Format a `yard_line` (yards from the offense's own goal line) as "KC 35".
+    pub fn format_ball_on(yard_line: u8, offense: &str, defense: &str) -> String {
+        match yard_line {
+            50 => "50".to_string(),
+            0..50 => format!("{offense} {yard_line}"),
+            _ => format!("{defense} {}", 100u8.saturating_sub(yard_line)),
+        }
+    }
 }
 
 /// Down as a strongly-typed enum
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Enum)]
 #[serde(rename_all = "lowercase")]
 pub enum Down {
     First,
@@ -106,21 +362,98 @@ pub enum Down {
 }
 
 /// Possession indicator
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Enum)]
 #[serde(rename_all = "lowercase")]
 pub enum Possession {
     Home,
     Away,
 }
 
+impl Possession {
+    /// The away team drives right toward the home side, the home team left.
+    pub fn direction(self) -> Direction {
+        match self {
+            Possession::Home => Direction::Left,
+            Possession::Away => Direction::Right,
+        }
+    }
+}
+
+/// Direction of travel across the display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Enum)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Left,
+    Right,
+}
+
 /// Last play information (simplified)
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct LastPlay {
     pub play_type: PlayType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 }
 
+/// Summary of a single offensive drive
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DriveSummary {
+    /// Team with the ball for this drive
+    pub possession: Possession,
+    /// How the drive ended. Absent while the drive is still in progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<DriveResult>,
+    /// Number of plays from scrimmage (kickoffs excluded)
+    pub plays: u16,
+    /// Net yards gained from scrimmage
+    pub yards: i16,
+    /// Game clock consumed by the drive in "M:SS" format
+    pub time_of_possession: String,
+    /// Period the drive started in
+    pub start_period: FootballPeriod,
+    /// Game clock when the drive started
+    pub start_clock: String,
+}
+
+/// How a drive ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DriveResult {
+    Touchdown,
+    FieldGoal,
+    MissedFieldGoal,
+    Punt,
+    Turnover,
+    TurnoverOnDowns,
+    Safety,
+    EndOfHalf,
+    EndOfGame,
+}
+
+/// Team statistics for both sides of a game
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FootballBoxScore {
+    pub home: FootballTeamStats,
+    pub away: FootballTeamStats,
+}
+
+/// Per-team box score totals
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FootballTeamStats {
+    pub abbreviation: String,
+    /// Net yards from scrimmage (passing + rushing)
+    pub total_yards: i16,
+    /// Net passing yards (sack yardage subtracted)
+    pub passing_yards: i16,
+    pub rushing_yards: i16,
+    /// Interceptions thrown plus fumbles lost
+    pub turnovers: u8,
+    /// Time on offense in "M:SS" format
+    pub time_of_possession: String,
+    pub third_down_attempts: u8,
+    pub third_down_conversions: u8,
+}
+
 /// Play type from ESPN API.
 ///
 /// These IDs are reverse-engineered from ESPN's undocumented API.
@@ -128,7 +461,7 @@ pub struct LastPlay {
 /// - Live API observation from multiple NFL games
 /// - <https://gist.github.com/nntrn/ee26cb2a0716de0947a0a4e9a157bc1c>
 /// - <https://gist.github.com/akeaswaran/b48b02f1c94f873c6655e7129910fc3b>
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Enum)]
 #[serde(rename_all = "snake_case")]
 pub enum PlayType {
     // === Administrative / Game Flow ===
@@ -229,7 +562,7 @@ impl PlayType {
         if play_type == PlayType::Unknown {
             tracing::warn!(
                 play_type_id = %id,
-                "Unknown ESPN play type ID encountered - please report this!"
+                "Unknown ESPN play type ID encountered - please report this (see /api/admin/unknown-plays)"
             );
         }
         play_type
@@ -244,12 +577,17 @@ impl PlayType {
             tracing::warn!(
                 play_type_id = %id,
                 play_text = %text.unwrap_or("<no text>"),
-                "Unknown ESPN play type ID encountered - please report this!"
+                "Unknown ESPN play type ID encountered - please report this (see /api/admin/unknown-plays)"
             );
         }
         play_type
     }
 
+    /// Whether `id` maps to a known play type. Doesn't log.
+    pub fn is_known_espn_id(id: &str) -> bool {
+        Self::from_espn_id_inner(id) != PlayType::Unknown
+    }
+
     fn from_espn_id_inner(id: &str) -> Self {
         match id {
             // Administrative / Game Flow
@@ -400,3 +738,29 @@ impl PlayType {
         )
     }
 }
+
+/// An ESPN play type ID with no `PlayType` mapping yet
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnknownPlayType {
+    /// ESPN play type ID (e.g., "999")
+    pub play_type_id: String,
+    /// ESPN's name for the play type, when it sends one
+    pub espn_name: Option<String>,
+    /// Distinct plays seen with this ID
+    pub count: usize,
+    /// First time a play with this ID was seen (Unix seconds)
+    pub first_seen: i64,
+    /// Latest time a play with this ID was seen (Unix seconds)
+    pub last_seen: i64,
+    /// The first few plays seen with this ID
+    pub samples: Vec<UnknownPlaySample>,
+}
+
+/// A play ESPN sent with an unknown type ID
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnknownPlaySample {
+    /// ESPN event the play was in
+    pub event_id: String,
+    /// Play description
+    pub text: Option<String>,
+}
@@ -0,0 +1,156 @@
+//! GraphQL endpoint over the same game and team data as the REST API, for
+//! web dashboards that want to pick their fields rather than take the
+//! Pico-sized payloads. Enabled with `server.graphql`.
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{Context, EmptyMutation, EmptySubscription, ErrorExtensions, Object, Schema};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::extract::State;
+use axum::response::{Html, IntoResponse};
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::auth::ApiKey;
+use crate::basketball::transform::{transform_from_scoreboard, transform_from_summary};
+use crate::basketball::types::{BasketballGameDetail, BasketballGameResponse};
+use crate::error::AppError;
+use crate::football::handler::{self, fetch_game, fetch_scoreboard};
+use crate::football::types::{FootballGameResponse, GamesQuery};
+use crate::shared::transform::skip_malformed;
+use crate::sport::{BasketballLeague, FootballLeague};
+use crate::team::handler::measure_team_colors;
+use crate::team::types::TeamColors;
+
+pub type ScoreboardSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+pub fn schema() -> ScoreboardSchema {
+    Schema::build(Query, EmptyMutation, EmptySubscription).finish()
+}
+
+impl From<AppError> for async_graphql::Error {
+    fn from(error: AppError) -> Self {
+        let (_, code, message) = error.into_parts();
+        let code = serde_json::to_value(code).unwrap_or_default();
+        async_graphql::Error::new(message)
+            .extend_with(|_, extensions| extensions.set("code", code.as_str().unwrap_or_default()))
+    }
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// Games on a football league's current scoreboard (nfl or ncaaf)
+    async fn football_games(
+        &self,
+        ctx: &Context<'_>,
+        league: String,
+        include_mock: Option<bool>,
+    ) -> async_graphql::Result<Vec<FootballGameResponse>> {
+        let state = ctx.data::<Arc<AppState>>()?;
+        let league = FootballLeague::from_league(&league)?;
+        let query = GamesQuery {
+            include_mock,
+            ..GamesQuery::default()
+        };
+        let include_mock = handler::include_mock(state, league, &query);
+        Ok(fetch_scoreboard(state, league, include_mock).await?)
+    }
+
+    /// One football game by ESPN event ID, or `sim_*` mock game ID
+    async fn football_game(
+        &self,
+        ctx: &Context<'_>,
+        league: String,
+        event_id: String,
+    ) -> async_graphql::Result<FootballGameResponse> {
+        let state = ctx.data::<Arc<AppState>>()?;
+        let league = FootballLeague::from_league(&league)?;
+        Ok(fetch_game(state, league, event_id, true).await?)
+    }
+
+    /// Games on a basketball league's current scoreboard (nba or ncaab)
+    async fn basketball_games(
+        &self,
+        ctx: &Context<'_>,
+        league: String,
+    ) -> async_graphql::Result<Vec<BasketballGameResponse>> {
+        let state = ctx.data::<Arc<AppState>>()?;
+        let league = BasketballLeague::from_league(&league)?;
+        let scoreboard = state.provider.fetch_scoreboard(league.into(), None).await?;
+        Ok(scoreboard
+            .events
+            .iter()
+            .filter_map(|e| skip_malformed(&e.id, transform_from_scoreboard(e, league)))
+            .collect())
+    }
+
+    /// One basketball game by ESPN event ID, with team fouls
+    async fn basketball_game(
+        &self,
+        ctx: &Context<'_>,
+        league: String,
+        event_id: String,
+    ) -> async_graphql::Result<BasketballGameDetail> {
+        let state = ctx.data::<Arc<AppState>>()?;
+        let league = BasketballLeague::from_league(&league)?;
+        if !event_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AppError::InvalidEventId(event_id).into());
+        }
+        let summary = state.provider.fetch_summary(league.into(), &event_id).await?;
+        Ok(transform_from_summary(&summary, league)?)
+    }
+
+    /// Simulated games in the mock repository
+    async fn mock_games(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<FootballGameResponse>> {
+        let state = ctx.data::<Arc<AppState>>()?;
+        let games = state.game_repository.list().await;
+        Ok(games.iter().map(|g| g.to_game_response()).collect())
+    }
+
+    /// Colors measured from an NFL team's logo
+    async fn team_colors(
+        &self,
+        ctx: &Context<'_>,
+        team: String,
+        #[graphql(default = 4)] count: u8,
+    ) -> async_graphql::Result<TeamColors> {
+        let state = ctx.data::<Arc<AppState>>()?;
+        Ok(measure_team_colors(state, &team, count).await?)
+    }
+}
+
+/// POST /graphql
+pub async fn graphql(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let request = request.into_inner().data(state.clone());
+    state.graphql.execute(request).await.into()
+}
+
+/// GET /graphql — GraphiQL, for exploring the schema in a browser
+pub async fn graphiql() -> impl IntoResponse {
+    Html(GraphiQLSource::build().endpoint("/graphql").finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_exposes_games_and_teams() {
+        let sdl = schema().sdl();
+        assert!(sdl.contains("footballGames(league: String!, includeMock: Boolean)"));
+        assert!(sdl.contains("union FootballGameResponse = FootballPregame | FootballLive | FootballFinal"));
+        assert!(sdl.contains("teamColors(team: String!, count: Int! = 4): TeamColors!"));
+    }
+
+    #[test]
+    fn test_errors_carry_their_code() {
+        let error: async_graphql::Error = AppError::InvalidEventId("abc".to_string()).into();
+        let code = error.extensions.unwrap().get("code").cloned();
+        assert_eq!(code, Some(async_graphql::Value::from("invalid_event_id")));
+    }
+}
@@ -0,0 +1,280 @@
+//! Conversions from the REST response types to protobuf messages. Enums
+//! become their JSON names, so both APIs spell values the same way.
+
+use super::proto;
+use crate::basketball::types::{
+    BasketballFinal, BasketballFinalDetail, BasketballGameDetail, BasketballGameResponse,
+    BasketballLive, BasketballLiveDetail, BasketballPregame, BasketballTeamScore,
+    BasketballTeamScoreDetail,
+};
+use crate::football::types::{
+    FootballFinal, FootballGameResponse, FootballLive, FootballPregame, FootballTeamScore,
+    LastPlay, Situation,
+};
+use crate::shared::transform::json_name as name;
+use crate::shared::types::{Color, Season, Team, Weather};
+
+impl From<Color> for proto::Color {
+    fn from(color: Color) -> Self {
+        Self {
+            r: color.r.into(),
+            g: color.g.into(),
+            b: color.b.into(),
+        }
+    }
+}
+
+impl From<Team> for proto::Team {
+    fn from(team: Team) -> Self {
+        Self {
+            abbreviation: team.abbreviation,
+            color: Some(team.color.into()),
+            color_secondary: Some(team.color_secondary.into()),
+            record: team.record,
+            rank: team.rank.map(u32::from),
+            logo_path: team.logo_path,
+        }
+    }
+}
+
+impl From<FootballTeamScore> for proto::TeamScore {
+    fn from(team: FootballTeamScore) -> Self {
+        Self {
+            abbreviation: team.abbreviation,
+            color: Some(team.color.into()),
+            color_secondary: Some(team.color_secondary.into()),
+            record: team.record,
+            rank: team.rank.map(u32::from),
+            logo_path: team.logo_path,
+            score: team.score.into(),
+            timeouts: team.timeouts.map(u32::from),
+            linescore: team.linescore.into_iter().map(u32::from).collect(),
+            fouls: None,
+        }
+    }
+}
+
+impl From<BasketballTeamScore> for proto::TeamScore {
+    fn from(team: BasketballTeamScore) -> Self {
+        Self {
+            abbreviation: team.abbreviation,
+            color: Some(team.color.into()),
+            color_secondary: Some(team.color_secondary.into()),
+            record: team.record,
+            rank: team.rank.map(u32::from),
+            logo_path: team.logo_path,
+            score: team.score.into(),
+            timeouts: None,
+            linescore: Vec::new(),
+            fouls: None,
+        }
+    }
+}
+
+impl From<BasketballTeamScoreDetail> for proto::TeamScore {
+    fn from(team: BasketballTeamScoreDetail) -> Self {
+        Self {
+            abbreviation: team.abbreviation,
+            color: Some(team.color.into()),
+            color_secondary: Some(team.color_secondary.into()),
+            record: team.record,
+            rank: team.rank.map(u32::from),
+            logo_path: team.logo_path,
+            score: team.score.into(),
+            timeouts: None,
+            linescore: Vec::new(),
+            fouls: Some(team.fouls.into()),
+        }
+    }
+}
+
+impl From<Season> for proto::Season {
+    fn from(season: Season) -> Self {
+        Self {
+            year: season.year.into(),
+            r#type: name(season.season_type),
+            week: season.week.map(u32::from),
+            label: season.label,
+        }
+    }
+}
+
+impl From<Weather> for proto::Weather {
+    fn from(weather: Weather) -> Self {
+        Self {
+            temp: weather.temp.into(),
+            description: weather.description,
+        }
+    }
+}
+
+impl From<Situation> for proto::Situation {
+    fn from(situation: Situation) -> Self {
+        Self {
+            down: name(situation.down),
+            distance: situation.distance.into(),
+            yard_line: situation.yard_line.into(),
+            possession: name(situation.possession),
+            red_zone: situation.red_zone,
+            goal_to_go: situation.goal_to_go,
+            ball_on: situation.ball_on,
+            direction: name(situation.direction),
+        }
+    }
+}
+
+impl From<LastPlay> for proto::LastPlay {
+    fn from(play: LastPlay) -> Self {
+        Self {
+            play_type: name(play.play_type),
+            text: play.text,
+        }
+    }
+}
+
+impl From<FootballPregame> for proto::FootballPregame {
+    fn from(game: FootballPregame) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            season: game.season.map(Into::into),
+            start_time: game.start_time,
+            seconds_until_start: game.seconds_until_start,
+            venue: game.venue,
+            broadcast: game.broadcast,
+            weather: game.weather.map(Into::into),
+        }
+    }
+}
+
+impl From<FootballLive> for proto::FootballLive {
+    fn from(game: FootballLive) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            season: game.season.map(Into::into),
+            period: name(game.period),
+            clock: game.clock,
+            clock_running: game.clock_running,
+            inside_two_minutes: game.inside_two_minutes,
+            is_close_game: game.is_close_game,
+            situation: game.situation.map(Into::into),
+            last_play: game.last_play.map(Into::into),
+            weather: game.weather.map(Into::into),
+        }
+    }
+}
+
+impl From<FootballFinal> for proto::FootballFinal {
+    fn from(game: FootballFinal) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            season: game.season.map(Into::into),
+            status: name(game.status),
+            winner: name(game.winner),
+        }
+    }
+}
+
+impl From<FootballGameResponse> for proto::FootballGame {
+    fn from(game: FootballGameResponse) -> Self {
+        use proto::FootballState as State;
+        let (event_id, state) = match game {
+            FootballGameResponse::Pregame(g) => (g.event_id.clone(), State::Pregame(g.into())),
+            FootballGameResponse::Live(g) => (g.event_id.clone(), State::Live(g.into())),
+            FootballGameResponse::Final(g) => (g.event_id.clone(), State::Final(g.into())),
+        };
+        Self {
+            event_id,
+            state: Some(state),
+        }
+    }
+}
+
+impl From<BasketballPregame> for proto::BasketballPregame {
+    fn from(game: BasketballPregame) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            start_time: game.start_time,
+            seconds_until_start: game.seconds_until_start,
+            venue: game.venue,
+            broadcast: game.broadcast,
+        }
+    }
+}
+
+impl From<BasketballLive> for proto::BasketballLive {
+    fn from(game: BasketballLive) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            period: name(game.period),
+            clock: game.clock,
+        }
+    }
+}
+
+impl From<BasketballFinal> for proto::BasketballFinal {
+    fn from(game: BasketballFinal) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            status: name(game.status),
+            winner: name(game.winner),
+        }
+    }
+}
+
+impl From<BasketballLiveDetail> for proto::BasketballLive {
+    fn from(game: BasketballLiveDetail) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            period: name(game.period),
+            clock: game.clock,
+        }
+    }
+}
+
+impl From<BasketballFinalDetail> for proto::BasketballFinal {
+    fn from(game: BasketballFinalDetail) -> Self {
+        Self {
+            home: Some(game.home.into()),
+            away: Some(game.away.into()),
+            status: name(game.status),
+            winner: name(game.winner),
+        }
+    }
+}
+
+impl From<BasketballGameResponse> for proto::BasketballGame {
+    fn from(game: BasketballGameResponse) -> Self {
+        use proto::BasketballState as State;
+        let (event_id, state) = match game {
+            BasketballGameResponse::Pregame(g) => (g.event_id.clone(), State::Pregame(g.into())),
+            BasketballGameResponse::Live(g) => (g.event_id.clone(), State::Live(g.into())),
+            BasketballGameResponse::Final(g) => (g.event_id.clone(), State::Final(g.into())),
+        };
+        Self {
+            event_id,
+            state: Some(state),
+        }
+    }
+}
+
+impl From<BasketballGameDetail> for proto::BasketballGame {
+    fn from(game: BasketballGameDetail) -> Self {
+        use proto::BasketballState as State;
+        let (event_id, state) = match game {
+            BasketballGameDetail::Pregame(g) => (g.event_id.clone(), State::Pregame(g.into())),
+            BasketballGameDetail::Live(g) => (g.event_id.clone(), State::Live(g.into())),
+            BasketballGameDetail::Final(g) => (g.event_id.clone(), State::Final(g.into())),
+        };
+        Self {
+            event_id,
+            state: Some(state),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! gRPC service over the same game data as the REST API, for LAN services
+//! that prefer protobuf tooling. Enabled by setting `server.grpc_port`; the
+//! schema for clients is `proto/scoreboard.proto`.
+
+mod convert;
+pub mod proto;
+
+use axum::extract::FromRequestParts;
+use axum::http::{self, StatusCode};
+use futures_util::stream::{self, Stream};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tonic::{Request, Response, Status};
+
+use crate::AppState;
+use crate::auth::ApiKey;
+use crate::basketball::transform::{transform_from_scoreboard, transform_from_summary};
+use crate::error::AppError;
+use crate::football::handler::{self, fetch_game, fetch_scoreboard};
+use crate::football::types::GamesQuery;
+use crate::shared::transform::skip_malformed;
+use crate::sport::{BasketballLeague, FootballLeague};
+use proto::scoreboard_server::{Scoreboard, ScoreboardServer};
+
+/// Seconds between checks in `WatchGame` when the client doesn't say
+const DEFAULT_WATCH_INTERVAL_SECS: u32 = 5;
+
+/// Bounds on a client's `WatchGame` interval, so a client can't poll ESPN
+/// through us faster than the REST rate limit would allow
+const WATCH_INTERVAL_SECS: std::ops::RangeInclusive<u32> = 1..=60;
+
+impl From<AppError> for Status {
+    fn from(error: AppError) -> Self {
+        let (status, code, message) = error.into_parts();
+        let grpc_code = match status {
+            StatusCode::BAD_REQUEST => tonic::Code::InvalidArgument,
+            StatusCode::UNAUTHORIZED => tonic::Code::Unauthenticated,
+            StatusCode::FORBIDDEN => tonic::Code::PermissionDenied,
+            StatusCode::NOT_FOUND => tonic::Code::NotFound,
+            StatusCode::TOO_MANY_REQUESTS => tonic::Code::ResourceExhausted,
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
+                tonic::Code::Unavailable
+            }
+            _ => tonic::Code::Internal,
+        };
+        let mut status = Status::new(grpc_code, message);
+        let code = serde_json::to_value(code).unwrap_or_default();
+        if let Ok(code) = code.as_str().unwrap_or_default().parse() {
+            status.metadata_mut().insert("x-error-code", code);
+        }
+        status
+    }
+}
+
+/// Check the request's credentials the same way REST routes do, from its
+/// `x-api-key` or `authorization` metadata.
+async fn authenticate<T>(state: &Arc<AppState>, request: &Request<T>) -> Result<(), Status> {
+    let mut builder = http::Request::builder();
+    if let Some(headers) = builder.headers_mut() {
+        *headers = request.metadata().clone().into_headers();
+    }
+    let (mut parts, ()) = builder
+        .body(())
+        .map_err(|e| Status::internal(e.to_string()))?
+        .into_parts();
+    ApiKey::from_request_parts(&mut parts, state).await?;
+    Ok(())
+}
+
+pub struct ScoreboardService {
+    state: Arc<AppState>,
+}
+
+impl ScoreboardService {
+    pub fn new(state: Arc<AppState>) -> ScoreboardServer<Self> {
+        ScoreboardServer::new(Self { state })
+    }
+}
+
+/// Polling state for one `WatchGame` call
+struct Watch {
+    state: Arc<AppState>,
+    league: FootballLeague,
+    event_id: String,
+    interval: Duration,
+    last: Option<proto::FootballGame>,
+    done: bool,
+}
+
+#[tonic::async_trait]
+impl Scoreboard for ScoreboardService {
+    async fn list_football_games(
+        &self,
+        request: Request<proto::ListGamesRequest>,
+    ) -> Result<Response<proto::FootballGames>, Status> {
+        authenticate(&self.state, &request).await?;
+        let request = request.into_inner();
+        let league = FootballLeague::from_league(&request.league)?;
+        let query = GamesQuery {
+            include_mock: request.include_mock,
+            ..GamesQuery::default()
+        };
+        let include_mock = handler::include_mock(&self.state, league, &query);
+        let games = fetch_scoreboard(&self.state, league, include_mock).await?;
+        Ok(Response::new(proto::FootballGames {
+            games: games.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn get_football_game(
+        &self,
+        request: Request<proto::GetGameRequest>,
+    ) -> Result<Response<proto::FootballGame>, Status> {
+        authenticate(&self.state, &request).await?;
+        let request = request.into_inner();
+        let league = FootballLeague::from_league(&request.league)?;
+        let game = fetch_game(&self.state, league, request.event_id, true).await?;
+        Ok(Response::new(game.into()))
+    }
+
+    async fn list_basketball_games(
+        &self,
+        request: Request<proto::ListGamesRequest>,
+    ) -> Result<Response<proto::BasketballGames>, Status> {
+        authenticate(&self.state, &request).await?;
+        let league = BasketballLeague::from_league(&request.into_inner().league)?;
+        let scoreboard = self.state.provider.fetch_scoreboard(league.into(), None).await?;
+        let games = scoreboard
+            .events
+            .iter()
+            .filter_map(|e| skip_malformed(&e.id, transform_from_scoreboard(e, league)))
+            .map(Into::into)
+            .collect();
+        Ok(Response::new(proto::BasketballGames { games }))
+    }
+
+    async fn get_basketball_game(
+        &self,
+        request: Request<proto::GetGameRequest>,
+    ) -> Result<Response<proto::BasketballGame>, Status> {
+        authenticate(&self.state, &request).await?;
+        let request = request.into_inner();
+        let league = BasketballLeague::from_league(&request.league)?;
+        if !request.event_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AppError::InvalidEventId(request.event_id).into());
+        }
+        let summary = self
+            .state
+            .provider
+            .fetch_summary(league.into(), &request.event_id)
+            .await?;
+        Ok(Response::new(transform_from_summary(&summary, league)?.into()))
+    }
+
+    async fn list_mock_games(
+        &self,
+        request: Request<proto::ListMockGamesRequest>,
+    ) -> Result<Response<proto::FootballGames>, Status> {
+        authenticate(&self.state, &request).await?;
+        let games = self.state.game_repository.list().await;
+        Ok(Response::new(proto::FootballGames {
+            games: games.iter().map(|g| g.to_game_response().into()).collect(),
+        }))
+    }
+
+    type WatchGameStream =
+        Pin<Box<dyn Stream<Item = Result<proto::FootballGame, Status>> + Send + 'static>>;
+
+    /// Send the game now and again each time it changes. The stream ends
+    /// after the game goes final, or with the error if a fetch fails.
+    async fn watch_game(
+        &self,
+        request: Request<proto::WatchGameRequest>,
+    ) -> Result<Response<Self::WatchGameStream>, Status> {
+        authenticate(&self.state, &request).await?;
+        let request = request.into_inner();
+        let league = FootballLeague::from_league(&request.league)?;
+        let interval_secs = request
+            .interval_secs
+            .unwrap_or(DEFAULT_WATCH_INTERVAL_SECS)
+            .clamp(*WATCH_INTERVAL_SECS.start(), *WATCH_INTERVAL_SECS.end());
+        let watch = Watch {
+            state: self.state.clone(),
+            league,
+            event_id: request.event_id,
+            interval: Duration::from_secs(interval_secs.into()),
+            last: None,
+            done: false,
+        };
+
+        let updates = stream::unfold(watch, |mut watch| async move {
+            loop {
+                if watch.done {
+                    return None;
+                }
+                if watch.last.is_some() {
+                    tokio::time::sleep(watch.interval).await;
+                }
+                let fetched =
+                    fetch_game(&watch.state, watch.league, watch.event_id.clone(), true).await;
+                let game: proto::FootballGame = match fetched {
+                    Ok(game) => game.into(),
+                    Err(error) => {
+                        watch.done = true;
+                        return Some((Err(error.into()), watch));
+                    }
+                };
+                if watch.last.as_ref() == Some(&game) {
+                    continue;
+                }
+                watch.done = matches!(game.state, Some(proto::FootballState::Final(_)));
+                watch.last = Some(game.clone());
+                return Some((Ok(game), watch));
+            }
+        });
+        Ok(Response::new(Box::pin(updates)))
+    }
+}
+
+/// Serve the gRPC API on `bind_address` until shutdown.
+pub async fn serve(state: Arc<AppState>, bind_address: String) {
+    let address = match tokio::net::lookup_host(&bind_address).await.map(|mut a| a.next()) {
+        Ok(Some(address)) => address,
+        Ok(None) | Err(_) => {
+            tracing::error!(address = %bind_address, "Invalid gRPC bind address");
+            return;
+        }
+    };
+    tracing::info!("gRPC server running on {}", address);
+    if let Err(e) = tonic::transport::Server::builder()
+        .add_service(ScoreboardService::new(state))
+        .serve(address)
+        .await
+    {
+        tracing::error!(error = %e, "gRPC server stopped");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::{FootballFinal, FootballGameResponse, FootballTeamScore};
+    use crate::shared::types::{Color, FinalStatus, Winner};
+    use prost::Message;
+
+    fn team(abbreviation: &str, score: u8) -> FootballTeamScore {
+        FootballTeamScore::for_test(abbreviation, score)
+            .with_color(Color { r: 227, g: 24, b: 55 })
+            .with_record("12-5")
+            .with_linescore(vec![7, 3, 14, 3])
+    }
+
+    #[test]
+    fn test_final_game_round_trips_with_json_names() {
+        let game = FootballGameResponse::Final(FootballFinal {
+            event_id: "401547417".to_string(),
+            home: team("KC", 27),
+            away: team("BUF", 24),
+            season: None,
+            status: FinalStatus::FinalOvertime,
+            winner: Winner::Home,
+            leaders: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        });
+
+        let message: proto::FootballGame = game.into();
+        let decoded = proto::FootballGame::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.event_id, "401547417");
+
+        let Some(proto::FootballState::Final(game)) = decoded.state else {
+            panic!("expected a final game");
+        };
+        assert_eq!(game.status, "final/OT");
+        assert_eq!(game.winner, "home");
+        let home = game.home.unwrap();
+        assert_eq!(home.score, 27);
+        assert_eq!(home.linescore, vec![7, 3, 14, 3]);
+        assert_eq!(home.color, Some(proto::Color { r: 227, g: 24, b: 55 }));
+    }
+
+    #[test]
+    fn test_errors_map_to_grpc_codes() {
+        let status = Status::from(AppError::InvalidEventId("abc".to_string()));
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.metadata().get("x-error-code").unwrap(), "invalid_event_id");
+
+        let status = Status::from(AppError::MockGameNotFound("sim_1".to_string()));
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(Status::from(AppError::MissingApiKey).code(), tonic::Code::Unauthenticated);
+    }
+}
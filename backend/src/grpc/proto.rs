@@ -0,0 +1,279 @@
+//! Protobuf messages of the `scoreboard.v1` package, matching
+//! `proto/scoreboard.proto`, and the service generated from them by build.rs.
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListGamesRequest {
+    #[prost(string, tag = "1")]
+    pub league: String,
+    #[prost(bool, optional, tag = "2")]
+    pub include_mock: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetGameRequest {
+    #[prost(string, tag = "1")]
+    pub league: String,
+    #[prost(string, tag = "2")]
+    pub event_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListMockGamesRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WatchGameRequest {
+    #[prost(string, tag = "1")]
+    pub league: String,
+    #[prost(string, tag = "2")]
+    pub event_id: String,
+    #[prost(uint32, optional, tag = "3")]
+    pub interval_secs: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Color {
+    #[prost(uint32, tag = "1")]
+    pub r: u32,
+    #[prost(uint32, tag = "2")]
+    pub g: u32,
+    #[prost(uint32, tag = "3")]
+    pub b: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Team {
+    #[prost(string, tag = "1")]
+    pub abbreviation: String,
+    #[prost(message, optional, tag = "2")]
+    pub color: Option<Color>,
+    #[prost(message, optional, tag = "3")]
+    pub color_secondary: Option<Color>,
+    #[prost(string, optional, tag = "4")]
+    pub record: Option<String>,
+    #[prost(uint32, optional, tag = "5")]
+    pub rank: Option<u32>,
+    #[prost(string, tag = "6")]
+    pub logo_path: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TeamScore {
+    #[prost(string, tag = "1")]
+    pub abbreviation: String,
+    #[prost(message, optional, tag = "2")]
+    pub color: Option<Color>,
+    #[prost(message, optional, tag = "3")]
+    pub color_secondary: Option<Color>,
+    #[prost(string, optional, tag = "4")]
+    pub record: Option<String>,
+    #[prost(uint32, optional, tag = "5")]
+    pub rank: Option<u32>,
+    #[prost(string, tag = "6")]
+    pub logo_path: String,
+    #[prost(uint32, tag = "7")]
+    pub score: u32,
+    #[prost(uint32, optional, tag = "8")]
+    pub timeouts: Option<u32>,
+    #[prost(uint32, repeated, tag = "9")]
+    pub linescore: Vec<u32>,
+    #[prost(uint32, optional, tag = "10")]
+    pub fouls: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Season {
+    #[prost(uint32, tag = "1")]
+    pub year: u32,
+    #[prost(string, tag = "2")]
+    pub r#type: String,
+    #[prost(uint32, optional, tag = "3")]
+    pub week: Option<u32>,
+    #[prost(string, tag = "4")]
+    pub label: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Weather {
+    #[prost(sint32, tag = "1")]
+    pub temp: i32,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Situation {
+    #[prost(string, tag = "1")]
+    pub down: String,
+    #[prost(uint32, tag = "2")]
+    pub distance: u32,
+    #[prost(uint32, tag = "3")]
+    pub yard_line: u32,
+    #[prost(string, tag = "4")]
+    pub possession: String,
+    #[prost(bool, tag = "5")]
+    pub red_zone: bool,
+    #[prost(bool, tag = "6")]
+    pub goal_to_go: bool,
+    #[prost(string, tag = "7")]
+    pub ball_on: String,
+    #[prost(string, tag = "8")]
+    pub direction: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LastPlay {
+    #[prost(string, tag = "1")]
+    pub play_type: String,
+    #[prost(string, optional, tag = "2")]
+    pub text: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FootballGames {
+    #[prost(message, repeated, tag = "1")]
+    pub games: Vec<FootballGame>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FootballGame {
+    #[prost(string, tag = "1")]
+    pub event_id: String,
+    #[prost(oneof = "FootballState", tags = "2, 3, 4")]
+    pub state: Option<FootballState>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum FootballState {
+    #[prost(message, tag = "2")]
+    Pregame(FootballPregame),
+    #[prost(message, tag = "3")]
+    Live(FootballLive),
+    #[prost(message, tag = "4")]
+    Final(FootballFinal),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FootballPregame {
+    #[prost(message, optional, tag = "1")]
+    pub home: Option<Team>,
+    #[prost(message, optional, tag = "2")]
+    pub away: Option<Team>,
+    #[prost(message, optional, tag = "3")]
+    pub season: Option<Season>,
+    #[prost(int64, tag = "4")]
+    pub start_time: i64,
+    #[prost(int64, tag = "5")]
+    pub seconds_until_start: i64,
+    #[prost(string, optional, tag = "6")]
+    pub venue: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub broadcast: Option<String>,
+    #[prost(message, optional, tag = "8")]
+    pub weather: Option<Weather>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FootballLive {
+    #[prost(message, optional, tag = "1")]
+    pub home: Option<TeamScore>,
+    #[prost(message, optional, tag = "2")]
+    pub away: Option<TeamScore>,
+    #[prost(message, optional, tag = "3")]
+    pub season: Option<Season>,
+    #[prost(string, tag = "4")]
+    pub period: String,
+    #[prost(string, tag = "5")]
+    pub clock: String,
+    #[prost(bool, tag = "6")]
+    pub clock_running: bool,
+    #[prost(bool, tag = "7")]
+    pub inside_two_minutes: bool,
+    #[prost(bool, tag = "8")]
+    pub is_close_game: bool,
+    #[prost(message, optional, tag = "9")]
+    pub situation: Option<Situation>,
+    #[prost(message, optional, tag = "10")]
+    pub last_play: Option<LastPlay>,
+    #[prost(message, optional, tag = "11")]
+    pub weather: Option<Weather>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FootballFinal {
+    #[prost(message, optional, tag = "1")]
+    pub home: Option<TeamScore>,
+    #[prost(message, optional, tag = "2")]
+    pub away: Option<TeamScore>,
+    #[prost(message, optional, tag = "3")]
+    pub season: Option<Season>,
+    #[prost(string, tag = "4")]
+    pub status: String,
+    #[prost(string, tag = "5")]
+    pub winner: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BasketballGames {
+    #[prost(message, repeated, tag = "1")]
+    pub games: Vec<BasketballGame>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BasketballGame {
+    #[prost(string, tag = "1")]
+    pub event_id: String,
+    #[prost(oneof = "BasketballState", tags = "2, 3, 4")]
+    pub state: Option<BasketballState>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum BasketballState {
+    #[prost(message, tag = "2")]
+    Pregame(BasketballPregame),
+    #[prost(message, tag = "3")]
+    Live(BasketballLive),
+    #[prost(message, tag = "4")]
+    Final(BasketballFinal),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BasketballPregame {
+    #[prost(message, optional, tag = "1")]
+    pub home: Option<Team>,
+    #[prost(message, optional, tag = "2")]
+    pub away: Option<Team>,
+    #[prost(int64, tag = "3")]
+    pub start_time: i64,
+    #[prost(int64, tag = "4")]
+    pub seconds_until_start: i64,
+    #[prost(string, optional, tag = "5")]
+    pub venue: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub broadcast: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BasketballLive {
+    #[prost(message, optional, tag = "1")]
+    pub home: Option<TeamScore>,
+    #[prost(message, optional, tag = "2")]
+    pub away: Option<TeamScore>,
+    #[prost(string, tag = "3")]
+    pub period: String,
+    #[prost(string, tag = "4")]
+    pub clock: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BasketballFinal {
+    #[prost(message, optional, tag = "1")]
+    pub home: Option<TeamScore>,
+    #[prost(message, optional, tag = "2")]
+    pub away: Option<TeamScore>,
+    #[prost(string, tag = "3")]
+    pub status: String,
+    #[prost(string, tag = "4")]
+    pub winner: String,
+}
+
+include!(concat!(env!("OUT_DIR"), "/scoreboard.v1.Scoreboard.rs"));
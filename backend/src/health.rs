@@ -0,0 +1,248 @@
+//! Liveness and readiness probes for orchestration and uptime monitors.
+//!
+//! `/health/live` only says the server is answering. `/health/ready` also checks
+//! that ESPN is reachable (at most every 30 seconds, so probes don't hammer it,
+//! and not at all when fixtures or a replay stand in for it) and that
+//! background tasks are still running, and reports cache state.
+
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use utoipa::ToSchema;
+
+use crate::AppState;
+use crate::clock::uptime;
+
+/// How long an ESPN check result is reused
+const ESPN_CHECK_TTL: Duration = Duration::from_secs(30);
+
+/// Longest to wait for ESPN, kept under typical probe timeouts
+const ESPN_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Failing,
+}
+
+/// Response from /health/live
+#[derive(Serialize, ToSchema)]
+pub struct LivenessResponse {
+    pub status: HealthStatus,
+    /// Seconds since the server started
+    pub uptime_secs: u64,
+}
+
+/// Response from /health/ready
+#[derive(Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// `failing` if ESPN is unreachable or a background task has stopped
+    pub status: HealthStatus,
+    /// Seconds since the server started
+    pub uptime_secs: u64,
+    /// Null when recorded fixtures or a replay are served instead of ESPN
+    pub espn: Option<EspnHealth>,
+    pub tasks: Vec<TaskHealth>,
+    pub caches: CacheHealth,
+}
+
+/// Result of the (cached) ESPN reachability check
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EspnHealth {
+    pub status: HealthStatus,
+    /// HTTP status ESPN answered with
+    pub http_status: Option<u16>,
+    /// Round trip of the check in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Why the check failed
+    pub error: Option<String>,
+    /// Seconds since the check ran
+    pub checked_secs_ago: u64,
+}
+
+/// A long-running background task
+#[derive(Serialize, ToSchema)]
+pub struct TaskHealth {
+    pub name: String,
+    pub running: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CacheHealth {
+    /// Processed logos held in memory
+    pub logos: usize,
+    /// Rendered frames held for deltas
+    pub frames: usize,
+    /// Seconds since ESPN last returned game data. Null until the first fetch.
+    pub espn_data_age_secs: Option<u64>,
+}
+
+impl CacheHealth {
+    pub fn new(state: &AppState) -> Self {
+        Self {
+            logos: state.logo_cache.memory_len(),
+            frames: state.frame_cache.frame_count(),
+            espn_data_age_secs: state
+                .espn_client
+                .last_success_age()
+                .map(|age| age.as_secs()),
+        }
+    }
+}
+
+/// Health state kept between probes.
+pub struct Health {
+    /// Whether game data comes from ESPN, so it's worth checking
+    from_espn: bool,
+    espn: tokio::sync::Mutex<Option<(Instant, EspnHealth)>>,
+    tasks: Mutex<Vec<(&'static str, JoinHandle<()>)>>,
+}
+
+impl Health {
+    pub fn new(from_espn: bool) -> Self {
+        Self {
+            from_espn,
+            espn: tokio::sync::Mutex::new(None),
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Report on a spawned background task, which should run until shutdown.
+    pub fn track(&self, name: &'static str, task: JoinHandle<()>) {
+        self.tasks.lock().unwrap().push((name, task));
+    }
+
+    fn tasks(&self) -> Vec<TaskHealth> {
+        self.tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, task)| TaskHealth {
+                name: name.to_string(),
+                running: !task.is_finished(),
+            })
+            .collect()
+    }
+
+    /// The last ESPN check, or a new one if it's stale, or `None` when game
+    /// data doesn't come from ESPN. Concurrent probes wait for a single check.
+    async fn espn(&self, state: &AppState) -> Option<EspnHealth> {
+        if !self.from_espn {
+            return None;
+        }
+        Some(self.check_espn(state).await)
+    }
+
+    async fn check_espn(&self, state: &AppState) -> EspnHealth {
+        let mut cached = self.espn.lock().await;
+        if let Some((at, health)) = cached.as_ref()
+            && at.elapsed() < ESPN_CHECK_TTL
+        {
+            return EspnHealth {
+                checked_secs_ago: at.elapsed().as_secs(),
+                ..health.clone()
+            };
+        }
+
+        let started = Instant::now();
+        let result = tokio::time::timeout(ESPN_CHECK_TIMEOUT, state.espn_client.ping()).await;
+        let latency_ms = Some(started.elapsed().as_millis() as u64);
+        let health = match result {
+            Ok(Ok(status)) if !status.is_server_error() => EspnHealth {
+                status: HealthStatus::Ok,
+                http_status: Some(status.as_u16()),
+                latency_ms,
+                error: None,
+                checked_secs_ago: 0,
+            },
+            Ok(Ok(status)) => EspnHealth {
+                status: HealthStatus::Failing,
+                http_status: Some(status.as_u16()),
+                latency_ms,
+                error: Some("ESPN returned a server error".to_string()),
+                checked_secs_ago: 0,
+            },
+            Ok(Err(e)) => EspnHealth {
+                status: HealthStatus::Failing,
+                http_status: None,
+                latency_ms,
+                error: Some(e.to_string()),
+                checked_secs_ago: 0,
+            },
+            Err(_) => EspnHealth {
+                status: HealthStatus::Failing,
+                http_status: None,
+                latency_ms: None,
+                error: Some(format!(
+                    "no answer within {}s",
+                    ESPN_CHECK_TIMEOUT.as_secs()
+                )),
+                checked_secs_ago: 0,
+            },
+        };
+        if health.status == HealthStatus::Failing {
+            tracing::warn!(error = ?health.error, "ESPN readiness check failed");
+        }
+        *cached = Some((started, health.clone()));
+        health
+    }
+}
+
+/// GET /health/live — the server is up and answering
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    operation_id = "get_liveness",
+    responses(
+        (status = 200, description = "Server is running", body = LivenessResponse),
+    ),
+    tag = "health"
+)]
+pub async fn live() -> Json<LivenessResponse> {
+    Json(LivenessResponse {
+        status: HealthStatus::Ok,
+        uptime_secs: uptime().as_secs(),
+    })
+}
+
+/// GET /health/ready — the server can serve game data
+///
+/// Fails with 503 when ESPN is unreachable or a background task has stopped.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    operation_id = "get_readiness",
+    responses(
+        (status = 200, description = "Ready to serve", body = ReadinessResponse),
+        (status = 503, description = "A dependency check failed", body = ReadinessResponse),
+    ),
+    tag = "health"
+)]
+pub async fn ready(State(state): State<Arc<AppState>>) -> (StatusCode, Json<ReadinessResponse>) {
+    let espn = state.health.espn(&state).await;
+    let tasks = state.health.tasks();
+
+    let espn_ok = espn.as_ref().is_none_or(|espn| espn.status == HealthStatus::Ok);
+    let ready = espn_ok && tasks.iter().all(|t| t.running);
+    let (code, status) = if ready {
+        (StatusCode::OK, HealthStatus::Ok)
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, HealthStatus::Failing)
+    };
+
+    (
+        code,
+        Json(ReadinessResponse {
+            status,
+            uptime_secs: uptime().as_secs(),
+            espn,
+            tasks,
+            caches: CacheHealth::new(&state),
+        }),
+    )
+}
@@ -0,0 +1,111 @@
+//! JSON arrays written to the response as they're serialized, for game lists
+//! too big to build in memory first (a full college football slate).
+//!
+//! Streamed responses are sent chunked, without a Content-Length, which the
+//! Pico firmware needs, so clients opt in with `?stream=true`. Items are
+//! produced lazily, so the transform and serialize steps happen while the
+//! body is sent and don't appear in the latency breakdown (see `timing`).
+
+use axum::body::{Body, Bytes};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+/// Bytes to collect before sending a chunk
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Query parameters for the game list endpoints
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct StreamQuery {
+    /// Send the list chunked as it's serialized, without a Content-Length
+    /// (default: false). Gets the first games to slow clients sooner on big slates.
+    pub stream: Option<bool>,
+}
+
+impl StreamQuery {
+    pub fn enabled(&self) -> bool {
+        self.stream.unwrap_or(false)
+    }
+}
+
+/// A JSON array response serializing `items` one at a time as the body is sent.
+pub fn array<T, I>(items: I) -> Response
+where
+    T: Serialize,
+    I: IntoIterator<Item = T>,
+    I::IntoIter: Send + 'static,
+{
+    let body = Body::from_stream(stream::iter(Chunks {
+        items: items.into_iter(),
+        written: 0,
+        done: false,
+    }));
+    ([(header::CONTENT_TYPE, "application/json")], body).into_response()
+}
+
+/// Splits the serialized array into chunks of about `CHUNK_SIZE` bytes.
+struct Chunks<I> {
+    items: I,
+    /// Items serialized so far
+    written: usize,
+    done: bool,
+}
+
+impl<T: Serialize, I: Iterator<Item = T>> Iterator for Chunks<I> {
+    type Item = Result<Bytes, serde_json::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+        if self.written == 0 {
+            chunk.push(b'[');
+        }
+        while chunk.len() < CHUNK_SIZE {
+            let Some(item) = self.items.next() else {
+                chunk.push(b']');
+                self.done = true;
+                break;
+            };
+            if self.written > 0 {
+                chunk.push(b',');
+            }
+            if let Err(e) = serde_json::to_writer(&mut chunk, &item) {
+                self.done = true;
+                return Some(Err(e));
+            }
+            self.written += 1;
+        }
+        Some(Ok(Bytes::from(chunk)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(items: Vec<u32>) -> String {
+        let chunks = Chunks {
+            items: items.into_iter(),
+            written: 0,
+            done: false,
+        };
+        chunks
+            .map(|chunk| String::from_utf8(chunk.unwrap().to_vec()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_chunks_form_a_json_array() {
+        assert_eq!(collect(vec![]), "[]");
+        assert_eq!(collect(vec![1, 2, 3]), "[1,2,3]");
+
+        let many: Vec<u32> = (0..10_000).collect();
+        let json = collect(many.clone());
+        assert_eq!(serde_json::from_str::<Vec<u32>>(&json).unwrap(), many);
+    }
+}
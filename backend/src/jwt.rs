@@ -0,0 +1,214 @@
+//! HS256 JSON Web Token validation for `Authorization: Bearer` authentication.
+//!
+//! Only what deployments behind an identity provider need: an HMAC-SHA256
+//! signature over the shared secret, an expiry, and optional issuer and audience
+//! checks. Scopes come from the space-separated `scope` claim.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::admin::types::{Scope, default_scopes};
+use crate::config::JwtConfig;
+use crate::error::AppError;
+
+#[derive(Debug, Deserialize)]
+struct Header {
+    alg: String,
+}
+
+/// The claims checked or used; others are ignored.
+#[derive(Debug, Deserialize)]
+pub struct Claims {
+    /// Subject, for logging
+    #[serde(default)]
+    pub sub: Option<String>,
+    exp: i64,
+    #[serde(default)]
+    nbf: Option<i64>,
+    #[serde(default)]
+    iss: Option<String>,
+    #[serde(default)]
+    aud: Option<Audience>,
+    /// Space-separated scope names (e.g., "games:read mock:write")
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Audience {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Claims {
+    /// Scopes granted by the token, or `games:read` if it names none we know.
+    pub fn scopes(&self) -> Vec<Scope> {
+        let scopes: Vec<Scope> = self
+            .scope
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|name| Scope::ALL.into_iter().find(|s| s.name() == name))
+            .collect();
+        if scopes.is_empty() {
+            default_scopes()
+        } else {
+            scopes
+        }
+    }
+}
+
+/// Validate a token's signature and claims at Unix time `now`, against the
+/// secret in `config`.
+pub fn validate(token: &str, config: &JwtConfig, now: i64) -> Result<Claims, AppError> {
+    let invalid = |reason: &str| AppError::InvalidToken(reason.to_string());
+    let secret = config
+        .secret
+        .as_deref()
+        .ok_or_else(|| invalid("bearer tokens are not accepted"))?;
+
+    let mut parts = token.split('.');
+    let (Some(header), Some(payload), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid("expected three dot-separated parts"));
+    };
+    let signed = &token[..header.len() + 1 + payload.len()];
+
+    let header: Header = decode_json(header).ok_or_else(|| invalid("malformed header"))?;
+    if header.alg != "HS256" {
+        return Err(invalid("only HS256 tokens are accepted"));
+    }
+
+    // verify_slice compares in constant time
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| invalid("malformed signature"))?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|_| invalid("unusable secret"))?;
+    mac.update(signed.as_bytes());
+    mac.verify_slice(&signature).map_err(|_| invalid("bad signature"))?;
+
+    let claims: Claims = decode_json(payload).ok_or_else(|| invalid("malformed claims"))?;
+    let leeway = config.leeway_secs as i64;
+    if claims.exp + leeway < now {
+        return Err(AppError::ExpiredToken);
+    }
+    if claims.nbf.is_some_and(|nbf| nbf - leeway > now) {
+        return Err(invalid("not valid yet"));
+    }
+    if let Some(issuer) = &config.issuer
+        && claims.iss.as_ref() != Some(issuer)
+    {
+        return Err(invalid("wrong issuer"));
+    }
+    if let Some(audience) = &config.audience {
+        let matches = match &claims.aud {
+            Some(Audience::One(aud)) => aud == audience,
+            Some(Audience::Many(auds)) => auds.contains(audience),
+            None => false,
+        };
+        if !matches {
+            return Err(invalid("wrong audience"));
+        }
+    }
+
+    Ok(claims)
+}
+
+fn decode_json<T: for<'de> Deserialize<'de>>(part: &str) -> Option<T> {
+    let bytes = URL_SAFE_NO_PAD.decode(part).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    fn token(header: &str, claims: &str, secret: &str) -> String {
+        let signed = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header),
+            URL_SAFE_NO_PAD.encode(claims)
+        );
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(signed.as_bytes());
+        format!("{}.{}", signed, URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+
+    fn hs256(claims: &str) -> String {
+        token(r#"{"alg":"HS256","typ":"JWT"}"#, claims, SECRET)
+    }
+
+    fn config() -> JwtConfig {
+        JwtConfig {
+            secret: Some(SECRET.to_string()),
+            issuer: None,
+            audience: None,
+            leeway_secs: 30,
+        }
+    }
+
+    #[test]
+    fn test_valid_token_scopes() {
+        let claims = validate(
+            &hs256(r#"{"sub":"dash","exp":2000,"scope":"games:read mock:write other"}"#),
+            &config(),
+            1000,
+        )
+        .unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("dash"));
+        assert_eq!(claims.scopes(), vec![Scope::GamesRead, Scope::MockWrite]);
+
+        let claims = validate(&hs256(r#"{"exp":2000}"#), &config(), 1000).unwrap();
+        assert_eq!(claims.scopes(), vec![Scope::GamesRead]);
+    }
+
+    #[test]
+    fn test_rejects_bad_signature_alg_and_expiry() {
+        let forged = token(r#"{"alg":"HS256"}"#, r#"{"exp":2000}"#, "other-secret");
+        assert!(matches!(
+            validate(&forged, &config(), 1000),
+            Err(AppError::InvalidToken(_))
+        ));
+
+        let unsigned = format!(
+            "{}.{}.",
+            URL_SAFE_NO_PAD.encode(r#"{"alg":"none"}"#),
+            URL_SAFE_NO_PAD.encode(r#"{"exp":2000}"#)
+        );
+        assert!(validate(&unsigned, &config(), 1000).is_err());
+
+        // Expired, but within the leeway, then past it
+        let expired = hs256(r#"{"exp":1000}"#);
+        assert!(validate(&expired, &config(), 1020).is_ok());
+        assert!(matches!(
+            validate(&expired, &config(), 1031),
+            Err(AppError::ExpiredToken)
+        ));
+    }
+
+    #[test]
+    fn test_checks_issuer_and_audience() {
+        let config = JwtConfig {
+            issuer: Some("https://id.example.com".to_string()),
+            audience: Some("scoreboard".to_string()),
+            ..config()
+        };
+        let good = hs256(
+            r#"{"exp":2000,"iss":"https://id.example.com","aud":["other","scoreboard"]}"#,
+        );
+        assert!(validate(&good, &config, 1000).is_ok());
+
+        let wrong_aud = hs256(r#"{"exp":2000,"iss":"https://id.example.com","aud":"other"}"#);
+        assert!(validate(&wrong_aud, &config, 1000).is_err());
+        let no_iss = hs256(r#"{"exp":2000,"aud":"scoreboard"}"#);
+        assert!(validate(&no_iss, &config, 1000).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! Log output setup, and the log level that can change while the server runs.
+//!
+//! The level comes from `log_level` in config (or --log-level), else RUST_LOG,
+//! else "info". A config reload applies a changed level; the admin API can also
+//! override it for a while, e.g. to catch a misbehaving ESPN response with
+//! `espn::deserialize=debug` without restarting.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing_subscriber::{
+    EnvFilter, Registry, fmt, layer::SubscriberExt, reload, util::SubscriberInitExt,
+};
+
+use crate::config::AppConfig;
+use crate::error::AppError;
+
+/// How log lines are written. Takes a restart to change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines, for local development
+    Pretty,
+    /// One JSON object per line, for log collectors (e.g., on Fly.io)
+    Json,
+}
+
+impl LogFormat {
+    /// `log_format` from config, else the LOG_FORMAT environment variable,
+    /// else pretty.
+    fn resolve(config: &AppConfig) -> Self {
+        config
+            .log_format
+            .unwrap_or_else(|| match std::env::var("LOG_FORMAT").as_deref() {
+                Ok("json") => LogFormat::Json,
+                _ => LogFormat::Pretty,
+            })
+    }
+}
+
+/// The filter for a configured log level, else RUST_LOG, else "info".
+pub fn log_filter(log_level: Option<&str>) -> Result<EnvFilter, AppError> {
+    match log_level {
+        Some(level) => {
+            parse(level).map_err(|e| AppError::InvalidConfig(format!("log_level: {}", e)))
+        }
+        None => Ok(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"))),
+    }
+}
+
+fn parse(level: &str) -> Result<EnvFilter, String> {
+    EnvFilter::try_new(level).map_err(|e| e.to_string())
+}
+
+/// Install the global subscriber for `config`, returning the handle that
+/// changes its level.
+pub fn init(config: &AppConfig) -> LogLevel {
+    let filter = log_filter(config.log_level.as_deref())
+        .unwrap_or_else(|e| panic!("Invalid log_level: {:?}", e));
+    let (filter, handle) = reload::Layer::new(filter);
+
+    match LogFormat::resolve(config) {
+        LogFormat::Json => tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt::layer().json())
+            .init(),
+        LogFormat::Pretty => tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt::layer())
+            .init(),
+    }
+
+    LogLevel {
+        handle,
+        state: Mutex::new(OverrideState::default()),
+    }
+}
+
+/// A level set through the admin API in place of the configured one
+#[derive(Debug, Clone)]
+pub struct LogOverride {
+    pub level: String,
+    /// When the configured level comes back (Unix seconds), if ever
+    pub expires_at: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+struct OverrideState {
+    current: Option<LogOverride>,
+    /// Bumped on every change, so a stale expiry doesn't undo a newer override
+    generation: u64,
+}
+
+/// The running log filter.
+pub struct LogLevel {
+    handle: reload::Handle<EnvFilter, Registry>,
+    state: Mutex<OverrideState>,
+}
+
+impl LogLevel {
+    /// The filter in effect, in RUST_LOG syntax.
+    pub fn current(&self) -> String {
+        self.handle
+            .with_current(|filter| filter.to_string())
+            .unwrap_or_default()
+    }
+
+    /// The admin override in effect, if any.
+    pub fn override_level(&self) -> Option<LogOverride> {
+        self.state.lock().unwrap().current.clone()
+    }
+
+    /// Apply the configured level, ending any override.
+    pub fn configure(&self, log_level: Option<&str>) -> Result<(), AppError> {
+        self.reset(&mut self.state.lock().unwrap(), log_level)
+    }
+
+    /// Use `level` instead of the configured level, for `duration` if given.
+    /// Returns the override's generation for `expire`.
+    pub fn set_override(
+        &self,
+        level: &str,
+        duration: Option<Duration>,
+    ) -> Result<(LogOverride, u64), AppError> {
+        let filter = parse(level).map_err(AppError::InvalidLogLevel)?;
+        let mut state = self.state.lock().unwrap();
+        self.swap(filter)?;
+        let applied = LogOverride {
+            level: level.to_string(),
+            expires_at: duration.map(|d| Utc::now().timestamp() + d.as_secs() as i64),
+        };
+        state.current = Some(applied.clone());
+        state.generation += 1;
+        Ok((applied, state.generation))
+    }
+
+    /// Go back to the configured level if the override from `generation` is
+    /// still the one in effect. Returns whether it was.
+    pub fn expire(&self, generation: u64, log_level: Option<&str>) -> Result<bool, AppError> {
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation || state.current.is_none() {
+            return Ok(false);
+        }
+        self.reset(&mut state, log_level)?;
+        Ok(true)
+    }
+
+    fn reset(&self, state: &mut OverrideState, log_level: Option<&str>) -> Result<(), AppError> {
+        self.swap(log_filter(log_level)?)?;
+        state.current = None;
+        state.generation += 1;
+        Ok(())
+    }
+
+    fn swap(&self, filter: EnvFilter) -> Result<(), AppError> {
+        self.handle
+            .reload(filter)
+            .map_err(|e| AppError::InvalidConfig(e.to_string()))
+    }
+}
@@ -1,21 +1,46 @@
-use axum::{routing::get, Router};
-use std::sync::Arc;
+use axum::{routing::{delete, get, post, put}, Router};
+use clap::Parser;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tower_http::cors::{Any, CorsLayer};
-use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 use utoipa::OpenApi;
 use utoipa_scalar::{Scalar, Servable};
 
+mod admin;
+mod aggregate;
+mod alerts;
 mod auth;
 mod basketball;
+mod broadcast;
+mod cli;
 mod clock;
 mod config;
+mod device;
 mod error;
 mod espn;
+mod export;
+mod feed;
+mod jwt;
 mod football;
+mod graphql;
+mod grpc;
+mod health;
+mod json_stream;
+mod logging;
 mod mock;
+mod poll;
+mod provider;
+mod rate_limit;
+mod reload;
+mod request_log;
+mod render;
 mod shared;
 mod sport;
+mod storage;
 mod team;
+mod timing;
+mod versioning;
 
 use config::AppConfig;
 use espn::EspnClient;
@@ -24,24 +49,77 @@ use espn::EspnClient;
 #[openapi(
     info(
         title = "Pico Scoreboard API",
-        description = "Multi-sport API for fetching game data from ESPN (NFL, NCAAF, NBA, NCAAB), optimized for Pi Pico displays",
+        description = "Multi-sport API for fetching game data from ESPN (NFL, NCAAF, NBA, NCAAB), optimized for Pi Pico displays. Paths are listed under /api, which serves the same shapes as /api/v1; /api/v2 serves the same routes with game clocks as numbers of seconds (null when there is no clock).",
         version = "2.0.0",
         contact(name = "Pico Scoreboard"),
     ),
     paths(
+        health::live,
+        health::ready,
         clock::time,
+        clock::clock_sync,
+        feed::finals_feed,
+        aggregate::get_all_games,
+        export::games_csv,
         football::handler::get_all_games,
         football::handler::get_game,
+        football::handler::get_unknown_plays,
         basketball::handler::get_all_games,
         basketball::handler::get_game,
         team::handler::get_football_team_logo,
         team::handler::get_basketball_team_logo,
+        team::handler::get_football_celebration,
+        team::handler::get_logo_sheet,
+        team::handler::get_logo_sheet_index,
+        team::handler::get_team_colors,
+        team::handler::get_team_color_swatch,
         mock::handler::list_mock_games,
         mock::handler::get_mock_game,
+        mock::handler::get_mock_game_drives,
+        mock::handler::get_mock_game_plays_csv,
+        mock::handler::get_mock_game_boxscore,
+        mock::handler::stream_mock_game,
         mock::handler::create_mock_game,
+        mock::handler::create_mock_game_batch,
+        mock::handler::create_mock_game_preset,
+        mock::handler::import_mock_game,
+        mock::handler::export_mock_game,
+        mock::handler::restore_mock_game,
+        mock::handler::seek_mock_game,
+        mock::handler::get_mock_team_logo,
+        render::handler::render_game,
+        render::handler::render_text,
+        render::handler::render_ticker,
+        render::handler::render_weather_icon,
+        render::handler::render_tile,
+        mock::handler::get_mock_clock,
+        mock::handler::update_mock_clock,
         mock::handler::delete_mock_game,
+        admin::handler::list_api_keys,
+        admin::handler::create_api_key,
+        admin::handler::revoke_api_key,
+        admin::handler::flush_caches,
+        admin::handler::reload_config,
+        admin::handler::get_config,
+        admin::handler::get_stats,
+        admin::handler::get_log_level,
+        admin::handler::set_log_level,
+        admin::handler::reset_log_level,
+        device::handler::register_device,
+        device::handler::list_devices,
+        device::handler::remove_device,
+        device::handler::assign_device,
+        device::handler::get_device_game,
     ),
     components(schemas(
+        football::types::UnknownPlayType,
+        football::types::UnknownPlaySample,
+        health::HealthStatus,
+        health::LivenessResponse,
+        health::ReadinessResponse,
+        health::EspnHealth,
+        health::TaskHealth,
+        health::CacheHealth,
         football::types::FootballGameResponse,
         football::types::FootballPregame,
         football::types::FootballLive,
@@ -49,10 +127,18 @@ use espn::EspnClient;
         football::types::FootballTeamScore,
         football::types::FootballPeriod,
         football::types::Situation,
+        football::types::GameLeaders,
+        football::types::Leader,
+        football::types::RecentPlay,
         football::types::Down,
         football::types::Possession,
+        football::types::Direction,
         football::types::LastPlay,
         football::types::PlayType,
+        football::types::DriveSummary,
+        football::types::DriveResult,
+        football::types::FootballBoxScore,
+        football::types::FootballTeamStats,
         basketball::types::BasketballGameResponse,
         basketball::types::BasketballPregame,
         basketball::types::BasketballLive,
@@ -63,24 +149,83 @@ use espn::EspnClient;
         basketball::types::BasketballFinalDetail,
         basketball::types::BasketballTeamScoreDetail,
         basketball::types::BasketballPeriod,
+        aggregate::LeagueGame,
+        aggregate::AnyGame,
         shared::types::Team,
         shared::types::Color,
         shared::types::Weather,
         shared::types::FinalStatus,
         shared::types::Winner,
+        shared::types::Season,
+        shared::types::SeasonType,
         mock::simulation::CreateGameRequest,
         mock::simulation::CreatePregameOptions,
         mock::simulation::CreateLiveOptions,
         mock::simulation::CreateFinalOptions,
+        mock::simulation::OvertimeRules,
+        mock::simulation::Aggressiveness,
+        mock::simulation::Pace,
+        mock::simulation::CreateBatchOptions,
+        mock::simulation::BatchMix,
+        mock::simulation::GameSnapshot,
+        mock::simulation::UpdateClockRequest,
+        mock::simulation::VirtualClockResponse,
+        team::types::OutputFormat,
+        team::types::Dither,
+        team::types::Compression,
+        team::types::Fit,
+        team::types::ResizeFilter,
+        team::types::Crop,
+        team::types::Rotation,
+        team::types::Flip,
+        team::types::LogoVariant,
+        team::types::AnimationFormat,
+        team::types::SpriteSheetIndex,
+        team::types::SpriteSheetTile,
+        team::types::TeamColors,
+        team::types::ColorShare,
+        team::types::SwatchColors,
+        render::font::Font,
+        render::layouts::Layout,
+        render::types::TextFormat,
+        render::types::TickerMode,
+        render::tile::TileSide,
+        render::tile::TileArrangement,
+        admin::types::Scope,
+        admin::types::KeySource,
+        admin::types::ApiKeyInfo,
+        admin::types::CreateApiKeyRequest,
+        admin::types::CreatedApiKey,
+        admin::types::CacheFlushResponse,
+        admin::types::ConfigReloadResponse,
+        admin::types::KeyUsage,
+        admin::types::StatsResponse,
+        admin::types::LogLevelResponse,
+        admin::types::SetLogLevelRequest,
+        espn::stats::EspnStats,
+        espn::stats::EndpointStats,
+        device::types::RegisterDeviceRequest,
+        device::types::RegisteredDevice,
+        device::types::DeviceInfo,
+        device::types::Assignment,
         clock::TimeResponse,
+        clock::ClockSyncResponse,
         error::ErrorResponse,
+        error::ErrorCode,
     )),
     modifiers(&SecurityAddon),
     tags(
         (name = "football", description = "Football game data and team logo endpoints (NFL, NCAAF)"),
         (name = "basketball", description = "Basketball game data and team logo endpoints (NBA, NCAAB)"),
+        (name = "all", description = "Games across every configured league"),
         (name = "mock", description = "Mock data endpoints for testing"),
-        (name = "clock", description = "Time and timezone endpoint")
+        (name = "render", description = "Server-rendered display frames"),
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "exports", description = "CSV exports for spreadsheets"),
+        (name = "feeds", description = "Atom feeds for feed readers and automation"),
+        (name = "clock", description = "Time, timezone, and clock sync endpoints"),
+        (name = "admin", description = "Key management and cache control (X-Admin-Key)"),
+        (name = "devices", description = "Device registration and management")
     )
 )]
 struct ApiDoc;
@@ -96,7 +241,18 @@ impl utoipa::Modify for SecurityAddon {
                     utoipa::openapi::security::ApiKey::Header(
                         utoipa::openapi::security::ApiKeyValue::with_description(
                             "X-Api-Key",
-                            "API key for authentication. When no key is configured on the server, authentication is disabled and this header is ignored.",
+                            "API key for authentication. When no key is configured on the server, authentication is disabled and this header is ignored. Servers with a JWT secret also accept `Authorization: Bearer <token>` in its place.",
+                        ),
+                    ),
+                ),
+            );
+            components.add_security_scheme(
+                "admin_key",
+                utoipa::openapi::security::SecurityScheme::ApiKey(
+                    utoipa::openapi::security::ApiKey::Header(
+                        utoipa::openapi::security::ApiKeyValue::with_description(
+                            "X-Admin-Key",
+                            "Admin key for /api/admin/* routes, separate from device API keys. When the server has no admin_key configured, these routes accept an X-Api-Key with the admin scope instead.",
                         ),
                     ),
                 ),
@@ -107,57 +263,215 @@ impl utoipa::Modify for SecurityAddon {
 
 /// Shared application state
 pub struct AppState {
+    /// ESPN itself, for health checks and upstream stats
     pub espn_client: EspnClient,
+    /// Where handlers get game data; ESPN unless something stands in for it
+    pub provider: Arc<dyn provider::SportsProvider>,
     pub config: AppConfig,
+    /// Command-line flags, reapplied on reload
+    pub cli: cli::Cli,
+    /// Settings that change on reload, read here rather than from `config`
+    pub live: RwLock<config::LiveConfig>,
+    pub log_level: logging::LogLevel,
+    pub api_keys: auth::KeyStore,
+    pub devices: device::DeviceRegistry,
+    /// State shared with other instances (see `storage`)
+    pub storage: Arc<dyn storage::Storage>,
     pub game_repository: mock::GameRepository,
+    pub logo_cache: team::cache::LogoCache,
+    pub frame_cache: render::delta::FrameCache,
+    pub rate_limiter: RwLock<Option<Arc<rate_limit::RateLimiter>>>,
+    pub health: health::Health,
+    pub unknown_plays: football::telemetry::UnknownPlays,
+    pub score_changes: football::changes::ScoreChanges,
+    pub graphql: graphql::ScoreboardSchema,
     pub geoip_reader: Option<maxminddb::Reader<memmap2::Mmap>>,
 }
 
+/// The `/api` routes, relative to the version prefix they're nested under.
+fn api_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/time", get(clock::clock_sync))
+        // Football endpoints
+        .route("/football/{league}/games", get(football::handler::get_all_games))
+        .route("/football/{league}/games/{event_id}", get(football::handler::get_game))
+        .route("/football/{league}/{team_id}/logo", get(team::get_football_team_logo))
+        .route(
+            "/football/{league}/{team_id}/celebration",
+            get(team::get_football_celebration),
+        )
+        .route("/teams/logos/sheet", get(team::get_logo_sheet))
+        .route("/teams/logos/sheet/index", get(team::get_logo_sheet_index))
+        .route("/teams/{team_id}/colors", get(team::get_team_colors))
+        .route("/teams/{team_id}/color", get(team::get_team_color_swatch))
+        // Basketball endpoints
+        .route("/basketball/{league}/games", get(basketball::handler::get_all_games))
+        .route("/basketball/{league}/games/{event_id}", get(basketball::handler::get_game))
+        .route("/basketball/{league}/{team_id}/logo", get(team::get_basketball_team_logo))
+        // Every configured league
+        .route("/all/games", get(aggregate::get_all_games))
+        // Exports and feeds
+        .route("/games.csv", get(export::games_csv))
+        .route("/feeds/finals.atom", get(feed::finals_feed))
+        // Rendered frames
+        .route("/render/games/{event_id}", get(render::render_game))
+        .route("/render/text", get(render::render_text))
+        .route("/render/ticker", get(render::render_ticker))
+        .route("/render/weather-icon", get(render::render_weather_icon))
+        .route("/render/tile/{event_id}/{side}", get(render::render_tile))
+        // Mock endpoints (unchanged, NFL-only)
+        .route(
+            "/mock/games",
+            get(mock::list_mock_games).post(mock::create_mock_game),
+        )
+        .route(
+            "/mock/games/{id}",
+            get(mock::get_mock_game).delete(mock::delete_mock_game),
+        )
+        .route("/mock/games/{id}/drives", get(mock::get_mock_game_drives))
+        .route("/mock/games/{id}/plays.csv", get(mock::get_mock_game_plays_csv))
+        .route("/mock/games/{id}/boxscore", get(mock::get_mock_game_boxscore))
+        .route("/mock/games/{id}/stream", get(mock::stream_mock_game))
+        .route("/mock/games/batch", post(mock::create_mock_game_batch))
+        .route("/mock/games/preset/{name}", post(mock::create_mock_game_preset))
+        .route("/mock/games/{id}/export", get(mock::export_mock_game))
+        .route("/mock/games/{id}/seek", post(mock::seek_mock_game))
+        .route("/mock/games/import", post(mock::restore_mock_game))
+        .route("/mock/games/import/{event_id}", post(mock::import_mock_game))
+        .route("/mock/teams/{team_id}/logo", get(mock::get_mock_team_logo))
+        .route("/mock/clock", get(mock::get_mock_clock))
+        // Devices
+        .route("/devices/register", post(device::register_device))
+        .route("/devices/{device_id}/game", get(device::get_device_game))
+        // Admin endpoints (X-Admin-Key)
+        .route("/admin/keys", get(admin::list_api_keys).post(admin::create_api_key))
+        .route("/admin/keys/{label}", delete(admin::revoke_api_key))
+        .route("/admin/devices", get(device::list_devices))
+        .route("/admin/devices/{device_id}", delete(device::remove_device))
+        .route("/admin/devices/{device_id}/assignment", put(device::assign_device))
+        .route("/admin/cache/flush", post(admin::flush_caches))
+        .route("/admin/config", get(admin::get_config))
+        .route("/admin/stats", get(admin::get_stats))
+        .route(
+            "/admin/log-level",
+            get(admin::get_log_level)
+                .put(admin::set_log_level)
+                .delete(admin::reset_log_level),
+        )
+        .route("/admin/unknown-plays", get(football::handler::get_unknown_plays))
+        .route("/admin/config/reload", post(admin::reload_config))
+        .route("/admin/mock/clock", post(mock::update_mock_clock))
+}
+
+/// Write the OpenAPI spec to `path`, or stdout for "-".
+fn export_openapi(path: &std::path::Path) -> std::io::Result<()> {
+    let spec = ApiDoc::openapi().to_pretty_json().map_err(std::io::Error::other)?;
+    if path.as_os_str() == "-" {
+        use std::io::Write;
+        writeln!(std::io::stdout(), "{}", spec)
+    } else {
+        std::fs::write(path, spec + "\n")
+    }
+}
+
 #[tokio::main]
 async fn main() {
-    // Initialize tracing with environment filter
-    // Supports RUST_LOG patterns like:
+    // Load configuration, with command-line flags over files and environment
+    let cli = cli::Cli::parse();
+    if let Some(path) = &cli.export_openapi {
+        if let Err(e) = export_openapi(path) {
+            eprintln!("Couldn't write OpenAPI spec to {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    let config = AppConfig::load(&cli);
+
+    // Initialize tracing with a filter swappable on config reload or through
+    // the admin API. --log-level or log_level in config, else RUST_LOG,
+    // supports patterns like:
     //   - "info" (default)
     //   - "info,espn::deserialize=debug" (show raw JSON on errors)
     //   - "debug" (verbose everything)
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-    // Use JSON format for production (Fly.io), human-readable for local dev
-    let use_json = std::env::var("LOG_FORMAT")
-        .map(|v| v == "json")
-        .unwrap_or(false);
-
-    if use_json {
-        tracing_subscriber::registry()
-            .with(env_filter)
-            .with(fmt::layer().json())
-            .init();
-    } else {
-        tracing_subscriber::registry()
-            .with(env_filter)
-            .with(fmt::layer())
-            .init();
-    }
+    // log_format (or LOG_FORMAT) = "json" for production (Fly.io), human-readable otherwise
+    let log_level = logging::init(&config);
+
+    clock::start_monotonic_clock();
+
+    // Cached scoreboards and registered devices, shared with other instances
+    // when Redis is configured
+    let storage = storage::connect(&config.storage).await;
 
-    // Load configuration
-    let config = AppConfig::load();
+    let api_keys = auth::KeyStore::new(&config);
+    let devices = device::DeviceRegistry::load(&config.provisioning, &api_keys, storage.clone()).await;
 
-    if config.api_key.is_none() {
+    if config.auth_disabled() {
         tracing::warn!(
             "No API key configured - authentication is disabled. \
              Set APP_API_KEY for production use."
         );
     } else {
-        tracing::info!("API key authentication is enabled");
+        tracing::info!(
+            device_keys = config.api_keys.len(),
+            bearer_tokens = config.jwt.secret.is_some(),
+            provisioning = config.provisioning.token.is_some(),
+            "API key authentication is enabled"
+        );
+    }
+    if config.admin_key_hash.is_none() && config.api_key.is_some() {
+        tracing::warn!(
+            "No admin key configured - api_key also opens the admin routes. \
+             Set APP_ADMIN_KEY_HASH to keep them behind a separate credential."
+        );
     }
 
     let bind_address = config.bind_address();
 
     // Create ESPN client with config
-    let espn_client = EspnClient::new(&config.espn);
+    let espn_client = EspnClient::new(&config.espn).with_scoreboard_cache(
+        storage.clone(),
+        poll::scoreboard_ttl(config.storage.scoreboard_ttl_secs, config.polling.interval_secs),
+    );
+
+    // Recorded responses stand in for ESPN when configured
+    let replay = config.replay.file.as_ref().and_then(|file| {
+        let speed = config.replay.speed;
+        match provider::replay::ReplayProvider::load(file.as_ref(), speed, espn_client.clone()) {
+            Ok(replay) => {
+                tracing::info!(file = %file, speed, "Replaying ESPN recording instead of ESPN");
+                Some(replay)
+            }
+            Err(e) => {
+                tracing::error!(file = %file, error = %e, "Couldn't read ESPN recording; using ESPN");
+                None
+            }
+        }
+    });
+    let from_espn = replay.is_none() && config.fixtures.dir.is_none();
+    let provider: Arc<dyn provider::SportsProvider> = match (replay, &config.fixtures.dir) {
+        (Some(replay), _) => Arc::new(replay),
+        (None, Some(dir)) => {
+            tracing::info!(dir = %dir, "Serving recorded fixtures instead of ESPN");
+            Arc::new(provider::fixture::FixtureProvider::new(
+                dir,
+                Duration::from_secs(config.fixtures.step_secs),
+            ))
+        }
+        (None, None) => Arc::new(espn_client.clone()),
+    };
+
+    // Processed logo cache (memory, plus disk if configured)
+    let logo_cache = team::cache::LogoCache::new(&config.logo_cache);
+
+    // Per-client rate limits for /api/*
+    let rate_limiter = rate_limit::RateLimiter::new(&config.rate_limit).map(Arc::new);
 
     // Create game repository for mock simulations
-    let game_repository = mock::GameRepository::new();
+    let mut game_repository = mock::GameRepository::new();
+    game_repository.set_max_play_history(config.mock.max_play_history);
+    if storage.is_shared() {
+        game_repository = game_repository.with_storage(storage.clone());
+    }
 
     // Load GeoIP database (optional — gracefully degrades if absent)
     let geoip_reader = match maxminddb::Reader::open_mmap(&config.geoip.mmdb_path) {
@@ -177,12 +491,80 @@ async fn main() {
 
     // Create shared application state
     let app_state = Arc::new(AppState {
+        provider,
         espn_client,
+        live: RwLock::new(config::LiveConfig::new(&config)),
         config,
+        cli,
+        log_level,
+        api_keys,
+        devices,
+        storage,
         game_repository,
+        logo_cache,
+        frame_cache: render::delta::FrameCache::new(),
+        rate_limiter: RwLock::new(rate_limiter),
+        health: health::Health::new(from_espn),
+        unknown_plays: football::telemetry::UnknownPlays::new(),
+        score_changes: football::changes::ScoreChanges::new(),
+        graphql: graphql::schema(),
         geoip_reader,
     });
 
+    #[cfg(unix)]
+    app_state.health.track(
+        "config_reload",
+        tokio::spawn(reload::reload_on_hangup(app_state.clone())),
+    );
+
+    // Devices changed on other instances, when storage is shared
+    if app_state.storage.is_shared() {
+        let every = Duration::from_secs(app_state.config.storage.device_sync_secs.max(1));
+        app_state.health.track(
+            "device_sync",
+            tokio::spawn(device::registry::sync_from_storage(app_state.clone(), every)),
+        );
+    }
+
+    // Background scoreboard polling, when an interval is configured and
+    // requests are served from ESPN
+    if app_state.config.polling.interval_secs > 0 && from_espn {
+        let polling = &app_state.config.polling;
+        app_state.health.track(
+            "poll",
+            tokio::spawn(poll::poll(
+                app_state.clone(),
+                polling.leagues.clone(),
+                poll::Cadence::new(polling),
+            )),
+        );
+    }
+
+    // Webhook alerts, when a URL is configured
+    if let Some(url) = app_state.config.alerts.webhook_url.clone() {
+        tracing::info!(format = ?app_state.config.alerts.format, "Webhook alerts enabled");
+        app_state.health.track(
+            "alerts",
+            tokio::spawn(alerts::monitor(app_state.clone(), url)),
+        );
+    }
+
+    // UDP score broadcasts, when a port is configured
+    if let Some(port) = app_state.config.broadcast.port {
+        app_state.health.track(
+            "broadcast",
+            tokio::spawn(broadcast::broadcast(app_state.clone(), port)),
+        );
+    }
+
+    // gRPC API, when a port is configured
+    if let Some(grpc_address) = app_state.config.grpc_bind_address() {
+        app_state.health.track(
+            "grpc",
+            tokio::spawn(grpc::serve(app_state.clone(), grpc_address)),
+        );
+    }
+
     // Build CORS layer
     let cors = CorsLayer::new()
         .allow_origin(Any)
@@ -190,37 +572,40 @@ async fn main() {
         .allow_headers(Any);
 
     // Build router
-    let app = Router::new()
+    let mut router = Router::new()
         .merge(Scalar::with_url("/", ApiDoc::openapi()))
-        .route("/health", get(health))
+        .route("/health/live", get(health::live))
+        .route("/health/ready", get(health::ready))
         .route("/time", get(clock::time))
-        // Football endpoints
-        .route("/api/football/{league}/games", get(football::handler::get_all_games))
-        .route("/api/football/{league}/games/{event_id}", get(football::handler::get_game))
-        .route("/api/football/{league}/{team_id}/logo", get(team::get_football_team_logo))
-        // Basketball endpoints
-        .route("/api/basketball/{league}/games", get(basketball::handler::get_all_games))
-        .route("/api/basketball/{league}/games/{event_id}", get(basketball::handler::get_game))
-        .route("/api/basketball/{league}/{team_id}/logo", get(team::get_basketball_team_logo))
-        // Mock endpoints (unchanged, NFL-only)
-        .route(
-            "/api/mock/games",
-            get(mock::list_mock_games).post(mock::create_mock_game),
-        )
-        .route(
-            "/api/mock/games/{id}",
-            get(mock::get_mock_game).delete(mock::delete_mock_game),
-        )
+        // Unversioned /api serves v1 shapes, so deployed firmware keeps working
+        .nest("/api", api_routes())
+        .nest("/api/v1", api_routes())
+        .nest(
+            "/api/v2",
+            api_routes().layer(axum::middleware::from_fn(versioning::v2_shim)),
+        );
+    if app_state.config.server.graphql {
+        tracing::info!("GraphQL enabled at /graphql");
+        router = router.route("/graphql", get(graphql::graphiql).post(graphql::graphql));
+    }
+    let app = router
+        .layer(axum::middleware::from_fn_with_state(
+            app_state.clone(),
+            rate_limit::rate_limit,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            app_state.clone(),
+            request_log::log_requests,
+        ))
         .layer(cors)
         .with_state(app_state);
 
     // Run server
     let listener = tokio::net::TcpListener::bind(&bind_address).await.unwrap();
     tracing::info!("Server running on http://{}", bind_address);
-    axum::serve(listener, app).await.unwrap();
-}
-
-async fn health() -> &'static str {
-    "OK"
+    // Peer addresses identify clients for rate limiting
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .await
+        .unwrap();
 }
 
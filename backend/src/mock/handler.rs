@@ -1,16 +1,37 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    body::Body,
+    extract::{Path, Query, State},
+    http::{HeaderMap, Response, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+    response::IntoResponse,
     Json,
 };
+use futures_util::stream::Stream;
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::auth::ApiKey;
+use chrono::{DateTime, Utc};
+
+use crate::auth::{AdminKey, ApiKey, MockWriteKey};
 use crate::error::{AppError, ErrorResponse};
-use crate::football::types::FootballGameResponse;
+use crate::export::Csv;
+use crate::football::transform;
+use crate::football::types::{DriveSummary, FootballBoxScore, FootballGameResponse};
+use crate::shared::transform::json_name;
+use crate::sport::FootballLeague;
+use crate::team::handler::serve_generated_logo;
+use crate::team::image::encode_png;
+use crate::team::types::LogoQuery;
 use crate::AppState;
 
-use super::simulation::CreateGameRequest;
+use super::simulation::{
+    batch_requests, format_clock, import_request, preset_options, CreateBatchOptions, CreateGameRequest,
+    GameSnapshot, SeekQuery, UpdateClockRequest, VirtualClockResponse,
+};
+use super::logo::placeholder_logo;
+use super::stream::game_events;
+use super::teams::find_team;
 
 /// GET /api/mock/games
 /// List all mock games in the repository
@@ -67,6 +88,257 @@ pub async fn get_mock_game(
     Ok(Json(game.to_game_response()))
 }
 
+/// GET /api/mock/games/{id}/drives
+/// Get the drive-by-drive summary of a mock game
+#[utoipa::path(
+    get,
+    path = "/api/mock/games/{id}/drives",
+    params(
+        ("id" = String, Path, description = "Game ID (e.g., 'sim_1')"),
+    ),
+    responses(
+        (status = 200, description = "Drives in the order they occurred, including those whose plays were dropped by `mock.max_play_history`.", body = Vec<DriveSummary>,
+            headers(("X-Plays-Evicted" = usize, description = "Plays dropped from the start of the game's history"))),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn get_mock_game_drives(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let game = state
+        .game_repository
+        .get(&id)
+        .await
+        .ok_or_else(|| AppError::MockGameNotFound(id))?;
+
+    Ok((
+        [("X-Plays-Evicted", game.plays_evicted().to_string())],
+        Json(game.to_drive_summaries()),
+    ))
+}
+
+/// GET /api/mock/games/{id}/plays.csv
+/// Export a mock game's play history as CSV
+#[utoipa::path(
+    get,
+    path = "/api/mock/games/{id}/plays.csv",
+    params(
+        ("id" = String, Path, description = "Game ID (e.g., 'sim_1')"),
+    ),
+    responses(
+        (status = 200, description = "CSV with a header row: sequence, period, clock, possession, play_type, yards_gained, clock_elapsed, description. `clock` is the game clock at the snap and `clock_elapsed` the seconds the play took. Plays dropped by `mock.max_play_history` are missing; `sequence` still counts them.", content_type = "text/csv"),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn get_mock_game_plays_csv(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Csv, AppError> {
+    let game = state
+        .game_repository
+        .get(&id)
+        .await
+        .ok_or_else(|| AppError::MockGameNotFound(id))?;
+
+    let mut csv = Csv::new(
+        "plays.csv",
+        &[
+            "sequence",
+            "period",
+            "clock",
+            "possession",
+            "play_type",
+            "yards_gained",
+            "clock_elapsed",
+            "description",
+        ],
+    );
+    for (i, play) in game.play_history().iter().enumerate() {
+        csv.row(&[
+            (game.plays_evicted() + i).to_string(),
+            json_name(play.period),
+            format_clock(play.clock_seconds),
+            json_name(play.possession),
+            json_name(play.play_type),
+            play.yards_gained.to_string(),
+            play.clock_elapsed.to_string(),
+            play.description.clone(),
+        ]);
+    }
+    Ok(csv)
+}
+
+/// GET /api/mock/games/{id}/stream
+/// Server-sent events as a mock game is simulated
+#[utoipa::path(
+    get,
+    path = "/api/mock/games/{id}/stream",
+    params(
+        ("id" = String, Path, description = "Game ID (e.g., 'sim_1')"),
+    ),
+    responses(
+        (status = 200, description = "Event stream: a `play` event per simulated play and a `game` event with the updated game after each change. Ends once the game is final.", content_type = "text/event-stream"),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn stream_mock_game(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    if state.game_repository.get(&id).await.is_none() {
+        return Err(AppError::MockGameNotFound(id));
+    }
+
+    let events = game_events(state.game_repository.clone(), id);
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// GET /api/mock/games/{id}/boxscore
+/// Get team statistics for a mock game
+#[utoipa::path(
+    get,
+    path = "/api/mock/games/{id}/boxscore",
+    params(
+        ("id" = String, Path, description = "Game ID (e.g., 'sim_1')"),
+    ),
+    responses(
+        (status = 200, description = "Team statistics (null for pregame)", body = Option<FootballBoxScore>),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn get_mock_game_boxscore(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Option<FootballBoxScore>>, AppError> {
+    let game = state
+        .game_repository
+        .get(&id)
+        .await
+        .ok_or_else(|| AppError::MockGameNotFound(id))?;
+
+    Ok(Json(game.to_box_score()))
+}
+
+/// GET /api/mock/games/{id}/export
+/// Export the complete internal state of a mock game
+#[utoipa::path(
+    get,
+    path = "/api/mock/games/{id}/export",
+    params(
+        ("id" = String, Path, description = "Game ID (e.g., 'sim_1')"),
+    ),
+    responses(
+        (status = 200, description = "Game snapshot, restorable via POST /api/mock/games/import", body = GameSnapshot),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn export_mock_game(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<GameSnapshot>, AppError> {
+    let game = state
+        .game_repository
+        .get(&id)
+        .await
+        .ok_or_else(|| AppError::MockGameNotFound(id))?;
+
+    Ok(Json(game.to_snapshot()))
+}
+
+/// POST /api/mock/games/import
+/// Restore a mock game from an exported snapshot
+#[utoipa::path(
+    post,
+    path = "/api/mock/games/import",
+    request_body = GameSnapshot,
+    responses(
+        (status = 201, description = "Game restored successfully under a new ID", body = FootballGameResponse),
+        (status = 400, description = "Invalid or unsupported snapshot", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = ["mock:write"])
+    ),
+    tag = "mock"
+)]
+pub async fn restore_mock_game(
+    _api_key: MockWriteKey,
+    State(state): State<Arc<AppState>>,
+    Json(snapshot): Json<GameSnapshot>,
+) -> Result<(StatusCode, Json<FootballGameResponse>), AppError> {
+    let game = state.game_repository.restore(snapshot.into_state()?).await;
+    Ok((StatusCode::CREATED, Json(game.to_game_response())))
+}
+
+/// POST /api/mock/games/{id}/seek
+/// Jump a live mock game to a point in game time.
+/// Rewinding replays the game from its seed, so the same plays happen again.
+#[utoipa::path(
+    post,
+    path = "/api/mock/games/{id}/seek",
+    params(
+        ("id" = String, Path, description = "Game ID (e.g., 'sim_1')"),
+        SeekQuery,
+    ),
+    responses(
+        (status = 200, description = "Game state at the target time", body = FootballGameResponse),
+        (status = 400, description = "Invalid target or game cannot be seeked", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = ["mock:write"])
+    ),
+    tag = "mock"
+)]
+pub async fn seek_mock_game(
+    _api_key: MockWriteKey,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<SeekQuery>,
+) -> Result<Json<FootballGameResponse>, AppError> {
+    let period = query.period().ok_or_else(|| {
+        AppError::InvalidSeekTarget(format!("quarter must be 1-8, got {}", query.quarter))
+    })?;
+    let clock = query.clock.as_deref().unwrap_or("15:00");
+
+    let game = state.game_repository.seek(&id, period, clock).await?;
+    Ok(Json(game.to_game_response()))
+}
+
 /// POST /api/mock/games
 /// Create a new mock game
 #[utoipa::path(
@@ -75,20 +347,123 @@ pub async fn get_mock_game(
     request_body = CreateGameRequest,
     responses(
         (status = 201, description = "Game created successfully", body = FootballGameResponse),
-        (status = 400, description = "Invalid request body", body = ErrorResponse),
+        (status = 400, description = "Invalid request body, or options the simulation can't run from", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
     ),
     security(
-        ("api_key" = [])
+        ("api_key" = ["mock:write"])
     ),
     tag = "mock"
 )]
 pub async fn create_mock_game(
-    _api_key: ApiKey,
+    _api_key: MockWriteKey,
     State(state): State<Arc<AppState>>,
     Json(request): Json<CreateGameRequest>,
 ) -> Result<(StatusCode, Json<FootballGameResponse>), AppError> {
-    let game = state.game_repository.create(request).await;
+    let game = state.game_repository.create(request).await?;
+    Ok((StatusCode::CREATED, Json(game.to_game_response())))
+}
+
+/// POST /api/mock/games/batch
+/// Create a slate of mock games in mixed states
+#[utoipa::path(
+    post,
+    path = "/api/mock/games/batch",
+    request_body = CreateBatchOptions,
+    responses(
+        (status = 201, description = "Games created successfully", body = Vec<FootballGameResponse>),
+        (status = 400, description = "Invalid request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = ["mock:write"])
+    ),
+    tag = "mock"
+)]
+pub async fn create_mock_game_batch(
+    _api_key: MockWriteKey,
+    State(state): State<Arc<AppState>>,
+    Json(options): Json<CreateBatchOptions>,
+) -> Result<(StatusCode, Json<Vec<FootballGameResponse>>), AppError> {
+    let mut responses = Vec::new();
+    for request in batch_requests(options) {
+        let game = state.game_repository.create(request).await?;
+        responses.push(game.to_game_response());
+    }
+    Ok((StatusCode::CREATED, Json(responses)))
+}
+
+/// POST /api/mock/games/preset/{name}
+/// Create a live mock game from a named scenario preset
+#[utoipa::path(
+    post,
+    path = "/api/mock/games/preset/{name}",
+    params(
+        ("name" = String, Path, description = "Preset name: two_minute_drill, goal_line_stand, hail_mary, blowout, comeback, snow_game"),
+    ),
+    responses(
+        (status = 201, description = "Game created successfully", body = FootballGameResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
+        (status = 404, description = "Unknown preset", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = ["mock:write"])
+    ),
+    tag = "mock"
+)]
+pub async fn create_mock_game_preset(
+    _api_key: MockWriteKey,
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<(StatusCode, Json<FootballGameResponse>), AppError> {
+    let options = preset_options(&name)?;
+    let game = state.game_repository.create(CreateGameRequest::Live(options)).await?;
+    Ok((StatusCode::CREATED, Json(game.to_game_response())))
+}
+
+/// POST /api/mock/games/import/{event_id}
+/// Snapshot a real NFL game from ESPN into a mock game that keeps simulating
+#[utoipa::path(
+    post,
+    path = "/api/mock/games/import/{event_id}",
+    params(
+        ("event_id" = String, Path, description = "ESPN event ID (numeric)"),
+    ),
+    responses(
+        (status = 201, description = "Game imported successfully", body = FootballGameResponse),
+        (status = 400, description = "Invalid event ID format, or a game state the simulation can't run from", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
+        (status = 404, description = "Game not found on current scoreboard", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN API", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = ["mock:write"])
+    ),
+    tag = "mock"
+)]
+pub async fn import_mock_game(
+    _api_key: MockWriteKey,
+    State(state): State<Arc<AppState>>,
+    Path(event_id): Path<String>,
+) -> Result<(StatusCode, Json<FootballGameResponse>), AppError> {
+    // Validate event_id is numeric only
+    if !event_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AppError::InvalidEventId(event_id));
+    }
+
+    let event = state.provider.fetch_game(FootballLeague::Nfl.into(), &event_id).await?;
+    state.unknown_plays.observe(&event);
+    let snapshot = transform::transform(&event, FootballLeague::Nfl)?;
+
+    let game = state
+        .game_repository
+        .create(import_request(&snapshot, &event_id))
+        .await?;
     Ok((StatusCode::CREATED, Json(game.to_game_response())))
 }
 
@@ -103,15 +478,16 @@ pub async fn create_mock_game(
     responses(
         (status = 204, description = "Game deleted successfully"),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key lacks the mock:write scope", body = ErrorResponse),
         (status = 404, description = "Game not found", body = ErrorResponse),
     ),
     security(
-        ("api_key" = [])
+        ("api_key" = ["mock:write"])
     ),
     tag = "mock"
 )]
 pub async fn delete_mock_game(
-    _api_key: ApiKey,
+    _api_key: MockWriteKey,
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> Result<StatusCode, AppError> {
@@ -121,3 +497,114 @@ pub async fn delete_mock_game(
         Err(AppError::MockGameNotFound(id))
     }
 }
+
+/// GET /api/mock/clock
+/// Get the current virtual time used by the simulation
+#[utoipa::path(
+    get,
+    path = "/api/mock/clock",
+    responses(
+        (status = 200, description = "Current virtual time", body = VirtualClockResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn get_mock_clock(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<VirtualClockResponse>, AppError> {
+    Ok(Json(state.game_repository.clock().to_response()))
+}
+
+/// POST /api/admin/mock/clock
+/// Advance or set the virtual time used by the simulation.
+/// Games catch up to the new time the next time they are fetched.
+/// Every mock game shares the clock, so this is an admin route.
+#[utoipa::path(
+    post,
+    path = "/api/admin/mock/clock",
+    request_body = UpdateClockRequest,
+    responses(
+        (status = 200, description = "Virtual time after the update", body = VirtualClockResponse),
+        (status = 400, description = "Invalid or backwards time change", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin key", body = ErrorResponse),
+        (status = 403, description = "No admin key configured, or key lacks the admin scope", body = ErrorResponse),
+    ),
+    security(
+        ("admin_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn update_mock_clock(
+    _admin_key: AdminKey,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<UpdateClockRequest>,
+) -> Result<Json<VirtualClockResponse>, AppError> {
+    let clock = state.game_repository.clock();
+
+    match (request.advance_seconds, request.time) {
+        (Some(seconds), None) => clock.advance(Duration::from_secs(seconds))?,
+        (None, Some(time)) => {
+            let target = time.parse::<DateTime<Utc>>().map_err(|_| {
+                AppError::InvalidVirtualTime(format!("'{}' is not an ISO 8601 datetime", time))
+            })?;
+            clock.set(target)?;
+        }
+        _ => {
+            return Err(AppError::InvalidVirtualTime(
+                "set exactly one of advance_seconds or time".to_string(),
+            ));
+        }
+    }
+
+    Ok(Json(clock.to_response()))
+}
+
+/// GET /api/mock/teams/{team_id}/logo
+/// Generated stand-in logo for an NFL team, rendered without contacting ESPN.
+/// Accepts the same processing options as the ESPN logo endpoints.
+#[utoipa::path(
+    get,
+    path = "/api/mock/teams/{team_id}/logo",
+    params(
+        ("team_id" = String, Path, description = "NFL team abbreviation (e.g., 'dal', 'nyg')"),
+        LogoQuery
+    ),
+    responses(
+        (status = 200, description = "Logo image", content(
+            ("image/png"),
+            ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("image/x-indexed"),
+            ("image/x-rgb888"),
+            ("image/x-rgb565"),
+            ("application/octet-stream")
+        )),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Team not found", body = ErrorResponse),
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "mock"
+)]
+pub async fn get_mock_team_logo(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(team_id): Path<String>,
+    Query(params): Query<LogoQuery>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, AppError> {
+    let team = find_team(&team_id).ok_or_else(|| AppError::TeamNotFound(team_id.clone()))?;
+
+    let key = format!("mock/{}", team.abbreviation);
+    serve_generated_logo(&state, &key, params, &headers, || {
+        encode_png(&placeholder_logo(team))
+    })
+    .await
+}
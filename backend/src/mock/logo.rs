@@ -0,0 +1,78 @@
+//! Generated stand-in logos for mock teams, so logo endpoints work with no
+//! ESPN access: the team abbreviation on a disc of the team's colors.
+
+use image::{Rgba, RgbaImage};
+
+use crate::render::font::{draw_text, text_width, GLYPH_HEIGHT};
+use crate::team::image::contrast_ratio;
+
+use super::teams::NflTeam;
+
+/// Matches the size of ESPN's logos, so processing behaves the same.
+const LOGO_SIZE: u32 = 500;
+
+/// Width of the secondary-color ring around the disc.
+const RING_WIDTH: f32 = 24.0;
+
+/// Widest the lettering may be, in pixels.
+const TEXT_WIDTH: u32 = 320;
+
+/// Draw a team's stand-in logo: a disc in the primary color ringed with the
+/// secondary color, with the abbreviation in black or white, whichever reads better.
+/// Outside the disc is transparent, like ESPN's logos.
+pub fn placeholder_logo(team: &NflTeam) -> RgbaImage {
+    let primary = (team.color.r, team.color.g, team.color.b);
+    let secondary = team.color_secondary;
+    let text = if contrast_ratio(primary, (0, 0, 0)) >= contrast_ratio(primary, (255, 255, 255)) {
+        Rgba([0, 0, 0, 255])
+    } else {
+        Rgba([255, 255, 255, 255])
+    };
+
+    let center = LOGO_SIZE as f32 / 2.0;
+    let radius = center - 4.0;
+    let mut img = RgbaImage::from_fn(LOGO_SIZE, LOGO_SIZE, |x, y| {
+        let distance = (x as f32 + 0.5 - center).hypot(y as f32 + 0.5 - center);
+        if distance > radius {
+            Rgba([0, 0, 0, 0])
+        } else if distance > radius - RING_WIDTH {
+            Rgba([secondary.r, secondary.g, secondary.b, 255])
+        } else {
+            Rgba([primary.0, primary.1, primary.2, 255])
+        }
+    });
+
+    let text_cells = text_width(team.abbreviation, 1).max(1);
+    let scale = TEXT_WIDTH / text_cells;
+    let left = (LOGO_SIZE - text_cells * scale) / 2;
+    let top = (LOGO_SIZE - GLYPH_HEIGHT * scale) / 2;
+    draw_text(&mut img, left, top, team.abbreviation, scale, text);
+
+    img
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::teams::find_team;
+
+    #[test]
+    fn test_placeholder_logo_layout() {
+        let team = find_team("KC").unwrap();
+        let img = placeholder_logo(team);
+
+        assert_eq!(img.dimensions(), (LOGO_SIZE, LOGO_SIZE));
+        // Corners are transparent, the rim is the secondary color
+        assert_eq!(img.get_pixel(0, 0)[3], 0);
+        let secondary = team.color_secondary;
+        assert_eq!(
+            *img.get_pixel(250, 10),
+            Rgba([secondary.r, secondary.g, secondary.b, 255])
+        );
+
+        // Red KC gets white lettering; the K's top-left cell is at the text origin
+        let scale = TEXT_WIDTH / 11;
+        let (left, top) = ((LOGO_SIZE - 11 * scale) / 2, (LOGO_SIZE - 7 * scale) / 2);
+        assert_eq!(*img.get_pixel(left, top), Rgba([255, 255, 255, 255]));
+    }
+}
@@ -1,6 +1,13 @@
 pub mod handler;
+pub mod logo;
 pub mod simulation;
+pub mod stream;
 pub mod teams;
 
-pub use handler::{create_mock_game, delete_mock_game, get_mock_game, list_mock_games};
+pub use handler::{
+    create_mock_game, create_mock_game_batch, create_mock_game_preset, delete_mock_game,
+    export_mock_game, get_mock_clock, get_mock_game, get_mock_game_boxscore,
+    get_mock_game_drives, get_mock_game_plays_csv, get_mock_team_logo, import_mock_game,
+    stream_mock_game, list_mock_games, restore_mock_game, seek_mock_game, update_mock_clock,
+};
 pub use simulation::GameRepository;
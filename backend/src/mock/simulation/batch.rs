@@ -0,0 +1,135 @@
+//! Bulk game creation: a full slate of games from a single request.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::football::types::FootballPeriod;
+use crate::mock::teams::NFL_TEAMS;
+
+use super::options::{
+    BatchMix, CreateBatchOptions, CreateFinalOptions, CreateGameRequest, CreateLiveOptions,
+    CreatePregameOptions,
+};
+use super::state::format_clock;
+
+/// Most games a batch can hold without a team playing twice.
+const MAX_BATCH_SIZE: u8 = (NFL_TEAMS.len() / 2) as u8;
+
+/// Build one create request per game in the batch.
+///
+/// Games are ordered like a Sunday slate: finished early games, then games in
+/// progress, then later kickoffs staggered by `stagger_seconds`.
+pub fn batch_requests(opts: CreateBatchOptions) -> Vec<CreateGameRequest> {
+    let mut rng = StdRng::seed_from_u64(opts.seed.unwrap_or_else(rand::random));
+    let count = opts.count.unwrap_or(14).clamp(1, MAX_BATCH_SIZE) as usize;
+    let stagger_seconds = opts.stagger_seconds.unwrap_or(120);
+
+    let (final_count, live_count) = split_counts(count, opts.mix);
+
+    // Each team appears at most once in the batch
+    let mut teams: Vec<&str> = NFL_TEAMS.iter().map(|t| t.abbreviation).collect();
+    teams.shuffle(&mut rng);
+    let mut matchups = teams
+        .chunks(2)
+        .map(|pair| (Some(pair[0].to_string()), Some(pair[1].to_string())));
+
+    let mut requests = Vec::with_capacity(count);
+    for index in 0..count {
+        let (home_team, away_team) = matchups.next().expect("Enough teams for the batch size");
+
+        let request = if index < final_count {
+            CreateGameRequest::Final(CreateFinalOptions {
+                home_team,
+                away_team,
+                overtime: Some(rng.gen_bool(0.05)),
+                ..Default::default()
+            })
+        } else if index < final_count + live_count {
+            let options = random_live_options(home_team, away_team, &mut rng, opts.time_scale);
+            CreateGameRequest::Live(options)
+        } else {
+            let kickoff_index = (index - final_count - live_count) as u32 + 1;
+            CreateGameRequest::Pregame(CreatePregameOptions {
+                home_team,
+                away_team,
+                in_seconds: Some(stagger_seconds.saturating_mul(kickoff_index)),
+                seed: Some(rng.r#gen()),
+                time_scale: opts.time_scale,
+                ..Default::default()
+            })
+        };
+        requests.push(request);
+    }
+
+    requests
+}
+
+/// Split the batch into (final, live) counts by weight; the rest are pregames.
+fn split_counts(count: usize, mix: Option<BatchMix>) -> (usize, usize) {
+    let mix = mix
+        .filter(|m| m.pregame as u32 + m.live as u32 + m.final_ as u32 > 0)
+        .unwrap_or(BatchMix {
+            pregame: 4,
+            live: 6,
+            final_: 4,
+        });
+
+    let total = mix.pregame as usize + mix.live as usize + mix.final_ as usize;
+    let final_count = count * mix.final_ as usize / total;
+    let live_count = count * mix.live as usize / total;
+    (final_count, live_count)
+}
+
+/// A game somewhere in regulation with a plausible score for that point.
+fn random_live_options(
+    home_team: Option<String>,
+    away_team: Option<String>,
+    rng: &mut StdRng,
+    time_scale: Option<f64>,
+) -> CreateLiveOptions {
+    let quarter = rng.gen_range(1..=4u8);
+    let period = match quarter {
+        1 => FootballPeriod::Q1,
+        2 => FootballPeriod::Q2,
+        3 => FootballPeriod::Q3,
+        _ => FootballPeriod::Q4,
+    };
+    let clock_seconds = rng.gen_range(1..900u16);
+
+    CreateLiveOptions {
+        home_team,
+        away_team,
+        home_score: Some(random_score(quarter, rng)),
+        away_score: Some(random_score(quarter, rng)),
+        period: Some(period),
+        clock: Some(format_clock(clock_seconds)),
+        seed: Some(rng.r#gen()),
+        time_scale,
+        ..Default::default()
+    }
+}
+
+/// Points scored over `quarters` quarters, built from typical per-quarter totals.
+fn random_score(quarters: u8, rng: &mut StdRng) -> u8 {
+    const QUARTER_POINTS: [u8; 6] = [0, 0, 3, 7, 7, 10];
+    (0..quarters)
+        .map(|_| QUARTER_POINTS[rng.gen_range(0..QUARTER_POINTS.len())])
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_counts_by_weight() {
+        let mix = BatchMix {
+            pregame: 1,
+            live: 2,
+            final_: 1,
+        };
+        assert_eq!(split_counts(8, Some(mix)), (2, 4));
+        assert_eq!(split_counts(14, None), (4, 6));
+    }
+}
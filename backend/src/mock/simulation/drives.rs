@@ -1,14 +1,25 @@
 //! Drive logic: scoring, turnovers, possession changes, down/distance updates.
 
+use std::ops::Range;
+
 use rand::Rng;
 
-use crate::football::types::{Down, PlayType, Possession};
+use crate::football::types::{
+    Down, DriveResult, DriveSummary, FootballPeriod, PlayType, Possession,
+};
 
-use super::plays::{PlayOutcome, ScoringPlay};
-use super::state::LiveState;
+use super::options::Aggressiveness;
+use super::plays::{Penalty, PlayOutcome, ScoringPlay};
+use super::state::{format_clock, is_overtime, LiveState, SimulatedPlay};
 
 /// Apply the outcome of a play to the game state.
 pub fn apply_play_outcome(state: &mut LiveState, outcome: &PlayOutcome) {
+    // A flag wipes out whatever else happened on the play
+    if let Some(penalty) = &outcome.penalty {
+        handle_penalty(state, penalty);
+        return;
+    }
+
     // Handle scoring plays first
     if let Some(scoring) = &outcome.scoring {
         match scoring {
@@ -39,8 +50,13 @@ fn handle_touchdown(state: &mut LiveState) {
     // Add 6 points
     add_score(state, 6);
 
-    // Extra point attempt (simplified: 94% success rate)
-    if state.rng.gen_bool(0.94) {
+    if should_go_for_two(state.aggressiveness, state.period, scoring_margin(state)) {
+        // Two-point conversion (roughly 48% league-wide)
+        if state.rng.gen_bool(0.48) {
+            add_score(state, 2);
+        }
+    } else if state.rng.gen_bool(0.94) {
+        // Extra point attempt (simplified: 94% success rate)
         add_score(state, 1);
     }
 
@@ -48,6 +64,31 @@ fn handle_touchdown(state: &mut LiveState) {
     setup_kickoff_after_score(state);
 }
 
+/// Points the offense leads by (negative when trailing).
+fn scoring_margin(state: &LiveState) -> i16 {
+    let (own, other) = match state.possession {
+        Possession::Home => (state.home_score, state.away_score),
+        Possession::Away => (state.away_score, state.home_score),
+    };
+    own as i16 - other as i16
+}
+
+/// Two-point decision after a touchdown, given the margin with the six points counted.
+fn should_go_for_two(aggressiveness: Aggressiveness, period: FootballPeriod, margin: i16) -> bool {
+    // Classic two-point chart margins
+    let chart = matches!(margin, -10 | -5 | -2 | 1 | 5 | 12);
+    let fourth_quarter = period == FootballPeriod::Q4 || is_overtime(period);
+
+    match aggressiveness {
+        Aggressiveness::Conservative => fourth_quarter && margin == -2,
+        Aggressiveness::Balanced => fourth_quarter && chart,
+        // Also goes for two down 8, to learn early whether a second TD can win it
+        Aggressiveness::Analytics => {
+            (fourth_quarter || period == FootballPeriod::Q3) && (chart || margin == -8)
+        }
+    }
+}
+
 fn handle_field_goal(state: &mut LiveState) {
     // Add 3 points
     add_score(state, 3);
@@ -59,11 +100,7 @@ fn handle_field_goal(state: &mut LiveState) {
 fn handle_safety(state: &mut LiveState) {
     // Safety scores 2 points for the DEFENSE
     let scoring_team = opponent(state.possession);
-    if scoring_team == Possession::Home {
-        state.home_score += 2;
-    } else {
-        state.away_score += 2;
-    }
+    state.add_points(scoring_team, 2);
 
     // After a safety, the team that was scored on kicks off (free kick)
     // This is a bit unusual - the team that got the safety kicks to the team that scored
@@ -174,6 +211,30 @@ fn update_field_position(state: &mut LiveState, outcome: &PlayOutcome) {
     state.yard_line = new_yard_line;
 }
 
+/// Enforce a penalty from the previous spot. The line to gain stays put, so
+/// the offense has a first down if the ball reaches it or the foul gives one
+/// automatically; otherwise the down is replayed, or lost for fouls that cost
+/// the down, which can turn the ball over on downs.
+fn handle_penalty(state: &mut LiveState, penalty: &Penalty) {
+    let yards = penalty.enforced_yards(state.yard_line);
+    let distance = state.distance as i16 - yards as i16;
+    state.yard_line = (state.yard_line as i16 + yards as i16).clamp(1, 99) as u8;
+
+    if penalty.automatic_first_down || distance <= 0 {
+        state.down = Down::First;
+        state.distance = 10.min(100 - state.yard_line);
+        return;
+    }
+
+    state.distance = distance.min(99) as u8;
+    if penalty.loss_of_down {
+        state.down = next_down(state.down);
+        if state.down == Down::First {
+            handle_turnover_on_downs(state);
+        }
+    }
+}
+
 fn handle_turnover_on_downs(state: &mut LiveState) {
     flip_possession(state);
     state.yard_line = 100 - state.yard_line;
@@ -191,11 +252,7 @@ fn setup_kickoff_after_score(state: &mut LiveState) {
 }
 
 fn add_score(state: &mut LiveState, points: u8) {
-    if state.possession == Possession::Home {
-        state.home_score = state.home_score.saturating_add(points);
-    } else {
-        state.away_score = state.away_score.saturating_add(points);
-    }
+    state.add_points(state.possession, points);
 }
 
 fn flip_possession(state: &mut LiveState) {
@@ -217,3 +274,311 @@ fn next_down(down: Down) -> Down {
         Down::Fourth => Down::First, // Will trigger turnover check
     }
 }
+
+/// Group a play history into drives.
+///
+/// A new drive starts whenever possession changes or a half ends. The last
+/// drive is closed with `EndOfGame` when `game_over` is set, otherwise it is
+/// reported as still in progress.
+pub fn summarize_drives(history: &[SimulatedPlay], game_over: bool) -> Vec<DriveSummary> {
+    split_drives(history, game_over)
+        .into_iter()
+        .map(|(plays, result)| summarize_drive(&history[plays], result))
+        .collect()
+}
+
+/// Remove whole drives from the front of `history` until at most `max` plays
+/// remain, adding their summaries to `evicted`. The last drive is never
+/// removed, since it may still be in progress. Returns the plays removed.
+pub fn evict_drives(
+    history: &mut Vec<SimulatedPlay>,
+    max: usize,
+    evicted: &mut Vec<DriveSummary>,
+) -> usize {
+    let mut removed = 0;
+    for (plays, result) in split_drives(history, false) {
+        if history.len() - removed <= max || plays.end == history.len() {
+            break;
+        }
+        evicted.push(summarize_drive(&history[plays.clone()], result));
+        removed = plays.end;
+    }
+    history.drain(..removed);
+    removed
+}
+
+/// The plays making up each drive in `history`, and how it ended.
+fn split_drives(
+    history: &[SimulatedPlay],
+    game_over: bool,
+) -> Vec<(Range<usize>, Option<DriveResult>)> {
+    let mut drives = Vec::new();
+    let mut start = 0;
+
+    for end in 1..=history.len() {
+        let last = &history[end - 1];
+        let next = history.get(end);
+
+        let drive_over = match next {
+            Some(next) => {
+                next.possession != last.possession || half_ended(last.period, next.period)
+            }
+            None => true,
+        };
+
+        if !drive_over {
+            continue;
+        }
+
+        let result = match next {
+            Some(next) => Some(terminal_result(last).unwrap_or(
+                if half_ended(last.period, next.period) {
+                    DriveResult::EndOfHalf
+                } else {
+                    DriveResult::TurnoverOnDowns
+                },
+            )),
+            None if game_over => Some(terminal_result(last).unwrap_or(DriveResult::EndOfGame)),
+            None => terminal_result(last),
+        };
+
+        drives.push((start..end, result));
+        start = end;
+    }
+
+    drives
+}
+
+fn summarize_drive(plays: &[SimulatedPlay], result: Option<DriveResult>) -> DriveSummary {
+    let first = &plays[0];
+
+    let scrimmage_plays = plays.iter().filter(|p| {
+        !matches!(p.play_type, PlayType::Kickoff | PlayType::KickoffReturn)
+    });
+
+    let yards = scrimmage_plays
+        .clone()
+        .filter(|p| {
+            !matches!(
+                p.play_type,
+                PlayType::Punt | PlayType::FieldGoalGood | PlayType::FieldGoalMissed
+            )
+        })
+        .map(|p| p.yards_gained as i16)
+        .sum();
+
+    let time_of_possession: u16 = scrimmage_plays.clone().map(|p| p.clock_used).sum();
+
+    DriveSummary {
+        possession: first.possession,
+        result,
+        plays: scrimmage_plays.count() as u16,
+        yards,
+        time_of_possession: format_clock(time_of_possession),
+        start_period: first.period,
+        start_clock: format_clock(first.clock_seconds),
+    }
+}
+
+/// Drive result implied by the play itself, if it is a drive-ending play.
+fn terminal_result(play: &SimulatedPlay) -> Option<DriveResult> {
+    match play.play_type {
+        PlayType::PassingTouchdown | PlayType::RushingTouchdown => Some(DriveResult::Touchdown),
+        PlayType::FieldGoalGood => Some(DriveResult::FieldGoal),
+        PlayType::FieldGoalMissed | PlayType::BlockedFieldGoal => {
+            Some(DriveResult::MissedFieldGoal)
+        }
+        PlayType::Punt => Some(DriveResult::Punt),
+        PlayType::Interception | PlayType::FumbleRecoveryOpponent => Some(DriveResult::Turnover),
+        PlayType::Safety => Some(DriveResult::Safety),
+        _ => None,
+    }
+}
+
+/// Whether moving from one period to the next ends a half (or overtime period).
+fn half_ended(previous: FootballPeriod, next: FootballPeriod) -> bool {
+    previous != next && !matches!(previous, FootballPeriod::Q1 | FootballPeriod::Q3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::simulation::engine::run_play;
+    use crate::mock::simulation::options::{OvertimeRules, Pace};
+    use crate::mock::simulation::plays::PenaltyOn;
+    use crate::mock::simulation::state::TeamInfo;
+    use crate::mock::teams::find_team;
+
+    fn play(
+        play_type: PlayType,
+        yards: i8,
+        possession: Possession,
+        period: FootballPeriod,
+    ) -> SimulatedPlay {
+        SimulatedPlay {
+            play_type,
+            yards_gained: yards,
+            description: String::new(),
+            clock_elapsed: 30,
+            clock_used: 30,
+            possession,
+            period,
+            clock_seconds: 600,
+        }
+    }
+
+    #[test]
+    fn test_drive_ends_on_touchdown() {
+        use FootballPeriod::Q1;
+        let history = vec![
+            play(PlayType::Kickoff, 0, Possession::Home, Q1),
+            play(PlayType::Rush, 5, Possession::Home, Q1),
+            play(PlayType::PassingTouchdown, 70, Possession::Home, Q1),
+            play(PlayType::Kickoff, 0, Possession::Away, Q1),
+        ];
+
+        let drives = summarize_drives(&history, false);
+
+        assert_eq!(drives.len(), 2);
+        assert_eq!(drives[0].result, Some(DriveResult::Touchdown));
+        assert_eq!(drives[0].plays, 2);
+        assert_eq!(drives[0].yards, 75);
+        // The kickoff is on neither team's clock
+        assert_eq!(drives[0].time_of_possession, "1:00");
+        assert_eq!(drives[1].possession, Possession::Away);
+        assert_eq!(drives[1].result, None);
+    }
+
+    #[test]
+    fn test_turnover_on_downs_and_end_of_half() {
+        use FootballPeriod::{Q2, Q3};
+        let history = vec![
+            play(PlayType::Rush, 0, Possession::Home, Q2),
+            play(PlayType::Rush, 2, Possession::Away, Q2),
+            play(PlayType::Kickoff, 0, Possession::Away, Q3),
+        ];
+
+        let drives = summarize_drives(&history, true);
+
+        assert_eq!(drives[0].result, Some(DriveResult::TurnoverOnDowns));
+        assert_eq!(drives[1].result, Some(DriveResult::EndOfHalf));
+        assert_eq!(drives[2].result, Some(DriveResult::EndOfGame));
+    }
+
+    fn live(down: Down, distance: u8, yard_line: u8) -> LiveState {
+        let team = |abbr| TeamInfo::from_nfl_team(find_team(abbr).unwrap(), None);
+        let mut state = LiveState::new(
+            team("KC"),
+            team("BUF"),
+            7,
+            1.0,
+            OvertimeRules::Regular,
+            Aggressiveness::default(),
+            Pace::default(),
+            None,
+            std::time::Instant::now(),
+        );
+        state.kickoff_pending = false;
+        state.possession = Possession::Home;
+        (state.down, state.distance, state.yard_line) = (down, distance, yard_line);
+        state
+    }
+
+    fn flag(on: PenaltyOn, yards: u8) -> PlayOutcome {
+        PlayOutcome {
+            play_type: PlayType::Penalty,
+            yards_gained: 0,
+            clock_elapsed: 0,
+            description: String::new(),
+            turnover: false,
+            scoring: None,
+            penalty: Some(Penalty {
+                on,
+                yards,
+                automatic_first_down: false,
+                loss_of_down: false,
+                spot_foul: false,
+            }),
+        }
+    }
+
+    fn situation(state: &LiveState) -> (Possession, Down, u8, u8) {
+        (state.possession, state.down, state.distance, state.yard_line)
+    }
+
+    #[test]
+    fn test_penalty_replays_the_down_from_the_new_spot() {
+        // Holding at the 10 is half the distance: 1st & 15 from the 5
+        let mut state = live(Down::First, 10, 10);
+        apply_play_outcome(&mut state, &flag(PenaltyOn::Offense, 10));
+        assert_eq!(situation(&state), (Possession::Home, Down::First, 15, 5));
+
+        // Offside on 3rd & 7 replays it; on 3rd & 2 it moves the chains
+        let mut state = live(Down::Third, 7, 40);
+        apply_play_outcome(&mut state, &flag(PenaltyOn::Defense, 5));
+        assert_eq!(situation(&state), (Possession::Home, Down::Third, 2, 45));
+        apply_play_outcome(&mut state, &flag(PenaltyOn::Defense, 5));
+        assert_eq!(situation(&state), (Possession::Home, Down::First, 10, 50));
+
+        let mut state = live(Down::Second, 6, 30);
+        apply_play_outcome(&mut state, &flag(PenaltyOn::Offsetting, 0));
+        assert_eq!(situation(&state), (Possession::Home, Down::Second, 6, 30));
+    }
+
+    #[test]
+    fn test_penalty_first_downs_and_loss_of_down() {
+        let mut holding = flag(PenaltyOn::Defense, 5);
+        holding.penalty.as_mut().unwrap().automatic_first_down = true;
+        let mut state = live(Down::Third, 8, 40);
+        apply_play_outcome(&mut state, &holding);
+        assert_eq!(situation(&state), (Possession::Home, Down::First, 10, 45));
+
+        // Pass interference is spotted, but no further than the 1
+        let mut interference = flag(PenaltyOn::Defense, 30);
+        let penalty = interference.penalty.as_mut().unwrap();
+        (penalty.automatic_first_down, penalty.spot_foul) = (true, true);
+        let mut state = live(Down::Second, 10, 90);
+        apply_play_outcome(&mut state, &interference);
+        assert_eq!(situation(&state), (Possession::Home, Down::First, 1, 99));
+
+        let mut grounding = flag(PenaltyOn::Offense, 10);
+        grounding.penalty.as_mut().unwrap().loss_of_down = true;
+        let mut state = live(Down::Second, 10, 50);
+        apply_play_outcome(&mut state, &grounding);
+        assert_eq!(situation(&state), (Possession::Home, Down::Third, 20, 40));
+
+        // Losing fourth down turns it over where the ball was marked off to
+        let mut state = live(Down::Fourth, 5, 50);
+        apply_play_outcome(&mut state, &grounding);
+        assert_eq!(situation(&state), (Possession::Away, Down::First, 10, 60));
+    }
+
+    #[test]
+    fn test_pre_snap_flags_take_no_time() {
+        let mut state = live(Down::First, 10, 40);
+        state.clock_seconds = 600;
+        run_play(&mut state, flag(PenaltyOn::Offense, 5));
+        assert_eq!(state.clock_seconds, 600);
+        assert_eq!(state.simulated_game_seconds, 0);
+        assert_eq!(state.home_stats.time_of_possession, 0);
+        assert_eq!(situation(&state), (Possession::Home, Down::First, 15, 35));
+    }
+
+    #[test]
+    fn test_stopped_clock_plays_count_the_time_that_ran() {
+        let mut state = live(Down::First, 10, 40);
+        state.clock_seconds = 600;
+        let incompletion = PlayOutcome {
+            play_type: PlayType::PassIncompletion,
+            clock_elapsed: 30,
+            penalty: None,
+            ..flag(PenaltyOn::Offense, 0)
+        };
+        run_play(&mut state, incompletion);
+        assert_eq!(state.clock_seconds, 595);
+        assert_eq!(state.play_history[0].clock_used, 5);
+        assert_eq!(state.home_stats.time_of_possession, 5);
+        let drives = summarize_drives(&state.play_history, false);
+        assert_eq!(drives[0].time_of_possession, "0:05");
+    }
+}
@@ -1,17 +1,22 @@
 //! Simulation engine: time advancement, quarter transitions, state management.
 
-use crate::football::types::{FootballPeriod, Possession};
+use crate::football::types::{Down, FootballPeriod, Possession};
+
+use std::time::Instant;
+
+use rand::Rng;
 
 use super::drives::apply_play_outcome;
-use super::plays::{generate_play, outcome_to_play};
-use super::state::LiveState;
+use super::options::OvertimeRules;
+use super::plays::{apply_tempo, generate_play, outcome_to_play, PlayOutcome, ScoringPlay};
+use super::state::{game_position, LiveState, OvertimeState, HALFTIME_SECONDS};
 
 /// Advance the game state to the current wall-clock time.
 ///
 /// This is called when a game is fetched, to simulate all plays
 /// that should have occurred since the last access.
-pub fn advance_to_now(state: &mut LiveState) {
-    let real_elapsed = state.game_start_instant.elapsed();
+pub fn advance_to_now(state: &mut LiveState, now: Instant) {
+    let real_elapsed = now.saturating_duration_since(state.game_start_instant);
     let target_game_seconds = (real_elapsed.as_secs_f64() * state.time_scale) as u64;
 
     // Only advance if we're behind the target time
@@ -27,54 +32,109 @@ fn advance_to_target(state: &mut LiveState, target_game_seconds: u64) {
     let target = target_game_seconds.min(state.simulated_game_seconds + MAX_GAME_SECONDS);
 
     while state.simulated_game_seconds < target && !is_game_over(state) {
-        // Handle halftime
-        if is_halftime(state) {
-            handle_halftime(state);
-            continue;
+        if !simulate_step(state, target) {
+            break;
         }
+    }
+}
 
-        // Handle quarter transitions
-        if state.clock_seconds == 0 {
-            if !handle_quarter_end(state) {
-                // Game over
-                break;
-            }
-            continue;
-        }
+/// Move the game forward until the clock reaches `clock_seconds` in `period`.
+///
+/// Stops at the first snap at or past the target, or when the game ends.
+pub fn advance_to_game_time(state: &mut LiveState, period: FootballPeriod, clock_seconds: u16) {
+    let target = game_position(period, clock_seconds);
 
-        // Generate and execute a play
-        let outcome = generate_play(state);
-        let play_duration = outcome.clock_elapsed.min(state.clock_seconds);
+    while game_position(state.period, state.clock_seconds) < target && !is_game_over(state) {
+        if !simulate_step(state, u64::MAX) {
+            break;
+        }
+    }
+}
 
-        // Apply the play
-        apply_play_outcome(state, &outcome);
+/// Simulate one play or period transition. Returns false once the game is over.
+///
+/// `target` bounds how much of the halftime intermission is consumed.
+fn simulate_step(state: &mut LiveState, target: u64) -> bool {
+    // Handle halftime
+    if is_halftime(state) {
+        start_halftime(state);
+        return true;
+    }
 
-        // Record the play
-        let play = outcome_to_play(&outcome);
-        state.last_play = Some(play.clone());
-        state.play_history.push(play);
+    // Let the halftime intermission run out before the second half
+    if state.period == FootballPeriod::Halftime {
+        let elapsed = (target - state.simulated_game_seconds)
+            .min(state.halftime_remaining as u64) as u16;
+        state.halftime_remaining -= elapsed;
+        state.simulated_game_seconds += elapsed as u64;
 
-        // Update game clock
-        if should_clock_run(&outcome) {
-            state.clock_seconds = state.clock_seconds.saturating_sub(play_duration);
-        } else {
-            // Clock stopped - minimal time passes
-            state.clock_seconds = state.clock_seconds.saturating_sub(5.min(play_duration));
+        if state.halftime_remaining == 0 {
+            handle_halftime(state);
         }
+        return true;
+    }
 
-        // Update clock running status for display
-        state.clock_running = should_clock_run(&outcome);
+    // Handle quarter transitions
+    if state.clock_seconds == 0 {
+        return handle_quarter_end(state);
+    }
 
-        // Track ACTUAL simulated game time (the full play duration)
-        state.simulated_game_seconds += play_duration as u64;
+    // Generate and execute a play
+    let outcome = generate_play(state);
+    run_play(state, outcome);
 
-        // Handle two-minute warning
-        if state.clock_seconds <= 120
-            && state.clock_seconds > 115
-            && matches!(state.period, FootballPeriod::Q2 | FootballPeriod::Q4)
-        {
-            state.clock_running = false;
-        }
+    true
+}
+
+/// Run a generated play: move the ball, count it, and run the clock.
+pub fn run_play(state: &mut LiveState, mut outcome: PlayOutcome) {
+    outcome.clock_elapsed = apply_tempo(outcome.clock_elapsed, state.pace);
+    let play_duration = outcome.clock_elapsed.min(state.clock_seconds);
+
+    // Capture the snap situation before the outcome changes it
+    let possession = state.possession;
+    let period = state.period;
+    let clock_seconds = state.clock_seconds;
+    let down = state.down;
+
+    // Apply the play
+    apply_play_outcome(state, &outcome);
+    state.track_overtime_possession(possession);
+
+    // Update game clock
+    if should_clock_run(&outcome) {
+        state.clock_seconds = state.clock_seconds.saturating_sub(play_duration);
+    } else {
+        // Clock stopped - minimal time passes
+        state.clock_seconds = state.clock_seconds.saturating_sub(5.min(play_duration));
+    }
+    let clock_used = clock_seconds - state.clock_seconds;
+
+    // Update box score totals for the offense
+    let converted = matches!(outcome.scoring, Some(ScoringPlay::Touchdown))
+        || (state.possession == possession && state.down == Down::First);
+    state
+        .stats_mut(possession)
+        .record_play(&outcome, down, converted, clock_used);
+
+    // Record the play
+    let mut play = outcome_to_play(&outcome, possession, period, clock_seconds);
+    play.clock_used = clock_used;
+    state.last_play = Some(play.clone());
+    state.play_history.push(play);
+
+    // Update clock running status for display
+    state.clock_running = should_clock_run(&outcome);
+
+    // Track ACTUAL simulated game time (the full play duration)
+    state.simulated_game_seconds += play_duration as u64;
+
+    // Handle two-minute warning
+    if state.clock_seconds <= 120
+        && state.clock_seconds > 115
+        && matches!(state.period, FootballPeriod::Q2 | FootballPeriod::Q4)
+    {
+        state.clock_running = false;
     }
 }
 
@@ -88,6 +148,13 @@ fn is_halftime(state: &LiveState) -> bool {
     state.period == FootballPeriod::Q2 && state.clock_seconds == 0
 }
 
+/// End the second quarter and start the halftime intermission.
+fn start_halftime(state: &mut LiveState) {
+    state.period = FootballPeriod::Halftime;
+    state.halftime_remaining = HALFTIME_SECONDS;
+    state.clock_running = false;
+}
+
 /// Handle halftime transition.
 fn handle_halftime(state: &mut LiveState) {
     state.period = FootballPeriod::Q3;
@@ -108,6 +175,8 @@ fn handle_halftime(state: &mut LiveState) {
 
 /// Handle end of quarter. Returns false if game is over.
 fn handle_quarter_end(state: &mut LiveState) -> bool {
+    let tied = state.home_score == state.away_score;
+
     match state.period {
         FootballPeriod::Q1 => {
             state.period = FootballPeriod::Q2;
@@ -123,40 +192,72 @@ fn handle_quarter_end(state: &mut LiveState) -> bool {
             state.clock_seconds = 900;
             true
         }
-        FootballPeriod::Q4 => {
-            // Check for overtime
-            if state.home_score == state.away_score {
-                state.period = FootballPeriod::OT;
-                state.clock_seconds = 600; // 10-minute OT in regular season
-                state.kickoff_pending = true;
-                // Reset timeouts for OT
-                state.home_timeouts = 2;
-                state.away_timeouts = 2;
-                true
-            } else {
-                // Game over
-                false
-            }
-        }
-        FootballPeriod::OT => {
-            // In NFL OT, first score wins (simplified)
-            // If still tied, go to double OT
-            if state.home_score == state.away_score {
-                state.period = FootballPeriod::OT2;
-                state.clock_seconds = 600;
-                true
-            } else {
-                false
-            }
+        FootballPeriod::Q4 if tied => {
+            start_overtime(state);
+            true
         }
-        FootballPeriod::OT2 => {
-            // Game over, even if tied (tie game)
-            false
+        FootballPeriod::OT | FootballPeriod::OT2 | FootballPeriod::OT3 | FootballPeriod::OT4
+            if tied && state.rules == OvertimeRules::Postseason =>
+        {
+            next_postseason_period(state);
+            true
         }
+        // Regulation ended with a leader, or a regular-season overtime ended (tie)
         _ => false,
     }
 }
 
+/// Start overtime with a fresh coin toss and kickoff.
+fn start_overtime(state: &mut LiveState) {
+    state.period = FootballPeriod::OT;
+    state.clock_seconds = match state.rules {
+        OvertimeRules::Regular => 600, // 10:00
+        OvertimeRules::Postseason => 900,
+    };
+
+    state.possession = if state.rng.gen_bool(0.5) {
+        Possession::Home
+    } else {
+        Possession::Away
+    };
+    state.kickoff_pending = true;
+    state.overtime = OvertimeState::start(state.possession);
+
+    let timeouts = match state.rules {
+        OvertimeRules::Regular => 2,
+        OvertimeRules::Postseason => 3,
+    };
+    state.home_timeouts = timeouts;
+    state.away_timeouts = timeouts;
+}
+
+/// Move to the next 15-minute postseason overtime period.
+///
+/// Play continues from the same spot between periods, except after the second
+/// period, which is treated like halftime.
+fn next_postseason_period(state: &mut LiveState) {
+    state.clock_seconds = 900;
+    state.period = match state.period {
+        FootballPeriod::OT => FootballPeriod::OT2,
+        FootballPeriod::OT2 => FootballPeriod::OT3,
+        // OT4 is the last period we can represent; keep playing in it
+        _ => FootballPeriod::OT4,
+    };
+
+    if state.period == FootballPeriod::OT3 {
+        let receiving = match state.overtime.first_possession {
+            Some(Possession::Home) => Possession::Away,
+            _ => Possession::Home,
+        };
+        let previous = state.possession;
+        state.possession = receiving;
+        state.track_overtime_possession(previous);
+        state.kickoff_pending = true;
+        state.home_timeouts = 3;
+        state.away_timeouts = 3;
+    }
+}
+
 /// Determine if clock should be running based on play outcome.
 fn should_clock_run(outcome: &super::plays::PlayOutcome) -> bool {
     use crate::football::types::PlayType;
@@ -0,0 +1,120 @@
+//! Snapshot a real ESPN game into create options for the simulator.
+//!
+//! The imported game keeps the teams, score, clock, and field position it had
+//! at import time, then the simulation takes over from there.
+
+use chrono::DateTime;
+
+use crate::football::types::FootballGameResponse;
+use crate::shared::types::{FinalStatus, Season, SeasonType, Weather};
+
+use super::options::{
+    CreateFinalOptions, CreateGameRequest, CreateLiveOptions, CreatePregameOptions,
+    CreateWeatherOptions, OvertimeRules,
+};
+
+/// Build a create request that reproduces the current state of an ESPN game.
+///
+/// The numeric event ID doubles as the simulation seed, so importing the same
+/// snapshot twice plays out the same way.
+pub fn import_request(game: &FootballGameResponse, event_id: &str) -> CreateGameRequest {
+    let seed = event_id.parse::<u64>().ok();
+
+    match game {
+        FootballGameResponse::Pregame(pregame) => CreateGameRequest::Pregame(CreatePregameOptions {
+            home_team: Some(pregame.home.abbreviation.clone()),
+            away_team: Some(pregame.away.abbreviation.clone()),
+            start_time: DateTime::from_timestamp(pregame.start_time, 0).map(|t| t.to_rfc3339()),
+            venue: pregame.venue.clone(),
+            broadcast: pregame.broadcast.clone(),
+            weather: pregame.weather.as_ref().map(to_weather_options),
+            rules: overtime_rules(pregame.season.as_ref()),
+            seed,
+            ..Default::default()
+        }),
+        FootballGameResponse::Live(live) => {
+            let situation = live.situation.as_ref();
+            CreateGameRequest::Live(CreateLiveOptions {
+                home_team: Some(live.home.abbreviation.clone()),
+                away_team: Some(live.away.abbreviation.clone()),
+                home_score: Some(live.home.score),
+                away_score: Some(live.away.score),
+                period: Some(live.period),
+                clock: Some(live.clock.clone()),
+                possession: situation.map(|s| s.possession),
+                down: situation.map(|s| s.down),
+                distance: situation.map(|s| s.distance),
+                yard_line: situation.map(|s| s.yard_line),
+                home_timeouts: live.home.timeouts,
+                away_timeouts: live.away.timeouts,
+                weather: live.weather.as_ref().map(to_weather_options),
+                rules: overtime_rules(live.season.as_ref()),
+                seed,
+                ..Default::default()
+            })
+        }
+        FootballGameResponse::Final(final_game) => CreateGameRequest::Final(CreateFinalOptions {
+            home_team: Some(final_game.home.abbreviation.clone()),
+            away_team: Some(final_game.away.abbreviation.clone()),
+            home_score: Some(final_game.home.score),
+            away_score: Some(final_game.away.score),
+            overtime: Some(matches!(final_game.status, FinalStatus::FinalOvertime)),
+        }),
+    }
+}
+
+/// Playoff games keep going until someone wins; the rest use the default.
+fn overtime_rules(season: Option<&Season>) -> Option<OvertimeRules> {
+    season
+        .filter(|season| season.season_type == SeasonType::Postseason)
+        .map(|_| OvertimeRules::Postseason)
+}
+
+fn to_weather_options(weather: &Weather) -> CreateWeatherOptions {
+    CreateWeatherOptions {
+        temp: Some(weather.temp),
+        description: Some(weather.description.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::{FootballLive, FootballPeriod, FootballTeamScore};
+
+    fn live(season_type: SeasonType) -> FootballGameResponse {
+        FootballGameResponse::Live(FootballLive {
+            event_id: "401".to_string(),
+            home: FootballTeamScore::for_test("KC", 20),
+            away: FootballTeamScore::for_test("BUF", 20),
+            season: Some(Season {
+                year: 2026,
+                season_type,
+                week: Some(2),
+                label: String::new(),
+            }),
+            period: FootballPeriod::OT,
+            clock: "8:00".to_string(),
+            clock_running: true,
+            inside_two_minutes: false,
+            is_close_game: true,
+            situation: None,
+            last_play: None,
+            weather: None,
+            leaders: None,
+            recent_plays: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        })
+    }
+
+    #[test]
+    fn test_postseason_imports_play_until_someone_wins() {
+        let rules = |season_type| match import_request(&live(season_type), "401") {
+            CreateGameRequest::Live(options) => options.rules,
+            _ => panic!("expected a live game"),
+        };
+        assert_eq!(rules(SeasonType::Postseason), Some(OvertimeRules::Postseason));
+        assert_eq!(rules(SeasonType::Regular), None);
+    }
+}
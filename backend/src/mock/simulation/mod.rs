@@ -6,12 +6,28 @@
 //! - `SimulatedGame`: Internal game state that converts to standard `GameResponse`
 //! - Simulation engine for realistic play-by-play progression
 
+mod batch;
 mod drives;
 mod engine;
+mod import;
 mod options;
 mod plays;
+mod presets;
 mod repository;
+mod roster;
+mod snapshot;
 mod state;
+mod stats;
+mod time;
 
-pub use options::{CreateFinalOptions, CreateGameRequest, CreateLiveOptions, CreatePregameOptions};
-pub use repository::GameRepository;
+pub use batch::batch_requests;
+pub use import::import_request;
+pub use options::{
+    Aggressiveness, BatchMix, CreateBatchOptions, CreateFinalOptions, CreateGameRequest,
+    CreateLiveOptions, CreatePregameOptions, OvertimeRules, Pace, SeekQuery,
+};
+pub use presets::preset_options;
+pub use repository::{GameRepository, DEFAULT_MAX_PLAY_HISTORY};
+pub use snapshot::GameSnapshot;
+pub use state::{format_clock, SimulatedGame, SimulatedPlay};
+pub use time::{UpdateClockRequest, VirtualClockResponse};
@@ -3,8 +3,8 @@
 //! Uses a discriminated union (tagged enum) to allow creating games
 //! in any of the three states: pregame, live, or final.
 
-use serde::Deserialize;
-use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::football::types::{Down, FootballPeriod, Possession};
 
@@ -37,6 +37,8 @@ pub struct CreatePregameOptions {
     /// When the game transitions to live state (ISO 8601 datetime).
     /// Default: ~30 seconds in the future.
     pub start_time: Option<String>,
+    /// Kick off this many seconds from now. Takes precedence over `start_time`.
+    pub in_seconds: Option<u32>,
 
     /// Stadium name. Random if not specified.
     pub venue: Option<String>,
@@ -52,6 +54,48 @@ pub struct CreatePregameOptions {
     /// 1.0 = real-time, 60.0 = 60x speed (full game in ~3 min).
     /// Default: 60.0
     pub time_scale: Option<f64>,
+    /// Overtime rule set. Default: regular.
+    pub rules: Option<OvertimeRules>,
+    /// Fourth-down and two-point decision style. Default: balanced.
+    pub aggressiveness: Option<Aggressiveness>,
+    /// Scoring environment. Default: average.
+    pub pace: Option<Pace>,
+}
+
+/// Which NFL overtime rules apply if the game is tied after regulation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OvertimeRules {
+    /// One 10-minute period; the game can end in a tie.
+    #[default]
+    Regular,
+    /// 15-minute periods that continue until there is a winner.
+    Postseason,
+}
+
+/// How boldly simulated coaches make fourth-down and two-point decisions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Aggressiveness {
+    /// Punts and kicks unless it is 4th-and-inches near midfield; rarely goes for two.
+    Conservative,
+    /// Traditional decisions: short yardage near midfield, two-point chart in the 4th quarter.
+    #[default]
+    Balanced,
+    /// Goes for it on short yardage across most of the field; two-point chart from the 3rd quarter.
+    Analytics,
+}
+
+/// Scoring environment: how easily offenses move the ball and how fast they play.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Pace {
+    /// Defenses dominate and offenses burn clock (think 9-6).
+    LowScoring,
+    #[default]
+    Average,
+    /// Big plays and hurry-up offenses (think 45-42).
+    Shootout,
 }
 
 /// Weather options for pregame creation.
@@ -79,6 +123,7 @@ pub struct CreateLiveOptions {
     pub away_score: Option<u8>,
 
     /// Current period. Default: Q1.
+    /// `Halftime` starts a full intermission before the second half.
     pub period: Option<FootballPeriod>,
     /// Game clock in "MM:SS" format (e.g., "8:42"). Default: "15:00".
     pub clock: Option<String>,
@@ -97,12 +142,21 @@ pub struct CreateLiveOptions {
     /// Away team remaining timeouts. Default: 3.
     pub away_timeouts: Option<u8>,
 
+    /// Weather conditions. None if not specified.
+    pub weather: Option<CreateWeatherOptions>,
+
     /// Random seed for simulation progression.
     pub seed: Option<u64>,
     /// Time acceleration factor.
     /// 1.0 = real-time, 60.0 = 60x speed.
     /// Default: 60.0
     pub time_scale: Option<f64>,
+    /// Overtime rule set. Default: regular.
+    pub rules: Option<OvertimeRules>,
+    /// Fourth-down and two-point decision style. Default: balanced.
+    pub aggressiveness: Option<Aggressiveness>,
+    /// Scoring environment. Default: average.
+    pub pace: Option<Pace>,
 }
 
 /// Options for creating a final (completed) game.
@@ -123,3 +177,56 @@ pub struct CreateFinalOptions {
     /// Whether the game went to overtime. Default: false.
     pub overtime: Option<bool>,
 }
+
+/// Request body for creating several games at once.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct CreateBatchOptions {
+    /// Number of games to create, clamped to 1-16 (every team plays at most once).
+    /// Default: 14.
+    pub count: Option<u8>,
+    /// Relative share of pregame, live, and final games. Default: 4 / 6 / 4.
+    pub mix: Option<BatchMix>,
+    /// Seconds between successive pregame kickoffs. Default: 120.
+    pub stagger_seconds: Option<u32>,
+    /// Random seed for team matchups and game states. Random if not specified.
+    pub seed: Option<u64>,
+    /// Time acceleration factor for live and pregame games. Default: 60.0
+    pub time_scale: Option<f64>,
+}
+
+/// Relative weights for the states of games in a batch.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchMix {
+    #[serde(default)]
+    pub pregame: u8,
+    #[serde(default)]
+    pub live: u8,
+    #[serde(default, rename = "final")]
+    pub final_: u8,
+}
+
+/// Query parameters for seeking a game to a point in time.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SeekQuery {
+    /// Quarter to jump to (1-4; 5 and up are overtime periods)
+    pub quarter: u8,
+    /// Game clock within the quarter, "M:SS" (default: start of the quarter)
+    pub clock: Option<String>,
+}
+
+impl SeekQuery {
+    /// The period named by `quarter`.
+    pub fn period(&self) -> Option<FootballPeriod> {
+        match self.quarter {
+            1 => Some(FootballPeriod::Q1),
+            2 => Some(FootballPeriod::Q2),
+            3 => Some(FootballPeriod::Q3),
+            4 => Some(FootballPeriod::Q4),
+            5 => Some(FootballPeriod::OT),
+            6 => Some(FootballPeriod::OT2),
+            7 => Some(FootballPeriod::OT3),
+            8 => Some(FootballPeriod::OT4),
+            _ => None,
+        }
+    }
+}
@@ -5,8 +5,30 @@ use rand::Rng;
 
 use crate::football::types::{Down, FootballPeriod, PlayType, Possession};
 
+use super::options::{Aggressiveness, Pace};
+use super::roster::Roster;
 use super::state::{LiveState, SimulatedPlay};
 
+/// Run directions used in rushing play text.
+const RUN_DIRECTIONS: &[&str] = &[
+    "left end",
+    "left tackle",
+    "left guard",
+    "up the middle",
+    "right guard",
+    "right tackle",
+    "right end",
+];
+
+/// Field zones used in passing play text.
+const PASS_SIDES: &[&str] = &["left", "middle", "right"];
+
+/// Rosters of the two teams on the field for a play.
+struct Players<'a> {
+    offense: &'a Roster,
+    defense: &'a Roster,
+}
+
 /// The outcome of generating a play.
 pub struct PlayOutcome {
     pub play_type: PlayType,
@@ -17,6 +39,8 @@ pub struct PlayOutcome {
     pub turnover: bool,
     /// If this play scores points (touchdown, field goal, safety)
     pub scoring: Option<ScoringPlay>,
+    /// If a flag wiped out this play, and how it's enforced
+    pub penalty: Option<Penalty>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -26,6 +50,118 @@ pub enum ScoringPlay {
     Safety,
 }
 
+/// Who a penalty is called on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PenaltyOn {
+    Offense,
+    Defense,
+    /// Fouls on both teams cancel out
+    Offsetting,
+}
+
+/// An accepted penalty. The play it was called on doesn't count; the ball
+/// moves from the previous spot instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Penalty {
+    pub on: PenaltyOn,
+    /// Yards marked off, before the half-the-distance rule
+    pub yards: u8,
+    /// A defensive foul that gives the offense a first down whatever the yardage
+    pub automatic_first_down: bool,
+    /// An offensive foul that also costs the down (e.g., intentional grounding)
+    pub loss_of_down: bool,
+    /// Enforced at the spot of the foul, up to the 1 (pass interference)
+    pub spot_foul: bool,
+}
+
+impl Penalty {
+    /// Yards the offense moves from `yard_line`. A penalty that would cover
+    /// more than half the distance to the goal line moves the ball half the
+    /// distance instead, except spot fouls, which stop at the 1.
+    pub fn enforced_yards(&self, yard_line: u8) -> i8 {
+        match self.on {
+            PenaltyOn::Offsetting => 0,
+            PenaltyOn::Offense => -(self.yards.min(yard_line / 2) as i8),
+            PenaltyOn::Defense => {
+                let to_goal = 100 - yard_line;
+                let yards = if self.spot_foul {
+                    self.yards.min(to_goal - 1)
+                } else {
+                    self.yards.min(to_goal / 2)
+                };
+                yards as i8
+            }
+        }
+    }
+}
+
+/// A foul the simulation calls, with its standard enforcement.
+struct Foul {
+    name: &'static str,
+    penalty: Penalty,
+    /// Called before the snap, so no time comes off the clock
+    pre_snap: bool,
+}
+
+impl Foul {
+    const fn new(name: &'static str, on: PenaltyOn, yards: u8) -> Self {
+        Self {
+            name,
+            penalty: Penalty {
+                on,
+                yards,
+                automatic_first_down: false,
+                loss_of_down: false,
+                spot_foul: false,
+            },
+            pre_snap: false,
+        }
+    }
+
+    const fn pre_snap(mut self) -> Self {
+        self.pre_snap = true;
+        self
+    }
+
+    const fn automatic_first_down(mut self) -> Self {
+        self.penalty.automatic_first_down = true;
+        self
+    }
+
+    const fn loss_of_down(mut self) -> Self {
+        self.penalty.loss_of_down = true;
+        self
+    }
+
+    const fn spot_foul(mut self) -> Self {
+        self.penalty.spot_foul = true;
+        self
+    }
+}
+
+/// Chance a scrimmage play draws an accepted penalty
+const PENALTY_RATE: f64 = 0.06;
+
+/// Fouls called on scrimmage plays, weighted roughly as often as in the NFL
+const FOULS: &[(Foul, u32)] = &[
+    (Foul::new("False Start", PenaltyOn::Offense, 5).pre_snap(), 20),
+    (Foul::new("Offensive Holding", PenaltyOn::Offense, 10), 22),
+    (Foul::new("Delay of Game", PenaltyOn::Offense, 5).pre_snap(), 5),
+    (Foul::new("Intentional Grounding", PenaltyOn::Offense, 10).loss_of_down(), 3),
+    (Foul::new("Defensive Offside", PenaltyOn::Defense, 5).pre_snap(), 8),
+    (Foul::new("Neutral Zone Infraction", PenaltyOn::Defense, 5).pre_snap(), 4),
+    (Foul::new("Defensive Holding", PenaltyOn::Defense, 5).automatic_first_down(), 8),
+    (Foul::new("Roughing the Passer", PenaltyOn::Defense, 15).automatic_first_down(), 4),
+    // Yardage is where the foul happened, drawn when it's called
+    (
+        Foul::new("Defensive Pass Interference", PenaltyOn::Defense, 0)
+            .automatic_first_down()
+            .spot_foul(),
+        8,
+    ),
+    (Foul::new("Offsetting Penalties", PenaltyOn::Offsetting, 0), 3),
+];
+
 /// Generate the next play based on game situation.
 pub fn generate_play(state: &mut LiveState) -> PlayOutcome {
     // Extract the values we need before borrowing rng mutably
@@ -38,16 +174,40 @@ pub fn generate_play(state: &mut LiveState) -> PlayOutcome {
     let possession = state.possession;
     let home_score = state.home_score;
     let away_score = state.away_score;
+    let aggressiveness = state.aggressiveness;
+    let pace = state.pace;
+
+    let players = match possession {
+        Possession::Home => Players {
+            offense: &state.home_team.roster,
+            defense: &state.away_team.roster,
+        },
+        Possession::Away => Players {
+            offense: &state.away_team.roster,
+            defense: &state.home_team.roster,
+        },
+    };
+    let receiving = match possession {
+        Possession::Home => &state.home_team.abbreviation,
+        Possession::Away => &state.away_team.abbreviation,
+    };
+    let rng = &mut state.rng;
 
     // Handle kickoff situation
     if kickoff_pending {
-        return generate_kickoff(&mut state.rng);
+        return generate_kickoff(rng, &players, receiving);
+    }
+
+    // A flag wipes out the play
+    if rng.gen_bool(PENALTY_RATE) {
+        return generate_penalty(rng, &players, yard_line);
     }
 
     // Fourth down decisions
     if down == Down::Fourth {
         return generate_fourth_down_play(
-            &mut state.rng,
+            rng,
+            &players,
             down,
             distance,
             yard_line,
@@ -56,19 +216,21 @@ pub fn generate_play(state: &mut LiveState) -> PlayOutcome {
             possession,
             home_score,
             away_score,
+            aggressiveness,
+            pace,
         );
     }
 
     // Regular play selection based on situation
-    let play_type = select_play_type(&mut state.rng, down, distance, period, clock_seconds, yard_line);
+    let play_type = select_play_type(rng, down, distance, period, clock_seconds, yard_line);
 
     match play_type {
-        PlayType::Rush => generate_rush_play(&mut state.rng, yard_line),
+        PlayType::Rush => generate_rush_play(rng, &players, yard_line, pace),
         PlayType::PassReception | PlayType::PassIncompletion => {
-            generate_pass_play(&mut state.rng, yard_line, distance)
+            generate_pass_play(rng, &players, yard_line, distance, pace)
         }
-        PlayType::Sack => generate_sack_play(&mut state.rng),
-        _ => generate_rush_play(&mut state.rng, yard_line), // Fallback
+        PlayType::Sack => generate_sack_play(rng, &players),
+        _ => generate_rush_play(rng, &players, yard_line, pace), // Fallback
     }
 }
 
@@ -169,7 +331,10 @@ fn select_play_type(
     }
 }
 
-fn generate_kickoff(rng: &mut StdRng) -> PlayOutcome {
+fn generate_kickoff(rng: &mut StdRng, players: &Players, receiving: &str) -> PlayOutcome {
+    // The receiving team has possession; the defense is kicking
+    let kicker = &players.defense.kicker;
+
     // Most kickoffs result in touchback
     let touchback = rng.gen_bool(0.65);
 
@@ -178,19 +343,34 @@ fn generate_kickoff(rng: &mut StdRng) -> PlayOutcome {
             play_type: PlayType::Kickoff,
             yards_gained: 0,
             clock_elapsed: 5,
-            description: "Kickoff, touchback.".to_string(),
+            description: format!("{} kicks 65 yards from 35 to end zone, Touchback.", kicker),
             turnover: false,
             scoring: None,
+            penalty: None,
         }
     } else {
-        let return_yards: i8 = rng.gen_range(15..35);
+        // Where the return is downed, never short of the 20
+        let spot: i8 = rng.gen_range(15..35).max(20);
+        let kick_distance: u8 = rng.gen_range(60..=65);
+        let returner = players.offense.returner(rng);
+        let tackler = players.defense.defender(rng);
         PlayOutcome {
             play_type: PlayType::KickoffReturn,
-            yards_gained: return_yards,
+            yards_gained: spot,
             clock_elapsed: rng.gen_range(5..10),
-            description: format!("Kickoff returned for {} yards.", return_yards),
+            description: format!(
+                "{} kicks {} yards from 35. {} to the {} {} for {} yards ({}).",
+                kicker,
+                kick_distance,
+                returner,
+                receiving,
+                spot,
+                spot as i16 - (65 - kick_distance as i16),
+                tackler
+            ),
             turnover: false,
             scoring: None,
+            penalty: None,
         }
     }
 }
@@ -198,6 +378,7 @@ fn generate_kickoff(rng: &mut StdRng) -> PlayOutcome {
 #[allow(clippy::too_many_arguments)]
 fn generate_fourth_down_play(
     rng: &mut StdRng,
+    players: &Players,
     _down: Down,
     distance: u8,
     yard_line: u8,
@@ -206,6 +387,8 @@ fn generate_fourth_down_play(
     possession: Possession,
     home_score: u8,
     away_score: u8,
+    aggressiveness: Aggressiveness,
+    pace: Pace,
 ) -> PlayOutcome {
     // Field goal range (roughly inside the 35 yard line, i.e., yard_line >= 65)
     let in_fg_range = yard_line >= 55;
@@ -213,8 +396,8 @@ fn generate_fourth_down_play(
     // Punt range (not in FG range and not desperate)
     let should_punt = !in_fg_range && yard_line < 60;
 
-    // Very short yardage might go for it
-    let go_for_it = distance <= 2 && yard_line >= 50;
+    // Short yardage might go for it, depending on the coach
+    let go_for_it = should_go_for_it(aggressiveness, distance, yard_line);
 
     // Late game desperation
     let desperate = clock_seconds < 120
@@ -222,7 +405,7 @@ fn generate_fourth_down_play(
         && ((possession == Possession::Home && home_score < away_score)
             || (possession == Possession::Away && away_score < home_score));
 
-    if in_fg_range && !desperate {
+    if in_fg_range && !desperate && !go_for_it {
         // Field goal attempt
         let fg_distance = 100 - yard_line + 17; // Add 17 for end zone + line of scrimmage
         let success_rate = match fg_distance {
@@ -232,24 +415,31 @@ fn generate_fourth_down_play(
             51..=55 => 0.55,
             _ => 0.40,
         };
+        let kicker = &players.offense.kicker;
 
         if rng.gen_bool(success_rate) {
             PlayOutcome {
                 play_type: PlayType::FieldGoalGood,
                 yards_gained: 0,
                 clock_elapsed: 5,
-                description: format!("{} yard field goal is GOOD!", fg_distance),
+                description: format!("{} {} yard field goal is GOOD.", kicker, fg_distance),
                 turnover: false,
                 scoring: Some(ScoringPlay::FieldGoal),
+                penalty: None,
             }
         } else {
+            let miss = ["Wide Right", "Wide Left", "Short"][rng.gen_range(0..3)];
             PlayOutcome {
                 play_type: PlayType::FieldGoalMissed,
                 yards_gained: 0,
                 clock_elapsed: 5,
-                description: format!("{} yard field goal is NO GOOD.", fg_distance),
+                description: format!(
+                    "{} {} yard field goal is No Good, {}.",
+                    kicker, fg_distance, miss
+                ),
                 turnover: true, // Opponent gets ball
                 scoring: None,
+                penalty: None,
             }
         }
     } else if should_punt && !desperate && !go_for_it {
@@ -259,73 +449,128 @@ fn generate_fourth_down_play(
             play_type: PlayType::Punt,
             yards_gained: -punt_distance, // Negative because it goes to opponent
             clock_elapsed: rng.gen_range(5..10),
-            description: format!("Punt for {} yards.", punt_distance),
+            description: format!("{} punts {} yards.", players.offense.punter, punt_distance),
             turnover: true,
             scoring: None,
+            penalty: None,
         }
     } else {
         // Go for it!
         if distance <= 2 {
             // Short yardage - try a run
-            generate_rush_play(rng, yard_line)
+            generate_rush_play(rng, players, yard_line, pace)
         } else {
             // Need more yards - pass
-            generate_pass_play(rng, yard_line, distance)
+            generate_pass_play(rng, players, yard_line, distance, pace)
+        }
+    }
+}
+
+/// Whether the offense keeps its offense on the field on fourth down.
+fn should_go_for_it(aggressiveness: Aggressiveness, distance: u8, yard_line: u8) -> bool {
+    match aggressiveness {
+        // Only 4th-and-inches in no-man's land
+        Aggressiveness::Conservative => distance <= 1 && (50..55).contains(&yard_line),
+        // Short yardage near midfield; always kicks in field goal range
+        Aggressiveness::Balanced => distance <= 2 && (50..55).contains(&yard_line),
+        // Short yardage almost anywhere, including instead of short field goals
+        Aggressiveness::Analytics => {
+            (distance <= 1 && yard_line >= 30)
+                || (distance <= 3 && yard_line >= 45)
+                || (distance <= 5 && (55..=70).contains(&yard_line))
         }
     }
 }
 
-fn generate_rush_play(rng: &mut StdRng, yard_line: u8) -> PlayOutcome {
+fn generate_rush_play(
+    rng: &mut StdRng,
+    players: &Players,
+    yard_line: u8,
+    pace: Pace,
+) -> PlayOutcome {
+    let carrier = players.offense.ball_carrier(rng).to_string();
+    let tackler = players.defense.defender(rng).to_string();
+    let direction = RUN_DIRECTIONS[rng.gen_range(0..RUN_DIRECTIONS.len())];
+
     // Fumble chance (~1%)
     if rng.gen_bool(0.01) {
         let fumble_recovered_by_opponent = rng.gen_bool(0.5);
         if fumble_recovered_by_opponent {
+            let recoverer = players.defense.defender(rng);
             return PlayOutcome {
                 play_type: PlayType::FumbleRecoveryOpponent,
                 yards_gained: 0,
                 clock_elapsed: rng.gen_range(5..10),
-                description: "FUMBLE! Recovered by the defense.".to_string(),
+                description: format!(
+                    "{} {}, FUMBLES ({}), RECOVERED by {}.",
+                    carrier, direction, tackler, recoverer
+                ),
                 turnover: true,
                 scoring: None,
+                penalty: None,
             };
         } else {
+            let yards = rng.gen_range(-3..=0);
             return PlayOutcome {
                 play_type: PlayType::FumbleRecoveryOwn,
-                yards_gained: rng.gen_range(-3..=0),
+                yards_gained: yards,
                 clock_elapsed: rng.gen_range(20..35),
-                description: "Fumble, recovered by the offense.".to_string(),
+                description: format!(
+                    "{} {} for {}, FUMBLES ({}), recovered by {}.",
+                    carrier,
+                    direction,
+                    format_yards(yards),
+                    tackler,
+                    carrier
+                ),
                 turnover: false,
                 scoring: None,
+                penalty: None,
             };
         }
     }
 
     // Generate yards with realistic distribution
-    let yards = generate_rush_yards(rng, yard_line);
+    let yards = generate_rush_yards(rng, yard_line, pace);
 
     // Check for touchdown
     let would_score = yard_line as i16 + yards as i16 >= 100;
     if would_score {
+        let td_yards = (100 - yard_line) as i8;
         return PlayOutcome {
             play_type: PlayType::RushingTouchdown,
-            yards_gained: (100 - yard_line) as i8,
+            yards_gained: td_yards,
             clock_elapsed: rng.gen_range(5..15),
-            description: format!("TOUCHDOWN! {} yard rushing TD!", 100 - yard_line),
+            description: format!(
+                "{} {} for {}, TOUCHDOWN.",
+                carrier,
+                direction,
+                format_yards(td_yards)
+            ),
             turnover: false,
             scoring: Some(ScoringPlay::Touchdown),
+            penalty: None,
         };
     }
 
     // Check for safety
     let would_safety = yard_line as i16 + yards as i16 <= 0;
     if would_safety {
+        let safety_yards = -(yard_line as i8);
         return PlayOutcome {
             play_type: PlayType::Safety,
-            yards_gained: -(yard_line as i8),
+            yards_gained: safety_yards,
             clock_elapsed: rng.gen_range(5..10),
-            description: "SAFETY! Tackled in the end zone!".to_string(),
+            description: format!(
+                "{} {} for {}, SAFETY ({}).",
+                carrier,
+                direction,
+                format_yards(safety_yards),
+                tackler
+            ),
             turnover: true,
             scoring: Some(ScoringPlay::Safety),
+            penalty: None,
         };
     }
 
@@ -340,61 +585,92 @@ fn generate_rush_play(rng: &mut StdRng, yard_line: u8) -> PlayOutcome {
         play_type: PlayType::Rush,
         yards_gained: yards,
         clock_elapsed: clock,
-        description: if yards > 0 {
-            format!("Rush for {} yards.", yards)
-        } else if yards == 0 {
-            "Rush for no gain.".to_string()
-        } else {
-            format!("Rush for a loss of {} yards.", -yards)
-        },
+        description: format!(
+            "{} {} for {} ({}).",
+            carrier,
+            direction,
+            format_yards(yards),
+            tackler
+        ),
         turnover: false,
         scoring: None,
+        penalty: None,
     }
 }
 
-fn generate_pass_play(rng: &mut StdRng, yard_line: u8, distance: u8) -> PlayOutcome {
+fn generate_pass_play(
+    rng: &mut StdRng,
+    players: &Players,
+    yard_line: u8,
+    distance: u8,
+    pace: Pace,
+) -> PlayOutcome {
     // Sack chance (~7%)
     if rng.gen_bool(0.07) {
-        return generate_sack_play(rng);
+        return generate_sack_play(rng, players);
     }
 
+    let passer = &players.offense.quarterback;
+    let target = players.offense.target(rng).to_string();
+    let defender = players.defense.defender(rng).to_string();
+    let side = PASS_SIDES[rng.gen_range(0..PASS_SIDES.len())];
+
     // Interception chance (~2.5%)
     if rng.gen_bool(0.025) {
         return PlayOutcome {
             play_type: PlayType::Interception,
             yards_gained: 0,
             clock_elapsed: rng.gen_range(5..10),
-            description: "INTERCEPTED!".to_string(),
+            description: format!(
+                "{} pass deep {} intended for {} INTERCEPTED by {}.",
+                passer, side, target, defender
+            ),
             turnover: true,
             scoring: None,
+            penalty: None,
         };
     }
 
-    // Incompletion chance (~35%)
-    if rng.gen_bool(0.35) {
+    // Incompletion chance (~35% at an average pace)
+    if rng.gen_bool(incompletion_rate(pace)) {
+        let depth = if rng.gen_bool(0.6) { "short" } else { "deep" };
         return PlayOutcome {
             play_type: PlayType::PassIncompletion,
             yards_gained: 0,
             clock_elapsed: rng.gen_range(5..10),
-            description: "Pass incomplete.".to_string(),
+            description: format!(
+                "{} pass incomplete {} {} to {}.",
+                passer, depth, side, target
+            ),
             turnover: false,
             scoring: None,
+            penalty: None,
         };
     }
 
     // Completed pass
-    let yards = generate_pass_yards(rng, yard_line, distance);
+    let yards = generate_pass_yards(rng, yard_line, distance, pace);
+    let depth = if yards >= 16 { "deep" } else { "short" };
 
     // Check for touchdown
     let would_score = yard_line as i16 + yards as i16 >= 100;
     if would_score {
+        let td_yards = (100 - yard_line) as i8;
         return PlayOutcome {
             play_type: PlayType::PassingTouchdown,
-            yards_gained: (100 - yard_line) as i8,
+            yards_gained: td_yards,
             clock_elapsed: rng.gen_range(5..15),
-            description: format!("TOUCHDOWN! {} yard passing TD!", 100 - yard_line),
+            description: format!(
+                "{} pass {} {} to {} for {}, TOUCHDOWN.",
+                passer,
+                depth,
+                side,
+                target,
+                format_yards(td_yards)
+            ),
             turnover: false,
             scoring: Some(ScoringPlay::Touchdown),
+            penalty: None,
         };
     }
 
@@ -409,30 +685,100 @@ fn generate_pass_play(rng: &mut StdRng, yard_line: u8, distance: u8) -> PlayOutc
         play_type: PlayType::PassReception,
         yards_gained: yards,
         clock_elapsed: clock,
-        description: if yards > 0 {
-            format!("Pass complete for {} yards.", yards)
-        } else {
-            format!("Pass complete for a loss of {} yards.", -yards)
-        },
+        description: format!(
+            "{} pass {} {} to {} for {} ({}).",
+            passer,
+            depth,
+            side,
+            target,
+            format_yards(yards),
+            defender
+        ),
         turnover: false,
         scoring: None,
+        penalty: None,
     }
 }
 
-fn generate_sack_play(rng: &mut StdRng) -> PlayOutcome {
+fn generate_penalty(rng: &mut StdRng, players: &Players, yard_line: u8) -> PlayOutcome {
+    let total: u32 = FOULS.iter().map(|(_, weight)| weight).sum();
+    let mut roll = rng.gen_range(0..total);
+    let (foul, _) = FOULS
+        .iter()
+        .find(|(_, weight)| {
+            let found = roll < *weight;
+            roll = roll.saturating_sub(*weight);
+            found
+        })
+        .unwrap_or(&FOULS[0]);
+
+    let mut penalty = foul.penalty;
+    if penalty.spot_foul {
+        penalty.yards = rng.gen_range(5..=35);
+    }
+    let yards = penalty.enforced_yards(yard_line);
+    let player = match penalty.on {
+        // Loss-of-down fouls are the passer's
+        PenaltyOn::Offense if penalty.loss_of_down => players.offense.quarterback.as_str(),
+        PenaltyOn::Offense => players.offense.target(rng),
+        _ => players.defense.defender(rng),
+    };
+    let description = match penalty.on {
+        PenaltyOn::Offsetting => "Penalties offset - No Play.".to_string(),
+        _ => format!(
+            "PENALTY on {}, {}, {} yards{} - No Play.",
+            player,
+            foul.name,
+            yards.unsigned_abs(),
+            if yards.unsigned_abs() < penalty.yards && !penalty.spot_foul {
+                " (half the distance)"
+            } else {
+                ""
+            }
+        ),
+    };
+
+    PlayOutcome {
+        play_type: PlayType::Penalty,
+        yards_gained: yards,
+        clock_elapsed: if foul.pre_snap { 0 } else { rng.gen_range(5..10) },
+        description,
+        turnover: false,
+        scoring: None,
+        penalty: Some(penalty),
+    }
+}
+
+fn generate_sack_play(rng: &mut StdRng, players: &Players) -> PlayOutcome {
     let yards_lost: i8 = rng.gen_range(3..=10);
+    let rusher = players.defense.defender(rng);
     PlayOutcome {
         play_type: PlayType::Sack,
         yards_gained: -yards_lost,
         clock_elapsed: rng.gen_range(25..40),
-        description: format!("SACKED for a loss of {} yards!", yards_lost),
+        description: format!(
+            "{} sacked by {} for {}.",
+            players.offense.quarterback,
+            rusher,
+            format_yards(-yards_lost)
+        ),
         turnover: false,
         scoring: None,
+        penalty: None,
+    }
+}
+
+/// Format a yardage the way ESPN play text does ("7 yards", "no gain", "-3 yards").
+fn format_yards(yards: i8) -> String {
+    match yards {
+        0 => "no gain".to_string(),
+        1 => "1 yard".to_string(),
+        _ => format!("{} yards", yards),
     }
 }
 
 /// Generate rushing yards with realistic distribution.
-fn generate_rush_yards(rng: &mut StdRng, yard_line: u8) -> i8 {
+fn generate_rush_yards(rng: &mut StdRng, yard_line: u8, pace: Pace) -> i8 {
     let roll: u8 = rng.gen_range(0..100);
 
     // Distribution: -3 to +75 with mean ~4.3
@@ -452,6 +798,7 @@ fn generate_rush_yards(rng: &mut StdRng, yard_line: u8) -> i8 {
         // Breakaway 20-75 (5%)
         rng.gen_range(20..=75)
     };
+    let yards = scale_gain(yards, pace);
 
     // Cap at remaining yards to goal (can't gain more than needed for TD)
     let max_yards = (100 - yard_line) as i8;
@@ -459,7 +806,7 @@ fn generate_rush_yards(rng: &mut StdRng, yard_line: u8) -> i8 {
 }
 
 /// Generate passing yards with realistic distribution.
-fn generate_pass_yards(rng: &mut StdRng, yard_line: u8, distance: u8) -> i8 {
+fn generate_pass_yards(rng: &mut StdRng, yard_line: u8, distance: u8, pace: Pace) -> i8 {
     let roll: u8 = rng.gen_range(0..100);
 
     // Adjust based on needed distance (tendency to throw for the first down)
@@ -481,18 +828,67 @@ fn generate_pass_yards(rng: &mut StdRng, yard_line: u8, distance: u8) -> i8 {
         // Big play 31-75 (10%)
         rng.gen_range(31..=75)
     };
+    let yards = scale_gain(yards, pace);
 
     // Cap at remaining yards
     let max_yards = (100 - yard_line) as i8;
     yards.min(max_yards)
 }
 
-/// Convert PlayOutcome to SimulatedPlay.
-pub fn outcome_to_play(outcome: &PlayOutcome) -> SimulatedPlay {
+/// Stretch or shrink positive gains for the scoring environment.
+fn scale_gain(yards: i8, pace: Pace) -> i8 {
+    let factor = match pace {
+        Pace::LowScoring => 0.55,
+        Pace::Average => 1.0,
+        Pace::Shootout => 1.1,
+    };
+    if yards > 0 {
+        (yards as f64 * factor).round().clamp(1.0, 99.0) as i8
+    } else {
+        yards
+    }
+}
+
+fn incompletion_rate(pace: Pace) -> f64 {
+    match pace {
+        Pace::LowScoring => 0.45,
+        Pace::Average => 0.35,
+        Pace::Shootout => 0.32,
+    }
+}
+
+/// Seconds a play takes off the clock, adjusted for offensive tempo.
+///
+/// Low-scoring games huddle and run the clock; shootouts play hurry-up,
+/// which gives both offenses more possessions. Plays that take no time
+/// (pre-snap fouls) still take none.
+pub fn apply_tempo(clock_elapsed: u16, pace: Pace) -> u16 {
+    if clock_elapsed == 0 {
+        return 0;
+    }
+    let factor = match pace {
+        Pace::LowScoring => 1.25,
+        Pace::Average => 1.0,
+        Pace::Shootout => 0.9,
+    };
+    ((clock_elapsed as f64 * factor).round() as u16).max(1)
+}
+
+/// Convert PlayOutcome to SimulatedPlay, tagged with the snap situation.
+pub fn outcome_to_play(
+    outcome: &PlayOutcome,
+    possession: Possession,
+    period: FootballPeriod,
+    clock_seconds: u16,
+) -> SimulatedPlay {
     SimulatedPlay {
         play_type: outcome.play_type,
         yards_gained: outcome.yards_gained,
         description: outcome.description.clone(),
         clock_elapsed: outcome.clock_elapsed,
+        clock_used: 0,
+        possession,
+        period,
+        clock_seconds,
     }
 }
@@ -0,0 +1,117 @@
+//! Named scenario presets for creating a live game in one call.
+//!
+//! Each preset is just a filled-in `CreateLiveOptions`, so anything a preset
+//! sets up can also be reproduced by hand through `POST /api/mock/games`.
+
+use crate::error::AppError;
+use crate::football::types::{Down, FootballPeriod, Possession};
+
+use super::options::{CreateLiveOptions, CreateWeatherOptions, Pace};
+
+/// Preset names, as listed in error messages.
+pub const VALID_PRESETS: &str =
+    "two_minute_drill, goal_line_stand, hail_mary, blowout, comeback, snow_game";
+
+/// Look up the live-game options for a named preset.
+pub fn preset_options(name: &str) -> Result<CreateLiveOptions, AppError> {
+    let options = match name {
+        // Trailing by 4 with the whole field to go
+        "two_minute_drill" => CreateLiveOptions {
+            home_team: Some("KC".to_string()),
+            away_team: Some("BUF".to_string()),
+            home_score: Some(20),
+            away_score: Some(24),
+            period: Some(FootballPeriod::Q4),
+            clock: Some("2:00".to_string()),
+            possession: Some(Possession::Home),
+            down: Some(Down::First),
+            distance: Some(10),
+            yard_line: Some(25),
+            home_timeouts: Some(2),
+            ..Default::default()
+        },
+        // Third and goal from the 2, defense protecting a lead
+        "goal_line_stand" => CreateLiveOptions {
+            home_team: Some("PIT".to_string()),
+            away_team: Some("BAL".to_string()),
+            home_score: Some(17),
+            away_score: Some(13),
+            period: Some(FootballPeriod::Q4),
+            clock: Some("1:45".to_string()),
+            possession: Some(Possession::Away),
+            down: Some(Down::Third),
+            distance: Some(2),
+            yard_line: Some(98),
+            away_timeouts: Some(1),
+            ..Default::default()
+        },
+        // One last snap from midfield, down by 6
+        "hail_mary" => CreateLiveOptions {
+            home_team: Some("GB".to_string()),
+            away_team: Some("DET".to_string()),
+            home_score: Some(21),
+            away_score: Some(27),
+            period: Some(FootballPeriod::Q4),
+            clock: Some("0:05".to_string()),
+            possession: Some(Possession::Home),
+            down: Some(Down::First),
+            distance: Some(10),
+            yard_line: Some(50),
+            home_timeouts: Some(0),
+            ..Default::default()
+        },
+        "blowout" => CreateLiveOptions {
+            home_team: Some("SF".to_string()),
+            away_team: Some("CAR".to_string()),
+            home_score: Some(42),
+            away_score: Some(7),
+            period: Some(FootballPeriod::Q3),
+            clock: Some("8:30".to_string()),
+            possession: Some(Possession::Away),
+            down: Some(Down::First),
+            distance: Some(10),
+            yard_line: Some(30),
+            ..Default::default()
+        },
+        // Down two scores in the fourth with the ball
+        "comeback" => CreateLiveOptions {
+            home_team: Some("DAL".to_string()),
+            away_team: Some("PHI".to_string()),
+            home_score: Some(14),
+            away_score: Some(28),
+            period: Some(FootballPeriod::Q4),
+            clock: Some("6:00".to_string()),
+            possession: Some(Possession::Home),
+            down: Some(Down::First),
+            distance: Some(10),
+            yard_line: Some(35),
+            ..Default::default()
+        },
+        "snow_game" => CreateLiveOptions {
+            home_team: Some("BUF".to_string()),
+            away_team: Some("MIA".to_string()),
+            home_score: Some(3),
+            away_score: Some(0),
+            period: Some(FootballPeriod::Q2),
+            clock: Some("10:00".to_string()),
+            possession: Some(Possession::Away),
+            down: Some(Down::Second),
+            distance: Some(7),
+            yard_line: Some(40),
+            weather: Some(CreateWeatherOptions {
+                temp: Some(24),
+                description: Some("Heavy Snow".to_string()),
+            }),
+            pace: Some(Pace::LowScoring),
+            ..Default::default()
+        },
+        _ => {
+            return Err(AppError::PresetNotFound {
+                name: name.to_string(),
+                valid: VALID_PRESETS,
+            });
+        }
+    };
+
+    Ok(options)
+}
@@ -1,28 +1,202 @@
 //! Thread-safe repository for storing game simulations.
+//!
+//! With shared storage (see `storage`), every game is also kept there, one
+//! field per game, and that copy is the one that counts: an instance picks up
+//! a game another saved since it last did before advancing it, and saves it
+//! back whenever it moves on. Two instances advancing the same game at the
+//! same moment can each hand out plays the other's save then replaces.
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use chrono::{DateTime, Duration, Utc};
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+use super::engine;
 use super::options::{CreateFinalOptions, CreateGameRequest, CreateLiveOptions, CreatePregameOptions};
 use super::state::{
-    FinalState, GameState, LiveState, PregameState, SimulatedGame, TeamInfo, WeatherInfo,
+    game_position, is_overtime, padded_linescore, period_index, FinalState, GameState, LiveState, OvertimeState,
+    PregameState, SimulatedGame, TeamInfo, WeatherInfo, HALFTIME_SECONDS,
 };
+use super::roster::Roster;
+use super::snapshot::{validate, GameSnapshot};
+use super::stats::TeamStats;
+use super::time::{SystemClock, TimeSource, VirtualClock};
+use crate::error::AppError;
 use crate::football::types::{Down, FootballPeriod, Possession};
+use crate::shared::transform::parse_clock;
 use crate::shared::types::Color;
-use crate::mock::teams::{get_matchup, NflTeam, NFL_TEAMS};
+use crate::mock::teams::{find_team, get_matchup};
+use crate::storage::Storage;
+
+/// Storage key for games shared between instances, a hash of each by ID
+const STORAGE_KEY: &str = "mock_games";
+
+/// A game as kept in shared storage
+#[derive(Serialize, Deserialize)]
+struct StoredGame {
+    /// New with every save, so an instance can tell whether its copy is current
+    revision: String,
+    state: GameState,
+    origin: Option<Box<LiveState>>,
+    /// Simulation time the game was saved at
+    saved_at: DateTime<Utc>,
+}
+
+/// Just the revision of a stored game, to check it without reading the rest
+#[derive(Deserialize)]
+struct StoredRevision {
+    revision: String,
+}
+
+/// A stored game and the copy last handed out.
+struct Entry {
+    game: SimulatedGame,
+    /// Shared by every reader until the simulation moves on
+    snapshot: Arc<SimulatedGame>,
+    /// Revision last saved to or loaded from shared storage
+    revision: Option<String>,
+}
+
+impl Entry {
+    fn new(game: SimulatedGame, observed_at: DateTime<Utc>) -> Self {
+        let snapshot = Arc::new(snapshot_game(&game, observed_at));
+        Self {
+            game,
+            snapshot,
+            revision: None,
+        }
+    }
+
+    /// A game loaded from shared storage, running on from where it was saved
+    /// as though it had been here all along.
+    fn load(id: &str, stored: StoredGame, now: Instant, now_utc: DateTime<Utc>) -> Self {
+        let behind = (now_utc - stored.saved_at).to_std().unwrap_or_default();
+        let saved_at = now.checked_sub(behind).unwrap_or(now);
+        let mut state = stored.state;
+        let mut origin = stored.origin;
+        if let GameState::Live(live) = &mut state {
+            live.resume(saved_at);
+        }
+        if let Some(origin) = &mut origin {
+            origin.resume(saved_at);
+        }
+        let game = SimulatedGame {
+            id: id.to_string(),
+            created_at: now,
+            last_accessed: now,
+            state,
+            observed_at: now_utc,
+            origin,
+        };
+        Self {
+            revision: Some(stored.revision),
+            ..Self::new(game, now_utc)
+        }
+    }
+
+    /// The game to save to shared storage under a new revision, which becomes
+    /// this copy's.
+    fn save(&mut self, now_utc: DateTime<Utc>) -> Option<Vec<u8>> {
+        let revision = hex::encode(rand::random::<[u8; 8]>());
+        let stored = StoredGame {
+            revision: revision.clone(),
+            state: clone_game_state(&self.game.state),
+            origin: self.game.origin.clone(),
+            saved_at: now_utc,
+        };
+        match serde_json::to_vec(&stored) {
+            Ok(json) => {
+                self.revision = Some(revision);
+                Some(json)
+            }
+            Err(e) => {
+                tracing::warn!(game = %self.game.id, error = %e, "Failed to serialize mock game");
+                None
+            }
+        }
+    }
+
+    /// Advance the game to `now`, keeping at most `max_plays` plays, and return
+    /// the current snapshot, copying the game only if it changed. Pregame
+    /// copies are cheap and carry the time their countdown is measured from,
+    /// so they're always fresh.
+    fn advance(
+        &mut self,
+        now: Instant,
+        now_utc: DateTime<Utc>,
+        max_plays: usize,
+    ) -> Arc<SimulatedGame> {
+        self.game.touch(now);
+        advance_game_state(&mut self.game.state, &mut self.game.origin, now, now_utc);
+        self.game.state.evict_plays(max_plays);
+        if matches!(self.game.state, GameState::Pregame(_))
+            || Progress::of(&self.game.state) != Progress::of(&self.snapshot.state)
+        {
+            self.refresh(now_utc);
+        }
+        self.snapshot.clone()
+    }
+
+    /// Replace the snapshot after changing the game.
+    fn refresh(&mut self, now_utc: DateTime<Utc>) -> Arc<SimulatedGame> {
+        self.snapshot = Arc::new(snapshot_game(&self.game, now_utc));
+        self.snapshot.clone()
+    }
+}
+
+/// How far a game has got; equal for two copies of a game only if nothing has
+/// happened in between.
+#[derive(PartialEq)]
+enum Progress {
+    Pregame,
+    Live {
+        simulated_seconds: u64,
+        plays: usize,
+        period: FootballPeriod,
+        clock_seconds: u16,
+    },
+    Final,
+}
+
+impl Progress {
+    fn of(state: &GameState) -> Self {
+        match state {
+            GameState::Pregame(_) => Progress::Pregame,
+            GameState::Live(live) => Progress::Live {
+                simulated_seconds: live.simulated_game_seconds,
+                plays: live.plays_evicted + live.play_history.len(),
+                period: live.period,
+                clock_seconds: live.clock_seconds,
+            },
+            GameState::Final(_) => Progress::Final,
+        }
+    }
+}
+
+/// Plays kept per game unless configured otherwise; a full game is about 180.
+pub const DEFAULT_MAX_PLAY_HISTORY: usize = 500;
 
 /// Thread-safe repository for active game simulations.
+///
+/// Each game has its own lock, held only while it advances, so readers of
+/// different games never wait on each other. Readers get a shared snapshot
+/// that is copied only when the game has moved since the last read.
 #[derive(Clone)]
 pub struct GameRepository {
-    games: Arc<RwLock<HashMap<String, SimulatedGame>>>,
+    games: Arc<RwLock<HashMap<String, Arc<Mutex<Entry>>>>>,
     next_id: Arc<AtomicU64>,
+    /// Time source for all simulation; can be advanced via the mock clock endpoint
+    clock: Arc<VirtualClock>,
+    /// Plays kept per game (`mock.max_play_history`); 0 keeps them all
+    max_play_history: Arc<AtomicUsize>,
+    /// Where games are shared with other instances, if they are
+    storage: Option<Arc<dyn Storage>>,
 }
 
 impl Default for GameRepository {
@@ -33,92 +207,337 @@ impl Default for GameRepository {
 
 impl GameRepository {
     pub fn new() -> Self {
+        Self::with_time_source(Arc::new(SystemClock))
+    }
+
+    /// Create a repository whose virtual clock follows `time_source` instead of the system clock.
+    pub fn with_time_source(time_source: Arc<dyn TimeSource>) -> Self {
         Self {
             games: Arc::new(RwLock::new(HashMap::new())),
             next_id: Arc::new(AtomicU64::new(1)),
+            clock: Arc::new(VirtualClock::new(time_source)),
+            max_play_history: Arc::new(AtomicUsize::new(DEFAULT_MAX_PLAY_HISTORY)),
+            storage: None,
         }
     }
 
-    /// Generate a unique game ID.
+    /// Share games with the other instances using `storage`.
+    pub fn with_storage(self, storage: Arc<dyn Storage>) -> Self {
+        Self {
+            storage: Some(storage),
+            ..self
+        }
+    }
+
+    /// Keep at most `max` plays per game from now on (0 keeps them all).
+    /// Longer histories are trimmed the next time each game is read.
+    pub fn set_max_play_history(&self, max: usize) {
+        self.max_play_history.store(max, Ordering::Relaxed);
+    }
+
+    fn max_plays(&self) -> usize {
+        self.max_play_history.load(Ordering::Relaxed)
+    }
+
+    /// The simulation clock shared by every game in the repository.
+    pub fn clock(&self) -> &VirtualClock {
+        &self.clock
+    }
+
+    /// Generate a unique game ID. Shared games get random ones, since other
+    /// instances count from 1 as well.
     fn generate_id(&self) -> String {
+        if self.storage.is_some() {
+            return format!("sim_{}", hex::encode(rand::random::<[u8; 6]>()));
+        }
         let id = self.next_id.fetch_add(1, Ordering::SeqCst);
         format!("sim_{}", id)
     }
 
-    /// Create a new game from the request options.
-    pub async fn create(&self, request: CreateGameRequest) -> SimulatedGame {
-        let id = self.generate_id();
-        let now = Instant::now();
-
+    /// Create a new game from the request options, unless they describe a
+    /// state the simulation can't run from.
+    pub async fn create(&self, request: CreateGameRequest) -> Result<Arc<SimulatedGame>, AppError> {
         let state = match request {
-            CreateGameRequest::Pregame(opts) => GameState::Pregame(create_pregame_state(opts)),
-            CreateGameRequest::Live(opts) => GameState::Live(Box::new(create_live_state(opts))),
+            CreateGameRequest::Pregame(opts) => {
+                GameState::Pregame(create_pregame_state(opts, self.clock.now_utc()))
+            }
+            CreateGameRequest::Live(opts) => {
+                GameState::Live(Box::new(create_live_state(opts, self.clock.now())))
+            }
             CreateGameRequest::Final(opts) => GameState::Final(create_final_state(opts)),
         };
+        validate(&state).map_err(AppError::InvalidGameOptions)?;
+
+        Ok(self.insert(state).await)
+    }
+
+    /// Add a game restored from a snapshot. Live games resume from their saved clock.
+    pub async fn restore(&self, mut state: GameState) -> Arc<SimulatedGame> {
+        if let GameState::Live(live) = &mut state {
+            live.resume(self.clock.now());
+        }
+
+        self.insert(state).await
+    }
+
+    /// Store a new game under a fresh ID.
+    async fn insert(&self, state: GameState) -> Arc<SimulatedGame> {
+        let id = self.generate_id();
+        let now = self.clock.now();
 
+        let origin = match &state {
+            GameState::Live(live) => Some(live.clone()),
+            _ => None,
+        };
         let game = SimulatedGame {
             id: id.clone(),
             created_at: now,
             last_accessed: now,
             state,
+            observed_at: self.clock.now_utc(),
+            origin,
         };
 
-        // Store in repository
-        {
-            let mut games = self.games.write().await;
-            games.insert(id.clone(), game);
+        // Advance state if needed before storing
+        let now_utc = self.clock.now_utc();
+        let mut entry = Entry::new(game, now_utc);
+        let snapshot = entry.advance(self.clock.now(), now_utc, self.max_plays());
+        let saved = self.storage.as_ref().and_then(|_| entry.save(now_utc));
+        self.games
+            .write()
+            .await
+            .insert(id.clone(), Arc::new(Mutex::new(entry)));
+        self.save(&id, saved).await;
+        snapshot
+    }
+
+    /// The stored entry for a game, brought up to date with shared storage.
+    async fn entry(&self, id: &str) -> Option<Arc<Mutex<Entry>>> {
+        if let Some(storage) = &self.storage {
+            match storage.get_field(STORAGE_KEY, id).await {
+                Ok(stored) => self.pick_up(id, stored).await,
+                Err(e) => tracing::warn!(game = %id, error = %e, "Couldn't read mock game from storage"),
+            }
+        }
+        self.games.read().await.get(id).cloned()
+    }
+
+    /// Replace this instance's copy of a game with the one in shared storage,
+    /// unless they're the same revision. A game saved here but missing from
+    /// storage was deleted on another instance.
+    async fn pick_up(&self, id: &str, stored: Option<Vec<u8>>) {
+        let local = self.games.read().await.get(id).cloned();
+        let local_revision = local.and_then(|entry| entry.lock().unwrap().revision.clone());
+        let Some(bytes) = stored else {
+            if local_revision.is_some() {
+                self.games.write().await.remove(id);
+            }
+            return;
+        };
+        let current = serde_json::from_slice::<StoredRevision>(&bytes)
+            .is_ok_and(|stored| local_revision == Some(stored.revision));
+        if current {
+            return;
         }
+        let stored: StoredGame = match serde_json::from_slice(&bytes) {
+            Ok(stored) => stored,
+            Err(e) => {
+                tracing::warn!(game = %id, error = %e, "Stored mock game unreadable — ignored");
+                return;
+            }
+        };
+        let entry = Entry::load(id, stored, self.clock.now(), self.clock.now_utc());
+        self.games
+            .write()
+            .await
+            .insert(id.to_string(), Arc::new(Mutex::new(entry)));
+    }
 
-        // Re-fetch and return (this also advances state if needed)
-        self.get(&id).await.expect("Game should exist after creation")
+    /// Advance a game to `now`, returning its snapshot and, with shared
+    /// storage, the game to save if it moved on.
+    fn advance(
+        &self,
+        entry: &Mutex<Entry>,
+        now: Instant,
+        now_utc: DateTime<Utc>,
+        max_plays: usize,
+    ) -> (Arc<SimulatedGame>, Option<Vec<u8>>) {
+        let mut entry = entry.lock().unwrap();
+        let before = Progress::of(&entry.game.state);
+        let snapshot = entry.advance(now, now_utc, max_plays);
+        let moved = Progress::of(&entry.game.state) != before;
+        let saved = (moved && self.storage.is_some())
+            .then(|| entry.save(now_utc))
+            .flatten();
+        (snapshot, saved)
     }
 
-    /// Get a game by ID, advancing its state if needed.
-    pub async fn get(&self, id: &str) -> Option<SimulatedGame> {
-        let mut games = self.games.write().await;
+    /// Save a game to shared storage, if there is any and the game changed.
+    async fn save(&self, id: &str, saved: Option<Vec<u8>>) {
+        let (Some(storage), Some(json)) = (&self.storage, saved) else {
+            return;
+        };
+        if let Err(e) = storage.set_field(STORAGE_KEY, id, &json).await {
+            tracing::warn!(game = %id, error = %e, "Failed to save mock game to storage");
+        }
+    }
 
-        if let Some(game) = games.get_mut(id) {
-            game.touch();
+    /// Get a game by ID, advancing its state if needed.
+    pub async fn get(&self, id: &str) -> Option<Arc<SimulatedGame>> {
+        let entry = self.entry(id).await?;
+        let (snapshot, saved) =
+            self.advance(&entry, self.clock.now(), self.clock.now_utc(), self.max_plays());
+        self.save(id, saved).await;
+        Some(snapshot)
+    }
 
-            // Advance state if needed
-            advance_game_state(&mut game.state);
+    /// List all games (with state advancement).
+    ///
+    /// Takes the map lock once, then advances every game to the same instant.
+    pub async fn list(&self) -> Vec<Arc<SimulatedGame>> {
+        if let Some(storage) = &self.storage {
+            match storage.get_fields(STORAGE_KEY).await {
+                Ok(mut stored) => {
+                    let local: Vec<String> = self.games.read().await.keys().cloned().collect();
+                    for id in local {
+                        let game = stored.remove(&id);
+                        self.pick_up(&id, game).await;
+                    }
+                    for (id, game) in stored {
+                        self.pick_up(&id, Some(game)).await;
+                    }
+                }
+                Err(e) => tracing::warn!(error = %e, "Couldn't read mock games from storage"),
+            }
+        }
+        let entries: Vec<(String, Arc<Mutex<Entry>>)> = self
+            .games
+            .read()
+            .await
+            .iter()
+            .map(|(id, entry)| (id.clone(), entry.clone()))
+            .collect();
 
-            // Clone the game response data
-            Some(SimulatedGame {
-                id: game.id.clone(),
-                created_at: game.created_at,
-                last_accessed: game.last_accessed,
-                state: clone_game_state(&game.state),
-            })
-        } else {
-            None
+        let now = self.clock.now();
+        let now_utc = self.clock.now_utc();
+        let max_plays = self.max_plays();
+        let mut games = Vec::with_capacity(entries.len());
+        for (id, entry) in entries {
+            let (snapshot, saved) = self.advance(&entry, now, now_utc, max_plays);
+            self.save(&id, saved).await;
+            games.push(snapshot);
         }
+        games
     }
 
-    /// List all games (with state advancement).
-    pub async fn list(&self) -> Vec<SimulatedGame> {
-        let ids: Vec<String> = {
-            let games = self.games.read().await;
-            games.keys().cloned().collect()
+    /// Move a game to `clock` in `period`.
+    ///
+    /// Targets ahead of the game are simulated forward; targets behind it replay
+    /// the game from its starting state, so the same seed reaches the same plays.
+    /// The game then keeps running from the target at its usual time scale.
+    pub async fn seek(
+        &self,
+        id: &str,
+        period: FootballPeriod,
+        clock: &str,
+) -> Result<Arc<SimulatedGame>, AppError> {
+        let clock_seconds = parse_clock(clock)
+            .ok_or_else(|| AppError::InvalidSeekTarget(format!("invalid clock '{}'", clock)))?;
+
+        let entry = self
+            .entry(id)
+            .await
+            .ok_or_else(|| AppError::MockGameNotFound(id.to_string()))?;
+        let (snapshot, saved) = self.seek_entry(&entry, period, clock_seconds)?;
+        self.save(id, saved).await;
+        Ok(snapshot)
+    }
+
+    /// Move a game to `clock_seconds` in `period` (see `seek`), returning its
+    /// snapshot and, with shared storage, the game to save.
+    fn seek_entry(
+        &self,
+        entry: &Mutex<Entry>,
+        period: FootballPeriod,
+        clock_seconds: u16,
+    ) -> Result<(Arc<SimulatedGame>, Option<Vec<u8>>), AppError> {
+        let mut entry = entry.lock().unwrap();
+
+        let now = self.clock.now();
+        let now_utc = self.clock.now_utc();
+        let max_plays = self.max_plays();
+        entry.advance(now, now_utc, max_plays);
+        let game = &mut entry.game;
+
+        let target = game_position(period, clock_seconds);
+        let rewind = match &game.state {
+            GameState::Live(live) => game_position(live.period, live.clock_seconds) > target,
+            GameState::Final(_) => true,
+            GameState::Pregame(_) => {
+                return Err(AppError::InvalidSeekTarget(
+                    "game has not kicked off yet".to_string(),
+                ));
+            }
         };
 
-        let mut result = Vec::with_capacity(ids.len());
-        for id in ids {
-            if let Some(game) = self.get(&id).await {
-                result.push(game);
+        if rewind {
+            let origin = game.origin.as_ref().ok_or_else(|| {
+                AppError::InvalidSeekTarget("game was created final and has no plays".to_string())
+            })?;
+            if game_position(origin.period, origin.clock_seconds) > target {
+                return Err(AppError::InvalidSeekTarget(
+                    "target is before the start of the simulation".to_string(),
+                ));
             }
+            game.state = GameState::Live(origin.clone());
+        }
+
+        if let GameState::Live(live) = &mut game.state {
+            engine::advance_to_game_time(live, period, clock_seconds);
+            live.sync_wall_clock(now);
         }
-        result
+        // Finish the game if the target was past the end
+        advance_game_state(&mut game.state, &mut game.origin, now, now_utc);
+        game.state.evict_plays(max_plays);
+
+        let snapshot = entry.refresh(now_utc);
+        let saved = self.storage.as_ref().and_then(|_| entry.save(now_utc));
+        Ok((snapshot, saved))
     }
 
     /// Delete a game by ID. Returns true if the game existed.
     pub async fn delete(&self, id: &str) -> bool {
-        let mut games = self.games.write().await;
-        games.remove(id).is_some()
+        let existed = self.entry(id).await.is_some();
+        self.games.write().await.remove(id);
+        if let Some(storage) = &self.storage
+            && let Err(e) = storage.remove_field(STORAGE_KEY, id).await
+        {
+            tracing::warn!(game = %id, error = %e, "Failed to delete mock game from storage");
+        }
+        existed
+    }
+}
+
+impl SimulatedGame {
+    /// The game's state, for export.
+    pub fn to_snapshot(&self) -> GameSnapshot {
+        GameSnapshot::new(clone_game_state(&self.state))
+    }
+}
+
+/// Copy of a stored game to hand out of the repository.
+fn snapshot_game(game: &SimulatedGame, observed_at: DateTime<Utc>) -> SimulatedGame {
+    SimulatedGame {
+        id: game.id.clone(),
+        created_at: game.created_at,
+        last_accessed: game.last_accessed,
+        state: clone_game_state(&game.state),
+        observed_at,
+        origin: None,
     }
 }
 
-/// Clone a GameState (needed because we can't derive Clone due to StdRng)
+/// Clone a GameState (pregame and final states don't derive Clone)
 fn clone_game_state(state: &GameState) -> GameState {
     match state {
         GameState::Pregame(p) => GameState::Pregame(PregameState {
@@ -130,52 +549,43 @@ fn clone_game_state(state: &GameState) -> GameState {
             weather: p.weather.clone(),
             seed: p.seed,
             time_scale: p.time_scale,
+            rules: p.rules,
+            aggressiveness: p.aggressiveness,
+            pace: p.pace,
         }),
-        GameState::Live(l) => GameState::Live(Box::new(LiveState {
-            home_team: l.home_team.clone(),
-            away_team: l.away_team.clone(),
-            home_score: l.home_score,
-            away_score: l.away_score,
-            period: l.period,
-            clock_seconds: l.clock_seconds,
-            clock_running: l.clock_running,
-            possession: l.possession,
-            down: l.down,
-            distance: l.distance,
-            yard_line: l.yard_line,
-            home_timeouts: l.home_timeouts,
-            away_timeouts: l.away_timeouts,
-            last_play: l.last_play.clone(),
-            play_history: l.play_history.clone(),
-            rng: StdRng::seed_from_u64(0), // Placeholder, won't be used for cloned state
-            game_start_instant: l.game_start_instant,
-            simulated_game_seconds: l.simulated_game_seconds,
-            time_scale: l.time_scale,
-            kickoff_pending: l.kickoff_pending,
-            weather: l.weather.clone(),
-        })),
+        GameState::Live(l) => GameState::Live(l.clone()),
         GameState::Final(f) => GameState::Final(FinalState {
             home_team: f.home_team.clone(),
             away_team: f.away_team.clone(),
             home_score: f.home_score,
             away_score: f.away_score,
             overtime: f.overtime,
+            play_history: f.play_history.clone(),
+            plays_evicted: f.plays_evicted,
+            evicted_drives: f.evicted_drives.clone(),
+            home_stats: f.home_stats.clone(),
+            away_stats: f.away_stats.clone(),
+            home_linescore: f.home_linescore.clone(),
+            away_linescore: f.away_linescore.clone(),
         }),
     }
 }
 
 // === State creation helpers ===
 
-fn create_pregame_state(opts: CreatePregameOptions) -> PregameState {
+fn create_pregame_state(opts: CreatePregameOptions, now: DateTime<Utc>) -> PregameState {
     let seed = opts.seed.unwrap_or_else(rand::random);
     let mut rng = StdRng::seed_from_u64(seed);
 
     let (home_team, away_team) = resolve_teams(opts.home_team, opts.away_team, &mut rng);
 
-    let start_time = opts
-        .start_time
-        .and_then(|s| s.parse::<DateTime<Utc>>().ok())
-        .unwrap_or_else(|| Utc::now() + Duration::seconds(30));
+    let start_time = match opts.in_seconds {
+        Some(secs) => now + Duration::seconds(secs as i64),
+        None => opts
+            .start_time
+            .and_then(|s| s.parse::<DateTime<Utc>>().ok())
+            .unwrap_or_else(|| now + Duration::seconds(30)),
+    };
 
     let venue = opts.venue.unwrap_or_else(|| random_venue(&mut rng));
     let broadcast = opts.broadcast.unwrap_or_else(|| random_broadcast(&mut rng));
@@ -210,20 +620,25 @@ fn create_pregame_state(opts: CreatePregameOptions) -> PregameState {
         weather,
         seed,
         time_scale,
+        rules: opts.rules.unwrap_or_default(),
+        aggressiveness: opts.aggressiveness.unwrap_or_default(),
+        pace: opts.pace.unwrap_or_default(),
     }
 }
 
-fn create_live_state(opts: CreateLiveOptions) -> LiveState {
+fn create_live_state(opts: CreateLiveOptions, now: Instant) -> LiveState {
     let seed = opts.seed.unwrap_or_else(rand::random);
     let mut rng = StdRng::seed_from_u64(seed);
 
     let (home_team, away_team) = resolve_teams(opts.home_team, opts.away_team, &mut rng);
 
     let period = opts.period.unwrap_or(FootballPeriod::Q1);
-    let clock_seconds = opts
-        .clock
-        .and_then(|c| parse_clock(&c))
-        .unwrap_or(900);
+    // The clock stays at 0:00 through halftime
+    let clock_seconds = if period == FootballPeriod::Halftime {
+        0
+    } else {
+        opts.clock.and_then(|c| parse_clock(&c)).unwrap_or(900)
+    };
 
     let possession = opts.possession.unwrap_or_else(|| {
         if rng.gen_bool(0.5) {
@@ -233,30 +648,62 @@ fn create_live_state(opts: CreateLiveOptions) -> LiveState {
         }
     });
 
+    let weather = opts.weather.map(|w| WeatherInfo {
+        temp: w.temp.unwrap_or_else(|| rng.gen_range(20..=85)),
+        description: w
+            .description
+            .unwrap_or_else(|| random_weather_description(&mut rng)),
+    });
+
     let time_scale = opts.time_scale.unwrap_or(60.0);
+    let yard_line = opts.yard_line.unwrap_or(25);
+    let home_score = opts.home_score.unwrap_or(0);
+    let away_score = opts.away_score.unwrap_or(0);
 
     LiveState {
         home_team,
         away_team,
-        home_score: opts.home_score.unwrap_or(0),
-        away_score: opts.away_score.unwrap_or(0),
+        home_score,
+        away_score,
         period,
         clock_seconds,
         clock_running: false,
         possession,
         down: opts.down.unwrap_or(Down::First),
-        distance: opts.distance.unwrap_or(10),
-        yard_line: opts.yard_line.unwrap_or(25),
+        // Goal to go inside the 10
+        distance: opts.distance.unwrap_or(10.min(100u8.saturating_sub(yard_line))),
+        yard_line,
         home_timeouts: opts.home_timeouts.unwrap_or(3),
         away_timeouts: opts.away_timeouts.unwrap_or(3),
         last_play: None,
         play_history: Vec::new(),
+        plays_evicted: 0,
+        evicted_drives: Vec::new(),
+        home_stats: TeamStats::default(),
+        away_stats: TeamStats::default(),
+        home_linescore: spread_score(home_score, period),
+        away_linescore: spread_score(away_score, period),
+        seed,
         rng,
-        game_start_instant: Instant::now(),
+        game_start_instant: now,
         simulated_game_seconds: 0,
         time_scale,
         kickoff_pending: opts.yard_line.is_none() && opts.possession.is_none(),
-        weather: None, // Weather not supported for directly-created live games
+        halftime_remaining: if period == FootballPeriod::Halftime {
+            HALFTIME_SECONDS
+        } else {
+            0
+        },
+        weather,
+        rules: opts.rules.unwrap_or_default(),
+        aggressiveness: opts.aggressiveness.unwrap_or_default(),
+        pace: opts.pace.unwrap_or_default(),
+        // Games created mid-overtime treat the current team as the first possession
+        overtime: if is_overtime(period) {
+            OvertimeState::start(possession)
+        } else {
+            OvertimeState::default()
+        },
     }
 }
 
@@ -285,6 +732,13 @@ fn create_final_state(opts: CreateFinalOptions) -> FinalState {
         home_score,
         away_score,
         overtime,
+        play_history: Vec::new(),
+        plays_evicted: 0,
+        evicted_drives: Vec::new(),
+        home_stats: TeamStats::default(),
+        away_stats: TeamStats::default(),
+        home_linescore: Vec::new(),
+        away_linescore: Vec::new(),
     }
 }
 
@@ -322,12 +776,6 @@ fn resolve_teams(
     )
 }
 
-/// Find a team by abbreviation (case-insensitive).
-fn find_team(abbr: &str) -> Option<&'static NflTeam> {
-    let abbr_upper = abbr.to_uppercase();
-    NFL_TEAMS.iter().find(|t| t.abbreviation == abbr_upper)
-}
-
 /// Generate a random W-L record.
 fn random_record(rng: &mut StdRng) -> String {
     let wins = rng.gen_range(0..=17);
@@ -382,16 +830,19 @@ fn generate_realistic_score(rng: &mut StdRng) -> u8 {
     24 // Fallback
 }
 
-/// Parse "MM:SS" format to seconds.
-fn parse_clock(clock: &str) -> Option<u16> {
-    let parts: Vec<&str> = clock.split(':').collect();
-    if parts.len() == 2 {
-        let mins: u16 = parts[0].parse().ok()?;
-        let secs: u16 = parts[1].parse().ok()?;
-        Some(mins * 60 + secs)
-    } else {
-        None
+/// Spread a starting score across the periods completed before `period`.
+///
+/// Live games created mid-game have no history, so the linescore is
+/// approximated by splitting the points evenly (remainder in Q1).
+fn spread_score(score: u8, period: FootballPeriod) -> Vec<u8> {
+    let completed = period_index(period);
+    if completed == 0 {
+        return vec![score];
     }
+
+    let mut linescore = vec![score / completed as u8; completed];
+    linescore[0] += score % completed as u8;
+    linescore
 }
 
 fn random_venue(rng: &mut StdRng) -> String {
@@ -442,9 +893,17 @@ fn random_weather_description(rng: &mut StdRng) -> String {
 }
 
 /// Advance game state (handle transitions and simulation)
-fn advance_game_state(state: &mut GameState) {
+///
+/// Records the live state at kickoff in `origin` when a pregame goes live.
+fn advance_game_state(
+    state: &mut GameState,
+    origin: &mut Option<Box<LiveState>>,
+    now: Instant,
+    now_utc: DateTime<Utc>,
+) {
     // Check for pregame -> live transition
-    let should_transition_to_live = matches!(state, GameState::Pregame(p) if p.should_start());
+    let should_transition_to_live =
+        matches!(state, GameState::Pregame(p) if p.should_start(now_utc));
 
     if should_transition_to_live {
         // Take ownership of the pregame state and convert to live
@@ -455,27 +914,40 @@ fn advance_game_state(state: &mut GameState) {
                 home_team: TeamInfo {
                     abbreviation: String::new(),
                     color: Color { r: 0, g: 0, b: 0 },
+                    color_secondary: Color { r: 0, g: 0, b: 0 },
                     record: None,
+                    roster: Roster::default(),
                 },
                 away_team: TeamInfo {
                     abbreviation: String::new(),
                     color: Color { r: 0, g: 0, b: 0 },
+                    color_secondary: Color { r: 0, g: 0, b: 0 },
                     record: None,
+                    roster: Roster::default(),
                 },
                 home_score: 0,
                 away_score: 0,
                 overtime: false,
+                play_history: Vec::new(),
+                plays_evicted: 0,
+                evicted_drives: Vec::new(),
+                home_stats: TeamStats::default(),
+                away_stats: TeamStats::default(),
+                home_linescore: Vec::new(),
+                away_linescore: Vec::new(),
             }),
         );
 
         if let GameState::Pregame(pregame) = old_state {
-            *state = GameState::Live(Box::new(pregame.into_live_state()));
+            let live = Box::new(pregame.into_live_state(now, now_utc));
+            *origin = Some(live.clone());
+            *state = GameState::Live(live);
         }
     }
 
     // Advance live games
     let should_end_game = if let GameState::Live(live) = state {
-        super::engine::advance_to_now(live);
+        engine::advance_to_now(live, now);
         live.is_game_over()
     } else {
         false
@@ -490,8 +962,130 @@ fn advance_game_state(state: &mut GameState) {
             away_team: live.away_team.clone(),
             home_score: live.home_score,
             away_score: live.away_score,
-            overtime: matches!(live.period, FootballPeriod::OT | FootballPeriod::OT2),
+            overtime: is_overtime(live.period),
+            play_history: std::mem::take(&mut live.play_history),
+            plays_evicted: live.plays_evicted,
+            evicted_drives: std::mem::take(&mut live.evicted_drives),
+            home_stats: std::mem::take(&mut live.home_stats),
+            away_stats: std::mem::take(&mut live.away_stats),
+            home_linescore: padded_linescore(&live.home_linescore, live.period),
+            away_linescore: padded_linescore(&live.away_linescore, live.period),
         };
         *state = GameState::Final(final_state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unchanged_games_share_one_snapshot() {
+        let repository = GameRepository::new();
+        let created = repository
+            .create(CreateGameRequest::Final(CreateFinalOptions::default()))
+            .await
+            .unwrap();
+
+        let first = repository.get(&created.id).await.unwrap();
+        let second = repository.get(&created.id).await.unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(repository.list().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_spots_the_simulation_cant_run_from() {
+        let repository = GameRepository::new();
+        let at = |yard_line, distance| {
+            CreateGameRequest::Live(CreateLiveOptions {
+                possession: Some(Possession::Home),
+                yard_line: Some(yard_line),
+                distance,
+                ..Default::default()
+            })
+        };
+        assert!(matches!(
+            repository.create(at(0, None)).await,
+            Err(AppError::InvalidGameOptions(_))
+        ));
+        assert!(matches!(
+            repository.create(at(50, Some(0))).await,
+            Err(AppError::InvalidGameOptions(_))
+        ));
+        assert!(repository.list().await.is_empty());
+
+        // Inside the 10 it's goal to go unless told otherwise
+        assert!(repository.create(at(95, None)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_play_history_is_capped() {
+        let repository = GameRepository::new();
+        repository.set_max_play_history(20);
+        let created = repository
+            .create(CreateGameRequest::Live(CreateLiveOptions {
+                seed: Some(7),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+
+        let game = repository
+            .seek(&created.id, FootballPeriod::Q4, "0:00")
+            .await
+            .unwrap();
+        assert!(game.play_history().len() <= 20);
+        assert!(game.plays_evicted() > 0);
+        // Totals still count the evicted plays
+        let box_score = game.to_box_score().unwrap();
+        assert!(box_score.home.total_yards != 0 || box_score.away.total_yards != 0);
+
+        // And so do the drive summaries
+        let uncapped = GameRepository::new();
+        let created = uncapped
+            .create(CreateGameRequest::Live(CreateLiveOptions {
+                seed: Some(7),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        let full = uncapped
+            .seek(&created.id, FootballPeriod::Q4, "0:00")
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(game.to_drive_summaries()).unwrap(),
+            serde_json::to_value(full.to_drive_summaries()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_instances_share_games_through_storage() {
+        let storage: Arc<dyn Storage> = Arc::new(crate::storage::MemoryStorage::default());
+        let first = GameRepository::new().with_storage(storage.clone());
+        let second = GameRepository::new().with_storage(storage);
+
+        let created = first
+            .create(CreateGameRequest::Live(CreateLiveOptions {
+                seed: Some(7),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        let listed = second.list().await;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, created.id);
+
+        // A seek on one instance is what the other reads next
+        let sought = second.seek(&created.id, FootballPeriod::Q3, "5:00").await.unwrap();
+        let read = first.get(&created.id).await.unwrap();
+        assert_eq!(
+            read.play_history().len() + read.plays_evicted(),
+            sought.play_history().len() + sought.plays_evicted()
+        );
+
+        assert!(first.delete(&created.id).await);
+        assert!(second.get(&created.id).await.is_none());
+        assert!(second.list().await.is_empty());
+    }
+}
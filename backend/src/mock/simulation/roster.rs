@@ -0,0 +1,104 @@
+//! Stable fake rosters so simulated play text reads like ESPN's.
+//!
+//! Each team's roster is seeded from its abbreviation, so "KC" always has the
+//! same quarterback across games and server restarts.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+const LAST_NAMES: &[&str] = &[
+    "Adams", "Allen", "Bailey", "Baker", "Bell", "Brooks", "Brown", "Campbell", "Carter",
+    "Clark", "Collins", "Cook", "Cooper", "Davis", "Edwards", "Evans", "Fisher", "Foster",
+    "Garcia", "Gordon", "Graham", "Gray", "Green", "Hall", "Harris", "Hayes", "Hill",
+    "Howard", "Hughes", "Jackson", "James", "Johnson", "Jones", "Kelly", "King", "Lewis",
+    "Martin", "Mitchell", "Moore", "Morgan", "Morris", "Murphy", "Nelson", "Parker", "Perry",
+    "Peterson", "Phillips", "Price", "Reed", "Richardson", "Roberts", "Robinson", "Rogers",
+    "Ross", "Russell", "Sanders", "Scott", "Simmons", "Smith", "Stewart", "Taylor", "Thomas",
+    "Thompson", "Turner", "Walker", "Ward", "Washington", "Watson", "White", "Williams",
+    "Wilson", "Wright", "Young",
+];
+
+/// Named players for one team, in ESPN's "F.Last" style.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Roster {
+    pub quarterback: String,
+    pub running_backs: Vec<String>,
+    pub receivers: Vec<String>,
+    pub defenders: Vec<String>,
+    pub kicker: String,
+    pub punter: String,
+}
+
+impl Roster {
+    /// Build the roster for a team. Always returns the same names for the same abbreviation.
+    pub fn for_team(abbreviation: &str) -> Self {
+        let seed = abbreviation
+            .bytes()
+            .fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        Self {
+            quarterback: random_name(&mut rng),
+            running_backs: (0..2).map(|_| random_name(&mut rng)).collect(),
+            receivers: (0..5).map(|_| random_name(&mut rng)).collect(),
+            defenders: (0..11).map(|_| random_name(&mut rng)).collect(),
+            kicker: random_name(&mut rng),
+            punter: random_name(&mut rng),
+        }
+    }
+
+    /// Pick who carries on a running play (occasionally a QB keeper).
+    pub fn ball_carrier(&self, rng: &mut StdRng) -> &str {
+        if rng.gen_bool(0.1) {
+            &self.quarterback
+        } else {
+            pick(&self.running_backs, rng)
+        }
+    }
+
+    /// Pick the intended receiver on a passing play.
+    pub fn target(&self, rng: &mut StdRng) -> &str {
+        // Running backs catch a share of passes too
+        if rng.gen_bool(0.15) {
+            pick(&self.running_backs, rng)
+        } else {
+            pick(&self.receivers, rng)
+        }
+    }
+
+    /// Pick who returns a kick.
+    pub fn returner(&self, rng: &mut StdRng) -> &str {
+        pick(&self.receivers, rng)
+    }
+
+    /// Pick a defender to credit with a tackle, sack, or takeaway.
+    pub fn defender(&self, rng: &mut StdRng) -> &str {
+        pick(&self.defenders, rng)
+    }
+}
+
+fn pick<'a>(names: &'a [String], rng: &mut StdRng) -> &'a str {
+    names.choose(rng).map(String::as_str).unwrap_or("Player")
+}
+
+fn random_name(rng: &mut StdRng) -> String {
+    let initial = (b'A' + rng.gen_range(0..26)) as char;
+    let last = LAST_NAMES[rng.gen_range(0..LAST_NAMES.len())];
+    format!("{}.{}", initial, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roster_is_stable_per_team() {
+        let first = Roster::for_team("KC");
+        let second = Roster::for_team("KC");
+        assert_eq!(first.quarterback, second.quarterback);
+        assert_eq!(first.receivers, second.receivers);
+        assert_ne!(first.quarterback, Roster::for_team("PHI").quarterback);
+    }
+}
@@ -0,0 +1,140 @@
+//! Portable snapshots of a simulated game, for sharing reproducible scenarios.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+use crate::football::types::FootballPeriod;
+
+use super::state::{GameState, LiveState, HALFTIME_SECONDS};
+
+/// Current snapshot format version. Bump when `GameState` changes incompatibly.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Complete serialized state of a mock game.
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GameSnapshot {
+    /// Snapshot format version
+    pub version: u32,
+    /// Full internal game state, including seed, simulated time, and play history
+    #[schema(value_type = Object)]
+    pub game: GameState,
+}
+
+impl GameSnapshot {
+    pub fn new(game: GameState) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            game,
+        }
+    }
+
+    /// Unwrap the game state, rejecting snapshots from other format versions
+    /// and states the simulation can't run from.
+    pub fn into_state(self) -> Result<GameState, AppError> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(AppError::InvalidSnapshot(format!(
+                "unsupported version {} (expected {})",
+                self.version, SNAPSHOT_VERSION
+            )));
+        }
+        validate(&self.game).map_err(AppError::InvalidSnapshot)?;
+        Ok(self.game)
+    }
+}
+
+/// Why the simulation can't run from `state`, if it can't. Checked on every
+/// created, imported, and restored game.
+pub fn validate(state: &GameState) -> Result<(), String> {
+    match state {
+        GameState::Pregame(pregame) => validate_time_scale(pregame.time_scale),
+        GameState::Live(live) => validate_live(live),
+        GameState::Final(_) => Ok(()),
+    }
+}
+
+fn validate_time_scale(time_scale: f64) -> Result<(), String> {
+    if time_scale.is_finite() && time_scale > 0.0 {
+        Ok(())
+    } else {
+        Err(format!("time_scale {} must be a positive number", time_scale))
+    }
+}
+
+fn validate_live(live: &LiveState) -> Result<(), String> {
+    validate_time_scale(live.time_scale)?;
+    if !(1..=99).contains(&live.yard_line) {
+        return Err(format!("yard_line {} must be 1-99", live.yard_line));
+    }
+    // Goal to go is as far as the distance can be
+    if live.distance == 0 || live.distance > 100 - live.yard_line {
+        return Err(format!(
+            "distance {} must be 1-{} from the {}",
+            live.distance,
+            100 - live.yard_line,
+            live.yard_line
+        ));
+    }
+    if live.clock_seconds > 900 {
+        return Err(format!("clock_seconds {} must be at most 900", live.clock_seconds));
+    }
+    if live.home_timeouts > 3 || live.away_timeouts > 3 {
+        return Err("timeouts must be at most 3".to_string());
+    }
+    let halftime = live.period == FootballPeriod::Halftime;
+    if halftime && (live.clock_seconds != 0 || live.halftime_remaining > HALFTIME_SECONDS) {
+        return Err(format!(
+            "halftime needs clock_seconds 0 and halftime_remaining at most {}",
+            HALFTIME_SECONDS
+        ));
+    }
+    if !halftime && live.halftime_remaining != 0 {
+        return Err("halftime_remaining must be 0 outside halftime".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::Down;
+    use crate::mock::simulation::options::{Aggressiveness, OvertimeRules, Pace};
+    use crate::mock::simulation::state::TeamInfo;
+    use crate::mock::teams::find_team;
+
+    fn snapshot(change: impl FnOnce(&mut LiveState)) -> GameSnapshot {
+        let team = |abbr| TeamInfo::from_nfl_team(find_team(abbr).unwrap(), None);
+        let mut live = LiveState::new(
+            team("KC"),
+            team("BUF"),
+            7,
+            60.0,
+            OvertimeRules::Regular,
+            Aggressiveness::default(),
+            Pace::default(),
+            None,
+            std::time::Instant::now(),
+        );
+        (live.down, live.distance, live.yard_line) = (Down::Third, 4, 96);
+        change(&mut live);
+        GameSnapshot::new(GameState::Live(Box::new(live)))
+    }
+
+    #[test]
+    fn test_snapshots_the_simulation_cant_run_are_rejected() {
+        assert!(snapshot(|_| {}).into_state().is_ok());
+
+        let invalid: [fn(&mut LiveState); 6] = [
+            |live| live.yard_line = 101,
+            |live| live.distance = 5,
+            |live| live.clock_seconds = 901,
+            |live| live.time_scale = 0.0,
+            |live| live.time_scale = f64::NAN,
+            |live| live.period = FootballPeriod::Halftime,
+        ];
+        for change in invalid {
+            let result = snapshot(change).into_state();
+            assert!(matches!(result, Err(AppError::InvalidSnapshot(_))));
+        }
+    }
+}
@@ -4,26 +4,46 @@
 //! allowing for realistic game progression. Each state converts to the
 //! corresponding `FootballGameResponse` variant.
 
-use std::time::Instant;
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
 
 use crate::football::types::{
-    Down, FootballFinal, FootballGameResponse, FootballLive, FootballPeriod, FootballPregame,
-    FootballTeamScore, LastPlay, PlayType, Possession, Situation,
+    Down, DriveSummary, FootballBoxScore, FootballFinal, FootballGameResponse, FootballLive, FootballPeriod, FootballPregame,
+    FootballTeamScore, LastPlay, PlayType, Possession, RecentPlay, Situation,
 };
+use crate::shared::transform::DEFAULT_SECONDARY_COLOR;
 use crate::shared::types::{Color, FinalStatus, Team, Weather, Winner};
 use crate::mock::teams::NflTeam;
+use crate::sport::{EspnLeague, FootballLeague};
+
+use super::drives::{evict_drives, summarize_drives};
+use super::options::{Aggressiveness, OvertimeRules, Pace};
+use super::roster::Roster;
+use super::stats::TeamStats;
 
 /// A simulated play with its effects.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulatedPlay {
     pub play_type: PlayType,
     pub yards_gained: i8,
     pub description: String,
     /// Seconds consumed by this play
     pub clock_elapsed: u16,
+    /// Seconds the game clock actually ran, less than `clock_elapsed` when
+    /// the play stopped it
+    #[serde(default)]
+    pub clock_used: u16,
+    /// Team with the ball when the play was snapped
+    pub possession: Possession,
+    /// Period the play occurred in
+    pub period: FootballPeriod,
+    /// Seconds remaining in the period at the snap
+    pub clock_seconds: u16,
 }
 
 /// A game in the repository with all simulation state.
@@ -36,32 +56,139 @@ pub struct SimulatedGame {
     pub last_accessed: Instant,
     /// Current game state
     pub state: GameState,
+    /// Simulation time this copy of the game was taken at
+    pub observed_at: DateTime<Utc>,
+    /// Live state the simulation started from (creation, kickoff, or restore).
+    /// Seeking backwards replays the game from here.
+    pub origin: Option<Box<LiveState>>,
 }
 
 impl SimulatedGame {
     /// Convert to the public `FootballGameResponse` type.
     pub fn to_game_response(&self) -> FootballGameResponse {
         match &self.state {
-            GameState::Pregame(state) => FootballGameResponse::Pregame(state.to_pregame_game(&self.id)),
+            GameState::Pregame(state) => {
+                FootballGameResponse::Pregame(state.to_pregame_game(&self.id, self.observed_at))
+            }
             GameState::Live(state) => FootballGameResponse::Live(state.to_live_game(&self.id)),
             GameState::Final(state) => FootballGameResponse::Final(state.to_final_game(&self.id)),
         }
     }
 
+    /// Group the play history into drive summaries.
+    ///
+    /// Pregame games (and finals created without a simulation) have no drives.
+    pub fn to_drive_summaries(&self) -> Vec<DriveSummary> {
+        match &self.state {
+            GameState::Pregame(_) => Vec::new(),
+            GameState::Live(state) => {
+                let mut drives = state.evicted_drives.clone();
+                drives.extend(summarize_drives(&state.play_history, false));
+                drives
+            }
+            GameState::Final(state) => {
+                let mut drives = state.evicted_drives.clone();
+                drives.extend(summarize_drives(&state.play_history, true));
+                drives
+            }
+        }
+    }
+
+    /// The plays still kept, oldest first. The `plays_evicted` plays before
+    /// them were dropped to bound memory.
+    pub fn play_history(&self) -> &[SimulatedPlay] {
+        match &self.state {
+            GameState::Pregame(_) => &[],
+            GameState::Live(state) => &state.play_history,
+            GameState::Final(state) => &state.play_history,
+        }
+    }
+
+    /// The last `count` plays, newest first, for `?include=recent_plays`.
+    pub fn recent_plays(&self, count: usize) -> Vec<RecentPlay> {
+        self.play_history()
+            .iter()
+            .rev()
+            .take(count)
+            .map(|play| RecentPlay {
+                play_type: play.play_type,
+                text: Some(play.description.clone()),
+                period: play.period,
+                clock: format_clock(play.clock_seconds),
+            })
+            .collect()
+    }
+
+    /// Plays dropped from the front of the history (see `GameState::evict_plays`).
+    pub fn plays_evicted(&self) -> usize {
+        match &self.state {
+            GameState::Pregame(_) => 0,
+            GameState::Live(state) => state.plays_evicted,
+            GameState::Final(state) => state.plays_evicted,
+        }
+    }
+
+    /// Whether the game has finished.
+    pub fn is_final(&self) -> bool {
+        matches!(self.state, GameState::Final(_))
+    }
+
+    /// Box score totals for both teams. Pregame games have none yet.
+    pub fn to_box_score(&self) -> Option<FootballBoxScore> {
+        let (home_team, away_team, home_stats, away_stats) = match &self.state {
+            GameState::Pregame(_) => return None,
+            GameState::Live(s) => (&s.home_team, &s.away_team, &s.home_stats, &s.away_stats),
+            GameState::Final(s) => (&s.home_team, &s.away_team, &s.home_stats, &s.away_stats),
+        };
+
+        Some(FootballBoxScore {
+            home: home_stats.to_team_stats(&home_team.abbreviation),
+            away: away_stats.to_team_stats(&away_team.abbreviation),
+        })
+    }
+
     /// Update the last_accessed timestamp
-    pub fn touch(&mut self) {
-        self.last_accessed = Instant::now();
+    pub fn touch(&mut self, now: Instant) {
+        self.last_accessed = now;
     }
 }
 
 /// Internal game state - more detailed than FootballGameResponse.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "lowercase")]
 pub enum GameState {
     Pregame(PregameState),
     Live(Box<LiveState>),
     Final(FinalState),
 }
 
+impl GameState {
+    /// Drop the oldest drives' plays so at most `max` are kept (0 keeps them
+    /// all), though the current drive is kept whole. Scores, stats,
+    /// linescores, and the dropped drives' summaries are kept separately, so
+    /// only the play-by-play loses the evicted plays.
+    pub fn evict_plays(&mut self, max: usize) {
+        let (history, evicted, drives) = match self {
+            GameState::Pregame(_) => return,
+            GameState::Live(state) => (
+                &mut state.play_history,
+                &mut state.plays_evicted,
+                &mut state.evicted_drives,
+            ),
+            GameState::Final(state) => (
+                &mut state.play_history,
+                &mut state.plays_evicted,
+                &mut state.evicted_drives,
+            ),
+        };
+        if max > 0 {
+            *evicted += evict_drives(history, max, drives);
+        }
+    }
+}
+
 /// Internal state for a pregame.
+#[derive(Serialize, Deserialize)]
 pub struct PregameState {
     pub home_team: TeamInfo,
     pub away_team: TeamInfo,
@@ -73,15 +200,26 @@ pub struct PregameState {
     pub seed: u64,
     /// Time scale for live simulation
     pub time_scale: f64,
+    /// Overtime rules applied once the game is live
+    pub rules: OvertimeRules,
+    /// Coaching decision style applied once the game is live
+    #[serde(default)]
+    pub aggressiveness: Aggressiveness,
+    /// Scoring environment applied once the game is live
+    #[serde(default)]
+    pub pace: Pace,
 }
 
 impl PregameState {
-    pub fn to_pregame_game(&self, event_id: &str) -> FootballPregame {
+    pub fn to_pregame_game(&self, event_id: &str, now: DateTime<Utc>) -> FootballPregame {
         FootballPregame {
             event_id: event_id.to_string(),
+            season: None,
             home: self.home_team.to_team(),
             away: self.away_team.to_team(),
             start_time: self.start_time.timestamp(),
+            seconds_until_start: self.seconds_until_start(now),
+            seconds_until_kickoff: Some(self.seconds_until_kickoff(now)),
             venue: Some(self.venue.clone()),
             broadcast: Some(self.broadcast.clone()),
             weather: self.weather.as_ref().map(|w| Weather {
@@ -91,24 +229,44 @@ impl PregameState {
         }
     }
 
+    /// Whole seconds until kickoff, rounded up so the countdown reaches 0 as
+    /// the game goes live; negative once kickoff time has passed.
+    pub fn seconds_until_start(&self, now: DateTime<Utc>) -> i64 {
+        let millis = (self.start_time - now).num_milliseconds();
+        millis.saturating_add(999).div_euclid(1000)
+    }
+
+    /// `seconds_until_start`, never negative.
+    pub fn seconds_until_kickoff(&self, now: DateTime<Utc>) -> u32 {
+        u32::try_from(self.seconds_until_start(now).max(0)).unwrap_or(u32::MAX)
+    }
+
     /// Check if it's time to transition to live state.
-    pub fn should_start(&self) -> bool {
-        Utc::now() >= self.start_time
+    pub fn should_start(&self, now: DateTime<Utc>) -> bool {
+        now >= self.start_time
     }
 
-    /// Transition to live state.
-    pub fn into_live_state(self) -> LiveState {
+    /// Transition to live state, with the game clock starting at the scheduled kickoff.
+    pub fn into_live_state(self, now: Instant, now_utc: DateTime<Utc>) -> LiveState {
+        let since_kickoff = (now_utc - self.start_time).to_std().unwrap_or_default();
+        let kickoff = now.checked_sub(since_kickoff).unwrap_or(now);
+
         LiveState::new(
             self.home_team,
             self.away_team,
             self.seed,
             self.time_scale,
+            self.rules,
+            self.aggressiveness,
+            self.pace,
             self.weather,
+            kickoff,
         )
     }
 }
 
 /// Internal state for a live game.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct LiveState {
     pub home_team: TeamInfo,
     pub away_team: TeamInfo,
@@ -128,9 +286,24 @@ pub struct LiveState {
     pub away_timeouts: u8,
     pub last_play: Option<SimulatedPlay>,
     pub play_history: Vec<SimulatedPlay>,
-    /// Random number generator for simulation
+    /// Plays dropped from the front of `play_history`
+    #[serde(default)]
+    pub plays_evicted: usize,
+    /// Summaries of the drives those plays made up
+    #[serde(default)]
+    pub evicted_drives: Vec<DriveSummary>,
+    pub home_stats: TeamStats,
+    pub away_stats: TeamStats,
+    /// Points scored per period, indexed by `period_index`
+    pub home_linescore: Vec<u8>,
+    pub away_linescore: Vec<u8>,
+    /// Seed the game was created with
+    pub seed: u64,
+    /// Random number generator for simulation (not serialized; see `reseed`)
+    #[serde(skip, default = "placeholder_rng")]
     pub rng: StdRng,
     /// When this game went live (wall-clock time)
+    #[serde(skip, default = "Instant::now")] // Reset by `resume`
     pub game_start_instant: Instant,
     /// Total game-seconds that have been simulated
     pub simulated_game_seconds: u64,
@@ -138,20 +311,57 @@ pub struct LiveState {
     pub time_scale: f64,
     /// Whether we're in a kickoff situation
     pub kickoff_pending: bool,
+    /// Game-seconds left in the halftime intermission (only used during `Halftime`)
+    pub halftime_remaining: u16,
     /// Weather info (persists from pregame)
     pub weather: Option<WeatherInfo>,
+    /// Overtime rule set for this game
+    pub rules: OvertimeRules,
+    /// Fourth-down and two-point decision style
+    #[serde(default)]
+    pub aggressiveness: Aggressiveness,
+    /// Scoring environment
+    #[serde(default)]
+    pub pace: Pace,
+    /// Possession tracking for the current overtime
+    pub overtime: OvertimeState,
+}
+
+/// Length of the halftime intermission, in game-seconds (13 minutes).
+pub const HALFTIME_SECONDS: u16 = 780;
+
+/// Possession tracking used to decide an overtime game.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct OvertimeState {
+    /// Team that received the overtime kickoff
+    pub first_possession: Option<Possession>,
+    /// Number of possessions started in overtime (including the current one)
+    pub possessions: u8,
+}
+
+impl OvertimeState {
+    /// Start overtime with `receiving` getting the ball first.
+    pub fn start(receiving: Possession) -> Self {
+        Self {
+            first_possession: Some(receiving),
+            possessions: 1,
+        }
+    }
 }
 
 impl LiveState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         home_team: TeamInfo,
         away_team: TeamInfo,
         seed: u64,
         time_scale: f64,
+        rules: OvertimeRules,
+        aggressiveness: Aggressiveness,
+        pace: Pace,
         weather: Option<WeatherInfo>,
+        game_start_instant: Instant,
     ) -> Self {
-        use rand::SeedableRng;
-
         let mut rng = StdRng::seed_from_u64(seed);
 
         // Coin toss - winner receives (random choice for simplicity)
@@ -177,18 +387,30 @@ impl LiveState {
             away_timeouts: 3,
             last_play: None,
             play_history: Vec::new(),
+            plays_evicted: 0,
+            evicted_drives: Vec::new(),
+            home_stats: TeamStats::default(),
+            away_stats: TeamStats::default(),
+            home_linescore: Vec::new(),
+            away_linescore: Vec::new(),
+            seed,
             rng,
-            game_start_instant: Instant::now(),
+            game_start_instant,
             simulated_game_seconds: 0,
             time_scale,
             kickoff_pending: true, // Start with opening kickoff
+            halftime_remaining: 0,
             weather,
+            rules,
+            aggressiveness,
+            pace,
+            overtime: OvertimeState::default(),
         }
     }
 
     pub fn to_live_game(&self, event_id: &str) -> FootballLive {
-        let situation = if self.kickoff_pending {
-            None // No situation during kickoff
+        let situation = if self.kickoff_pending || self.period == FootballPeriod::Halftime {
+            None // No situation during kickoff or halftime
         } else {
             Some(Situation {
                 down: self.down,
@@ -196,30 +418,42 @@ impl LiveState {
                 yard_line: self.yard_line,
                 possession: self.possession,
                 red_zone: self.yard_line >= 80, // Within 20 yards of end zone
+                goal_to_go: self.yard_line + self.distance >= 100,
+                ball_on: self.ball_on(),
+                direction: self.possession.direction(),
             })
         };
 
         FootballLive {
             event_id: event_id.to_string(),
+            season: None,
             home: FootballTeamScore {
                 abbreviation: self.home_team.abbreviation.clone(),
                 color: self.home_team.color,
+                color_secondary: self.home_team.color_secondary,
                 record: self.home_team.record.clone(),
                 rank: None,
+                logo_path: self.home_team.logo_path(),
                 score: self.home_score,
-                timeouts: self.home_timeouts,
+                timeouts: Some(self.home_timeouts),
+                linescore: padded_linescore(&self.home_linescore, self.period),
             },
             away: FootballTeamScore {
                 abbreviation: self.away_team.abbreviation.clone(),
                 color: self.away_team.color,
+                color_secondary: self.away_team.color_secondary,
                 record: self.away_team.record.clone(),
                 rank: None,
+                logo_path: self.away_team.logo_path(),
                 score: self.away_score,
-                timeouts: self.away_timeouts,
+                timeouts: Some(self.away_timeouts),
+                linescore: padded_linescore(&self.away_linescore, self.period),
             },
             period: self.period,
             clock: format_clock(self.clock_seconds),
             clock_running: self.clock_running,
+            inside_two_minutes: self.period.is_inside_two_minutes(self.clock_seconds),
+            is_close_game: self.period.is_close_game(self.home_score, self.away_score),
             situation,
             last_play: self.last_play.as_ref().map(|p| LastPlay {
                 play_type: p.play_type,
@@ -229,26 +463,110 @@ impl LiveState {
                 temp: w.temp,
                 description: w.description.clone(),
             }),
+            leaders: None,
+            recent_plays: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
+        }
+    }
+
+    /// Line of scrimmage as broadcasts show it, e.g. "KC 35".
+    fn ball_on(&self) -> String {
+        let (offense, defense) = match self.possession {
+            Possession::Home => (&self.home_team, &self.away_team),
+            Possession::Away => (&self.away_team, &self.home_team),
+        };
+        Situation::format_ball_on(self.yard_line, &offense.abbreviation, &defense.abbreviation)
+    }
+
+    /// Add points for a team, crediting them to the current period's linescore.
+    pub fn add_points(&mut self, team: Possession, points: u8) {
+        let index = period_index(self.period);
+        let (score, linescore) = match team {
+            Possession::Home => (&mut self.home_score, &mut self.home_linescore),
+            Possession::Away => (&mut self.away_score, &mut self.away_linescore),
+        };
+
+        *score = score.saturating_add(points);
+        if linescore.len() <= index {
+            linescore.resize(index + 1, 0);
+        }
+        linescore[index] = linescore[index].saturating_add(points);
+    }
+
+    /// Box score totals for the given team.
+    pub fn stats_mut(&mut self, team: Possession) -> &mut TeamStats {
+        match team {
+            Possession::Home => &mut self.home_stats,
+            Possession::Away => &mut self.away_stats,
         }
     }
 
+    /// Pick a deserialized game back up: rebuild the RNG and line the wall clock
+    /// up with the game-seconds already simulated.
+    ///
+    /// The RNG is reseeded from the seed and simulated time, so every copy of
+    /// the same snapshot plays out identically from this point.
+    pub fn resume(&mut self, now: Instant) {
+        self.rng = StdRng::seed_from_u64(self.seed ^ self.simulated_game_seconds);
+        self.sync_wall_clock(now);
+    }
+
+    /// Line the wall clock up with the game-seconds already simulated, so the
+    /// game continues from its current clock instead of catching up.
+    pub fn sync_wall_clock(&mut self, now: Instant) {
+        let elapsed = self.simulated_game_seconds as f64 / self.time_scale;
+        let elapsed = Duration::try_from_secs_f64(elapsed).unwrap_or_default();
+        self.game_start_instant = now.checked_sub(elapsed).unwrap_or(now);
+    }
+
+    /// Count a new overtime possession when the ball changes hands.
+    pub fn track_overtime_possession(&mut self, previous: Possession) {
+        if is_overtime(self.period) && self.possession != previous {
+            self.overtime.possessions = self.overtime.possessions.saturating_add(1);
+        }
+    }
+
+    /// Whether overtime has produced a winner.
+    ///
+    /// Both teams get a possession unless the kicking team scores on the first
+    /// one (a safety). Once both have had the ball, the next score wins.
+    fn overtime_decided(&self) -> bool {
+        if !is_overtime(self.period) || self.home_score == self.away_score {
+            return false;
+        }
+
+        let leader = if self.home_score > self.away_score {
+            Possession::Home
+        } else {
+            Possession::Away
+        };
+
+        self.overtime.possessions >= 3 || self.overtime.first_possession != Some(leader)
+    }
+
     /// Check if the game should end (transition to final).
     pub fn is_game_over(&self) -> bool {
-        // Game ends when Q4 (or OT) clock hits 0 and one team is ahead
+        if self.overtime_decided() {
+            return true;
+        }
+
+        // Otherwise the game can only end when the clock hits 0
         if self.clock_seconds > 0 {
             return false;
         }
 
         match self.period {
             FootballPeriod::Q4 => self.home_score != self.away_score,
-            FootballPeriod::OT | FootballPeriod::OT2 => self.home_score != self.away_score,
+            // Regular-season overtime ends in a tie; postseason keeps going
+            period if is_overtime(period) => self.rules == OvertimeRules::Regular,
             _ => false,
         }
     }
 
     /// Transition to final state.
     pub fn into_final_state(self) -> FinalState {
-        let overtime = matches!(self.period, FootballPeriod::OT | FootballPeriod::OT2);
+        let overtime = is_overtime(self.period);
 
         FinalState {
             home_team: self.home_team,
@@ -256,17 +574,38 @@ impl LiveState {
             home_score: self.home_score,
             away_score: self.away_score,
             overtime,
+            play_history: self.play_history,
+            plays_evicted: self.plays_evicted,
+            evicted_drives: self.evicted_drives,
+            home_stats: self.home_stats,
+            away_stats: self.away_stats,
+            home_linescore: padded_linescore(&self.home_linescore, self.period),
+            away_linescore: padded_linescore(&self.away_linescore, self.period),
         }
     }
 }
 
 /// Internal state for a completed game.
+#[derive(Serialize, Deserialize)]
 pub struct FinalState {
     pub home_team: TeamInfo,
     pub away_team: TeamInfo,
     pub home_score: u8,
     pub away_score: u8,
     pub overtime: bool,
+    /// Plays from the simulation that produced this result (empty if created final)
+    pub play_history: Vec<SimulatedPlay>,
+    /// Plays dropped from the front of `play_history`
+    #[serde(default)]
+    pub plays_evicted: usize,
+    /// Summaries of the drives those plays made up
+    #[serde(default)]
+    pub evicted_drives: Vec<DriveSummary>,
+    pub home_stats: TeamStats,
+    pub away_stats: TeamStats,
+    /// Points scored per period (empty if created final)
+    pub home_linescore: Vec<u8>,
+    pub away_linescore: Vec<u8>,
 }
 
 impl FinalState {
@@ -281,21 +620,28 @@ impl FinalState {
 
         FootballFinal {
             event_id: event_id.to_string(),
+            season: None,
             home: FootballTeamScore {
                 abbreviation: self.home_team.abbreviation.clone(),
                 color: self.home_team.color,
+                color_secondary: self.home_team.color_secondary,
                 record: self.home_team.record.clone(),
                 rank: None,
+                logo_path: self.home_team.logo_path(),
                 score: self.home_score,
-                timeouts: 0, // Timeouts don't matter for final
+                timeouts: None, // Timeouts don't matter for final
+                linescore: self.home_linescore.clone(),
             },
             away: FootballTeamScore {
                 abbreviation: self.away_team.abbreviation.clone(),
                 color: self.away_team.color,
+                color_secondary: self.away_team.color_secondary,
                 record: self.away_team.record.clone(),
                 rank: None,
+                logo_path: self.away_team.logo_path(),
                 score: self.away_score,
-                timeouts: 0,
+                timeouts: None,
+                linescore: self.away_linescore.clone(),
             },
             status: if self.overtime {
                 FinalStatus::FinalOvertime
@@ -303,16 +649,27 @@ impl FinalState {
                 FinalStatus::Final
             },
             winner,
+            leaders: None,
+            score_changed_since_last_poll: false,
+            lead_changed: false,
         }
     }
 }
 
 /// Team information for internal state.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamInfo {
     pub abbreviation: String,
     pub color: Color,
+    #[serde(default = "default_color_secondary")]
+    pub color_secondary: Color,
     pub record: Option<String>,
+    /// Generated players named in play descriptions
+    pub roster: Roster,
+}
+
+fn default_color_secondary() -> Color {
+    DEFAULT_SECONDARY_COLOR
 }
 
 impl TeamInfo {
@@ -320,7 +677,9 @@ impl TeamInfo {
         Self {
             abbreviation: team.abbreviation.to_string(),
             color: team.color,
+            color_secondary: team.color_secondary,
             record,
+            roster: Roster::for_team(team.abbreviation),
         }
     }
 
@@ -328,21 +687,70 @@ impl TeamInfo {
         Team {
             abbreviation: self.abbreviation.clone(),
             color: self.color,
+            color_secondary: self.color_secondary,
             record: self.record.clone(),
             rank: None,
+            logo_path: self.logo_path(),
         }
     }
+
+    /// Mock teams are NFL teams, whose logos are keyed by abbreviation.
+    pub fn logo_path(&self) -> String {
+        FootballLeague::Nfl.logo_path(&self.abbreviation, &self.abbreviation)
+    }
 }
 
 /// Weather information for internal state.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherInfo {
     pub temp: i16,
     pub description: String,
 }
 
+/// Stand-in RNG for deserialized state until `LiveState::resume` reseeds it.
+fn placeholder_rng() -> StdRng {
+    StdRng::seed_from_u64(0)
+}
+
+/// Whether a period is any overtime period.
+pub fn is_overtime(period: FootballPeriod) -> bool {
+    matches!(
+        period,
+        FootballPeriod::OT | FootballPeriod::OT2 | FootballPeriod::OT3 | FootballPeriod::OT4
+    )
+}
+
+/// Position of a period in the linescore (Q1 = 0, first overtime = 4).
+pub fn period_index(period: FootballPeriod) -> usize {
+    match period {
+        FootballPeriod::Q1 => 0,
+        FootballPeriod::Q2 | FootballPeriod::Halftime => 1,
+        FootballPeriod::Q3 => 2,
+        FootballPeriod::Q4 => 3,
+        FootballPeriod::OT => 4,
+        FootballPeriod::OT2 => 5,
+        FootballPeriod::OT3 => 6,
+        FootballPeriod::OT4 => 7,
+    }
+}
+
+/// Sort key for a moment in the game: later moments compare greater.
+pub fn game_position(period: FootballPeriod, clock_seconds: u16) -> (usize, Reverse<u16>) {
+    (period_index(period), Reverse(clock_seconds))
+}
+
+/// Linescore with a zero entry for every period played through `period`.
+pub fn padded_linescore(linescore: &[u8], period: FootballPeriod) -> Vec<u8> {
+    let mut padded = linescore.to_vec();
+    let len = period_index(period) + 1;
+    if padded.len() < len {
+        padded.resize(len, 0);
+    }
+    padded
+}
+
 /// Format clock seconds as "MM:SS".
-fn format_clock(seconds: u16) -> String {
+pub fn format_clock(seconds: u16) -> String {
     let mins = seconds / 60;
     let secs = seconds % 60;
     format!("{}:{:02}", mins, secs)
@@ -0,0 +1,76 @@
+//! Per-team box score accumulation as plays are simulated.
+
+use serde::{Deserialize, Serialize};
+
+use crate::football::types::{Down, FootballTeamStats, PlayType};
+
+use super::plays::PlayOutcome;
+use super::state::format_clock;
+
+/// Running box score totals for one team.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeamStats {
+    pub passing_yards: i16,
+    pub rushing_yards: i16,
+    pub turnovers: u8,
+    /// Game-seconds spent on offense, kickoffs excluded
+    pub time_of_possession: u16,
+    pub third_down_attempts: u8,
+    pub third_down_conversions: u8,
+}
+
+impl TeamStats {
+    /// Record a play run by this team.
+    ///
+    /// `down` is the down the play was snapped on and `converted` whether the
+    /// offense earned a new set of downs (or scored) on it.
+    pub fn record_play(
+        &mut self,
+        outcome: &PlayOutcome,
+        down: Down,
+        converted: bool,
+        duration: u16,
+    ) {
+        // Kickoffs are on neither team's clock
+        let kickoff = matches!(outcome.play_type, PlayType::Kickoff | PlayType::KickoffReturn);
+        if !kickoff {
+            self.time_of_possession = self.time_of_possession.saturating_add(duration);
+        }
+
+        match outcome.play_type {
+            PlayType::Rush | PlayType::RushingTouchdown | PlayType::FumbleRecoveryOwn => {
+                self.rushing_yards += outcome.yards_gained as i16;
+            }
+            // Sack yardage counts against net passing, as on ESPN box scores
+            PlayType::PassReception | PlayType::PassingTouchdown | PlayType::Sack => {
+                self.passing_yards += outcome.yards_gained as i16;
+            }
+            PlayType::Interception | PlayType::FumbleRecoveryOpponent => {
+                self.turnovers += 1;
+            }
+            _ => {}
+        }
+
+        // Penalties wipe out the snap, so the down is still to be tried
+        let from_scrimmage = !kickoff && outcome.play_type != PlayType::Penalty;
+        if down == Down::Third && from_scrimmage {
+            self.third_down_attempts += 1;
+            if converted {
+                self.third_down_conversions += 1;
+            }
+        }
+    }
+
+    pub fn to_team_stats(&self, abbreviation: &str) -> FootballTeamStats {
+        FootballTeamStats {
+            abbreviation: abbreviation.to_string(),
+            total_yards: self.passing_yards + self.rushing_yards,
+            passing_yards: self.passing_yards,
+            rushing_yards: self.rushing_yards,
+            turnovers: self.turnovers,
+            time_of_possession: format_clock(self.time_of_possession),
+            third_down_attempts: self.third_down_attempts,
+            third_down_conversions: self.third_down_conversions,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Time sources for the simulation.
+//!
+//! All simulation code reads time through `TimeSource` rather than calling
+//! `Instant::now` / `Utc::now` directly, so tests can jump the clock forward
+//! (e.g. "three hours pass") instead of sleeping.
+
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+
+/// Source of monotonic and wall-clock time.
+pub trait TimeSource: Send + Sync {
+    fn now(&self) -> Instant;
+    fn now_utc(&self) -> DateTime<Utc>;
+}
+
+/// The real system clock.
+pub struct SystemClock;
+
+impl TimeSource for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Furthest virtual time may run ahead of the underlying clock: well past
+/// any season a test would simulate, and far inside `Instant`'s range.
+const MAX_OFFSET: Duration = Duration::from_secs(10 * 365 * 24 * 60 * 60);
+
+/// Clock that follows another time source plus a manually advanced offset.
+///
+/// Virtual time only moves forward, so elapsed-time math in running games
+/// never goes negative.
+pub struct VirtualClock {
+    inner: Arc<dyn TimeSource>,
+    offset: RwLock<Duration>,
+}
+
+impl VirtualClock {
+    pub fn new(inner: Arc<dyn TimeSource>) -> Self {
+        Self {
+            inner,
+            offset: RwLock::new(Duration::ZERO),
+        }
+    }
+
+    /// How far virtual time is ahead of the underlying clock.
+    pub fn offset(&self) -> Duration {
+        *self.offset.read().expect("clock lock poisoned")
+    }
+
+    /// Move virtual time forward, by no more than `MAX_OFFSET` in total.
+    pub fn advance(&self, by: Duration) -> Result<(), AppError> {
+        let mut offset = self.offset.write().expect("clock lock poisoned");
+        *offset = offset
+            .checked_add(by)
+            .filter(|&ahead| ahead <= MAX_OFFSET)
+            .ok_or_else(|| {
+                AppError::InvalidVirtualTime(format!(
+                    "virtual time can't run more than {} days ahead",
+                    MAX_OFFSET.as_secs() / 86_400
+                ))
+            })?;
+        Ok(())
+    }
+
+    /// Jump virtual time to `target`, which must not be in the (virtual) past.
+    pub fn set(&self, target: DateTime<Utc>) -> Result<(), AppError> {
+        let ahead = (target - self.now_utc()).to_std().map_err(|_| {
+            AppError::InvalidVirtualTime(format!("{} is before the current virtual time", target))
+        })?;
+        self.advance(ahead)
+    }
+
+    pub fn to_response(&self) -> VirtualClockResponse {
+        VirtualClockResponse {
+            now: self.now_utc().timestamp(),
+            offset_seconds: self.offset().as_secs(),
+        }
+    }
+}
+
+impl TimeSource for VirtualClock {
+    // `advance` keeps the offset to MAX_OFFSET, far inside both clocks' ranges
+    fn now(&self) -> Instant {
+        self.inner
+            .now()
+            .checked_add(self.offset())
+            .expect("virtual clock offset out of range")
+    }
+
+    fn now_utc(&self) -> DateTime<Utc> {
+        chrono::Duration::from_std(self.offset())
+            .ok()
+            .and_then(|offset| self.inner.now_utc().checked_add_signed(offset))
+            .expect("virtual clock offset out of range")
+    }
+}
+
+/// Request body for moving the simulation clock. Set exactly one field.
+/// Virtual time can run at most ten years ahead of real time.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateClockRequest {
+    /// Advance virtual time by this many seconds.
+    pub advance_seconds: Option<u64>,
+    /// Jump virtual time to this moment (ISO 8601 datetime). Must not be in the past.
+    pub time: Option<String>,
+}
+
+/// Current state of the simulation clock.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VirtualClockResponse {
+    /// Current virtual Unix timestamp in seconds (UTC)
+    pub now: i64,
+    /// Seconds virtual time is ahead of real time
+    pub offset_seconds: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClock {
+        instant: Instant,
+        utc: DateTime<Utc>,
+    }
+
+    impl TimeSource for FixedClock {
+        fn now(&self) -> Instant {
+            self.instant
+        }
+
+        fn now_utc(&self) -> DateTime<Utc> {
+            self.utc
+        }
+    }
+
+    #[test]
+    fn test_virtual_clock_moves_forward_only() {
+        let utc = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let base = Arc::new(FixedClock {
+            instant: Instant::now(),
+            utc,
+        });
+        let clock = VirtualClock::new(base.clone());
+
+        clock.advance(Duration::from_secs(3 * 3600)).unwrap();
+        assert_eq!(clock.now() - base.instant, Duration::from_secs(3 * 3600));
+        assert_eq!(clock.now_utc().timestamp(), utc.timestamp() + 3 * 3600);
+
+        assert!(clock.set(utc).is_err());
+        clock.set(utc + chrono::Duration::hours(4)).unwrap();
+        assert_eq!(clock.offset(), Duration::from_secs(4 * 3600));
+    }
+
+    #[test]
+    fn test_virtual_clock_rejects_huge_advances() {
+        let clock = VirtualClock::new(Arc::new(SystemClock));
+        assert!(clock.advance(Duration::from_secs(u64::MAX)).is_err());
+        assert!(clock.advance(MAX_OFFSET + Duration::from_secs(1)).is_err());
+        assert_eq!(clock.offset(), Duration::ZERO);
+
+        clock.advance(MAX_OFFSET).unwrap();
+        assert!(clock.advance(Duration::from_secs(1)).is_err());
+        assert!(clock.now_utc() - Utc::now() > chrono::Duration::days(3649));
+    }
+}
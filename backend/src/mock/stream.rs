@@ -0,0 +1,133 @@
+//! Server-sent event stream of a mock game as it is simulated.
+//!
+//! The simulation only advances when a game is fetched, so the stream polls
+//! the repository on an interval and emits whatever happened in between.
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::response::sse::Event;
+use futures_util::stream::{self, Stream};
+use serde::Serialize;
+
+use crate::football::types::{FootballGameResponse, FootballPeriod, PlayType, Possession};
+
+use super::simulation::{format_clock, GameRepository, SimulatedGame, SimulatedPlay};
+
+/// How often the stream re-fetches the game (real time).
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Payload of a `play` event.
+#[derive(Debug, Serialize)]
+pub struct PlayEvent {
+    /// Position of the play in the game (0-based), counting evicted plays
+    pub sequence: usize,
+    pub period: FootballPeriod,
+    /// Game clock at the snap
+    pub clock: String,
+    pub possession: Possession,
+    pub play_type: PlayType,
+    pub yards_gained: i8,
+    pub text: String,
+}
+
+impl PlayEvent {
+    fn new(sequence: usize, play: &SimulatedPlay) -> Self {
+        Self {
+            sequence,
+            period: play.period,
+            clock: format_clock(play.clock_seconds),
+            possession: play.possession,
+            play_type: play.play_type,
+            yards_gained: play.yards_gained,
+            text: play.description.clone(),
+        }
+    }
+}
+
+struct StreamState {
+    repository: GameRepository,
+    id: String,
+    /// Plays already sent, counting from the game's first play
+    sent: usize,
+    pending: VecDeque<Event>,
+    /// State name ("pregame", "live", "final") at the last poll
+    last_state: Option<&'static str>,
+    first_poll: bool,
+    finished: bool,
+}
+
+/// Stream one `play` event per simulated play, followed by a `game` event with
+/// the updated game state. The stream ends after the game goes final (or is deleted).
+pub fn game_events(
+    repository: GameRepository,
+    id: String,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    let state = StreamState {
+        repository,
+        id,
+        sent: 0,
+        pending: VecDeque::new(),
+        last_state: None,
+        first_poll: true,
+        finished: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok(event), state));
+            }
+            if state.finished {
+                return None;
+            }
+
+            if !state.first_poll {
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+            state.first_poll = false;
+
+            match state.repository.get(&state.id).await {
+                Some(game) => queue_updates(&mut state, &game),
+                None => state.finished = true,
+            }
+        }
+    })
+}
+
+fn queue_updates(state: &mut StreamState, game: &SimulatedGame) {
+    let response = game.to_game_response();
+    let state_name = match response {
+        FootballGameResponse::Pregame(_) => "pregame",
+        FootballGameResponse::Live(_) => "live",
+        FootballGameResponse::Final(_) => "final",
+    };
+
+    // Plays evicted before they were sent are skipped
+    let evicted = game.plays_evicted();
+    let history = game.play_history();
+    let total = evicted + history.len();
+    let changed = total > state.sent || state.last_state != Some(state_name);
+
+    for (index, play) in history.iter().enumerate() {
+        let sequence = evicted + index;
+        if sequence >= state.sent {
+            state.pending.push_back(json_event("play", &PlayEvent::new(sequence, play)));
+        }
+    }
+    state.sent = total;
+
+    if changed {
+        state.pending.push_back(json_event("game", &response));
+    }
+    state.last_state = Some(state_name);
+    state.finished = game.is_final();
+}
+
+fn json_event(name: &str, payload: &impl Serialize) -> Event {
+    Event::default()
+        .event(name)
+        .json_data(payload)
+        .unwrap_or_else(|_| Event::default().event(name))
+}
@@ -4,52 +4,188 @@ use crate::shared::types::Color;
 pub struct NflTeam {
     pub abbreviation: &'static str,
     pub color: Color,
+    /// Accent color, for two-tone team blocks
+    pub color_secondary: Color,
 }
 
-/// All 32 NFL teams with their primary colors
+/// All 32 NFL teams with their primary and secondary colors
 pub const NFL_TEAMS: &[NflTeam] = &[
     // AFC East
-    NflTeam { abbreviation: "BUF", color: Color { r: 0, g: 51, b: 141 } },
-    NflTeam { abbreviation: "MIA", color: Color { r: 0, g: 142, b: 151 } },
-    NflTeam { abbreviation: "NE", color: Color { r: 0, g: 34, b: 68 } },
-    NflTeam { abbreviation: "NYJ", color: Color { r: 18, g: 87, b: 64 } },
+    NflTeam {
+        abbreviation: "BUF",
+        color: Color { r: 0, g: 51, b: 141 },
+        color_secondary: Color { r: 198, g: 12, b: 48 },
+    },
+    NflTeam {
+        abbreviation: "MIA",
+        color: Color { r: 0, g: 142, b: 151 },
+        color_secondary: Color { r: 252, g: 76, b: 2 },
+    },
+    NflTeam {
+        abbreviation: "NE",
+        color: Color { r: 0, g: 34, b: 68 },
+        color_secondary: Color { r: 198, g: 12, b: 48 },
+    },
+    NflTeam {
+        abbreviation: "NYJ",
+        color: Color { r: 18, g: 87, b: 64 },
+        color_secondary: Color { r: 255, g: 255, b: 255 },
+    },
     // AFC North
-    NflTeam { abbreviation: "BAL", color: Color { r: 36, g: 23, b: 115 } },
-    NflTeam { abbreviation: "CIN", color: Color { r: 251, g: 79, b: 20 } },
-    NflTeam { abbreviation: "CLE", color: Color { r: 49, g: 29, b: 0 } },
-    NflTeam { abbreviation: "PIT", color: Color { r: 255, g: 182, b: 18 } },
+    NflTeam {
+        abbreviation: "BAL",
+        color: Color { r: 36, g: 23, b: 115 },
+        color_secondary: Color { r: 158, g: 124, b: 12 },
+    },
+    NflTeam {
+        abbreviation: "CIN",
+        color: Color { r: 251, g: 79, b: 20 },
+        color_secondary: Color { r: 0, g: 0, b: 0 },
+    },
+    NflTeam {
+        abbreviation: "CLE",
+        color: Color { r: 49, g: 29, b: 0 },
+        color_secondary: Color { r: 255, g: 60, b: 0 },
+    },
+    NflTeam {
+        abbreviation: "PIT",
+        color: Color { r: 255, g: 182, b: 18 },
+        color_secondary: Color { r: 16, g: 24, b: 32 },
+    },
     // AFC South
-    NflTeam { abbreviation: "HOU", color: Color { r: 3, g: 32, b: 47 } },
-    NflTeam { abbreviation: "IND", color: Color { r: 0, g: 44, b: 95 } },
-    NflTeam { abbreviation: "JAX", color: Color { r: 16, g: 24, b: 32 } },
-    NflTeam { abbreviation: "TEN", color: Color { r: 12, g: 35, b: 64 } },
+    NflTeam {
+        abbreviation: "HOU",
+        color: Color { r: 3, g: 32, b: 47 },
+        color_secondary: Color { r: 167, g: 25, b: 48 },
+    },
+    NflTeam {
+        abbreviation: "IND",
+        color: Color { r: 0, g: 44, b: 95 },
+        color_secondary: Color { r: 162, g: 170, b: 173 },
+    },
+    NflTeam {
+        abbreviation: "JAX",
+        color: Color { r: 16, g: 24, b: 32 },
+        color_secondary: Color { r: 0, g: 103, b: 120 },
+    },
+    NflTeam {
+        abbreviation: "TEN",
+        color: Color { r: 12, g: 35, b: 64 },
+        color_secondary: Color { r: 75, g: 146, b: 219 },
+    },
     // AFC West
-    NflTeam { abbreviation: "DEN", color: Color { r: 251, g: 79, b: 20 } },
-    NflTeam { abbreviation: "KC", color: Color { r: 227, g: 24, b: 55 } },
-    NflTeam { abbreviation: "LV", color: Color { r: 0, g: 0, b: 0 } },
-    NflTeam { abbreviation: "LAC", color: Color { r: 0, g: 128, b: 198 } },
+    NflTeam {
+        abbreviation: "DEN",
+        color: Color { r: 251, g: 79, b: 20 },
+        color_secondary: Color { r: 0, g: 34, b: 68 },
+    },
+    NflTeam {
+        abbreviation: "KC",
+        color: Color { r: 227, g: 24, b: 55 },
+        color_secondary: Color { r: 255, g: 184, b: 28 },
+    },
+    NflTeam {
+        abbreviation: "LV",
+        color: Color { r: 0, g: 0, b: 0 },
+        color_secondary: Color { r: 165, g: 172, b: 175 },
+    },
+    NflTeam {
+        abbreviation: "LAC",
+        color: Color { r: 0, g: 128, b: 198 },
+        color_secondary: Color { r: 255, g: 194, b: 14 },
+    },
     // NFC East
-    NflTeam { abbreviation: "DAL", color: Color { r: 0, g: 53, b: 148 } },
-    NflTeam { abbreviation: "NYG", color: Color { r: 1, g: 35, b: 82 } },
-    NflTeam { abbreviation: "PHI", color: Color { r: 0, g: 76, b: 84 } },
-    NflTeam { abbreviation: "WSH", color: Color { r: 90, g: 20, b: 20 } },
+    NflTeam {
+        abbreviation: "DAL",
+        color: Color { r: 0, g: 53, b: 148 },
+        color_secondary: Color { r: 134, g: 147, b: 151 },
+    },
+    NflTeam {
+        abbreviation: "NYG",
+        color: Color { r: 1, g: 35, b: 82 },
+        color_secondary: Color { r: 163, g: 13, b: 45 },
+    },
+    NflTeam {
+        abbreviation: "PHI",
+        color: Color { r: 0, g: 76, b: 84 },
+        color_secondary: Color { r: 165, g: 172, b: 175 },
+    },
+    NflTeam {
+        abbreviation: "WSH",
+        color: Color { r: 90, g: 20, b: 20 },
+        color_secondary: Color { r: 255, g: 182, b: 18 },
+    },
     // NFC North
-    NflTeam { abbreviation: "CHI", color: Color { r: 11, g: 22, b: 42 } },
-    NflTeam { abbreviation: "DET", color: Color { r: 0, g: 118, b: 182 } },
-    NflTeam { abbreviation: "GB", color: Color { r: 24, g: 48, b: 40 } },
-    NflTeam { abbreviation: "MIN", color: Color { r: 79, g: 38, b: 131 } },
+    NflTeam {
+        abbreviation: "CHI",
+        color: Color { r: 11, g: 22, b: 42 },
+        color_secondary: Color { r: 200, g: 56, b: 3 },
+    },
+    NflTeam {
+        abbreviation: "DET",
+        color: Color { r: 0, g: 118, b: 182 },
+        color_secondary: Color { r: 176, g: 183, b: 188 },
+    },
+    NflTeam {
+        abbreviation: "GB",
+        color: Color { r: 24, g: 48, b: 40 },
+        color_secondary: Color { r: 255, g: 184, b: 28 },
+    },
+    NflTeam {
+        abbreviation: "MIN",
+        color: Color { r: 79, g: 38, b: 131 },
+        color_secondary: Color { r: 255, g: 198, b: 47 },
+    },
     // NFC South
-    NflTeam { abbreviation: "ATL", color: Color { r: 167, g: 25, b: 48 } },
-    NflTeam { abbreviation: "CAR", color: Color { r: 0, g: 133, b: 202 } },
-    NflTeam { abbreviation: "NO", color: Color { r: 211, g: 188, b: 141 } },
-    NflTeam { abbreviation: "TB", color: Color { r: 213, g: 10, b: 10 } },
+    NflTeam {
+        abbreviation: "ATL",
+        color: Color { r: 167, g: 25, b: 48 },
+        color_secondary: Color { r: 0, g: 0, b: 0 },
+    },
+    NflTeam {
+        abbreviation: "CAR",
+        color: Color { r: 0, g: 133, b: 202 },
+        color_secondary: Color { r: 16, g: 24, b: 32 },
+    },
+    NflTeam {
+        abbreviation: "NO",
+        color: Color { r: 211, g: 188, b: 141 },
+        color_secondary: Color { r: 16, g: 24, b: 31 },
+    },
+    NflTeam {
+        abbreviation: "TB",
+        color: Color { r: 213, g: 10, b: 10 },
+        color_secondary: Color { r: 52, g: 48, b: 43 },
+    },
     // NFC West
-    NflTeam { abbreviation: "ARI", color: Color { r: 151, g: 35, b: 63 } },
-    NflTeam { abbreviation: "LAR", color: Color { r: 0, g: 53, b: 148 } },
-    NflTeam { abbreviation: "SF", color: Color { r: 170, g: 0, b: 0 } },
-    NflTeam { abbreviation: "SEA", color: Color { r: 0, g: 34, b: 68 } },
+    NflTeam {
+        abbreviation: "ARI",
+        color: Color { r: 151, g: 35, b: 63 },
+        color_secondary: Color { r: 255, g: 182, b: 18 },
+    },
+    NflTeam {
+        abbreviation: "LAR",
+        color: Color { r: 0, g: 53, b: 148 },
+        color_secondary: Color { r: 255, g: 163, b: 0 },
+    },
+    NflTeam {
+        abbreviation: "SF",
+        color: Color { r: 170, g: 0, b: 0 },
+        color_secondary: Color { r: 173, g: 153, b: 93 },
+    },
+    NflTeam {
+        abbreviation: "SEA",
+        color: Color { r: 0, g: 34, b: 68 },
+        color_secondary: Color { r: 105, g: 190, b: 40 },
+    },
 ];
 
+/// Find a team by abbreviation (case-insensitive).
+pub fn find_team(abbr: &str) -> Option<&'static NflTeam> {
+    let abbr_upper = abbr.to_uppercase();
+    NFL_TEAMS.iter().find(|t| t.abbreviation == abbr_upper)
+}
+
 /// Get a random pair of different teams for a matchup
 pub fn get_matchup(rng: &mut impl rand::Rng) -> (&'static NflTeam, &'static NflTeam) {
     use rand::seq::SliceRandom;
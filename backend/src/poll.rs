@@ -0,0 +1,213 @@
+//! Background polling of ESPN scoreboards, so requests are answered from the
+//! scoreboard cache instead of each waiting on ESPN. Enabled by setting
+//! `polling.interval_secs`.
+//!
+//! Each scoreboard is fetched as often as its games need: every
+//! `interval_secs` while one is live, every `pregame_secs` (and at kickoff)
+//! while the next is still to start, and every `idle_secs` once they're all
+//! final, just to pick up the next slate. Each polled scoreboard is cached
+//! until two intervals past its next poll, so requests between slow polls
+//! are still answered from the cache.
+//!
+//! With several instances sharing storage, only the one holding the poller
+//! lease polls; the rest read what it caches. The lease lapses after three
+//! missed intervals, and the next instance to try takes over. If the poller
+//! stops, requests fetch from ESPN themselves once the cached scoreboards
+//! expire, until another instance takes over.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::Instant;
+
+use crate::config::PollingConfig;
+use crate::espn::types::{EspnEvent, EspnScoreboard};
+use crate::shared::transform::parse_espn_date;
+use crate::sport::League;
+use crate::AppState;
+
+/// Storage key for the poller lease
+const LEASE_KEY: &str = "poller";
+
+/// Intervals the poller lease outlasts its last renewal
+const LEASE_INTERVALS: u32 = 3;
+
+/// How often a scoreboard is fetched, by the state of its games
+#[derive(Debug, Clone, Copy)]
+pub struct Cadence {
+    pub live: Duration,
+    pub pregame: Duration,
+    pub idle: Duration,
+}
+
+impl Cadence {
+    pub fn new(config: &PollingConfig) -> Self {
+        Self {
+            live: Duration::from_secs(config.interval_secs),
+            pregame: Duration::from_secs(config.pregame_secs),
+            idle: Duration::from_secs(config.idle_secs),
+        }
+    }
+
+    /// Time until `event` needs a fresh scoreboard, as of `now` (Unix
+    /// seconds): `live` once it may have kicked off, `pregame` before that
+    /// but no later than kickoff, and never once it's final.
+    fn game(&self, event: &EspnEvent, now: i64) -> Option<Duration> {
+        match event.status.status_type.state.as_str() {
+            "post" => None,
+            "in" => Some(self.live),
+            _ => match u64::try_from(parse_espn_date(&event.date) - now) {
+                Ok(until_kickoff) if until_kickoff > 0 => {
+                    Some(self.pregame.min(Duration::from_secs(until_kickoff)))
+                }
+                _ => Some(self.live),
+            },
+        }
+    }
+
+    /// Time until the scoreboard is fetched again: when its soonest game
+    /// needs it, or `idle` if none does sooner.
+    fn scoreboard(&self, scoreboard: &EspnScoreboard, now: i64) -> Duration {
+        scoreboard
+            .events
+            .iter()
+            .filter_map(|event| self.game(event, now))
+            .fold(self.idle, Duration::min)
+    }
+
+    /// How long to cache a polled scoreboard: until its next poll, plus two
+    /// intervals for a late tick and a failed poll.
+    fn keep(&self, scoreboard: &EspnScoreboard, now: i64) -> Duration {
+        self.scoreboard(scoreboard, now) + self.live * 2
+    }
+}
+
+/// Poll each of `leagues` at its `cadence` while this instance holds the
+/// poller lease, until shutdown.
+pub async fn poll(state: Arc<AppState>, leagues: Vec<League>, cadence: Cadence) {
+    let holder = hex::encode(rand::random::<[u8; 8]>());
+    let mut polling = false;
+    // When each league's scoreboard is next due; leagues not in here are due
+    let mut due: HashMap<League, Instant> = HashMap::new();
+    let mut interval = tokio::time::interval(cadence.live);
+    loop {
+        interval.tick().await;
+        let leased = state
+            .storage
+            .lease(LEASE_KEY, &holder, cadence.live * LEASE_INTERVALS)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Couldn't renew poller lease");
+                false
+            });
+        if leased != polling {
+            polling = leased;
+            if polling {
+                tracing::info!(interval_secs = cadence.live.as_secs(), "Polling ESPN scoreboards");
+                // Whatever was scheduled is stale after a stint without the lease
+                due.clear();
+            } else if state.storage.is_shared() {
+                tracing::info!("Another instance is polling ESPN scoreboards");
+            }
+        }
+        if !polling {
+            continue;
+        }
+        for &league in &leagues {
+            let now = Instant::now();
+            if due.get(&league).is_some_and(|&at| at > now) {
+                continue;
+            }
+            let at = Utc::now().timestamp();
+            let keep = |scoreboard: &EspnScoreboard| cadence.keep(scoreboard, at);
+            match state.espn_client.refresh_scoreboard(league, None, keep).await {
+                Ok(scoreboard) => {
+                    let next = cadence.scoreboard(&scoreboard, at);
+                    tracing::debug!(league = league.name(), next_secs = next.as_secs(), "Polled scoreboard");
+                    due.insert(league, now + next);
+                }
+                // Try again next interval
+                Err(e) => {
+                    tracing::warn!(league = league.name(), error = ?e, "Couldn't poll scoreboard");
+                    due.remove(&league);
+                }
+            }
+        }
+    }
+}
+
+/// How long to cache scoreboards: `ttl_secs`, but while polling, at least
+/// long enough to bridge a missed poll.
+pub fn scoreboard_ttl(ttl_secs: u64, interval_secs: u64) -> Duration {
+    Duration::from_secs(ttl_secs.max(interval_secs * 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_792_342_800; // 2026-10-18T17:00:00Z
+
+    const CADENCE: Cadence = Cadence {
+        live: Duration::from_secs(10),
+        pregame: Duration::from_secs(60),
+        idle: Duration::from_secs(600),
+    };
+
+    fn event(state: &str, date: &str) -> EspnEvent {
+        serde_json::from_value(serde_json::json!({
+            "id": "401",
+            "date": date,
+            "status": {
+                "period": 0,
+                "displayClock": "0:00",
+                "type": { "id": "1", "state": state, "shortDetail": "" }
+            },
+            "competitions": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_polling_caches_scoreboards_past_the_next_poll() {
+        assert_eq!(scoreboard_ttl(0, 0), Duration::ZERO);
+        assert_eq!(scoreboard_ttl(5, 0), Duration::from_secs(5));
+        assert_eq!(scoreboard_ttl(0, 10), Duration::from_secs(20));
+        assert_eq!(scoreboard_ttl(60, 10), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_games_are_polled_by_state() {
+        let secs = |s| Some(Duration::from_secs(s));
+        assert_eq!(CADENCE.game(&event("in", "2026-10-18T16:00:00Z"), NOW), secs(10));
+        assert_eq!(CADENCE.game(&event("post", "2026-10-18T13:00:00Z"), NOW), None);
+        // Hours out, then down to kickoff, then late starting
+        assert_eq!(CADENCE.game(&event("pre", "2026-10-18T20:25:00Z"), NOW), secs(60));
+        assert_eq!(CADENCE.game(&event("pre", "2026-10-18T17:00:30Z"), NOW), secs(30));
+        assert_eq!(CADENCE.game(&event("pre", "2026-10-18T16:59:00Z"), NOW), secs(10));
+    }
+
+    #[test]
+    fn test_scoreboard_is_polled_for_its_soonest_game() {
+        let scoreboard = |events| EspnScoreboard {
+            events,
+            skipped: Vec::new(),
+        };
+        let finals = scoreboard(vec![event("post", "2026-10-18T13:00:00Z")]);
+        assert_eq!(CADENCE.scoreboard(&finals, NOW), Duration::from_secs(600));
+        assert_eq!(CADENCE.scoreboard(&scoreboard(Vec::new()), NOW), Duration::from_secs(600));
+
+        let slate = scoreboard(vec![
+            event("post", "2026-10-18T13:00:00Z"),
+            event("pre", "2026-10-18T20:25:00Z"),
+            event("in", "2026-10-18T16:25:00Z"),
+        ]);
+        assert_eq!(CADENCE.scoreboard(&slate, NOW), Duration::from_secs(10));
+
+        // Cached past the next poll, however far off it is
+        assert_eq!(CADENCE.keep(&slate, NOW), Duration::from_secs(30));
+        assert_eq!(CADENCE.keep(&finals, NOW), Duration::from_secs(620));
+    }
+}
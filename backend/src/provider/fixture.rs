@@ -0,0 +1,185 @@
+//! Recorded ESPN responses served from a directory, for offline development
+//! and reproducible bug reports. Enabled by setting `fixtures.dir` (or
+//! `--fixtures DIR`).
+//!
+//! Files are laid out by league, under our API path for it:
+//!
+//! ```text
+//! fixtures/
+//!   football/nfl/scoreboard.json         a scoreboard, served as-is
+//!   football/nfl/scoreboard/001.json     or a sequence, in name order
+//!   football/nfl/scoreboard/002.json
+//!   football/nfl/summary/401547417.json  a game's summary
+//!   football/nfl/logos/kc.png            a team's logo, for every variant
+//! ```
+//!
+//! A sequence moves to its next file every `fixtures.step_secs` after the
+//! server starts and stays on its last, so a recorded game plays out and
+//! ends final. Files are read on each request, so they can be edited while
+//! the server runs. A league with no scoreboard recorded has no games.
+//!
+//! Record a scoreboard with, e.g.:
+//!
+//! ```text
+//! curl -o fixtures/football/nfl/scoreboard.json \
+//!   https://site.api.espn.com/apis/site/v2/sports/football/nfl/scoreboard
+//! ```
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::{SportsProvider, parse};
+use crate::error::AppError;
+use crate::espn::client::parse_scoreboard;
+use crate::espn::types::{EspnFootballSummary, EspnScoreboard, EspnSummary, RawScoreboard};
+use crate::shared::types::SeasonType;
+use crate::sport::League;
+use crate::team::types::LogoVariant;
+
+pub struct FixtureProvider {
+    dir: PathBuf,
+    /// Time on each file of a scoreboard sequence; zero stays on the first
+    step: Duration,
+    started: Instant,
+}
+
+impl FixtureProvider {
+    pub fn new(dir: impl Into<PathBuf>, step: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            step,
+            started: Instant::now(),
+        }
+    }
+
+    fn league_dir(&self, league: League) -> PathBuf {
+        self.dir.join(league.api_path())
+    }
+
+    /// The file for a game or team ID under `kind` (e.g. "summary"). IDs come
+    /// from request paths, so anything but letters and digits finds nothing.
+    async fn read_id(&self, league: League, kind: &str, id: &str, extension: &str) -> Option<Vec<u8>> {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        let file = format!("{}.{extension}", id.to_lowercase());
+        read(&self.league_dir(league).join(kind).join(file)).await
+    }
+
+    /// A game's recorded summary.
+    async fn summary(&self, league: League, event_id: &str) -> Result<Vec<u8>, AppError> {
+        self.read_id(league, "summary", event_id, "json")
+            .await
+            .ok_or_else(|| AppError::GameNotFound(event_id.to_string()))
+    }
+
+    /// The scoreboard file to serve now, if the league has one.
+    async fn scoreboard_path(&self, league: League) -> Option<PathBuf> {
+        let single = self.league_dir(league).join("scoreboard.json");
+        if tokio::fs::try_exists(&single).await.unwrap_or(false) {
+            return Some(single);
+        }
+        let sequence = sequence(&self.league_dir(league).join("scoreboard")).await;
+        let index = step_index(self.started.elapsed(), self.step, sequence.len())?;
+        sequence.into_iter().nth(index)
+    }
+}
+
+/// The JSON files in `dir`, in name order.
+async fn sequence(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return files;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Which of `len` files in a sequence to serve after `elapsed`.
+fn step_index(elapsed: Duration, step: Duration, len: usize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    if step.is_zero() {
+        return Some(0);
+    }
+    let steps = (elapsed.as_secs_f64() / step.as_secs_f64()) as usize;
+    Some(steps.min(last))
+}
+
+/// A fixture's contents, or `None` if it isn't there.
+async fn read(path: &Path) -> Option<Vec<u8>> {
+    match tokio::fs::read(path).await {
+        Ok(body) => Some(body),
+        Err(e) => {
+            if e.kind() != ErrorKind::NotFound {
+                tracing::warn!(path = %path.display(), error = %e, "Couldn't read fixture");
+            }
+            None
+        }
+    }
+}
+
+#[async_trait]
+impl SportsProvider for FixtureProvider {
+    /// The recorded scoreboard, whatever `season_type` asks for.
+    async fn fetch_scoreboard(
+        &self,
+        league: League,
+        _season_type: Option<SeasonType>,
+    ) -> Result<EspnScoreboard, AppError> {
+        let Some(path) = self.scoreboard_path(league).await else {
+            return Ok(EspnScoreboard::default());
+        };
+        let Some(body) = read(&path).await else {
+            return Ok(EspnScoreboard::default());
+        };
+        parse_scoreboard(parse::<RawScoreboard>(&body)?, || {})
+    }
+
+    async fn fetch_summary(&self, league: League, event_id: &str) -> Result<EspnSummary, AppError> {
+        parse(&self.summary(league, event_id).await?)
+    }
+
+    async fn fetch_football_summary(
+        &self,
+        league: League,
+        event_id: &str,
+    ) -> Result<EspnFootballSummary, AppError> {
+        parse(&self.summary(league, event_id).await?)
+    }
+
+    async fn fetch_logo(
+        &self,
+        league: League,
+        team_id: &str,
+        _variant: LogoVariant,
+    ) -> Result<Bytes, AppError> {
+        self.read_id(league, "logos", team_id, "png")
+            .await
+            .map(Bytes::from)
+            .ok_or_else(|| AppError::TeamNotFound(team_id.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_advances_and_stays_on_last() {
+        let step = Duration::from_secs(30);
+        assert_eq!(step_index(Duration::ZERO, step, 3), Some(0));
+        assert_eq!(step_index(Duration::from_secs(45), step, 3), Some(1));
+        assert_eq!(step_index(Duration::from_secs(3600), step, 3), Some(2));
+        assert_eq!(step_index(Duration::from_secs(3600), Duration::ZERO, 3), Some(0));
+        assert_eq!(step_index(Duration::ZERO, step, 0), None);
+    }
+}
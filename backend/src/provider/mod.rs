@@ -0,0 +1,183 @@
+//! Where game data comes from. Handlers read through `AppState::provider`
+//! rather than calling `EspnClient` directly, so another source (recorded
+//! fixtures, a test double) can stand in for ESPN without touching them.
+//!
+//! Providers speak ESPN's response types, since that's what the transforms
+//! take; a provider for another source converts into them.
+
+pub mod fixture;
+pub mod replay;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+
+use crate::error::AppError;
+use crate::espn::EspnClient;
+use crate::espn::types::{EspnEvent, EspnFootballSummary, EspnScoreboard, EspnSummary};
+use crate::shared::types::SeasonType;
+use crate::sport::League;
+use crate::team::types::LogoVariant;
+
+/// Parse a recorded ESPN response, reporting where it didn't match.
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, AppError> {
+    let jd = &mut serde_json::Deserializer::from_slice(body);
+    serde_path_to_error::deserialize(jd).map_err(|err| AppError::EspnDeserialize {
+        path: err.path().to_string(),
+        message: err.inner().to_string(),
+    })
+}
+
+/// A source of scoreboards, games, and team logos.
+#[async_trait]
+pub trait SportsProvider: Send + Sync {
+    /// A league's scoreboard. Without a `season_type`, the part of the
+    /// season in progress.
+    async fn fetch_scoreboard(
+        &self,
+        league: League,
+        season_type: Option<SeasonType>,
+    ) -> Result<EspnScoreboard, AppError>;
+
+    /// A game's summary (basketball single-game detail).
+    async fn fetch_summary(&self, league: League, event_id: &str) -> Result<EspnSummary, AppError>;
+
+    /// A football game's summary, with its play-by-play.
+    async fn fetch_football_summary(
+        &self,
+        league: League,
+        event_id: &str,
+    ) -> Result<EspnFootballSummary, AppError>;
+
+    /// A team's logo as PNG bytes.
+    async fn fetch_logo(
+        &self,
+        league: League,
+        team_id: &str,
+        variant: LogoVariant,
+    ) -> Result<Bytes, AppError>;
+
+    /// A single game from the league's current scoreboard.
+    async fn fetch_game(&self, league: League, event_id: &str) -> Result<EspnEvent, AppError> {
+        let scoreboard = self.fetch_scoreboard(league, None).await?;
+
+        if let Some(event) = scoreboard.events.into_iter().find(|event| event.id == event_id) {
+            return Ok(event);
+        }
+        // Report why the game was skipped rather than that it's missing
+        match scoreboard
+            .skipped
+            .into_iter()
+            .find(|skipped| skipped.id.as_deref() == Some(event_id))
+        {
+            Some(skipped) => Err(skipped.error),
+            None => Err(AppError::GameNotFound(event_id.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl SportsProvider for EspnClient {
+    async fn fetch_scoreboard(
+        &self,
+        league: League,
+        season_type: Option<SeasonType>,
+    ) -> Result<EspnScoreboard, AppError> {
+        EspnClient::fetch_scoreboard(self, league, season_type).await
+    }
+
+    async fn fetch_summary(&self, league: League, event_id: &str) -> Result<EspnSummary, AppError> {
+        self.fetch_game_summary(league, event_id).await
+    }
+
+    async fn fetch_football_summary(
+        &self,
+        league: League,
+        event_id: &str,
+    ) -> Result<EspnFootballSummary, AppError> {
+        EspnClient::fetch_football_summary(self, league, event_id).await
+    }
+
+    async fn fetch_logo(
+        &self,
+        league: League,
+        team_id: &str,
+        variant: LogoVariant,
+    ) -> Result<Bytes, AppError> {
+        EspnClient::fetch_logo(self, league, team_id, variant).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::espn::types::SkippedEvent;
+    use crate::sport::FootballLeague;
+
+    /// Serves one fixed scoreboard
+    struct Fixed(fn() -> EspnScoreboard);
+
+    #[async_trait]
+    impl SportsProvider for Fixed {
+        async fn fetch_scoreboard(
+            &self,
+            _league: League,
+            _season_type: Option<SeasonType>,
+        ) -> Result<EspnScoreboard, AppError> {
+            Ok((self.0)())
+        }
+
+        async fn fetch_summary(&self, _: League, event_id: &str) -> Result<EspnSummary, AppError> {
+            Err(AppError::GameNotFound(event_id.to_string()))
+        }
+
+        async fn fetch_football_summary(
+            &self,
+            _: League,
+            event_id: &str,
+        ) -> Result<EspnFootballSummary, AppError> {
+            Err(AppError::GameNotFound(event_id.to_string()))
+        }
+
+        async fn fetch_logo(&self, _: League, team_id: &str, _: LogoVariant) -> Result<Bytes, AppError> {
+            Err(AppError::TeamNotFound(team_id.to_string()))
+        }
+    }
+
+    fn scoreboard() -> EspnScoreboard {
+        let event = serde_json::from_value(serde_json::json!({
+            "id": "401",
+            "date": "2026-10-18T17:00Z",
+            "status": {
+                "period": 0,
+                "displayClock": "0:00",
+                "type": { "id": "1", "state": "pre", "shortDetail": "10/18 - 1:00 PM EDT" }
+            },
+            "competitions": []
+        }))
+        .unwrap();
+        EspnScoreboard {
+            events: vec![event],
+            skipped: vec![SkippedEvent {
+                id: Some("402".to_string()),
+                error: AppError::MissingCompetition("402".to_string()),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_game_finds_event_or_explains_why_not() {
+        let provider: &dyn SportsProvider = &Fixed(scoreboard);
+        let nfl = League::Football(FootballLeague::Nfl);
+
+        assert_eq!(provider.fetch_game(nfl, "401").await.unwrap().id, "401");
+        assert!(matches!(
+            provider.fetch_game(nfl, "402").await,
+            Err(AppError::MissingCompetition(_))
+        ));
+        assert!(matches!(
+            provider.fetch_game(nfl, "403").await,
+            Err(AppError::GameNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+//! Replays an ESPN recording (see `espn::record`) in place of ESPN, for
+//! reproducing what a display showed during a real game. Enabled by setting
+//! `replay.file` (or `--replay FILE`).
+//!
+//! Replay time starts at the recording's first response when the server
+//! starts, running `replay.speed` times as fast as it was recorded. Each
+//! request gets the latest response recorded for its URL by then (the first,
+//! before there was one), so a game plays out as ESPN served it and stays
+//! on its last response once the recording ends. Logos weren't recorded, so
+//! they still come from ESPN.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use super::{SportsProvider, parse};
+use crate::error::AppError;
+use crate::espn::EspnClient;
+use crate::espn::client::{parse_scoreboard, scoreboard_path, summary_path};
+use crate::espn::record::Recorded;
+use crate::espn::types::{EspnFootballSummary, EspnScoreboard, EspnSummary, RawScoreboard};
+use crate::shared::types::SeasonType;
+use crate::sport::League;
+use crate::team::types::LogoVariant;
+
+/// Responses for one URL as (Unix milliseconds, body), oldest first
+type Timeline = Vec<(i64, Arc<str>)>;
+
+pub struct ReplayProvider {
+    timelines: HashMap<String, Timeline>,
+    /// When the recording's first response arrived, in Unix milliseconds
+    start: i64,
+    speed: f64,
+    started: Instant,
+    logos: EspnClient,
+}
+
+impl ReplayProvider {
+    /// Read a recording to replay at `speed` times recorded speed, fetching
+    /// logos with `logos`.
+    pub fn load(path: &Path, speed: f64, logos: EspnClient) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let mut timelines: HashMap<String, Timeline> = HashMap::new();
+        let mut skipped = 0;
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<Recorded>(line) {
+                Ok(recorded) => timelines
+                    .entry(recorded.path)
+                    .or_default()
+                    .push((recorded.at, recorded.body.into())),
+                Err(_) => skipped += 1,
+            }
+        }
+        if skipped > 0 {
+            tracing::warn!(path = %path.display(), skipped, "Skipped unreadable lines in recording");
+        }
+        for timeline in timelines.values_mut() {
+            timeline.sort_by_key(|(at, _)| *at);
+        }
+        let start = timelines.values().filter_map(|t| t.first()).map(|(at, _)| *at).min();
+
+        let speed = if speed > 0.0 {
+            speed
+        } else {
+            tracing::warn!(speed, "Replay speed must be positive; replaying at 1x");
+            1.0
+        };
+        Ok(Self {
+            timelines,
+            start: start.unwrap_or_default(),
+            speed,
+            started: Instant::now(),
+            logos,
+        })
+    }
+
+    /// Replay time, in Unix milliseconds of the recording.
+    fn now(&self) -> i64 {
+        self.start + (self.started.elapsed().as_millis() as f64 * self.speed) as i64
+    }
+
+    /// The response to serve now for a URL, if any was recorded.
+    fn response(&self, path: &str) -> Option<&Arc<str>> {
+        latest(self.timelines.get(path)?, self.now())
+    }
+}
+
+/// The latest response by `at`, or the first if `at` is before all of them.
+fn latest(timeline: &Timeline, at: i64) -> Option<&Arc<str>> {
+    let after = timeline.partition_point(|(recorded, _)| *recorded <= at);
+    timeline.get(after.saturating_sub(1)).map(|(_, body)| body)
+}
+
+#[async_trait]
+impl SportsProvider for ReplayProvider {
+    /// The recorded scoreboard for `season_type`, or the league's default
+    /// scoreboard if only that was recorded. No games if neither was.
+    async fn fetch_scoreboard(
+        &self,
+        league: League,
+        season_type: Option<SeasonType>,
+    ) -> Result<EspnScoreboard, AppError> {
+        let body = self
+            .response(&scoreboard_path(&league, season_type))
+            .or_else(|| self.response(&scoreboard_path(&league, None)));
+        match body {
+            Some(body) => parse_scoreboard(parse::<RawScoreboard>(body.as_bytes())?, || {}),
+            None => Ok(EspnScoreboard::default()),
+        }
+    }
+
+    async fn fetch_summary(&self, league: League, event_id: &str) -> Result<EspnSummary, AppError> {
+        let body = self
+            .response(&summary_path(&league, event_id))
+            .ok_or_else(|| AppError::GameNotFound(event_id.to_string()))?;
+        parse(body.as_bytes())
+    }
+
+    async fn fetch_football_summary(
+        &self,
+        league: League,
+        event_id: &str,
+    ) -> Result<EspnFootballSummary, AppError> {
+        let body = self
+            .response(&summary_path(&league, event_id))
+            .ok_or_else(|| AppError::GameNotFound(event_id.to_string()))?;
+        parse(body.as_bytes())
+    }
+
+    async fn fetch_logo(
+        &self,
+        league: League,
+        team_id: &str,
+        variant: LogoVariant,
+    ) -> Result<Bytes, AppError> {
+        self.logos.fetch_logo(league, team_id, variant).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_response_by_replay_time() {
+        let timeline: Timeline = vec![(1_000, "first".into()), (2_000, "second".into())];
+        assert_eq!(latest(&timeline, 500).map(|b| &**b), Some("first"));
+        assert_eq!(latest(&timeline, 1_999).map(|b| &**b), Some("first"));
+        assert_eq!(latest(&timeline, 2_000).map(|b| &**b), Some("second"));
+        assert_eq!(latest(&timeline, 9_000).map(|b| &**b), Some("second"));
+        assert_eq!(latest(&Vec::new(), 9_000), None);
+    }
+}
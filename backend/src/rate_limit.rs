@@ -0,0 +1,203 @@
+//! Per-client token-bucket rate limiting for `/api/*`.
+//!
+//! Requests are counted against the key their `X-Api-Key` authenticates as, or
+//! the client IP when there is none (signed URLs, auth disabled, or a key that
+//! doesn't authenticate, so made-up keys can't each get a fresh bucket). A device
+//! polling in a tight loop gets 429s instead of hammering the backend and ESPN.
+//!
+//! The client IP is the connecting address, or with `server.trust_proxy_headers`
+//! the one the proxy forwarded.
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, Request, State},
+    http::{Extensions, HeaderMap},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use lru::LruCache;
+use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::AppState;
+use crate::auth::{keys_equal, match_key};
+use crate::clock::client_ip;
+use crate::config::RateLimitConfig;
+use crate::error::AppError;
+
+/// Most clients tracked at once; the least recently seen are forgotten first.
+const MAX_CLIENTS: usize = 4096;
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+pub struct RateLimiter {
+    buckets: Mutex<LruCache<String, Bucket>>,
+    /// Tokens added per second
+    rate: f64,
+    /// Bucket capacity
+    burst: f64,
+}
+
+impl RateLimiter {
+    /// A limiter for the configured rate, or `None` when limiting is disabled.
+    pub fn new(config: &RateLimitConfig) -> Option<Self> {
+        if config.requests_per_minute == 0 {
+            return None;
+        }
+        Some(Self {
+            buckets: Mutex::new(LruCache::new(NonZeroUsize::new(MAX_CLIENTS).unwrap())),
+            rate: config.requests_per_minute as f64 / 60.0,
+            burst: config.burst.max(1) as f64,
+        })
+    }
+
+    /// Take a token for `client`, or return how long until one is available.
+    fn check(&self, client: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.get_or_insert_mut(client.to_string(), || Bucket {
+            tokens: self.burst,
+            updated: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.burst);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+}
+
+/// Who a request counts against: the label of the key it authenticates with,
+/// otherwise its IP (`peer`, unless forwarding headers are trusted). The key
+/// lookup is left in `extensions` for authentication to reuse.
+fn client_id(
+    state: &AppState,
+    headers: &HeaderMap,
+    extensions: &mut Extensions,
+    peer: Option<IpAddr>,
+) -> String {
+    let label = headers
+        .get("x-api-key")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|key| match state.config.api_key.as_deref() {
+            Some(api_key) if keys_equal(api_key, key) => Some("api_key".to_string()),
+            _ => match_key(extensions, &state.api_keys, key).map(|(label, _)| label),
+        });
+    match (label, request_ip(headers, peer, state.config.server.trust_proxy_headers)) {
+        (Some(label), _) => format!("key:{}", label),
+        (None, Some(ip)) => format!("ip:{}", ip),
+        (None, None) => "anonymous".to_string(),
+    }
+}
+
+/// The client's IP: the connecting address, or the one the proxy forwarded if
+/// its headers are trusted.
+fn request_ip(headers: &HeaderMap, peer: Option<IpAddr>, trust_proxy_headers: bool) -> Option<IpAddr> {
+    if trust_proxy_headers {
+        client_ip(headers).or(peer)
+    } else {
+        peer
+    }
+}
+
+/// Middleware rejecting `/api/*` requests over the client's limit with 429.
+pub async fn rate_limit(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response<Body> {
+    let limiter = state.rate_limiter.read().unwrap().clone();
+    let Some(limiter) = limiter else {
+        return next.run(request).await;
+    };
+    if !request.uri().path().starts_with("/api/") {
+        return next.run(request).await;
+    }
+
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let (mut parts, body) = request.into_parts();
+    let client = client_id(&state, &parts.headers, &mut parts.extensions, peer);
+    let request = Request::from_parts(parts, body);
+    match limiter.check(&client, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            let retry_after_secs = wait.as_secs_f64().ceil().max(1.0) as u64;
+            tracing::debug!(client = %client, retry_after_secs, "Rate limited");
+            AppError::RateLimited { retry_after_secs }.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_minute: u32, burst: u32) -> RateLimiter {
+        RateLimiter::new(&RateLimitConfig {
+            requests_per_minute,
+            burst,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_burst_then_refill() {
+        let limiter = limiter(60, 3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.check("pico", start).is_ok());
+        }
+        let wait = limiter.check("pico", start).unwrap_err();
+        assert_eq!(wait.as_secs_f64().round(), 1.0);
+
+        // One token a second at 60/min
+        assert!(limiter.check("pico", start + Duration::from_secs(1)).is_ok());
+        assert!(limiter.check("pico", start + Duration::from_secs(1)).is_err());
+    }
+
+    #[test]
+    fn test_clients_have_separate_buckets() {
+        let limiter = limiter(60, 1);
+        let now = Instant::now();
+        assert!(limiter.check("kitchen", now).is_ok());
+        assert!(limiter.check("kitchen", now).is_err());
+        assert!(limiter.check("garage", now).is_ok());
+    }
+
+    #[test]
+    fn test_forwarded_ips_only_count_when_trusted() {
+        let peer = Some(IpAddr::from([192, 168, 1, 20]));
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "203.0.113.7".parse().unwrap());
+
+        assert_eq!(request_ip(&headers, peer, false), peer);
+        assert_eq!(
+            request_ip(&headers, peer, true),
+            Some(IpAddr::from([203, 0, 113, 7]))
+        );
+        assert_eq!(request_ip(&HeaderMap::new(), peer, true), peer);
+    }
+
+    #[test]
+    fn test_zero_rate_disables() {
+        let config = RateLimitConfig {
+            requests_per_minute: 0,
+            burst: 10,
+        };
+        assert!(RateLimiter::new(&config).is_none());
+    }
+}
@@ -0,0 +1,61 @@
+//! Reloading settings without a restart, on SIGHUP or `POST /api/admin/config/reload`.
+//!
+//! Only the `LiveConfig` settings change: log level, rate limits, mock settings,
+//! and display profiles. Open connections, including game streams, stay up.
+
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::config::{AppConfig, LiveConfig};
+use crate::error::AppError;
+use crate::logging::log_filter;
+use crate::rate_limit::RateLimiter;
+
+/// Re-read the configuration and apply its live settings. Returns the names of
+/// the settings that changed. Nothing changes if the configuration is invalid.
+pub fn reload(state: &AppState) -> Result<Vec<&'static str>, AppError> {
+    let config =
+        AppConfig::try_load(&state.cli).map_err(|e| AppError::InvalidConfig(e.to_string()))?;
+    let next = LiveConfig::new(&config);
+    // Check the level before changing anything
+    log_filter(next.log_level.as_deref())?;
+
+    let mut live = state.live.write().unwrap();
+    let changed = live.changes(&next);
+    // A new configured level also ends any override from the admin API
+    if changed.contains(&"log_level") {
+        state.log_level.configure(next.log_level.as_deref())?;
+    }
+    // A new limiter starts every client with a full bucket
+    if changed.contains(&"rate_limit") {
+        *state.rate_limiter.write().unwrap() = RateLimiter::new(&next.rate_limit).map(Arc::new);
+    }
+    if changed.contains(&"mock") {
+        state
+            .game_repository
+            .set_max_play_history(next.mock.max_play_history);
+    }
+    *live = next;
+
+    tracing::info!(changed = ?changed, "Configuration reloaded");
+    Ok(changed)
+}
+
+/// Reload the configuration on every SIGHUP.
+#[cfg(unix)]
+pub async fn reload_on_hangup(state: Arc<AppState>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangups = match signal(SignalKind::hangup()) {
+        Ok(hangups) => hangups,
+        Err(e) => {
+            tracing::warn!(error = %e, "Can't listen for SIGHUP — reload via the admin API instead");
+            return;
+        }
+    };
+    while hangups.recv().await.is_some() {
+        if let Err(e) = reload(&state) {
+            tracing::error!(error = ?e, "Configuration reload failed — keeping current settings");
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Frame versions for conditional polling, and the changed region between two
+//! frames so a client only redraws the pixels that moved (usually score digits
+//! or the clock).
+
+use image::RgbaImage;
+use lru::LruCache;
+use sha2::{Digest, Sha256};
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// Recently served frames kept to diff against
+const CAPACITY: usize = 128;
+
+/// Frames recently sent to clients, by ETag.
+pub struct FrameCache {
+    frames: Mutex<LruCache<String, RgbaImage>>,
+}
+
+impl FrameCache {
+    pub fn new() -> Self {
+        Self {
+            frames: Mutex::new(LruCache::new(NonZeroUsize::new(CAPACITY).unwrap())),
+        }
+    }
+
+    pub fn get(&self, etag: &str) -> Option<RgbaImage> {
+        self.frames.lock().unwrap().get(etag).cloned()
+    }
+
+    pub fn put(&self, etag: String, frame: RgbaImage) {
+        self.frames.lock().unwrap().put(etag, frame);
+    }
+
+    /// Number of frames held to diff against.
+    pub fn frame_count(&self) -> usize {
+        self.frames.lock().unwrap().len()
+    }
+
+    /// Forget every frame, returning how many there were. Clients then get full
+    /// frames until they've polled again.
+    pub fn clear(&self) -> usize {
+        let mut frames = self.frames.lock().unwrap();
+        let len = frames.len();
+        frames.clear();
+        len
+    }
+}
+
+/// A changed region: offset and size in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirtyRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Strong ETag (quoted) for a frame's pixels in a given encoding. `encoding`
+/// distinguishes formats that would otherwise share pixels.
+pub fn frame_etag(frame: &RgbaImage, encoding: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(encoding.as_bytes());
+    hasher.update(frame.width().to_le_bytes());
+    hasher.update(frame.height().to_le_bytes());
+    hasher.update(frame.as_raw());
+    format!("\"{}\"", &hex::encode(hasher.finalize())[..16])
+}
+
+/// Smallest rectangle containing every pixel that differs between two frames,
+/// or `None` if they're identical. Frames must be the same size.
+pub fn dirty_rect(previous: &RgbaImage, next: &RgbaImage) -> Option<DirtyRect> {
+    let mut bounds: Option<(u32, u32, u32, u32)> = None;
+    for (x, y, pixel) in next.enumerate_pixels() {
+        if previous.get_pixel(x, y) == pixel {
+            continue;
+        }
+        let (left, top, right, bottom) = bounds.get_or_insert((x, y, x, y));
+        *left = (*left).min(x);
+        *top = (*top).min(y);
+        *right = (*right).max(x);
+        *bottom = (*bottom).max(y);
+    }
+    bounds.map(|(left, top, right, bottom)| DirtyRect {
+        x: left,
+        y: top,
+        width: right - left + 1,
+        height: bottom - top + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_dirty_rect_bounds_changes() {
+        let previous = RgbaImage::new(64, 32);
+        assert_eq!(dirty_rect(&previous, &previous), None);
+
+        let mut next = previous.clone();
+        next.put_pixel(50, 3, Rgba([255, 255, 255, 255]));
+        next.put_pixel(58, 9, Rgba([255, 255, 255, 255]));
+        assert_eq!(
+            dirty_rect(&previous, &next),
+            Some(DirtyRect {
+                x: 50,
+                y: 3,
+                width: 9,
+                height: 7
+            })
+        );
+    }
+
+    #[test]
+    fn test_frame_etag_depends_on_pixels_and_encoding() {
+        let frame = RgbaImage::new(8, 8);
+        let mut changed = frame.clone();
+        changed.put_pixel(0, 0, Rgba([1, 0, 0, 0]));
+
+        assert_eq!(frame_etag(&frame, "png"), frame_etag(&frame, "png"));
+        assert_ne!(frame_etag(&frame, "png"), frame_etag(&frame, "rgb565"));
+        assert_ne!(frame_etag(&frame, "png"), frame_etag(&changed, "png"));
+        assert_eq!(frame_etag(&frame, "png").len(), 18);
+    }
+}
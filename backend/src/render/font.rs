@@ -0,0 +1,295 @@
+//! Small bitmap fonts for drawing text onto images without font files.
+
+use image::{Rgba, RgbaImage};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Glyph width in font pixels.
+pub const GLYPH_WIDTH: u32 = 5;
+
+/// Glyph height in font pixels.
+pub const GLYPH_HEIGHT: u32 = 7;
+
+/// Embedded bitmap fonts
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+pub enum Font {
+    /// 3x5 pixel glyphs, for fitting more text on tiny panels
+    #[serde(rename = "3x5")]
+    Small,
+    /// 5x7 pixel glyphs
+    #[default]
+    #[serde(rename = "5x7")]
+    Regular,
+}
+
+impl Font {
+    /// Glyph width in font pixels.
+    pub fn glyph_width(&self) -> u32 {
+        match self {
+            Font::Small => 3,
+            Font::Regular => GLYPH_WIDTH,
+        }
+    }
+
+    /// Glyph height in font pixels.
+    pub fn glyph_height(&self) -> u32 {
+        match self {
+            Font::Small => 5,
+            Font::Regular => GLYPH_HEIGHT,
+        }
+    }
+
+    /// Rows of a glyph, top to bottom, with the leftmost pixel in bit
+    /// `glyph_width - 1`. Rows past `glyph_height` are zero.
+    pub fn rows(&self, c: char) -> [u8; 7] {
+        match self {
+            Font::Small => {
+                let mut rows = [0; 7];
+                rows[..5].copy_from_slice(&glyph_small(c));
+                rows
+            }
+            Font::Regular => glyph(c),
+        }
+    }
+
+    /// Leftmost inked column and inked width of a glyph, or `None` for blanks.
+    fn ink(&self, c: char) -> Option<(u32, u32)> {
+        let bits = self.rows(c).iter().fold(0, |acc, row| acc | row);
+        if bits == 0 {
+            return None;
+        }
+        let width = self.glyph_width();
+        let first = (0..width).find(|col| bits & (1 << (width - 1 - col)) != 0)?;
+        let last = (0..width)
+            .rev()
+            .find(|col| bits & (1 << (width - 1 - col)) != 0)?;
+        Some((first, last - first + 1))
+    }
+}
+
+/// How text is laid out: font, pixel scale, and glyph spacing.
+#[derive(Debug, Clone, Copy)]
+pub struct TextStyle {
+    pub font: Font,
+    /// Image pixels per font pixel
+    pub scale: u32,
+    /// Pack glyphs by their inked width instead of a fixed cell,
+    /// so narrow characters like `1` and `:` don't leave gaps.
+    pub proportional: bool,
+}
+
+impl TextStyle {
+    /// Columns skipped at the left of the glyph cell and the distance to the
+    /// next glyph, both in font pixels.
+    fn placement(&self, c: char) -> (u32, u32) {
+        let width = self.font.glyph_width();
+        if !self.proportional {
+            return (0, width + 1);
+        }
+        match self.font.ink(c) {
+            Some((first, inked)) => (first, inked + 1),
+            // Blanks still need to separate words
+            None => (0, width / 2 + 1),
+        }
+    }
+
+    /// Width in pixels of `text`, without trailing spacing.
+    pub fn width(&self, text: &str) -> u32 {
+        let advance: u32 = text.chars().map(|c| self.placement(c).1).sum();
+        advance.saturating_sub(1) * self.scale
+    }
+
+    /// Height in pixels of one line of text.
+    pub fn line_height(&self) -> u32 {
+        self.font.glyph_height() * self.scale
+    }
+
+    /// Draw `text` with its top-left corner at (`x`, `y`). Pixels falling
+    /// outside the image are skipped.
+    pub fn draw(&self, img: &mut RgbaImage, x: u32, y: u32, text: &str, color: Rgba<u8>) {
+        let (width, scale) = (self.font.glyph_width(), self.scale);
+        let mut left = x;
+        for c in text.chars() {
+            let (skip, advance) = self.placement(c);
+            for (row, bits) in self.font.rows(c).iter().enumerate() {
+                for col in skip..width {
+                    if bits & (1 << (width - 1 - col)) == 0 {
+                        continue;
+                    }
+                    let x0 = left + (col - skip) * scale;
+                    let y0 = y + row as u32 * scale;
+                    for py in y0..(y0 + scale).min(img.height()) {
+                        for px in x0..(x0 + scale).min(img.width()) {
+                            img.put_pixel(px, py, color);
+                        }
+                    }
+                }
+            }
+            left += advance * scale;
+        }
+    }
+}
+
+/// Rows of a 5x7 glyph, top to bottom, with the leftmost pixel in bit 4.
+/// Lowercase letters draw as uppercase; unsupported characters draw as blanks.
+#[rustfmt::skip]
+pub fn glyph(c: char) -> [u8; 7] {
+    match c.to_ascii_uppercase() {
+        'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'B' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+        'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+        'D' => [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110],
+        'E' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+        'F' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+        'G' => [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111],
+        'H' => [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        'J' => [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100],
+        'K' => [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001],
+        'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+        'M' => [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001],
+        'N' => [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001],
+        'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'Q' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101],
+        'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001],
+        'S' => [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+        'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+        'U' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'V' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+        'W' => [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010],
+        'X' => [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001],
+        'Y' => [0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100],
+        'Z' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111],
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        ':' => [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000],
+        '-' => [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000],
+        '/' => [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000],
+        '.' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100],
+        ',' => [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000],
+        '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100],
+        '\'' => [0b01100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000],
+        '+' => [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000],
+        _ => [0; 7],
+    }
+}
+
+/// Rows of a 3x5 glyph, top to bottom, with the leftmost pixel in bit 2.
+/// Lowercase letters draw as uppercase; unsupported characters draw as blanks.
+#[rustfmt::skip]
+fn glyph_small(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+        '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+        '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+        '\'' => [0b010, 0b010, 0b000, 0b000, 0b000],
+        '+' => [0b000, 0b010, 0b111, 0b010, 0b000],
+        _ => [0; 5],
+    }
+}
+
+/// Width in pixels of `text` in the 5x7 font at `scale`, without trailing spacing.
+pub fn text_width(text: &str, scale: u32) -> u32 {
+    regular(scale).width(text)
+}
+
+/// Draw `text` in the 5x7 font with its top-left corner at (`x`, `y`), each font
+/// pixel `scale` image pixels square. Pixels falling outside the image are skipped.
+pub fn draw_text(img: &mut RgbaImage, x: u32, y: u32, text: &str, scale: u32, color: Rgba<u8>) {
+    regular(scale).draw(img, x, y, text, color);
+}
+
+fn regular(scale: u32) -> TextStyle {
+    TextStyle {
+        font: Font::Regular,
+        scale,
+        proportional: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proportional_spacing_packs_narrow_glyphs() {
+        let fixed = TextStyle {
+            font: Font::Regular,
+            scale: 1,
+            proportional: false,
+        };
+        let packed = TextStyle {
+            proportional: true,
+            ..fixed
+        };
+
+        assert_eq!(fixed.width("1:1"), 17);
+        // '1' is 3 columns wide and ':' is 2
+        assert_eq!(packed.width("1:1"), 10);
+        assert_eq!(packed.width("W"), 5);
+    }
+
+    #[test]
+    fn test_small_font_draws_within_cell() {
+        let style = TextStyle {
+            font: Font::Small,
+            scale: 2,
+            proportional: false,
+        };
+        let mut img = RgbaImage::new(style.width("T"), style.line_height());
+        style.draw(&mut img, 0, 0, "T", Rgba([255, 0, 0, 255]));
+
+        assert_eq!((img.width(), img.height()), (6, 10));
+        assert_eq!(img.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(0, 9).0, [0, 0, 0, 0]);
+        assert_eq!(img.get_pixel(2, 9).0, [255, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,187 @@
+//! Scoreboard frame content and rendering. Where things go on the frame is up
+//! to the chosen layout (see `layouts`).
+
+use chrono::DateTime;
+use image::{Rgba, RgbaImage};
+
+use crate::football::types::{FootballGameResponse, FootballPeriod, Possession};
+use crate::shared::types::{FinalStatus, Winner};
+
+use super::layouts::Layout;
+
+pub const TEXT: Rgba<u8> = Rgba([255, 255, 255, 255]);
+/// Losing score in a final game
+pub const DIMMED_TEXT: Rgba<u8> = Rgba([110, 110, 110, 255]);
+pub const POSSESSION_MARKER: Rgba<u8> = Rgba([255, 200, 0, 255]);
+pub const BACKGROUND: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+/// Colors and text size for a frame.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameStyle {
+    pub text: Rgba<u8>,
+    /// Losing score in a final game
+    pub dimmed_text: Rgba<u8>,
+    pub marker: Rgba<u8>,
+    pub background: Rgba<u8>,
+    /// Image pixels per font pixel
+    pub scale: u32,
+}
+
+impl FrameStyle {
+    /// Light text on black, for LED matrices.
+    pub fn led(height: u32) -> Self {
+        Self {
+            text: TEXT,
+            dimmed_text: DIMMED_TEXT,
+            marker: POSSESSION_MARKER,
+            background: BACKGROUND,
+            scale: text_scale(height),
+        }
+    }
+
+    /// Black on white with no grays and text a size up, for 1-bit e-paper.
+    pub fn eink(height: u32) -> Self {
+        let black = Rgba([0, 0, 0, 255]);
+        Self {
+            text: black,
+            dimmed_text: black,
+            marker: black,
+            background: Rgba([255, 255, 255, 255]),
+            scale: (height / 24).max(1),
+        }
+    }
+}
+
+/// One team's row of the scoreboard.
+#[derive(Debug)]
+pub struct FrameTeam {
+    pub abbreviation: String,
+    /// Absent before kickoff
+    pub score: Option<u8>,
+    /// Draw the marker beside the score: possession, or the winner on e-paper
+    pub has_possession: bool,
+    /// Lost a finished game
+    pub dimmed: bool,
+}
+
+/// Everything drawn on a scoreboard frame apart from the logos.
+#[derive(Debug)]
+pub struct FrameContent {
+    pub away: FrameTeam,
+    pub home: FrameTeam,
+    /// Bottom line, e.g. "Q2 8:42" or "FINAL"
+    pub status: String,
+}
+
+impl FrameContent {
+    /// Pull the displayed values out of a game. `utc_offset` (seconds) localizes
+    /// the kickoff time shown before the game.
+    pub fn from_game(game: &FootballGameResponse, utc_offset: i32) -> Self {
+        let team = |abbreviation: &str, score, has_possession, dimmed| FrameTeam {
+            abbreviation: abbreviation.to_string(),
+            score,
+            has_possession,
+            dimmed,
+        };
+
+        match game {
+            FootballGameResponse::Pregame(g) => {
+                let kickoff = DateTime::from_timestamp(g.start_time + utc_offset as i64, 0)
+                    .map(|t| t.format("%-I:%M%p").to_string())
+                    .unwrap_or_default();
+                Self {
+                    away: team(&g.away.abbreviation, None, false, false),
+                    home: team(&g.home.abbreviation, None, false, false),
+                    status: kickoff,
+                }
+            }
+            FootballGameResponse::Live(g) => {
+                let possession = g.situation.as_ref().map(|s| s.possession);
+                let status = match g.period {
+                    FootballPeriod::Halftime => "HALF".to_string(),
+                    period => format!("{} {}", period_label(period), g.clock),
+                };
+                Self {
+                    away: team(
+                        &g.away.abbreviation,
+                        Some(g.away.score),
+                        possession == Some(Possession::Away),
+                        false,
+                    ),
+                    home: team(
+                        &g.home.abbreviation,
+                        Some(g.home.score),
+                        possession == Some(Possession::Home),
+                        false,
+                    ),
+                    status,
+                }
+            }
+            FootballGameResponse::Final(g) => Self {
+                away: team(
+                    &g.away.abbreviation,
+                    Some(g.away.score),
+                    false,
+                    matches!(g.winner, Winner::Home),
+                ),
+                home: team(
+                    &g.home.abbreviation,
+                    Some(g.home.score),
+                    false,
+                    matches!(g.winner, Winner::Away),
+                ),
+                status: match g.status {
+                    FinalStatus::Final => "FINAL".to_string(),
+                    FinalStatus::FinalOvertime => "FINAL/OT".to_string(),
+                },
+            },
+        }
+    }
+
+    /// Mark the winner of a finished game instead of dimming the loser, for
+    /// displays that can't show gray.
+    pub fn mark_winner(&mut self) {
+        if !self.away.dimmed && !self.home.dimmed {
+            return;
+        }
+        let (away_won, home_won) = (self.home.dimmed, self.away.dimmed);
+        self.away.has_possession = away_won;
+        self.home.has_possession = home_won;
+        self.away.dimmed = false;
+        self.home.dimmed = false;
+    }
+}
+
+fn period_label(period: FootballPeriod) -> &'static str {
+    match period {
+        FootballPeriod::Q1 => "Q1",
+        FootballPeriod::Q2 => "Q2",
+        FootballPeriod::Q3 => "Q3",
+        FootballPeriod::Q4 => "Q4",
+        FootballPeriod::OT => "OT",
+        FootballPeriod::OT2 => "OT2",
+        FootballPeriod::OT3 => "OT3",
+        FootballPeriod::OT4 => "OT4",
+        FootballPeriod::Halftime => "HALF",
+    }
+}
+
+/// Text scale for a frame: 1 up to 63 pixels tall, 2 up to 95, and so on.
+pub fn text_scale(height: u32) -> u32 {
+    (height / 32).max(1)
+}
+
+/// Draw the frame. `logos` are (away, home), already sized to
+/// `layout.logo_size(width, height, style.scale)`.
+pub fn render_frame(
+    content: &FrameContent,
+    logos: [Option<&RgbaImage>; 2],
+    layout: Layout,
+    style: &FrameStyle,
+    width: u32,
+    height: u32,
+) -> RgbaImage {
+    let mut frame = RgbaImage::from_pixel(width, height, style.background);
+    layout.draw(&mut frame, content, logos, style);
+    frame
+}
@@ -0,0 +1,664 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{HeaderMap, Response, StatusCode, header},
+};
+use image::{Rgba, RgbaImage, imageops};
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::auth::ApiKey;
+use crate::clock::resolve_utc_offset;
+use crate::config::DeviceProfile;
+use crate::error::{AppError, ErrorResponse};
+use crate::football::handler::fetch_game;
+use crate::football::types::FootballGameResponse;
+use crate::mock::logo::placeholder_logo;
+use crate::mock::teams::find_team;
+use crate::sport::FootballLeague;
+use crate::team::image::{
+    adjust_colors, blend_with_background, decode_png, dither_rgb565, encode_bmp_24,
+    encode_indexed_raw, encode_mono_luma, encode_mono_raw, encode_png, encode_ppm_p6, encode_qoi,
+    encode_rgb565_raw, encode_rgb888_raw, orient, parse_hex_color, quantize, resize_image,
+    threshold_black_white,
+};
+use crate::team::types::{Fit, LogoVariant, OutputFormat, ResizeFilter};
+
+use super::delta::{dirty_rect, frame_etag};
+use super::font::TextStyle;
+use super::frame::{FrameContent, FrameStyle, render_frame};
+use super::text::{render_lines, wrap_lines};
+use super::ticker::{frame_count, ticker_frames, ticker_strip};
+use super::tile::{TileArrangement, TileSide, render_tile as render_tile_image};
+use super::types::{
+    RenderQuery, TextFormat, TextQuery, TickerMode, TickerQuery, TileQuery, WeatherIconQuery,
+};
+use super::weather::WeatherIcon;
+
+/// Frame and ticker width when neither the request nor a device sets one
+const DEFAULT_WIDTH: u32 = 64;
+
+/// Frame height when neither the request nor a device sets one
+const DEFAULT_HEIGHT: u32 = 32;
+
+/// Tile width and height when the request doesn't set them
+const DEFAULT_TILE_SIZE: u32 = 32;
+
+/// Longest text accepted by the text endpoints, in characters
+const MAX_TEXT_LENGTH: usize = 256;
+
+/// Most frames the ticker endpoint will pre-cut
+const MAX_TICKER_FRAMES: u32 = 1024;
+
+/// Suggested delay between scroll steps, for roughly 25 steps per second
+const SCROLL_INTERVAL_MS: u32 = 40;
+
+/// GET /api/render/games/{event_id}
+///
+/// Renders a finished scoreboard frame (logos, scores, clock, and possession)
+/// so thin clients can blit it straight to the display. Each frame has an ETag;
+/// sending it back in `If-None-Match` gets a 304 while nothing has changed, or
+/// with `delta=true`, just the changed rectangle once something has.
+#[utoipa::path(
+    get,
+    path = "/api/render/games/{event_id}",
+    params(
+        ("event_id" = String, Path, description = "ESPN event ID (numeric), or a mock game ID (e.g., 'sim_1')"),
+        RenderQuery
+    ),
+    responses(
+        (status = 200, description = "Rendered frame, or with X-Delta the changed region at X-Delta-X, X-Delta-Y", content(
+            ("image/png"),
+            ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("application/octet-stream")
+        )),
+        (status = 304, description = "Frame unchanged since the ETag in If-None-Match"),
+        (status = 400, description = "Invalid league, event ID format, or device", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "render"
+)]
+pub async fn render_game(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(event_id): Path<String>,
+    Query(params): Query<RenderQuery>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, AppError> {
+    let league = FootballLeague::from_league(params.league.as_deref().unwrap_or("nfl"))?;
+    let profile = device_profile(&state, &params.device)?;
+    let device = profile.as_ref();
+    let is_mock = event_id.starts_with("sim_");
+    let game = fetch_game(&state, league, event_id, true).await?;
+
+    let utc_offset = params
+        .utc_offset
+        .or_else(|| resolve_utc_offset(&state, &headers, &chrono::Utc::now()))
+        .unwrap_or(0);
+    let eink = params.eink || device.is_some_and(|d| d.eink);
+    let mut content = FrameContent::from_game(&game, utc_offset);
+    if eink {
+        content.mark_winner();
+    }
+
+    let width = params.width.or(device.map(|d| d.width)).unwrap_or(DEFAULT_WIDTH);
+    let height = params.height.or(device.map(|d| d.height)).unwrap_or(DEFAULT_HEIGHT);
+    let (width, height) = (width.clamp(8, 512), height.clamp(8, 512));
+    let layout = params.layout.or(device.and_then(|d| d.layout)).unwrap_or_default();
+    let format = params.format.or(device.and_then(|d| d.format));
+    let encoding = frame_encoding(format, eink);
+    let style = if eink {
+        FrameStyle::eink(height)
+    } else {
+        FrameStyle::led(height)
+    };
+    let size = layout.logo_size(width, height, style.scale);
+    let (away, home) = abbreviations(&game);
+    let (mut away_logo, mut home_logo) = tokio::join!(
+        team_logo(&state, league, is_mock, away, size),
+        team_logo(&state, league, is_mock, home, size),
+    );
+    if eink {
+        for logo in [&mut away_logo, &mut home_logo].into_iter().flatten() {
+            *logo = threshold_black_white(logo);
+        }
+    }
+
+    let frame = render_frame(
+        &content,
+        [away_logo.as_ref(), home_logo.as_ref()],
+        layout,
+        &style,
+        width,
+        height,
+    );
+    let (frame, colors) = apply_device(frame, device, format);
+
+    let etag = frame_etag(&frame, &format!("{:?}/{}", encoding, colors));
+    let known = if_none_match(&headers);
+    if known.contains(&etag.as_str()) {
+        return Ok(not_modified(&etag));
+    }
+
+    // Diff against the newest frame the client says it has, if we still have it
+    let previous = if params.delta {
+        known
+            .iter()
+            .filter_map(|tag| state.frame_cache.get(tag))
+            .find(|previous| previous.dimensions() == frame.dimensions())
+    } else {
+        None
+    };
+    state.frame_cache.put(etag.clone(), frame.clone());
+
+    let Some(previous) = previous else {
+        let (response, body) = encode_frame(&frame, encoding, colors)?;
+        return Ok(response
+            .header(header::CACHE_CONTROL, "no-cache")
+            .header(header::ETAG, etag)
+            .body(Body::from(body))
+            .unwrap());
+    };
+    let Some(rect) = dirty_rect(&previous, &frame) else {
+        return Ok(not_modified(&etag));
+    };
+
+    let region = imageops::crop_imm(&frame, rect.x, rect.y, rect.width, rect.height).to_image();
+    let (response, body) = encode_frame(&region, encoding, colors)?;
+    Ok(response
+        .header(header::CACHE_CONTROL, "no-cache")
+        .header(header::ETAG, etag)
+        .header("X-Delta", "true")
+        .header("X-Delta-X", rect.x)
+        .header("X-Delta-Y", rect.y)
+        .header("X-Frame-Width", frame.width())
+        .header("X-Frame-Height", frame.height())
+        .body(Body::from(body))
+        .unwrap())
+}
+
+/// GET /api/render/tile/{event_id}/{side}
+///
+/// Renders one team's logo with its score beside or below it, so firmware that
+/// lays out its own scoreboard can fetch exactly the two images it composes.
+#[utoipa::path(
+    get,
+    path = "/api/render/tile/{event_id}/{side}",
+    params(
+        ("event_id" = String, Path, description = "ESPN event ID (numeric), or a mock game ID (e.g., 'sim_1')"),
+        ("side" = TileSide, Path, description = "Team to draw: away or home"),
+        TileQuery
+    ),
+    responses(
+        (status = 200, description = "Rendered tile", content(
+            ("image/png"),
+            ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("application/octet-stream")
+        )),
+        (status = 304, description = "Tile unchanged since the ETag in If-None-Match"),
+        (status = 400, description = "Invalid league, event ID format, or device", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "render"
+)]
+pub async fn render_tile(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path((event_id, side)): Path<(String, TileSide)>,
+    Query(params): Query<TileQuery>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, AppError> {
+    let league = FootballLeague::from_league(params.league.as_deref().unwrap_or("nfl"))?;
+    let profile = device_profile(&state, &params.device)?;
+    let device = profile.as_ref();
+    let is_mock = event_id.starts_with("sim_");
+    let game = fetch_game(&state, league, event_id, true).await?;
+
+    // Tiles never show the status line, so the kickoff time's offset doesn't matter
+    let eink = params.eink || device.is_some_and(|d| d.eink);
+    let mut content = FrameContent::from_game(&game, 0);
+    if eink {
+        content.mark_winner();
+    }
+    let (team, abbreviation) = match side {
+        TileSide::Away => (&content.away, abbreviations(&game).0),
+        TileSide::Home => (&content.home, abbreviations(&game).1),
+    };
+
+    let width = params.width.unwrap_or(DEFAULT_TILE_SIZE).clamp(8, 256);
+    let height = params.height.unwrap_or(DEFAULT_TILE_SIZE).clamp(8, 256);
+    let arrangement = params
+        .arrangement
+        .unwrap_or_else(|| TileArrangement::for_size(width, height));
+    let format = params.format.or(device.and_then(|d| d.format));
+    let encoding = frame_encoding(format, eink);
+    let style = if eink {
+        FrameStyle::eink(height)
+    } else {
+        FrameStyle::led(height)
+    };
+
+    let size = arrangement.logo_size(width, height, style.scale);
+    let mut logo = team_logo(&state, league, is_mock, abbreviation, size).await;
+    if eink {
+        logo = logo.map(|logo| threshold_black_white(&logo));
+    }
+
+    let tile = render_tile_image(team, logo.as_ref(), arrangement, &style, width, height);
+    let (tile, colors) = apply_device(tile, device, format);
+
+    let etag = frame_etag(&tile, &format!("{:?}/{}", encoding, colors));
+    if if_none_match(&headers).contains(&etag.as_str()) {
+        return Ok(not_modified(&etag));
+    }
+    let (response, body) = encode_frame(&tile, encoding, colors)?;
+    Ok(response
+        .header(header::CACHE_CONTROL, "no-cache")
+        .header(header::ETAG, etag)
+        .body(Body::from(body))
+        .unwrap())
+}
+
+/// (away, home) team abbreviations.
+fn abbreviations(game: &FootballGameResponse) -> (&str, &str) {
+    match game {
+        FootballGameResponse::Pregame(g) => (&g.away.abbreviation, &g.home.abbreviation),
+        FootballGameResponse::Live(g) => (&g.away.abbreviation, &g.home.abbreviation),
+        FootballGameResponse::Final(g) => (&g.away.abbreviation, &g.home.abbreviation),
+    }
+}
+
+/// The requested format, else 1-bit rows for e-paper, else PNG.
+fn frame_encoding(format: Option<OutputFormat>, eink: bool) -> FrameEncoding {
+    match (format, eink) {
+        (Some(format), _) => FrameEncoding::Image(format),
+        (None, true) => FrameEncoding::Mono,
+        (None, false) => FrameEncoding::Image(OutputFormat::Png),
+    }
+}
+
+/// Apply a device's gamma, dithering, and rotation to a rendered image,
+/// returning it with the palette size for indexed output.
+fn apply_device(
+    mut frame: RgbaImage,
+    device: Option<&DeviceProfile>,
+    format: Option<OutputFormat>,
+) -> (RgbaImage, usize) {
+    let Some(device) = device else {
+        return (frame, 256);
+    };
+    adjust_colors(&mut frame, device.gamma.unwrap_or(1.0), 1.0, 1.0);
+    if let (Some(OutputFormat::Rgb565), Some(method)) = (format, device.dither) {
+        frame = dither_rgb565(&frame, method);
+    }
+    let frame = orient(frame, device.rotate, None);
+    (frame, device.colors.map_or(256, usize::from))
+}
+
+/// Entity tags listed in `If-None-Match`, quotes included and weak prefixes removed.
+fn if_none_match(headers: &HeaderMap) -> Vec<&str> {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .collect()
+}
+
+fn not_modified(etag: &str) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::NOT_MODIFIED)
+        .header(header::ETAG, etag)
+        .header(header::CACHE_CONTROL, "no-cache")
+        .body(Body::empty())
+        .unwrap()
+}
+
+/// A team logo sized for the frame. Mock games use generated logos so they render
+/// offline; a logo that can't be fetched is left out rather than failing the frame.
+async fn team_logo(
+    state: &AppState,
+    league: FootballLeague,
+    is_mock: bool,
+    abbreviation: &str,
+    size: u32,
+) -> Option<RgbaImage> {
+    let img = if is_mock {
+        image::DynamicImage::ImageRgba8(placeholder_logo(find_team(abbreviation)?))
+    } else {
+        let bytes = state
+            .provider
+            .fetch_logo(league.into(), abbreviation, LogoVariant::Scoreboard)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!(team = abbreviation, error = ?e, "Logo unavailable for frame");
+            })
+            .ok()?;
+        decode_png(&bytes).ok()?
+    };
+
+    Some(resize_image(
+        &img,
+        size.max(1),
+        size.max(1),
+        Fit::Contain,
+        ResizeFilter::default(),
+        None,
+    ))
+}
+
+/// How a rendered frame is sent
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum FrameEncoding {
+    Image(OutputFormat),
+    /// 1 bit per pixel, set for white, rows padded to whole bytes
+    Mono,
+}
+
+/// Encode a frame (or a region of one), returning the body and a response with
+/// the content headers set.
+pub(crate) fn encode_frame(
+    frame: &RgbaImage,
+    encoding: FrameEncoding,
+    colors: usize,
+) -> Result<(axum::http::response::Builder, Vec<u8>), AppError> {
+    let format = match encoding {
+        FrameEncoding::Image(format) => format,
+        FrameEncoding::Mono => {
+            let response = Response::builder()
+                .status(StatusCode::OK)
+                .header(header::CONTENT_TYPE, "application/octet-stream")
+                .header("X-Image-Width", frame.width())
+                .header("X-Image-Height", frame.height())
+                .header("X-Pixel-Format", "mono_hlsb");
+            return Ok((response, encode_mono_luma(frame)));
+        }
+    };
+
+    let body = match format {
+        OutputFormat::Png => encode_png(frame)?,
+        OutputFormat::Ppm => encode_ppm_p6(frame),
+        OutputFormat::Rgb888 => encode_rgb888_raw(frame),
+        OutputFormat::Rgb565 => encode_rgb565_raw(frame),
+        OutputFormat::Bmp => encode_bmp_24(frame, false),
+        OutputFormat::Indexed => encode_indexed_raw(&quantize(frame, colors.clamp(2, 256))),
+        OutputFormat::Qoi => encode_qoi(frame),
+    };
+
+    let mut response = Response::builder().status(StatusCode::OK);
+
+    // Raw formats carry no header, so describe the buffer layout
+    if format.is_raw() {
+        let pixel_format = match format {
+            OutputFormat::Rgb565 => "rgb565",
+            OutputFormat::Indexed => "indexed8",
+            _ => "rgb888",
+        };
+        response = response
+            .header(header::CONTENT_TYPE, "application/octet-stream")
+            .header("X-Image-Width", frame.width())
+            .header("X-Image-Height", frame.height())
+            .header("X-Pixel-Format", pixel_format);
+        if format == OutputFormat::Rgb565 {
+            response = response.header("X-Byte-Order", "little-endian");
+        }
+    } else {
+        response = response.header(header::CONTENT_TYPE, format.content_type());
+    }
+
+    Ok((response, body))
+}
+
+/// GET /api/render/weather-icon
+///
+/// Draws a small icon for a weather description, so pregame screens can show
+/// the forecast without icon art in firmware.
+#[utoipa::path(
+    get,
+    path = "/api/render/weather-icon",
+    params(WeatherIconQuery),
+    responses(
+        (status = 200, description = "Weather icon. The matched icon is named in X-Weather-Icon", content(
+            ("image/png"),
+            ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("application/octet-stream")
+        )),
+        (status = 400, description = "Invalid color", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "render"
+)]
+pub async fn render_weather_icon(
+    _api_key: ApiKey,
+    Query(params): Query<WeatherIconQuery>,
+) -> Result<Response<Body>, AppError> {
+    let icon = WeatherIcon::from_description(&params.description);
+    let mut img = icon.render(params.size.clamp(8, 128));
+    if let Some(hex) = &params.background_color {
+        img = blend_with_background(&img, parse_hex_color(hex)?);
+    }
+
+    let format = params.format.unwrap_or(OutputFormat::Png);
+    let (response, body) = encode_frame(&img, FrameEncoding::Image(format), 256)?;
+    Ok(response
+        .header(header::CACHE_CONTROL, "public, max-age=86400")
+        .header("X-Weather-Icon", icon.name())
+        .body(Body::from(body))
+        .unwrap())
+}
+
+/// GET /api/render/text
+///
+/// Rasterizes text with an embedded bitmap font, handling measuring and line
+/// wrapping so firmware can draw ticker messages without its own font code.
+#[utoipa::path(
+    get,
+    path = "/api/render/text",
+    params(TextQuery),
+    responses(
+        (status = 200, description = "Rendered text. Size is in X-Image-Width and X-Image-Height", content(
+            ("image/png"),
+            ("application/octet-stream")
+        )),
+        (status = 400, description = "Invalid text, color, or device", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "render"
+)]
+pub async fn render_text(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<TextQuery>,
+) -> Result<Response<Body>, AppError> {
+    validate_text(&params.text)?;
+    let profile = device_profile(&state, &params.device)?;
+    let device = profile.as_ref();
+    let format = text_format(params.format, device);
+    let (color, background) = text_colors(&params.color, &params.background_color, format)?;
+
+    let style = TextStyle {
+        font: params.font,
+        scale: params.scale.clamp(1, 8),
+        proportional: params.proportional,
+    };
+    let max_width = params.max_width.or(device.map(|d| d.width));
+    let lines = wrap_lines(&style, &params.text, max_width);
+    let img = render_lines(&style, &lines, params.line_spacing.clamp(0, 16), color, background);
+
+    let (response, body) = text_response(&img, format)?;
+    Ok(response
+        .header("X-Line-Count", lines.len())
+        .body(Body::from(body))
+        .unwrap())
+}
+
+/// GET /api/render/ticker
+///
+/// Lays out a long message (e.g., the last play) for smooth horizontal scrolling,
+/// either as one strip for the client to window across or as pre-cut frames.
+#[utoipa::path(
+    get,
+    path = "/api/render/ticker",
+    params(TickerQuery),
+    responses(
+        (status = 200, description = "Ticker strip or stacked frames. Scrolling hints are in X-Scroll-Step, X-Scroll-Interval-Ms, and X-Frame-Count", content(
+            ("image/png"),
+            ("application/octet-stream")
+        )),
+        (status = 400, description = "Invalid text, color, or device, or too many frames", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "render"
+)]
+pub async fn render_ticker(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<TickerQuery>,
+) -> Result<Response<Body>, AppError> {
+    validate_text(&params.text)?;
+    let profile = device_profile(&state, &params.device)?;
+    let device = profile.as_ref();
+    let format = text_format(params.format, device);
+    let (color, background) = text_colors(&params.color, &params.background_color, format)?;
+
+    let style = TextStyle {
+        font: params.font,
+        scale: params.scale.clamp(1, 8),
+        proportional: params.proportional,
+    };
+    let width = params
+        .width
+        .or(device.map(|d| d.width))
+        .unwrap_or(DEFAULT_WIDTH)
+        .clamp(8, 512);
+    let height = params
+        .height
+        .or(device.map(|d| d.height))
+        .unwrap_or(style.line_height())
+        .clamp(1, 128);
+    let step = params.step.unwrap_or(style.scale).max(1);
+
+    let strip = ticker_strip(&style, &params.text, width, height, color, background);
+    let frame_count = frame_count(strip.width(), width, step);
+    let img = match params.mode {
+        TickerMode::Strip => strip,
+        TickerMode::Frames => {
+            if frame_count > MAX_TICKER_FRAMES {
+                return Err(AppError::InvalidText(format!(
+                    "scrolling takes {} frames, more than {}. Use a larger step",
+                    frame_count, MAX_TICKER_FRAMES
+                )));
+            }
+            ticker_frames(&strip, width, step)
+        }
+    };
+
+    let (response, body) = text_response(&img, format)?;
+    Ok(response
+        .header("X-Panel-Width", width)
+        .header("X-Scroll-Step", step)
+        .header("X-Scroll-Interval-Ms", SCROLL_INTERVAL_MS)
+        .header("X-Frame-Count", frame_count)
+        .body(Body::from(body))
+        .unwrap())
+}
+
+/// The device profile named in the request, if any.
+fn device_profile(
+    state: &AppState,
+    name: &Option<String>,
+) -> Result<Option<DeviceProfile>, AppError> {
+    let live = state.live.read().unwrap();
+    name.as_deref()
+        .map(|name| live.device(name).cloned())
+        .transpose()
+}
+
+/// The requested text format, else the device's if it has a text equivalent, else PNG.
+fn text_format(format: Option<TextFormat>, device: Option<&DeviceProfile>) -> TextFormat {
+    format
+        .or_else(|| device.and_then(|d| d.format).and_then(TextFormat::from_output))
+        .unwrap_or_default()
+}
+
+fn validate_text(text: &str) -> Result<(), AppError> {
+    if text.trim().is_empty() {
+        return Err(AppError::InvalidText("text is empty".to_string()));
+    }
+    if text.chars().count() > MAX_TEXT_LENGTH {
+        return Err(AppError::InvalidText(format!(
+            "text is longer than {} characters",
+            MAX_TEXT_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+/// Text and background colors. Mono output only records coverage, so it never
+/// has a background; raw formats have no alpha, so they default to black.
+fn text_colors(
+    color: &Option<String>,
+    background_color: &Option<String>,
+    format: TextFormat,
+) -> Result<(Rgba<u8>, Option<Rgba<u8>>), AppError> {
+    let (r, g, b) = parse_hex_color(color.as_deref().unwrap_or("FFFFFF"))?;
+    let background = match (background_color, format) {
+        (_, TextFormat::Mono) => None,
+        (Some(hex), _) => Some(parse_hex_color(hex)?),
+        (None, TextFormat::Png) => None,
+        (None, _) => Some((0, 0, 0)),
+    };
+    Ok((
+        Rgba([r, g, b, 255]),
+        background.map(|(r, g, b)| Rgba([r, g, b, 255])),
+    ))
+}
+
+/// Encode rendered text, returning the body and a response with the content
+/// type and size headers set.
+fn text_response(
+    img: &RgbaImage,
+    format: TextFormat,
+) -> Result<(axum::http::response::Builder, Vec<u8>), AppError> {
+    let (body, pixel_format) = match format {
+        TextFormat::Png => (encode_png(img)?, None),
+        TextFormat::Mono => (encode_mono_raw(img), Some("mono_hlsb")),
+        TextFormat::Rgb565 => (encode_rgb565_raw(img), Some("rgb565")),
+        TextFormat::Rgb888 => (encode_rgb888_raw(img), Some("rgb888")),
+    };
+
+    let mut response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CACHE_CONTROL, "public, max-age=86400")
+        .header("X-Image-Width", img.width())
+        .header("X-Image-Height", img.height());
+    response = match pixel_format {
+        Some(pixel_format) => response
+            .header(header::CONTENT_TYPE, "application/octet-stream")
+            .header("X-Pixel-Format", pixel_format),
+        None => response.header(header::CONTENT_TYPE, "image/png"),
+    };
+    if format == TextFormat::Rgb565 {
+        response = response.header("X-Byte-Order", "little-endian");
+    }
+
+    Ok((response, body))
+}
@@ -0,0 +1,375 @@
+//! Frame layouts: where logos, scores, and the status line go, so one game can be
+//! drawn for panels of different sizes and orientations.
+
+use image::{Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::font::{GLYPH_HEIGHT, GLYPH_WIDTH, draw_text, text_width};
+use super::frame::{FrameContent, FrameStyle, FrameTeam};
+
+/// Arrangement of a rendered scoreboard frame
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Layout {
+    /// One row per team (logo, abbreviation, score) above the status line
+    #[default]
+    Stacked,
+    /// Away on the left half and home on the right, each a logo over its score,
+    /// with the status line across the bottom
+    Split,
+    /// A single line of logos, abbreviations, and scores, for short wide panels
+    Ticker,
+    /// The two logos side by side, with no text
+    LogoOnly,
+}
+
+impl Layout {
+    /// Side length of the square logo cells for a frame of this size, with text
+    /// drawn at `scale`.
+    pub fn logo_size(&self, width: u32, height: u32, scale: u32) -> u32 {
+        match self {
+            Layout::Stacked => height.saturating_sub((GLYPH_HEIGHT + 1) * scale) / 2,
+            Layout::Split => {
+                let text_rows = 2 * (GLYPH_HEIGHT + 1) * scale;
+                (width / 2).min(height.saturating_sub(text_rows))
+            }
+            Layout::Ticker => height,
+            Layout::LogoOnly => (width / 2).min(height),
+        }
+    }
+
+    /// Draw `content` and the (away, home) `logos` onto `frame`.
+    pub fn draw(
+        &self,
+        frame: &mut RgbaImage,
+        content: &FrameContent,
+        logos: [Option<&RgbaImage>; 2],
+        style: &FrameStyle,
+    ) {
+        match self {
+            Layout::Stacked => stacked(frame, content, logos, style),
+            Layout::Split => split(frame, content, logos, style),
+            Layout::Ticker => ticker(frame, content, logos, style),
+            Layout::LogoOnly => logo_only(frame, content, logos, style),
+        }
+    }
+}
+
+fn teams<'a>(
+    content: &'a FrameContent,
+    logos: [Option<&'a RgbaImage>; 2],
+) -> [(&'a FrameTeam, Option<&'a RgbaImage>); 2] {
+    [(&content.away, logos[0]), (&content.home, logos[1])]
+}
+
+fn stacked(
+    frame: &mut RgbaImage,
+    content: &FrameContent,
+    logos: [Option<&RgbaImage>; 2],
+    style: &FrameStyle,
+) {
+    let (width, height) = frame.dimensions();
+    let scale = style.scale;
+    let row_height = Layout::Stacked.logo_size(width, height, scale);
+    let text_height = GLYPH_HEIGHT * scale;
+
+    for (row, (team, logo)) in teams(content, logos).into_iter().enumerate() {
+        let top = row as u32 * row_height;
+        let text_top = top + row_height.saturating_sub(text_height) / 2;
+
+        if let Some(logo) = logo {
+            overlay_centered(frame, logo, 0, top, row_height, row_height);
+        }
+        draw_text(
+            frame,
+            row_height + scale,
+            text_top,
+            &team.abbreviation,
+            scale,
+            style.text,
+        );
+
+        let score = team.score.map(|s| s.to_string()).unwrap_or_default();
+        let score_left = width.saturating_sub(text_width(&score, scale));
+        draw_score(frame, team, &score, score_left, text_top, scale, style);
+    }
+
+    draw_status(frame, &content.status, scale, style);
+}
+
+fn split(
+    frame: &mut RgbaImage,
+    content: &FrameContent,
+    logos: [Option<&RgbaImage>; 2],
+    style: &FrameStyle,
+) {
+    let (width, height) = frame.dimensions();
+    let scale = style.scale;
+    let half = width / 2;
+    let logo_area = height.saturating_sub(2 * (GLYPH_HEIGHT + 1) * scale);
+    let score_top = logo_area + scale;
+
+    for (side, (team, logo)) in teams(content, logos).into_iter().enumerate() {
+        let left = side as u32 * half;
+        if let Some(logo) = logo {
+            overlay_centered(frame, logo, left, 0, half, logo_area);
+        }
+
+        // Before kickoff there's no score, so name the team instead
+        let label = match team.score {
+            Some(score) => score.to_string(),
+            None => team.abbreviation.clone(),
+        };
+        let label_left = left + half.saturating_sub(text_width(&label, scale)) / 2;
+        draw_score(frame, team, &label, label_left, score_top, scale, style);
+    }
+
+    draw_status(frame, &content.status, scale, style);
+}
+
+fn ticker(
+    frame: &mut RgbaImage,
+    content: &FrameContent,
+    logos: [Option<&RgbaImage>; 2],
+    style: &FrameStyle,
+) {
+    let (width, height) = frame.dimensions();
+    // Ticker panels are short, so text only grows once there is room for two lines
+    let scale = style.scale.max(height / (2 * (GLYPH_HEIGHT + 1)));
+    let space = (GLYPH_WIDTH + 1) * scale;
+    let text_top = height.saturating_sub(GLYPH_HEIGHT * scale) / 2;
+
+    let mut cursor = 0;
+    for (team, logo) in teams(content, logos) {
+        if let Some(logo) = logo {
+            overlay_centered(frame, logo, cursor, 0, height, height);
+            cursor += height + scale;
+        }
+        draw_text(frame, cursor, text_top, &team.abbreviation, scale, style.text);
+        cursor += text_width(&team.abbreviation, scale) + space;
+
+        if let Some(score) = team.score {
+            let score = score.to_string();
+            // Leave room for the possession marker before the score
+            cursor += 3 * scale;
+            draw_score(frame, team, &score, cursor, text_top, scale, style);
+            cursor += text_width(&score, scale) + space;
+        }
+        cursor += space;
+    }
+
+    // Right-aligned when there's room, otherwise straight after the teams
+    let status_left = width
+        .saturating_sub(text_width(&content.status, scale))
+        .max(cursor);
+    draw_text(frame, status_left, text_top, &content.status, scale, style.text);
+}
+
+fn logo_only(
+    frame: &mut RgbaImage,
+    content: &FrameContent,
+    logos: [Option<&RgbaImage>; 2],
+    style: &FrameStyle,
+) {
+    let (width, height) = frame.dimensions();
+    let scale = style.scale;
+    let half = width / 2;
+
+    for (side, (team, logo)) in teams(content, logos).into_iter().enumerate() {
+        let left = side as u32 * half;
+        if let Some(logo) = logo {
+            let mut logo = logo.clone();
+            if team.dimmed {
+                dim(&mut logo);
+            }
+            overlay_centered(frame, &logo, left, 0, half, height);
+        }
+
+        // Without text, possession is a marker centered under the logo
+        if team.has_possession {
+            let size = 2 * scale;
+            let marker_left = left + half.saturating_sub(size) / 2;
+            fill(frame, marker_left, height.saturating_sub(size), size, style.marker);
+        }
+    }
+}
+
+/// Draw a score (or other label) at (`left`, `top`), dimmed for the loser,
+/// with the possession marker to its left.
+pub(super) fn draw_score(
+    frame: &mut RgbaImage,
+    team: &FrameTeam,
+    score: &str,
+    left: u32,
+    top: u32,
+    scale: u32,
+    style: &FrameStyle,
+) {
+    let color = if team.dimmed { style.dimmed_text } else { style.text };
+    draw_text(frame, left, top, score, scale, color);
+
+    if team.has_possession {
+        let size = 2 * scale;
+        let marker_left = left.saturating_sub(size + 2 * scale);
+        let marker_top = top + (GLYPH_HEIGHT * scale).saturating_sub(size) / 2;
+        fill(frame, marker_left, marker_top, size, style.marker);
+    }
+}
+
+/// Status line centered along the bottom edge.
+fn draw_status(frame: &mut RgbaImage, status: &str, scale: u32, style: &FrameStyle) {
+    let (width, height) = frame.dimensions();
+    let left = width.saturating_sub(text_width(status, scale)) / 2;
+    let top = height.saturating_sub(GLYPH_HEIGHT * scale);
+    draw_text(frame, left, top, status, scale, style.text);
+}
+
+/// Overlay `logo` centered in the cell with top-left (`left`, `top`).
+pub(super) fn overlay_centered(
+    frame: &mut RgbaImage,
+    logo: &RgbaImage,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+) {
+    let x = left as i64 + (width as i64 - logo.width() as i64) / 2;
+    let y = top as i64 + (height as i64 - logo.height() as i64) / 2;
+    image::imageops::overlay(frame, logo, x, y);
+}
+
+/// Square marker with top-left (`left`, `top`).
+fn fill(frame: &mut RgbaImage, left: u32, top: u32, size: u32, color: Rgba<u8>) {
+    let (width, height) = frame.dimensions();
+    for y in top..(top + size).min(height) {
+        for x in left..(left + size).min(width) {
+            frame.put_pixel(x, y, color);
+        }
+    }
+}
+
+/// Darken a losing team's logo, like its dimmed score.
+fn dim(logo: &mut RgbaImage) {
+    for pixel in logo.pixels_mut() {
+        let Rgba([r, g, b, a]) = *pixel;
+        *pixel = Rgba([r / 3, g / 3, b / 3, a]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::types::Possession;
+    use crate::render::frame::{BACKGROUND, POSSESSION_MARKER, TEXT, render_frame};
+
+    fn content(status: &str, possession: Option<Possession>) -> FrameContent {
+        let team = |abbreviation: &str, side| FrameTeam {
+            abbreviation: abbreviation.to_string(),
+            score: Some(7),
+            has_possession: possession == Some(side),
+            dimmed: false,
+        };
+        FrameContent {
+            away: team("KC", Possession::Away),
+            home: team("BUF", Possession::Home),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_logo_sizes() {
+        assert_eq!(Layout::Stacked.logo_size(64, 32, 1), 12);
+        assert_eq!(Layout::Stacked.logo_size(128, 64, 2), 24);
+        assert_eq!(Layout::Split.logo_size(64, 32, 1), 16);
+        assert_eq!(Layout::Ticker.logo_size(128, 16, 1), 16);
+        assert_eq!(Layout::LogoOnly.logo_size(64, 32, 1), 32);
+        assert_eq!(Layout::LogoOnly.logo_size(32, 64, 2), 16);
+    }
+
+    #[test]
+    fn test_stacked_places_status_and_possession() {
+        let content = content("Q1 15:00", Some(Possession::Home));
+        let frame = render_frame(&content, [None, None], Layout::Stacked, &FrameStyle::led(32), 64, 32);
+        assert_eq!(frame.dimensions(), (64, 32));
+
+        // Possession marker sits in the home row (second), left of the score
+        let score_left = 64 - text_width("7", 1);
+        let marker = (score_left - 4, 12 + 5);
+        assert_eq!(*frame.get_pixel(marker.0, marker.1), POSSESSION_MARKER);
+        assert_eq!(*frame.get_pixel(marker.0, 5), BACKGROUND);
+
+        // The status line's "Q" starts in the bottom row of text
+        let status_left = (64 - text_width("Q1 15:00", 1)) / 2;
+        assert_eq!(*frame.get_pixel(status_left + 1, 25), TEXT);
+    }
+
+    #[test]
+    fn test_split_centers_scores_under_logos() {
+        let logo = RgbaImage::from_pixel(16, 16, Rgba([0, 0, 255, 255]));
+        let content = content("HALF", Some(Possession::Away));
+        let frame = render_frame(&content, [Some(&logo), Some(&logo)], Layout::Split, &FrameStyle::led(32), 64, 32);
+
+        // Logos centered in each 32-pixel half
+        assert_eq!(frame.get_pixel(8, 0).0, [0, 0, 255, 255]);
+        assert_eq!(frame.get_pixel(7, 0), &BACKGROUND);
+        assert_eq!(frame.get_pixel(40, 15).0, [0, 0, 255, 255]);
+
+        // "7" is centered in the half below the logo, possession marked to its left
+        let score_left = (32 - text_width("7", 1)) / 2;
+        assert_eq!(*frame.get_pixel(score_left, 17), TEXT);
+        assert_eq!(*frame.get_pixel(score_left - 4, 20), POSSESSION_MARKER);
+    }
+
+    #[test]
+    fn test_ticker_right_aligns_status() {
+        let frame = render_frame(
+            &content("FINAL", None),
+            [None, None],
+            Layout::Ticker,
+            &FrameStyle::led(8),
+            128,
+            8,
+        );
+
+        // "F" starts with a full-width top row
+        let status_left = 128 - text_width("FINAL", 1);
+        assert_eq!(*frame.get_pixel(status_left, 0), TEXT);
+        assert_eq!(*frame.get_pixel(status_left - 2, 0), BACKGROUND);
+    }
+
+    #[test]
+    fn test_logo_only_dims_loser() {
+        let logo = RgbaImage::from_pixel(32, 32, Rgba([240, 60, 30, 255]));
+        let mut content = content("FINAL", None);
+        content.home.dimmed = true;
+        let frame = render_frame(
+            &content,
+            [Some(&logo), Some(&logo)],
+            Layout::LogoOnly,
+            &FrameStyle::led(32),
+            64,
+            32,
+        );
+
+        assert_eq!(frame.get_pixel(10, 10).0, [240, 60, 30, 255]);
+        assert_eq!(frame.get_pixel(42, 10).0, [80, 20, 10, 255]);
+    }
+
+    #[test]
+    fn test_eink_marks_winner_in_black() {
+        let mut content = content("FINAL", None);
+        content.home.dimmed = true;
+        content.mark_winner();
+        let style = FrameStyle::eink(48);
+        let frame = render_frame(&content, [None, None], Layout::Stacked, &style, 96, 48);
+
+        // Scale 2: rows are (48 - 16) / 2 = 16 tall, and the away score is marked
+        let score_left = 96 - text_width("7", 2);
+        let marker_top = (16 - 4) / 2;
+        assert_eq!(frame.get_pixel(score_left - 8, marker_top).0, [0, 0, 0, 255]);
+        assert_eq!(frame.get_pixel(score_left - 8, 16 + marker_top).0, [255; 4]);
+        // Neither score is grayed out
+        assert_eq!(frame.get_pixel(score_left, 16 + 1).0, [0, 0, 0, 255]);
+    }
+}
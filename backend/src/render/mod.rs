@@ -0,0 +1,12 @@
+pub mod delta;
+pub mod font;
+pub mod frame;
+pub mod handler;
+pub mod layouts;
+pub mod text;
+pub mod ticker;
+pub mod tile;
+pub mod types;
+pub mod weather;
+
+pub use handler::{render_game, render_text, render_ticker, render_tile, render_weather_icon};
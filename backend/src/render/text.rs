@@ -0,0 +1,109 @@
+//! Multi-line text layout for the text rendering endpoint.
+
+use image::{Rgba, RgbaImage};
+
+use super::font::TextStyle;
+
+/// Split `text` into lines, breaking at newlines and, when `max_width` is set,
+/// between words so each line fits. Words wider than `max_width` on their own
+/// are broken between characters.
+pub fn wrap_lines(style: &TextStyle, text: &str, max_width: Option<u32>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let Some(max_width) = max_width else {
+            lines.push(paragraph.to_string());
+            continue;
+        };
+
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+            if style.width(&candidate) <= max_width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for c in word.chars() {
+                line.push(c);
+                if style.width(&line) > max_width && line.chars().count() > 1 {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, c.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Draw `lines` left-aligned onto an image just large enough to hold them,
+/// with `line_spacing` font pixels between lines. The background is transparent
+/// unless `background` is given.
+pub fn render_lines(
+    style: &TextStyle,
+    lines: &[String],
+    line_spacing: u32,
+    color: Rgba<u8>,
+    background: Option<Rgba<u8>>,
+) -> RgbaImage {
+    let width = lines
+        .iter()
+        .map(|line| style.width(line))
+        .max()
+        .unwrap_or(0);
+    let pitch = style.line_height() + line_spacing * style.scale;
+    let height = (pitch * lines.len() as u32).saturating_sub(line_spacing * style.scale);
+
+    let mut img = RgbaImage::from_pixel(
+        width.max(1),
+        height.max(1),
+        background.unwrap_or(Rgba([0, 0, 0, 0])),
+    );
+    for (i, line) in lines.iter().enumerate() {
+        style.draw(&mut img, 0, i as u32 * pitch, line, color);
+    }
+    img
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::font::Font;
+
+    const STYLE: TextStyle = TextStyle {
+        font: Font::Regular,
+        scale: 1,
+        proportional: false,
+    };
+
+    #[test]
+    fn test_wrap_lines_breaks_between_words() {
+        // Each character is 6 pixels, so 41 pixels fits 7 characters
+        let lines = wrap_lines(&STYLE, "KC 24 - PHI 21", Some(41));
+        assert_eq!(lines, vec!["KC 24 -", "PHI 21"]);
+
+        assert_eq!(
+            wrap_lines(&STYLE, "KC 24\nPHI 21", None),
+            vec!["KC 24", "PHI 21"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_lines_splits_long_words() {
+        let lines = wrap_lines(&STYLE, "TOUCHDOWN", Some(29));
+        assert_eq!(lines, vec!["TOUCH", "DOWN"]);
+    }
+
+    #[test]
+    fn test_render_lines_sizes_to_text() {
+        let lines = vec!["AB".to_string(), "C".to_string()];
+        let img = render_lines(&STYLE, &lines, 1, Rgba([255, 255, 255, 255]), None);
+        assert_eq!((img.width(), img.height()), (11, 15));
+    }
+}
@@ -0,0 +1,83 @@
+//! Scrolling ticker: a single line of text laid out on a strip wide enough to
+//! scroll in from the right edge of the panel and out past the left.
+
+use image::{Rgba, RgbaImage, imageops};
+
+use super::font::TextStyle;
+
+/// Draw `text` on one line, with a blank panel's width on either side and the
+/// text centered vertically in `height`. Showing the `width`-pixel window at
+/// offsets 0 through `strip.width() - width` scrolls the whole message past.
+pub fn ticker_strip(
+    style: &TextStyle,
+    text: &str,
+    width: u32,
+    height: u32,
+    color: Rgba<u8>,
+    background: Option<Rgba<u8>>,
+) -> RgbaImage {
+    let text = text.replace('\n', " ");
+    let strip_width = 2 * width + style.width(&text);
+    let mut strip = RgbaImage::from_pixel(
+        strip_width,
+        height,
+        background.unwrap_or(Rgba([0, 0, 0, 0])),
+    );
+    let top = height.saturating_sub(style.line_height()) / 2;
+    style.draw(&mut strip, width, top, &text, color);
+    strip
+}
+
+/// Number of `width`-pixel windows taken from `strip` when advancing `step` pixels.
+pub fn frame_count(strip_width: u32, width: u32, step: u32) -> u32 {
+    strip_width.saturating_sub(width) / step.max(1) + 1
+}
+
+/// Cut the strip into panel-sized frames, each `step` pixels further along,
+/// stacked top to bottom so raw encodings are the frames back to back.
+pub fn ticker_frames(strip: &RgbaImage, width: u32, step: u32) -> RgbaImage {
+    let height = strip.height();
+    let count = frame_count(strip.width(), width, step);
+    let mut frames = RgbaImage::new(width, height * count);
+    for i in 0..count {
+        let window = imageops::crop_imm(strip, i * step.max(1), 0, width, height);
+        imageops::replace(&mut frames, &*window, 0, (i * height) as i64);
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::font::Font;
+
+    const STYLE: TextStyle = TextStyle {
+        font: Font::Regular,
+        scale: 1,
+        proportional: false,
+    };
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    #[test]
+    fn test_ticker_strip_pads_both_sides() {
+        let strip = ticker_strip(&STYLE, "TD", 16, 9, RED, None);
+
+        assert_eq!(strip.dimensions(), (16 + 11 + 16, 9));
+        // "T" top row starts right after the leading blank, one row down
+        assert_eq!(*strip.get_pixel(16, 1), RED);
+        assert_eq!(strip.get_pixel(15, 1).0, [0, 0, 0, 0]);
+        assert_eq!(strip.get_pixel(16, 0).0, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_ticker_frames_step_through_strip() {
+        let strip = ticker_strip(&STYLE, "TD", 16, 8, RED, None);
+        assert_eq!(frame_count(strip.width(), 16, 4), 7);
+
+        let frames = ticker_frames(&strip, 16, 4);
+        assert_eq!(frames.dimensions(), (16, 8 * 7));
+        // Frame 4 starts at offset 16, so the text's first column is on its left edge
+        assert_eq!(*frames.get_pixel(0, 4 * 8), RED);
+        assert_eq!(frames.get_pixel(0, 3 * 8).0, [0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,163 @@
+//! Single-team tiles: one team's logo with its score, for firmware that composes
+//! the scoreboard itself from an away tile and a home tile.
+
+use image::RgbaImage;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::font::{GLYPH_HEIGHT, GLYPH_WIDTH, text_width};
+use super::frame::{FrameStyle, FrameTeam};
+use super::layouts::{draw_score, overlay_centered};
+
+/// Which team of a game a tile shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TileSide {
+    Away,
+    Home,
+}
+
+/// Where the score goes relative to the logo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TileArrangement {
+    /// Score to the right of the logo
+    Beside,
+    /// Score under the logo
+    Below,
+}
+
+impl TileArrangement {
+    /// Beside for wide tiles, below for square and tall ones.
+    pub fn for_size(width: u32, height: u32) -> Self {
+        if width > height {
+            TileArrangement::Beside
+        } else {
+            TileArrangement::Below
+        }
+    }
+
+    /// Side length of the square logo cell for a tile of this size, leaving room
+    /// for a two-digit score (and its possession marker) drawn at `scale`.
+    pub fn logo_size(&self, width: u32, height: u32, scale: u32) -> u32 {
+        match self {
+            TileArrangement::Beside => {
+                let text_columns = (2 * (GLYPH_WIDTH + 1) + 4) * scale;
+                height.min(width.saturating_sub(text_columns))
+            }
+            TileArrangement::Below => {
+                width.min(height.saturating_sub((GLYPH_HEIGHT + 1) * scale))
+            }
+        }
+    }
+}
+
+/// Draw a tile. `logo` is already sized to `arrangement.logo_size(width, height, style.scale)`.
+pub fn render_tile(
+    team: &FrameTeam,
+    logo: Option<&RgbaImage>,
+    arrangement: TileArrangement,
+    style: &FrameStyle,
+    width: u32,
+    height: u32,
+) -> RgbaImage {
+    let mut tile = RgbaImage::from_pixel(width, height, style.background);
+    let scale = style.scale;
+    let size = arrangement.logo_size(width, height, scale);
+
+    // Before kickoff there's no score, so name the team instead
+    let label = match team.score {
+        Some(score) => score.to_string(),
+        None => team.abbreviation.clone(),
+    };
+    let label_width = text_width(&label, scale);
+    let text_height = GLYPH_HEIGHT * scale;
+
+    let (label_left, label_top) = match arrangement {
+        TileArrangement::Beside => {
+            if let Some(logo) = logo {
+                overlay_centered(&mut tile, logo, 0, 0, size, height);
+            }
+            let text_area = width.saturating_sub(size);
+            (
+                size + text_area.saturating_sub(label_width) / 2,
+                height.saturating_sub(text_height) / 2,
+            )
+        }
+        TileArrangement::Below => {
+            if let Some(logo) = logo {
+                overlay_centered(&mut tile, logo, 0, 0, width, size);
+            }
+            (
+                width.saturating_sub(label_width) / 2,
+                height.saturating_sub(text_height),
+            )
+        }
+    };
+    draw_score(&mut tile, team, &label, label_left, label_top, scale, style);
+
+    tile
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::frame::{BACKGROUND, POSSESSION_MARKER, TEXT};
+    use image::Rgba;
+
+    fn team(score: Option<u8>, has_possession: bool) -> FrameTeam {
+        FrameTeam {
+            abbreviation: "KC".to_string(),
+            score,
+            has_possession,
+            dimmed: false,
+        }
+    }
+
+    #[test]
+    fn test_arrangement_follows_shape() {
+        assert_eq!(TileArrangement::for_size(48, 16), TileArrangement::Beside);
+        assert_eq!(TileArrangement::for_size(32, 32), TileArrangement::Below);
+        assert_eq!(TileArrangement::Beside.logo_size(48, 16, 1), 16);
+        assert_eq!(TileArrangement::Beside.logo_size(24, 16, 1), 8);
+        assert_eq!(TileArrangement::Below.logo_size(32, 32, 1), 24);
+    }
+
+    #[test]
+    fn test_below_centers_score_under_logo() {
+        let logo = RgbaImage::from_pixel(24, 24, Rgba([0, 0, 255, 255]));
+        let tile = render_tile(
+            &team(Some(7), true),
+            Some(&logo),
+            TileArrangement::Below,
+            &FrameStyle::led(32),
+            32,
+            32,
+        );
+
+        assert_eq!(tile.get_pixel(4, 0).0, [0, 0, 255, 255]);
+        assert_eq!(*tile.get_pixel(3, 0), BACKGROUND);
+
+        // "7" has a full-width top row, with the possession marker to its left
+        let score_left = (32 - text_width("7", 1)) / 2;
+        assert_eq!(*tile.get_pixel(score_left, 25), TEXT);
+        assert_eq!(*tile.get_pixel(score_left - 4, 28), POSSESSION_MARKER);
+    }
+
+    #[test]
+    fn test_beside_names_team_before_kickoff() {
+        let tile = render_tile(
+            &team(None, false),
+            None,
+            TileArrangement::Beside,
+            &FrameStyle::led(16),
+            48,
+            16,
+        );
+
+        // "KC" is centered in the 32 pixels right of the logo cell
+        let label_left = 16 + (32 - text_width("KC", 1)) / 2;
+        assert_eq!(*tile.get_pixel(label_left, 4), TEXT);
+        assert_eq!(*tile.get_pixel(label_left - 1, 4), BACKGROUND);
+    }
+}
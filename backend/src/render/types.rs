@@ -0,0 +1,230 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::team::types::OutputFormat;
+
+use super::font::Font;
+use super::layouts::Layout;
+use super::tile::TileArrangement;
+
+/// Query parameters for the rendered scoreboard frame
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RenderQuery {
+    /// Frame width in pixels, clamped to 8-512 (default: 64)
+    pub width: Option<u32>,
+
+    /// Frame height in pixels, clamped to 8-512 (default: 32)
+    pub height: Option<u32>,
+
+    /// Arrangement of logos, scores, and status (default: stacked).
+    /// `split` and `logo_only` suit wide panels, `ticker` short ones.
+    pub layout: Option<Layout>,
+
+    /// Output format (default: png). Raw formats are sent as `application/octet-stream`.
+    pub format: Option<OutputFormat>,
+
+    /// League the event belongs to (default: nfl). Ignored for mock games.
+    pub league: Option<String>,
+
+    /// UTC offset in seconds for the pregame kickoff time, as returned by `/time`.
+    /// Inferred from the client's IP address if not specified.
+    pub utc_offset: Option<i32>,
+
+    /// Configured device profile supplying the panel size, layout, format,
+    /// rotation, and color settings not given explicitly
+    pub device: Option<String>,
+
+    /// When `If-None-Match` names a recent frame, respond with only the rectangle
+    /// that changed since it (default: false)
+    #[serde(default)]
+    pub delta: bool,
+
+    /// Black-and-white rendering for e-paper: larger text, thresholded logos, and
+    /// the winner marked rather than the loser grayed out (default: false).
+    /// Without a `format`, frames are sent as 1-bit rows with white as 1.
+    #[serde(default)]
+    pub eink: bool,
+}
+
+/// Query parameters for the text rendering endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TextQuery {
+    /// Text to render. `\n` starts a new line; lowercase letters render as uppercase.
+    pub text: String,
+
+    /// Bitmap font (default: 5x7)
+    #[serde(default)]
+    pub font: Font,
+
+    /// Text color as hex RGB888 without # (default: "FFFFFF"). Ignored for mono output.
+    pub color: Option<String>,
+
+    /// Background color as hex RGB888 without #. Transparent if not specified
+    /// (black for raw formats). Ignored for mono output.
+    pub background_color: Option<String>,
+
+    /// Image pixels per font pixel, clamped to 1-8 (default: 1)
+    #[serde(default = "default_scale")]
+    pub scale: u32,
+
+    /// Wrap lines between words to fit this many pixels. Defaults to the
+    /// device's panel width when `device` is given.
+    pub max_width: Option<u32>,
+
+    /// Font pixels between lines, clamped to 0-16 (default: 1)
+    #[serde(default = "default_line_spacing")]
+    pub line_spacing: u32,
+
+    /// Space glyphs by their inked width instead of a fixed cell (default: false)
+    #[serde(default)]
+    pub proportional: bool,
+
+    /// Output format (default: png, or the device's format if it has a text equivalent)
+    pub format: Option<TextFormat>,
+
+    /// Configured device profile supplying the wrap width and format
+    pub device: Option<String>,
+}
+
+fn default_scale() -> u32 {
+    1
+}
+
+fn default_line_spacing() -> u32 {
+    1
+}
+
+/// Output formats for rendered text
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TextFormat {
+    #[default]
+    Png,
+    /// 1 bit per pixel, rows padded to whole bytes, leftmost pixel in the
+    /// most significant bit (MicroPython `framebuf.MONO_HLSB`)
+    Mono,
+    /// Raw RGB565, little-endian
+    Rgb565,
+    /// Raw RGB888
+    Rgb888,
+}
+
+impl TextFormat {
+    /// The text format matching a device's preferred image format, if any.
+    pub fn from_output(format: OutputFormat) -> Option<Self> {
+        match format {
+            OutputFormat::Png => Some(TextFormat::Png),
+            OutputFormat::Rgb565 => Some(TextFormat::Rgb565),
+            OutputFormat::Rgb888 => Some(TextFormat::Rgb888),
+            _ => None,
+        }
+    }
+}
+
+/// Query parameters for the scrolling ticker endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TickerQuery {
+    /// Text to scroll, drawn on a single line
+    pub text: String,
+
+    /// Panel width in pixels, clamped to 8-512 (default: 64)
+    pub width: Option<u32>,
+
+    /// Panel height in pixels, clamped to 1-128. Defaults to the height of one
+    /// line of text.
+    pub height: Option<u32>,
+
+    /// Bitmap font (default: 5x7)
+    #[serde(default)]
+    pub font: Font,
+
+    /// Text color as hex RGB888 without # (default: "FFFFFF"). Ignored for mono output.
+    pub color: Option<String>,
+
+    /// Background color as hex RGB888 without #. Transparent if not specified
+    /// (black for raw formats). Ignored for mono output.
+    pub background_color: Option<String>,
+
+    /// Image pixels per font pixel, clamped to 1-8 (default: 1)
+    #[serde(default = "default_scale")]
+    pub scale: u32,
+
+    /// Space glyphs by their inked width instead of a fixed cell (default: false)
+    #[serde(default)]
+    pub proportional: bool,
+
+    /// `strip` returns the whole message on one image; `frames` returns panel-sized
+    /// frames stacked top to bottom (default: strip)
+    #[serde(default)]
+    pub mode: TickerMode,
+
+    /// Pixels to advance per frame. Defaults to `X-Scroll-Step`, one font pixel.
+    pub step: Option<u32>,
+
+    /// Output format (default: png, or the device's format if it has a text equivalent)
+    pub format: Option<TextFormat>,
+
+    /// Configured device profile supplying the panel size and format
+    pub device: Option<String>,
+}
+
+/// How the ticker is returned
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TickerMode {
+    /// One image to scroll a panel-sized window across
+    #[default]
+    Strip,
+    /// Every scroll position pre-cut into its own frame
+    Frames,
+}
+
+/// Query parameters for the weather icon endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct WeatherIconQuery {
+    /// Weather description as reported for the game (e.g., "Light Rain")
+    pub description: String,
+
+    /// Icon size in pixels, clamped to 8-128 (default: 16). Multiples of 16 stay crisp.
+    #[serde(default = "default_icon_size")]
+    pub size: u32,
+
+    /// Output format (default: png). Raw formats are sent as `application/octet-stream`.
+    pub format: Option<OutputFormat>,
+
+    /// Background color as hex RGB888 without #. Transparent if not specified
+    /// (black for formats without alpha).
+    pub background_color: Option<String>,
+}
+
+fn default_icon_size() -> u32 {
+    16
+}
+
+/// Query parameters for the single-team tile endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TileQuery {
+    /// Tile width in pixels (default: 32)
+    pub width: Option<u32>,
+
+    /// Tile height in pixels (default: 32)
+    pub height: Option<u32>,
+
+    /// Score beside or below the logo. Defaults to beside for wide tiles and
+    /// below otherwise.
+    pub arrangement: Option<TileArrangement>,
+
+    /// Output format (default: png). Raw formats are sent as `application/octet-stream`.
+    pub format: Option<OutputFormat>,
+
+    /// League the event belongs to (default: nfl). Ignored for mock games.
+    pub league: Option<String>,
+
+    /// Configured device profile supplying the format, rotation, and color
+    /// settings not given explicitly. Its panel size is not used.
+    pub device: Option<String>,
+
+    /// Black-and-white rendering for e-paper, as for full frames (default: false)
+    #[serde(default)]
+    pub eink: bool,
+}
@@ -0,0 +1,271 @@
+//! Small weather icons for pregame screens, drawn from 16x16 pixel-art layers.
+
+use image::{Rgba, RgbaImage, imageops};
+
+/// Side length of the icon artwork in pixels
+pub const ICON_SIZE: u32 = 16;
+
+const SUN_COLOR: Rgba<u8> = Rgba([255, 200, 0, 255]);
+const CLOUD_COLOR: Rgba<u8> = Rgba([200, 200, 210, 255]);
+const STORM_CLOUD_COLOR: Rgba<u8> = Rgba([120, 120, 135, 255]);
+const RAIN_COLOR: Rgba<u8> = Rgba([70, 140, 255, 255]);
+const SNOW_COLOR: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const FOG_COLOR: Rgba<u8> = Rgba([160, 160, 160, 255]);
+const WIND_COLOR: Rgba<u8> = Rgba([200, 220, 255, 255]);
+
+const SUN: [&str; 16] = [
+    "................",
+    ".......##.......",
+    "..#....##....#..",
+    "...#........#...",
+    "......####......",
+    ".....######.....",
+    "....########....",
+    ".##.########.##.",
+    ".##.########.##.",
+    "....########....",
+    ".....######.....",
+    "......####......",
+    "...#........#...",
+    "..#....##....#..",
+    ".......##.......",
+    "................",
+];
+
+/// Sun peeking out from behind a cloud, drawn first
+const SMALL_SUN: [&str; 8] = [
+    "................",
+    "...#..#..#......",
+    "....#####.......",
+    "...#######......",
+    "..#########.....",
+    "...#######......",
+    "....#####.......",
+    "...#..#..#......",
+];
+
+const CLOUD: [&str; 8] = [
+    "......####......",
+    ".....######.....",
+    "..##.#######....",
+    ".#############..",
+    "################",
+    "################",
+    "################",
+    ".##############.",
+];
+
+const RAIN: [&str; 5] = [
+    "..#....#....#...",
+    ".#....#....#....",
+    "................",
+    "....#....#....#.",
+    "...#....#....#..",
+];
+
+const SNOW: [&str; 5] = [
+    "..#.....#.....#.",
+    ".###...###...###",
+    "..#.....#.....#.",
+    "................",
+    ".....#.....#....",
+];
+
+const BOLT: [&str; 7] = [
+    ".......####.....",
+    "......####......",
+    ".....######.....",
+    ".......###......",
+    "......##........",
+    ".....#..........",
+    "....#...........",
+];
+
+const FOG: [&str; 16] = [
+    "................",
+    "................",
+    "................",
+    "..############..",
+    "................",
+    "############....",
+    "................",
+    "....############",
+    "................",
+    "..############..",
+    "................",
+    "#########.......",
+    "................",
+    ".....##########.",
+    "................",
+    "................",
+];
+
+const WIND: [&str; 16] = [
+    "................",
+    "................",
+    "..........###...",
+    ".............#..",
+    ".............#..",
+    "############.#..",
+    "................",
+    "##############..",
+    "................",
+    "##########......",
+    "..........#.....",
+    "..........#.....",
+    ".........#......",
+    "................",
+    "................",
+    "................",
+];
+
+/// Weather conditions that have an icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherIcon {
+    Sunny,
+    PartlyCloudy,
+    Cloudy,
+    Rain,
+    Snow,
+    Thunderstorm,
+    Fog,
+    Wind,
+}
+
+impl WeatherIcon {
+    /// Match an ESPN weather description (e.g., "Light Rain", "Mostly Sunny").
+    /// Descriptions that match nothing get the plain cloud.
+    pub fn from_description(description: &str) -> Self {
+        let description = description.to_lowercase();
+        let has = |words: &[&str]| words.iter().any(|w| description.contains(w));
+
+        // Most severe first, so "Rain and Thunder" gets the storm
+        if has(&["thunder", "t-storm", "storm"]) {
+            WeatherIcon::Thunderstorm
+        } else if has(&["snow", "flurr", "sleet", "ice", "wintry", "blizzard"]) {
+            WeatherIcon::Snow
+        } else if has(&["rain", "shower", "drizzle"]) {
+            WeatherIcon::Rain
+        } else if has(&["fog", "haze", "mist", "smoke"]) {
+            WeatherIcon::Fog
+        } else if has(&["wind", "breez"]) {
+            WeatherIcon::Wind
+        } else if has(&["partly", "mostly sunny", "mostly clear", "intermittent"]) {
+            WeatherIcon::PartlyCloudy
+        } else if has(&["cloud", "overcast"]) {
+            WeatherIcon::Cloudy
+        } else if has(&["sun", "clear", "fair"]) {
+            WeatherIcon::Sunny
+        } else {
+            WeatherIcon::Cloudy
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeatherIcon::Sunny => "sunny",
+            WeatherIcon::PartlyCloudy => "partly_cloudy",
+            WeatherIcon::Cloudy => "cloudy",
+            WeatherIcon::Rain => "rain",
+            WeatherIcon::Snow => "snow",
+            WeatherIcon::Thunderstorm => "thunderstorm",
+            WeatherIcon::Fog => "fog",
+            WeatherIcon::Wind => "wind",
+        }
+    }
+
+    /// Draw the icon at `size` pixels square on a transparent background.
+    /// Multiples of 16 keep the pixel art crisp.
+    pub fn render(&self, size: u32) -> RgbaImage {
+        let mut icon = RgbaImage::new(ICON_SIZE, ICON_SIZE);
+        match self {
+            WeatherIcon::Sunny => draw_layer(&mut icon, &SUN, 0, SUN_COLOR),
+            WeatherIcon::PartlyCloudy => {
+                draw_layer(&mut icon, &SMALL_SUN, 0, SUN_COLOR);
+                draw_layer(&mut icon, &CLOUD, 7, CLOUD_COLOR);
+            }
+            WeatherIcon::Cloudy => draw_layer(&mut icon, &CLOUD, 4, CLOUD_COLOR),
+            WeatherIcon::Rain => {
+                draw_layer(&mut icon, &CLOUD, 1, CLOUD_COLOR);
+                draw_layer(&mut icon, &RAIN, 10, RAIN_COLOR);
+            }
+            WeatherIcon::Snow => {
+                draw_layer(&mut icon, &CLOUD, 1, CLOUD_COLOR);
+                draw_layer(&mut icon, &SNOW, 10, SNOW_COLOR);
+            }
+            WeatherIcon::Thunderstorm => {
+                draw_layer(&mut icon, &CLOUD, 1, STORM_CLOUD_COLOR);
+                draw_layer(&mut icon, &BOLT, 8, SUN_COLOR);
+            }
+            WeatherIcon::Fog => draw_layer(&mut icon, &FOG, 0, FOG_COLOR),
+            WeatherIcon::Wind => draw_layer(&mut icon, &WIND, 0, WIND_COLOR),
+        }
+
+        if size == ICON_SIZE {
+            return icon;
+        }
+        imageops::resize(&icon, size, size, imageops::FilterType::Nearest)
+    }
+}
+
+/// Paint the `#` cells of `rows` starting `top` rows down.
+fn draw_layer(icon: &mut RgbaImage, rows: &[&str], top: u32, color: Rgba<u8>) {
+    for (y, row) in rows.iter().enumerate() {
+        for (x, cell) in row.bytes().enumerate() {
+            if cell == b'#' {
+                icon.put_pixel(x as u32, top + y as u32, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_description() {
+        let cases = [
+            ("Sunny", WeatherIcon::Sunny),
+            ("Mostly Sunny", WeatherIcon::PartlyCloudy),
+            ("Partly Cloudy", WeatherIcon::PartlyCloudy),
+            ("Overcast", WeatherIcon::Cloudy),
+            ("Light Rain", WeatherIcon::Rain),
+            ("Rain and Snow", WeatherIcon::Snow),
+            ("Scattered T-Storms", WeatherIcon::Thunderstorm),
+            ("Fog", WeatherIcon::Fog),
+            ("Breezy", WeatherIcon::Wind),
+            ("Indoors", WeatherIcon::Cloudy),
+        ];
+        for (description, icon) in cases {
+            assert_eq!(WeatherIcon::from_description(description), icon, "{description}");
+        }
+    }
+
+    #[test]
+    fn test_layers_fit_icon() {
+        let layers: [(&[&str], u32); 8] = [
+            (&SUN, 0),
+            (&SMALL_SUN, 0),
+            (&CLOUD, 7),
+            (&RAIN, 10),
+            (&SNOW, 10),
+            (&BOLT, 8),
+            (&FOG, 0),
+            (&WIND, 0),
+        ];
+        for (rows, top) in layers {
+            assert!(top as usize + rows.len() <= ICON_SIZE as usize);
+            assert!(rows.iter().all(|row| row.len() == ICON_SIZE as usize));
+        }
+    }
+
+    #[test]
+    fn test_render_scales_with_nearest() {
+        let icon = WeatherIcon::Sunny.render(32);
+        assert_eq!(icon.dimensions(), (32, 32));
+        // Top ray at (7, 1) covers (14..16, 2..4) when doubled
+        assert_eq!(*icon.get_pixel(14, 2), SUN_COLOR);
+        assert_eq!(icon.get_pixel(0, 0).0, [0, 0, 0, 0]);
+    }
+}
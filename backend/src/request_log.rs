@@ -0,0 +1,127 @@
+//! One structured log line per HTTP request: method, path, status, latency, and
+//! who made it (API key label and device ID). `log_format = "json"` turns these
+//! into JSON objects like every other log line. Response bytes are also counted
+//! against the labeled key that made the request.
+
+use axum::{
+    body::{Body, HttpBody},
+    extract::{MatchedPath, Request, State},
+    http::{header, request::Parts},
+    middleware::Next,
+    response::Response,
+};
+use futures_util::TryStreamExt;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::AppState;
+use crate::{timing, versioning};
+
+/// Who a request authenticated as. The middleware puts an empty one in the
+/// request's extensions and the auth extractors fill it in.
+#[derive(Debug, Clone, Default)]
+pub struct RequestIdentity(Arc<Mutex<Option<Identity>>>);
+
+#[derive(Debug, Clone)]
+struct Identity {
+    /// Key label, or how the request authenticated (e.g., "admin_key", "signed_url")
+    key: String,
+    /// Registered device the key belongs to
+    device: Option<String>,
+}
+
+/// Record who the request in `parts` authenticated as.
+pub fn record_identity(parts: &Parts, key: impl Into<String>, device: Option<&str>) {
+    if let Some(identity) = parts.extensions.get::<RequestIdentity>() {
+        *identity.0.lock().unwrap() = Some(Identity {
+            key: key.into(),
+            device: device.map(String::from),
+        });
+    }
+}
+
+/// Middleware logging each request once its response is ready. For streams,
+/// the latency is the time to the first byte.
+pub async fn log_requests(
+    State(state): State<Arc<AppState>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let identity = RequestIdentity::default();
+    request.extensions_mut().insert(identity.clone());
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string());
+    let started = Instant::now();
+
+    let (response, breakdown) = timing::measure(next.run(request)).await;
+
+    let elapsed = started.elapsed();
+    let latency_ms = elapsed.as_micros() as f64 / 1000.0;
+    let status = response.status().as_u16();
+    let identity = identity.0.lock().unwrap().take();
+    let key = identity.as_ref().map(|i| i.key.as_str());
+    let device = identity.as_ref().and_then(|i| i.device.as_deref());
+
+    if response.status().is_server_error() {
+        tracing::warn!(target: "http", %method, %path, status, latency_ms, key, device, "Request failed");
+    } else if path.starts_with("/health/") {
+        // Probes every few seconds would drown out everything else
+        tracing::debug!(target: "http", %method, %path, status, latency_ms, "Request");
+    } else {
+        tracing::info!(target: "http", %method, %path, status, latency_ms, key, device, "Request");
+    }
+
+    // Budgets are set once for every version of a route
+    let budget = route
+        .as_deref()
+        .and_then(|r| state.config.latency.budget(&versioning::unversioned(r)));
+    if let Some(budget) = budget.filter(|b| elapsed > *b) {
+        let ms = |d: std::time::Duration| d.as_micros() as f64 / 1000.0;
+        let breakdown = (!breakdown.is_empty()).then_some(breakdown);
+        tracing::warn!(
+            target: "http",
+            %method,
+            %path,
+            route = route.as_deref(),
+            latency_ms,
+            budget_ms = budget.as_millis() as u64,
+            espn_ms = breakdown.map(|b| ms(b.espn)),
+            transform_ms = breakdown.map(|b| ms(b.transform)),
+            serialize_ms = breakdown.map(|b| ms(b.serialize)),
+            key,
+            device,
+            "Request over latency budget"
+        );
+    }
+
+    match identity {
+        Some(identity) => count_bytes(state, identity.key, response),
+        None => response,
+    }
+}
+
+/// Count the response body's bytes against the key labeled `label`: all at
+/// once when its length is known, else chunk by chunk as it streams. Bodies of
+/// known length are left alone so they keep their Content-Length.
+fn count_bytes(state: Arc<AppState>, label: String, response: Response) -> Response {
+    let length = response
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .or_else(|| response.body().size_hint().exact());
+    if let Some(length) = length {
+        state.api_keys.record_bytes(&label, length);
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let counted = body.into_data_stream().inspect_ok(move |chunk| {
+        state.api_keys.record_bytes(&label, chunk.len() as u64);
+    });
+    Response::from_parts(parts, Body::from_stream(counted))
+}
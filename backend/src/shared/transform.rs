@@ -1,6 +1,8 @@
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 
+use crate::error::AppError;
 use crate::espn::types::{EspnCompetitor, EspnEvent};
+use crate::sport::EspnLeague;
 
 use super::types::{Color, Team, Winner};
 
@@ -15,15 +17,51 @@ pub fn parse_espn_date(date: &str) -> i64 {
         })
 }
 
-/// Parse a hex color string (without #) to RGB
+/// Seconds from now until a Unix timestamp; negative once it has passed, and
+/// 0 for an unknown (0) timestamp.
+pub fn seconds_until(timestamp: i64) -> i64 {
+    if timestamp == 0 {
+        return 0;
+    }
+    timestamp - Utc::now().timestamp()
+}
+
+/// Seconds from now until a Unix timestamp, never negative; `None` for an
+/// unknown (0) timestamp.
+pub fn seconds_until_kickoff(timestamp: i64) -> Option<u32> {
+    if timestamp == 0 {
+        return None;
+    }
+    u32::try_from(seconds_until(timestamp).max(0)).ok()
+}
+
+/// Secondary color for teams without one
+pub const DEFAULT_SECONDARY_COLOR: Color = Color { r: 255, g: 255, b: 255 };
+
+/// A competitor's secondary color, white if ESPN lists none.
+pub fn secondary_color(competitor: &EspnCompetitor) -> Color {
+    competitor
+        .team
+        .alternate_color
+        .as_deref()
+        .map_or(DEFAULT_SECONDARY_COLOR, parse_hex_color)
+}
+
+/// Parse a hex color string (without #) to RGB. Channels missing or not hex
+/// are 0.
 pub fn parse_hex_color(hex: &str) -> Color {
     let hex = hex.trim_start_matches('#');
+    let channel = |range| {
+        hex.get(range)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+            .unwrap_or(0)
+    };
 
-    let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(0);
-    let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(0);
-    let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0);
-
-    Color { r, g, b }
+    Color {
+        r: channel(0..2),
+        g: channel(2..4),
+        b: channel(4..6),
+    }
 }
 
 /// Get broadcast network from event
@@ -60,28 +98,166 @@ pub fn parse_rank(competitor: &EspnCompetitor, is_college: bool) -> Option<u8> {
 }
 
 /// Transform ESPN competitor to our shared Team type (for pregame, no score)
-pub fn to_team(competitor: &EspnCompetitor, is_college: bool) -> Team {
+pub fn to_team(competitor: &EspnCompetitor, league: impl EspnLeague) -> Team {
     Team {
         abbreviation: competitor.team.abbreviation.clone(),
         color: parse_hex_color(competitor.team.color.as_deref().unwrap_or("000000")),
+        color_secondary: secondary_color(competitor),
         record: competitor.records.first().map(|r| r.summary.clone()),
-        rank: parse_rank(competitor, is_college),
+        rank: parse_rank(competitor, league.is_college()),
+        logo_path: league.logo_path(&competitor.team.id, &competitor.team.abbreviation),
     }
 }
 
+/// The event's competition (ESPN sends one per event)
+pub fn first_competition<'a, T>(competitions: &'a [T], event_id: &str) -> Result<&'a T, AppError> {
+    competitions
+        .first()
+        .ok_or_else(|| AppError::MissingCompetition(event_id.to_string()))
+}
+
 /// Extract home and away competitors from competition
-pub fn get_competitors(
-    competitors: &[EspnCompetitor],
-) -> (&EspnCompetitor, &EspnCompetitor) {
-    let home = competitors
-        .iter()
-        .find(|c| c.home_away == "home")
-        .expect("No home competitor found");
-
-    let away = competitors
-        .iter()
-        .find(|c| c.home_away == "away")
-        .expect("No away competitor found");
-
-    (home, away)
+pub fn get_competitors<'a>(
+    competitors: &'a [EspnCompetitor],
+    event_id: &str,
+) -> Result<(&'a EspnCompetitor, &'a EspnCompetitor), AppError> {
+    let find = |side: &'static str| {
+        competitors
+            .iter()
+            .find(|c| c.home_away == side)
+            .ok_or_else(|| AppError::MissingCompetitor {
+                event_id: event_id.to_string(),
+                side,
+            })
+    };
+
+    Ok((find("home")?, find("away")?))
+}
+
+/// Check that an event has the competition and competitors every transform needs.
+pub fn check_event(event: &EspnEvent) -> Result<(), AppError> {
+    let competition = first_competition(&event.competitions, &event.id)?;
+    get_competitors(&competition.competitors, &event.id).map(|_| ())
+}
+
+/// The transformed game, or `None` after logging why the event was left out
+/// of a game list.
+pub fn skip_malformed<T>(event_id: &str, result: Result<T, AppError>) -> Option<T> {
+    result
+        .map_err(|e| tracing::warn!(event_id, error = ?e, "Skipping ESPN event that can't be transformed"))
+        .ok()
+}
+
+/// Parse "MM:SS" format to seconds.
+pub fn parse_clock(clock: &str) -> Option<u16> {
+    let parts: Vec<&str> = clock.split(':').collect();
+    if parts.len() == 2 {
+        let mins: u16 = parts[0].parse().ok()?;
+        let secs: u16 = parts[1].parse().ok()?;
+        Some(mins * 60 + secs)
+    } else {
+        None
+    }
+}
+
+/// An enum value's name in the JSON API (e.g., "Q4" or "final/OT"), for
+/// formats that carry it as plain text.
+pub fn json_name(value: impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sport::{BasketballLeague, FootballLeague};
+
+    fn event(competitions: serde_json::Value) -> EspnEvent {
+        serde_json::from_value(serde_json::json!({
+            "id": "401",
+            "date": "2026-10-18T17:00Z",
+            "status": {
+                "period": 1,
+                "displayClock": "15:00",
+                "type": { "id": "1", "state": "pre", "shortDetail": "10/18 - 1:00 PM EDT" }
+            },
+            "competitions": competitions
+        }))
+        .unwrap()
+    }
+
+    fn competitor(home_away: &str, abbreviation: &str) -> serde_json::Value {
+        serde_json::json!({
+            "team": { "id": abbreviation, "abbreviation": abbreviation },
+            "homeAway": home_away
+        })
+    }
+
+    #[test]
+    fn test_check_event_rejects_missing_pieces() {
+        let complete = event(serde_json::json!([{
+            "competitors": [competitor("home", "KC"), competitor("away", "BUF")]
+        }]));
+        assert!(check_event(&complete).is_ok());
+
+        assert!(matches!(
+            check_event(&event(serde_json::json!([]))),
+            Err(AppError::MissingCompetition(_))
+        ));
+        assert!(matches!(
+            check_event(&event(serde_json::json!([{ "competitors": [competitor("away", "BUF")] }]))),
+            Err(AppError::MissingCompetitor { side: "home", .. })
+        ));
+        assert!(matches!(
+            check_event(&event(serde_json::json!([{ "competitors": [competitor("home", "KC")] }]))),
+            Err(AppError::MissingCompetitor { side: "away", .. })
+        ));
+    }
+
+    #[test]
+    fn test_short_hex_colors_dont_panic() {
+        assert_eq!(parse_hex_color("ff").r, 255);
+        assert_eq!(parse_hex_color("").b, 0);
+        assert_eq!(parse_hex_color("#00ff00").g, 255);
+    }
+
+    #[test]
+    fn test_countdowns_agree_and_unknown_starts_are_zero() {
+        assert_eq!(seconds_until(0), 0);
+        assert_eq!(seconds_until_kickoff(0), None);
+
+        let passed = Utc::now().timestamp() - 60;
+        assert!(seconds_until(passed) <= -60);
+        assert_eq!(seconds_until_kickoff(passed), Some(0));
+
+        let ahead = Utc::now().timestamp() + 3600;
+        let kickoff = seconds_until_kickoff(ahead).unwrap();
+        assert!(i64::from(kickoff).abs_diff(seconds_until(ahead)) <= 1);
+    }
+
+    #[test]
+    fn test_logo_path_by_id_for_college_teams() {
+        let team = |id: &str, abbreviation: &str| -> EspnCompetitor {
+            serde_json::from_value(serde_json::json!({
+                "team": { "id": id, "abbreviation": abbreviation },
+                "homeAway": "home"
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            to_team(&team("6", "DAL"), FootballLeague::Nfl).logo_path,
+            "/api/football/nfl/dal/logo"
+        );
+        assert_eq!(
+            to_team(&team("228", "CLEM"), FootballLeague::Ncaaf).logo_path,
+            "/api/football/ncaaf/228/logo"
+        );
+        assert_eq!(
+            to_team(&team("150", "DUKE"), BasketballLeague::Ncaab).logo_path,
+            "/api/basketball/ncaab/150/logo"
+        );
+    }
 }
@@ -1,8 +1,9 @@
-use serde::Serialize;
+use async_graphql::{Enum, SimpleObject};
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 /// RGB color as a strongly-typed struct
-#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, ToSchema, SimpleObject)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -11,26 +12,31 @@ pub struct Color {
 
 /// Team data shared across all game states.
 /// Used by both football and basketball pregame responses.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct Team {
     pub abbreviation: String,
     pub color: Color,
+    /// Secondary color for two-tone displays (white if ESPN lists none)
+    pub color_secondary: Color,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record: Option<String>,
     /// AP/Coaches ranking (college sports only; absent for pro leagues)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<u8>,
+    /// Path of the team's logo endpoint, e.g. "/api/football/ncaaf/228/logo"
+    /// (college teams by ESPN team ID, since their abbreviations collide)
+    pub logo_path: String,
 }
 
 /// Weather information (football only — basketball is indoor)
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
 pub struct Weather {
     pub temp: i16,
     pub description: String,
 }
 
 /// Final status variants — universal across all sports
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema, Enum)]
 #[serde(rename_all = "lowercase")]
 pub enum FinalStatus {
     Final,
@@ -39,10 +45,60 @@ pub enum FinalStatus {
 }
 
 /// Winner indicator — universal across all sports
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema, Enum)]
 #[serde(rename_all = "lowercase")]
 pub enum Winner {
     Home,
     Away,
     Tie,
 }
+
+/// Part of the season a game belongs to. Parsed from ESPN's numeric
+/// `seasontype` (1-4) in queries, or its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Enum)]
+pub enum SeasonType {
+    #[serde(rename(serialize = "preseason", deserialize = "1"), alias = "preseason")]
+    Preseason,
+    #[serde(rename(serialize = "regular", deserialize = "2"), alias = "regular")]
+    Regular,
+    #[serde(rename(serialize = "postseason", deserialize = "3"), alias = "postseason")]
+    Postseason,
+    #[serde(rename(serialize = "offseason", deserialize = "4"), alias = "offseason")]
+    Offseason,
+}
+
+impl SeasonType {
+    /// Parse ESPN's season type number.
+    pub fn from_espn(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Self::Preseason),
+            2 => Some(Self::Regular),
+            3 => Some(Self::Postseason),
+            4 => Some(Self::Offseason),
+            _ => None,
+        }
+    }
+
+    /// ESPN's season type number, as its `seasontype` query parameter takes it.
+    pub fn espn_id(self) -> u8 {
+        match self {
+            Self::Preseason => 1,
+            Self::Regular => 2,
+            Self::Postseason => 3,
+            Self::Offseason => 4,
+        }
+    }
+}
+
+/// Season and week a game is played in
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+pub struct Season {
+    pub year: u16,
+    #[serde(rename = "type")]
+    pub season_type: SeasonType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub week: Option<u8>,
+    /// Ready-to-show name for the week, e.g. "Preseason Wk 2", "Week 5",
+    /// or "Divisional Round"
+    pub label: String,
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::error::AppError;
 
 /// Trait for league types that can be used with the ESPN API.
@@ -16,6 +18,22 @@ pub trait EspnLeague {
 
     /// Whether this is a college league (affects ranking display, period format, etc.).
     fn is_college(&self) -> bool;
+
+    /// Our API path segments for the league (e.g., "football/nfl").
+    fn api_path(&self) -> &'static str;
+
+    /// Path of a team's logo endpoint (e.g., "/api/football/ncaaf/228/logo").
+    ///
+    /// College teams are addressed by ESPN's numeric team ID, since college
+    /// abbreviations collide; pro teams by abbreviation, as ESPN's CDN keys them.
+    fn logo_path(&self, team_id: &str, abbreviation: &str) -> String {
+        let team = if self.is_college() {
+            team_id.to_string()
+        } else {
+            abbreviation.to_lowercase()
+        };
+        format!("/api/{}/{}/logo", self.api_path(), team)
+    }
 }
 
 /// Football league identifiers.
@@ -60,6 +78,13 @@ impl EspnLeague for FootballLeague {
     fn is_college(&self) -> bool {
         matches!(self, Self::Ncaaf)
     }
+
+    fn api_path(&self) -> &'static str {
+        match self {
+            Self::Nfl => "football/nfl",
+            Self::Ncaaf => "football/ncaaf",
+        }
+    }
 }
 
 /// Basketball league identifiers.
@@ -104,4 +129,148 @@ impl EspnLeague for BasketballLeague {
     fn is_college(&self) -> bool {
         matches!(self, Self::Ncaab)
     }
+
+    fn api_path(&self) -> &'static str {
+        match self {
+            Self::Nba => "basketball/nba",
+            Self::Ncaab => "basketball/ncaab",
+        }
+    }
+}
+
+/// Any supported league, for endpoints that span sports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum League {
+    Football(FootballLeague),
+    Basketball(BasketballLeague),
+}
+
+impl League {
+    pub const ALL: [League; 4] = [
+        League::Football(FootballLeague::Nfl),
+        League::Football(FootballLeague::Ncaaf),
+        League::Basketball(BasketballLeague::Nba),
+        League::Basketball(BasketballLeague::Ncaab),
+    ];
+
+    pub fn from_league(s: &str) -> Result<Self, AppError> {
+        FootballLeague::from_league(s)
+            .map(League::Football)
+            .or_else(|_| BasketballLeague::from_league(s).map(League::Basketball))
+            .map_err(|_| AppError::InvalidLeague {
+                league: s.to_string(),
+                valid: "nfl, ncaaf, nba, ncaab",
+            })
+    }
+
+    /// The given league, or all of them.
+    pub fn one_or_all(league: Option<&str>) -> Result<Vec<Self>, AppError> {
+        match league {
+            Some(league) => Ok(vec![Self::from_league(league)?]),
+            None => Ok(Self::ALL.to_vec()),
+        }
+    }
+
+    /// Our API path segments for the league (e.g., "football/nfl").
+    pub fn api_path(self) -> &'static str {
+        match self {
+            League::Football(league) => league.api_path(),
+            League::Basketball(league) => league.api_path(),
+        }
+    }
+
+    /// Short name used in query parameters (e.g., "nfl").
+    pub fn name(self) -> &'static str {
+        let path = self.api_path();
+        &path[path.find('/').map_or(0, |i| i + 1)..]
+    }
+}
+
+/// Leagues in config files are their short names ("nfl").
+impl Serialize for League {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for League {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        League::from_league(&name).map_err(|_| {
+            serde::de::Error::unknown_variant(&name, &["nfl", "ncaaf", "nba", "ncaab"])
+        })
+    }
+}
+
+impl From<FootballLeague> for League {
+    fn from(league: FootballLeague) -> Self {
+        League::Football(league)
+    }
+}
+
+impl From<BasketballLeague> for League {
+    fn from(league: BasketballLeague) -> Self {
+        League::Basketball(league)
+    }
+}
+
+impl EspnLeague for League {
+    fn espn_sport(&self) -> &'static str {
+        match self {
+            League::Football(league) => league.espn_sport(),
+            League::Basketball(league) => league.espn_sport(),
+        }
+    }
+
+    fn espn_league(&self) -> &'static str {
+        match self {
+            League::Football(league) => league.espn_league(),
+            League::Basketball(league) => league.espn_league(),
+        }
+    }
+
+    fn espn_logo_path(&self) -> &'static str {
+        match self {
+            League::Football(league) => league.espn_logo_path(),
+            League::Basketball(league) => league.espn_logo_path(),
+        }
+    }
+
+    fn is_college(&self) -> bool {
+        match self {
+            League::Football(league) => league.is_college(),
+            League::Basketball(league) => league.is_college(),
+        }
+    }
+
+    fn api_path(&self) -> &'static str {
+        League::api_path(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_league_across_sports() {
+        assert_eq!(League::from_league("nba").unwrap(), League::Basketball(BasketballLeague::Nba));
+        assert_eq!(League::from_league("ncaaf").unwrap().name(), "ncaaf");
+        assert!(matches!(
+            League::from_league("mlb"),
+            Err(AppError::InvalidLeague { valid: "nfl, ncaaf, nba, ncaab", .. })
+        ));
+        assert_eq!(League::one_or_all(None).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_league_serializes_as_its_name() {
+        let leagues: Vec<League> = serde_json::from_str(r#"["nfl", "ncaab"]"#).unwrap();
+        assert_eq!(
+            leagues,
+            [League::Football(FootballLeague::Nfl), League::Basketball(BasketballLeague::Ncaab)]
+        );
+        assert_eq!(serde_json::to_string(&leagues).unwrap(), r#"["nfl","ncaab"]"#);
+        assert!(serde_json::from_str::<League>(r#""mlb""#).is_err());
+    }
 }
@@ -0,0 +1,314 @@
+//! Where state that instances can share is kept: ESPN scoreboards cached for
+//! `storage.scoreboard_ttl_secs`, registered devices, mock games, and which
+//! instance polls ESPN (see `poll`).
+//!
+//! The default keeps it in this process. Setting `storage.redis_url` keeps it
+//! in Redis instead, so several instances behind a load balancer serve the
+//! same devices and mock games and share scoreboard fetches. Each instance
+//! keeps its own mock clock (`/api/admin/mock/clock`); a game moved ahead on
+//! one carries on from there on the others.
+//!
+//! Storage is best effort: a failed read or write is logged, and callers go
+//! on as if nothing was stored.
+
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::StorageConfig;
+
+/// Longest wait to connect to Redis or for it to answer. Storage is only
+/// worth using while it's faster than going without.
+const REDIS_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug)]
+pub struct StorageError(String);
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<redis::RedisError> for StorageError {
+    fn from(error: redis::RedisError) -> Self {
+        Self(error.to_string())
+    }
+}
+
+/// A key-value store for bytes.
+#[async_trait]
+pub trait Storage: fmt::Debug + Send + Sync {
+    /// Whether other instances see what this one stores.
+    fn is_shared(&self) -> bool;
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StorageError>;
+
+    /// Store a value, forgetting it after `ttl` if given.
+    async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), StorageError>;
+
+    /// Every field of the hash at `key`, which is empty if there is none.
+    async fn get_fields(&self, key: &str) -> Result<HashMap<String, Vec<u8>>, StorageError>;
+
+    /// One field of the hash at `key`.
+    async fn get_field(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>, StorageError>;
+
+    /// Store one field of the hash at `key`, leaving the others as they are,
+    /// so instances changing different fields don't overwrite each other.
+    async fn set_field(&self, key: &str, field: &str, value: &[u8]) -> Result<(), StorageError>;
+
+    /// Remove one field of the hash at `key`.
+    async fn remove_field(&self, key: &str, field: &str) -> Result<(), StorageError>;
+
+    /// Take the lease on `key` for `holder`, or renew it if `holder` already
+    /// has it, for `ttl`. Returns whether `holder` has it, which it keeps
+    /// until `ttl` passes without a renewal.
+    async fn lease(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, StorageError>;
+}
+
+/// A stored value and when it expires, if it does
+type Entry = (Vec<u8>, Option<Instant>);
+
+/// Storage in this process, lost on restart
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: Mutex<HashMap<String, Entry>>,
+    hashes: Mutex<HashMap<String, HashMap<String, Vec<u8>>>>,
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    fn is_shared(&self) -> bool {
+        false
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries
+            .get(key)
+            .filter(|(_, expires)| expires.is_none_or(|at| at > Instant::now()))
+            .map(|(value, _)| value.clone()))
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), StorageError> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (_, expires)| expires.is_none_or(|at| at > now));
+        entries.insert(key.to_string(), (value.to_vec(), ttl.map(|ttl| now + ttl)));
+        Ok(())
+    }
+
+    async fn get_fields(&self, key: &str) -> Result<HashMap<String, Vec<u8>>, StorageError> {
+        Ok(self.hashes.lock().unwrap().get(key).cloned().unwrap_or_default())
+    }
+
+    async fn get_field(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let hashes = self.hashes.lock().unwrap();
+        Ok(hashes.get(key).and_then(|fields| fields.get(field)).cloned())
+    }
+
+    async fn set_field(&self, key: &str, field: &str, value: &[u8]) -> Result<(), StorageError> {
+        let mut hashes = self.hashes.lock().unwrap();
+        hashes
+            .entry(key.to_string())
+            .or_default()
+            .insert(field.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    async fn remove_field(&self, key: &str, field: &str) -> Result<(), StorageError> {
+        if let Some(fields) = self.hashes.lock().unwrap().get_mut(key) {
+            fields.remove(field);
+        }
+        Ok(())
+    }
+
+    async fn lease(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, StorageError> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        let held_by_other = entries.get(key).is_some_and(|(value, expires)| {
+            value != holder.as_bytes() && expires.is_none_or(|at| at > now)
+        });
+        if held_by_other {
+            return Ok(false);
+        }
+        entries.insert(key.to_string(), (holder.as_bytes().to_vec(), Some(now + ttl)));
+        Ok(true)
+    }
+}
+
+/// Renews the lease in KEYS[1] if ARGV[1] holds it, else takes it if free
+const LEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'PX', ARGV[2]) then
+    return 1
+end
+return 0
+"#;
+
+/// Storage in Redis, shared by every instance using the same `key_prefix`
+pub struct RedisStorage {
+    connection: ConnectionManager,
+    prefix: String,
+}
+
+impl fmt::Debug for RedisStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisStorage").field("prefix", &self.prefix).finish()
+    }
+}
+
+impl RedisStorage {
+    /// Connect to Redis. A lost connection is re-established on the next
+    /// command rather than retried in the meantime.
+    pub async fn connect(url: &str, prefix: &str) -> Result<Self, StorageError> {
+        let client = redis::Client::open(url)?;
+        let config = ConnectionManagerConfig::new()
+            .set_connection_timeout(REDIS_TIMEOUT)
+            .set_response_timeout(REDIS_TIMEOUT)
+            .set_number_of_retries(0);
+        Ok(Self {
+            connection: ConnectionManager::new_with_config(client, config).await?,
+            prefix: prefix.to_string(),
+        })
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}:{}", self.prefix, key)
+    }
+}
+
+#[async_trait]
+impl Storage for RedisStorage {
+    fn is_shared(&self) -> bool {
+        true
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let mut connection = self.connection.clone();
+        Ok(connection.get(self.key(key)).await?)
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), StorageError> {
+        let mut connection = self.connection.clone();
+        match ttl {
+            Some(ttl) => {
+                let millis = ttl.as_millis().max(1) as u64;
+                let () = redis::cmd("SET")
+                    .arg(self.key(key))
+                    .arg(value)
+                    .arg("PX")
+                    .arg(millis)
+                    .query_async(&mut connection)
+                    .await?;
+            }
+            None => {
+                let () = connection.set(self.key(key), value).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_fields(&self, key: &str) -> Result<HashMap<String, Vec<u8>>, StorageError> {
+        let mut connection = self.connection.clone();
+        Ok(connection.hgetall(self.key(key)).await?)
+    }
+
+    async fn get_field(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let mut connection = self.connection.clone();
+        Ok(connection.hget(self.key(key), field).await?)
+    }
+
+    async fn set_field(&self, key: &str, field: &str, value: &[u8]) -> Result<(), StorageError> {
+        let mut connection = self.connection.clone();
+        let _: i64 = connection.hset(self.key(key), field, value).await?;
+        Ok(())
+    }
+
+    async fn remove_field(&self, key: &str, field: &str) -> Result<(), StorageError> {
+        let mut connection = self.connection.clone();
+        let _: i64 = connection.hdel(self.key(key), field).await?;
+        Ok(())
+    }
+
+    async fn lease(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, StorageError> {
+        let mut connection = self.connection.clone();
+        let held: i64 = redis::cmd("EVAL")
+            .arg(LEASE_SCRIPT)
+            .arg(1)
+            .arg(self.key(key))
+            .arg(holder)
+            .arg(ttl.as_millis().max(1) as u64)
+            .query_async(&mut connection)
+            .await?;
+        Ok(held == 1)
+    }
+}
+
+/// The configured storage. If Redis can't be reached at startup, state stays
+/// in this process rather than keeping the server down.
+pub async fn connect(config: &StorageConfig) -> Arc<dyn Storage> {
+    let Some(url) = &config.redis_url else {
+        return Arc::new(MemoryStorage::default());
+    };
+    match RedisStorage::connect(url, &config.key_prefix).await {
+        Ok(redis) => {
+            tracing::info!(prefix = %config.key_prefix, "Sharing state through Redis");
+            Arc::new(redis)
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Couldn't connect to Redis — keeping state in memory, unshared");
+            Arc::new(MemoryStorage::default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_memory_storage_forgets_expired_values() {
+        let storage = MemoryStorage::default();
+        storage.set("kept", b"1", None).await.unwrap();
+        storage.set("expired", b"2", Some(Duration::ZERO)).await.unwrap();
+
+        assert_eq!(storage.get("kept").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(storage.get("expired").await.unwrap(), None);
+        assert_eq!(storage.get("missing").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_storage_fields_change_independently() {
+        let storage = MemoryStorage::default();
+        assert!(storage.get_fields("devices").await.unwrap().is_empty());
+        storage.set_field("devices", "a", b"1").await.unwrap();
+        storage.set_field("devices", "b", b"2").await.unwrap();
+        storage.set_field("devices", "a", b"3").await.unwrap();
+        storage.remove_field("devices", "b").await.unwrap();
+        storage.remove_field("missing", "b").await.unwrap();
+
+        let fields = storage.get_fields("devices").await.unwrap();
+        assert_eq!(fields, HashMap::from([("a".to_string(), b"3".to_vec())]));
+        assert_eq!(storage.get_field("devices", "a").await.unwrap(), Some(b"3".to_vec()));
+        assert_eq!(storage.get_field("devices", "b").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_lease_is_held_until_it_lapses() {
+        let storage = MemoryStorage::default();
+        let ttl = Duration::from_secs(60);
+        assert!(storage.lease("poller", "a", ttl).await.unwrap());
+        assert!(!storage.lease("poller", "b", ttl).await.unwrap());
+        assert!(storage.lease("poller", "a", ttl).await.unwrap());
+
+        storage.lease("poller", "a", Duration::ZERO).await.unwrap();
+        assert!(storage.lease("poller", "b", ttl).await.unwrap());
+    }
+}
@@ -0,0 +1,124 @@
+//! Short looping animations built around a team logo, such as the touchdown
+//! celebration, so firmware can play them without storing animation data.
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, Rgba, RgbaImage};
+
+use crate::error::AppError;
+
+/// Celebration frames: the logo on black, inside a border that alternates
+/// between `colors` and grows and shrinks over the loop.
+pub fn celebration_frames(
+    logo: &RgbaImage,
+    width: u32,
+    height: u32,
+    colors: [(u8, u8, u8); 2],
+    frame_count: usize,
+) -> Vec<RgbaImage> {
+    let max_border = (width.min(height) / 8).max(1);
+
+    (0..frame_count)
+        .map(|i| {
+            let (r, g, b) = colors[i % 2];
+            // Triangle wave: thickest halfway through the loop
+            let phase = i.min(frame_count - i) as u32;
+            let border = 1 + (max_border - 1) * 2 * phase / frame_count.max(1) as u32;
+
+            let mut frame = RgbaImage::from_fn(width, height, |x, y| {
+                let edge = x.min(y).min(width - 1 - x).min(height - 1 - y);
+                if edge < border {
+                    Rgba([r, g, b, 255])
+                } else {
+                    Rgba([0, 0, 0, 255])
+                }
+            });
+            let x = (width as i64 - logo.width() as i64) / 2;
+            let y = (height as i64 - logo.height() as i64) / 2;
+            image::imageops::overlay(&mut frame, logo, x, y);
+            frame
+        })
+        .collect()
+}
+
+/// Encode frames as a GIF that loops forever.
+pub fn encode_gif(frames: Vec<RgbaImage>, frame_ms: u16) -> Result<Vec<u8>, AppError> {
+    let mut buffer = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut buffer, 10);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+        let delay = Delay::from_numer_denom_ms(frame_ms as u32, 1);
+        let frames = frames
+            .into_iter()
+            .map(|f| Frame::from_parts(f, 0, 0, delay));
+        encoder
+            .encode_frames(frames)
+            .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+    }
+    Ok(buffer)
+}
+
+/// Encode frames as an animated PNG that loops forever.
+pub fn encode_apng(frames: &[RgbaImage], frame_ms: u16) -> Result<Vec<u8>, AppError> {
+    let (width, height) = frames.first().map_or((1, 1), |f| f.dimensions());
+
+    let mut buffer = Vec::new();
+    let mut encoder = png::Encoder::new(&mut buffer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+    encoder
+        .set_frame_delay(frame_ms, 1000)
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+    for frame in frames {
+        writer
+            .write_image_data(frame.as_raw())
+            .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+    }
+    writer
+        .finish()
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames() -> Vec<RgbaImage> {
+        let logo = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+        celebration_frames(&logo, 32, 32, [(255, 0, 0), (0, 0, 255)], 8)
+    }
+
+    #[test]
+    fn test_celebration_frames_alternate_border_colors() {
+        let frames = frames();
+
+        assert_eq!(frames.len(), 8);
+        assert_eq!(*frames[0].get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(*frames[1].get_pixel(0, 0), Rgba([0, 0, 255, 255]));
+        // Logo stays centered on black
+        assert_eq!(*frames[3].get_pixel(16, 16), Rgba([255, 255, 255, 255]));
+        assert_eq!(*frames[0].get_pixel(8, 8), Rgba([0, 0, 0, 255]));
+        // Border is thickest mid-loop
+        assert_eq!(*frames[4].get_pixel(3, 16), Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_encode_gif_and_apng_headers() {
+        let gif = encode_gif(frames(), 100).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+
+        let apng = encode_apng(&frames(), 100).unwrap();
+        assert_eq!(&apng[1..4], b"PNG");
+        assert!(apng.windows(4).any(|w| w == b"acTL"));
+    }
+}
@@ -0,0 +1,294 @@
+//! Cache of fully processed logo responses.
+//!
+//! Entries live in an in-memory LRU and, when `logo_cache.disk_dir` is set,
+//! on disk as well so they survive restarts. Each disk entry is a `.bin` body
+//! plus a `.json` file with its headers, named by a hash of the cache key.
+//! Once the disk entries outgrow `logo_cache.disk_max_mb`, the oldest written
+//! are removed.
+
+use bytes::Bytes;
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+use crate::config::LogoCacheConfig;
+
+/// An encoded logo and the headers that describe it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderedLogo {
+    /// Content headers (type, disposition, raw buffer layout)
+    pub headers: Vec<(String, String)>,
+    #[serde(skip)]
+    pub body: Bytes,
+}
+
+pub struct LogoCache {
+    memory: Mutex<LruCache<String, RenderedLogo>>,
+    disk_dir: Option<PathBuf>,
+    /// Most bytes of entries kept on disk
+    disk_max_bytes: u64,
+    /// Bytes of entries on disk, as of the last eviction plus writes since
+    disk_bytes: AtomicU64,
+}
+
+impl LogoCache {
+    pub fn new(config: &LogoCacheConfig) -> Self {
+        let disk_dir = config.disk_dir.as_ref().map(PathBuf::from).filter(|dir| {
+            match std::fs::create_dir_all(dir) {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!(
+                        path = %dir.display(),
+                        error = %e,
+                        "Logo cache directory unavailable — caching logos in memory only"
+                    );
+                    false
+                }
+            }
+        });
+
+        // Trim what earlier runs left, in case the cap has shrunk since
+        let disk_max_bytes = config.disk_max_mb.saturating_mul(1024 * 1024);
+        let disk_bytes = disk_dir
+            .as_deref()
+            .map_or(0, |dir| evict(dir, disk_max_bytes).unwrap_or(0));
+
+        Self {
+            memory: Mutex::new(LruCache::new(
+                NonZeroUsize::new(config.capacity).unwrap_or(NonZeroUsize::MIN),
+            )),
+            disk_dir,
+            disk_max_bytes,
+            disk_bytes: AtomicU64::new(disk_bytes),
+        }
+    }
+
+    /// Look up a logo in memory, then on disk.
+    pub async fn get(&self, key: &str) -> Option<RenderedLogo> {
+        if let Some(logo) = self.memory.lock().unwrap().get(key) {
+            return Some(logo.clone());
+        }
+
+        let path = entry_path(self.disk_dir.as_deref()?, key);
+        let body = tokio::fs::read(path.with_extension("bin")).await.ok()?;
+        let meta = tokio::fs::read(path.with_extension("json")).await.ok()?;
+        let mut logo: RenderedLogo = serde_json::from_slice(&meta).ok()?;
+        logo.body = Bytes::from(body);
+
+        self.memory
+            .lock()
+            .unwrap()
+            .put(key.to_string(), logo.clone());
+        Some(logo)
+    }
+
+    /// Store a logo in memory and, if configured, on disk.
+    pub async fn put(&self, key: String, logo: RenderedLogo) {
+        if let Some(dir) = &self.disk_dir {
+            let path = entry_path(dir, &key);
+            match write_entry(&path, &logo).await {
+                Ok(written) => self.evict_if_full(dir, written).await,
+                Err(e) => tracing::warn!(
+                    path = %path.display(),
+                    error = %e,
+                    "Failed to write logo cache entry"
+                ),
+            }
+        }
+
+        self.memory.lock().unwrap().put(key, logo);
+    }
+
+    /// Count `written` bytes against the disk cap, removing the oldest entries
+    /// once it's exceeded.
+    async fn evict_if_full(&self, dir: &Path, written: u64) {
+        let bytes = self.disk_bytes.fetch_add(written, Ordering::Relaxed) + written;
+        if bytes <= self.disk_max_bytes {
+            return;
+        }
+        let (dir, max_bytes) = (dir.to_path_buf(), self.disk_max_bytes);
+        match tokio::task::spawn_blocking(move || evict(&dir, max_bytes)).await {
+            Ok(Ok(remaining)) => self.disk_bytes.store(remaining, Ordering::Relaxed),
+            Ok(Err(e)) => tracing::warn!(error = %e, "Failed to evict logo cache entries"),
+            Err(e) => tracing::warn!(error = %e, "Logo cache eviction panicked"),
+        }
+    }
+
+    /// Number of logos held in memory.
+    pub fn memory_len(&self) -> usize {
+        self.memory.lock().unwrap().len()
+    }
+
+    /// Drop every cached logo, in memory and on disk. Returns how many were in
+    /// memory.
+    pub async fn clear(&self) -> usize {
+        let cleared = {
+            let mut memory = self.memory.lock().unwrap();
+            let len = memory.len();
+            memory.clear();
+            len
+        };
+
+        if let Some(dir) = &self.disk_dir {
+            match clear_dir(dir).await {
+                Ok(()) => self.disk_bytes.store(0, Ordering::Relaxed),
+                Err(e) => tracing::warn!(
+                    path = %dir.display(),
+                    error = %e,
+                    "Failed to clear logo cache directory"
+                ),
+            }
+        }
+        cleared
+    }
+}
+
+/// Remove the cache's entry files, leaving anything else in the directory.
+async fn clear_dir(dir: &Path) -> std::io::Result<()> {
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if matches!(path.extension().and_then(|e| e.to_str()), Some("bin" | "json")) {
+            tokio::fs::remove_file(path).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the body first: an entry only counts once its headers exist. Returns
+/// the bytes written.
+async fn write_entry(path: &Path, logo: &RenderedLogo) -> std::io::Result<u64> {
+    let meta = serde_json::to_vec(logo)?;
+    tokio::fs::write(path.with_extension("bin"), &logo.body).await?;
+    tokio::fs::write(path.with_extension("json"), &meta).await?;
+    Ok((logo.body.len() + meta.len()) as u64)
+}
+
+/// Remove the oldest written entries in `dir` until they total at most
+/// `max_bytes`. Returns the bytes left.
+fn evict(dir: &Path, max_bytes: u64) -> std::io::Result<u64> {
+    // Size and time written of each entry, by file stem. The headers are
+    // written last, so a body without them sorts first.
+    let mut entries: HashMap<PathBuf, (u64, SystemTime)> = HashMap::new();
+    for file in std::fs::read_dir(dir)? {
+        let path = file?.path();
+        let ext = path.extension().and_then(|e| e.to_str());
+        if !matches!(ext, Some("bin" | "json")) {
+            continue;
+        }
+        let meta = std::fs::metadata(&path)?;
+        let entry = entries
+            .entry(path.with_extension(""))
+            .or_insert((0, SystemTime::UNIX_EPOCH));
+        entry.0 += meta.len();
+        if ext == Some("json") {
+            entry.1 = meta.modified()?;
+        }
+    }
+
+    let mut total: u64 = entries.values().map(|(size, _)| size).sum();
+    let mut oldest: Vec<_> = entries.into_iter().collect();
+    oldest.sort_by_key(|(_, (_, modified))| *modified);
+    for (stem, (size, _)) in oldest {
+        if total <= max_bytes {
+            break;
+        }
+        for ext in ["json", "bin"] {
+            match std::fs::remove_file(stem.with_extension(ext)) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        total -= size;
+    }
+    Ok(total)
+}
+
+/// Disk path (without extension) for a cache key.
+fn entry_path(dir: &Path, key: &str) -> PathBuf {
+    dir.join(hex::encode(Sha256::digest(key.as_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logo(body: &'static [u8]) -> RenderedLogo {
+        RenderedLogo {
+            headers: vec![("content-type".to_string(), "image/png".to_string())],
+            body: Bytes::from_static(body),
+        }
+    }
+
+    fn cache(dir: &Path, disk_max_mb: u64) -> LogoCache {
+        LogoCache::new(&LogoCacheConfig {
+            capacity: 1,
+            disk_dir: Some(dir.to_string_lossy().into_owned()),
+            disk_max_mb,
+        })
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("logo-cache-{}", hex::encode(rand::random::<[u8; 8]>())))
+    }
+
+    #[tokio::test]
+    async fn test_entries_reload_from_disk() {
+        let dir = temp_dir();
+        let first = cache(&dir, 1);
+        first.put("dal".to_string(), logo(b"star")).await;
+        first.put("nyg".to_string(), logo(b"ny")).await;
+        // Only one fits in memory, so this one is read back from disk
+        assert_eq!(first.get("dal").await.unwrap().body, Bytes::from_static(b"star"));
+
+        let restarted = cache(&dir, 1);
+        assert_eq!(restarted.memory_len(), 0);
+        let nyg = restarted.get("nyg").await.unwrap();
+        assert_eq!(nyg.body, Bytes::from_static(b"ny"));
+        assert_eq!(nyg.headers, logo(b"").headers);
+        assert!(restarted.get("phi").await.is_none());
+
+        restarted.clear().await;
+        assert!(cache(&dir, 1).get("dal").await.is_none());
+
+        // With no room on disk, entries only last in memory
+        let full = cache(&dir, 0);
+        full.put("dal".to_string(), logo(b"star")).await;
+        assert!(full.get("dal").await.is_some());
+        assert!(cache(&dir, 0).get("dal").await.is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_evict_removes_oldest_entries_first() {
+        let dir = temp_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        for (i, name) in ["old", "mid", "new"].into_iter().enumerate() {
+            let path = dir.join(name);
+            std::fs::write(path.with_extension("bin"), [0; 60]).unwrap();
+            std::fs::write(path.with_extension("json"), [0; 40]).unwrap();
+            let modified = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(i as u64);
+            std::fs::File::options()
+                .write(true)
+                .open(path.with_extension("json"))
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+        std::fs::write(dir.join("README"), "not an entry").unwrap();
+
+        assert_eq!(evict(&dir, 300).unwrap(), 300);
+        assert_eq!(evict(&dir, 250).unwrap(), 200);
+        assert!(!dir.join("old.bin").exists() && !dir.join("old.json").exists());
+        assert!(dir.join("mid.json").exists() && dir.join("README").exists());
+        assert_eq!(evict(&dir, 0).unwrap(), 0);
+        assert!(!dir.join("new.bin").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,20 +1,39 @@
 use axum::{
+    Json,
     body::Body,
     extract::{Path, Query, State},
     http::{HeaderMap, Response, StatusCode, header},
 };
+use bytes::Bytes;
+use futures_util::future::try_join_all;
+use image::{Rgba, RgbaImage};
 use std::sync::Arc;
 
 use crate::AppState;
 use crate::auth::ApiKey;
 use crate::error::{AppError, ErrorResponse};
-use crate::sport::{BasketballLeague, EspnLeague, FootballLeague};
+use crate::mock::teams::{NFL_TEAMS, find_team};
+use crate::render::handler::{FrameEncoding, encode_frame};
+use crate::shared::types::Color;
+use crate::sport::{BasketballLeague, EspnLeague, FootballLeague, League};
 
+use super::animation::{celebration_frames, encode_apng, encode_gif};
+use super::cache::RenderedLogo;
 use super::image::{
-    blend_with_background, decode_png, encode_png, encode_ppm_p6, encode_rgb565_raw,
-    encode_rgb888_raw, parse_hex_color, resize_image,
+    adjust_colors, blend_with_background, contrasting_background, decode_png, dither_rgb565,
+    dominant_colors, encode_bmp_24, encode_indexed_raw, encode_png, encode_png_indexed,
+    encode_ppm_p6, encode_qoi, encode_rgb565_raw, encode_rgb888_raw, encode_rle, orient, pad_image,
+    parse_hex_color, quantize, resize_image, trim_transparent,
 };
-use super::types::{LogoQuery, OutputFormat};
+use super::sheet::{compose_sheet, sheet_index, tile_size};
+use super::types::{
+    AnimationFormat, CelebrationQuery, ColorShare, ColorSwatchQuery, Compression, Crop, Fit,
+    LogoQuery, LogoVariant, OutputFormat, ResizeFilter, SpriteSheetIndex, SwatchColors, TeamColors,
+    TeamColorsQuery,
+};
+
+/// `background_color` value that picks black or white to contrast with the logo.
+const AUTO_BACKGROUND: &str = "auto";
 
 /// Determine output format from Accept header.
 /// Uses get_all() to check all Accept header values, since browsers and API
@@ -31,6 +50,15 @@ fn parse_accept_header(headers: &HeaderMap) -> OutputFormat {
             if accept_str.contains("image/x-portable-pixmap") {
                 return OutputFormat::Ppm;
             }
+            if accept_str.contains("image/bmp") {
+                return OutputFormat::Bmp;
+            }
+            if accept_str.contains("image/x-indexed") {
+                return OutputFormat::Indexed;
+            }
+            if accept_str.contains("image/qoi") {
+                return OutputFormat::Qoi;
+            }
         }
     }
     // Default to PNG for */*, image/png, or any other value
@@ -41,33 +69,250 @@ fn parse_accept_header(headers: &HeaderMap) -> OutputFormat {
 async fn get_team_logo_impl(
     _api_key: ApiKey,
     state: State<Arc<AppState>>,
-    league: impl EspnLeague,
+    league: League,
     team_id: String,
     params: LogoQuery,
     headers: HeaderMap,
 ) -> Result<Response<Body>, AppError> {
-    let output_format = parse_accept_header(&headers);
-    // Parse background color early to fail fast on invalid input
-    let background = if let Some(ref hex) = params.background_color {
-        Some(parse_hex_color(hex)?)
-    } else {
-        None
+    let params = apply_device(&state, params)?;
+    let output_format = params
+        .format
+        .unwrap_or_else(|| parse_accept_header(&headers));
+
+    // Every query parameter changes the output, so all of them are part of the key
+    let cache_key = format!(
+        "{}/{}/{:?}/{:?}",
+        league.espn_logo_path(),
+        team_id.to_lowercase(),
+        output_format,
+        params
+    );
+
+    logo_response(
+        &state,
+        cache_key,
+        render_logo(&state, league, &team_id, &params, output_format),
+    )
+    .await
+}
+
+/// Serve a rendered logo from the cache, rendering and storing it on a miss.
+async fn logo_response(
+    state: &AppState,
+    cache_key: String,
+    render: impl Future<Output = Result<RenderedLogo, AppError>>,
+) -> Result<Response<Body>, AppError> {
+    let (logo, cache_status) = match state.logo_cache.get(&cache_key).await {
+        Some(logo) => (logo, "HIT"),
+        None => {
+            let logo = render.await?;
+            state.logo_cache.put(cache_key, logo.clone()).await;
+            (logo, "MISS")
+        }
     };
 
-    let supports_transparency = output_format == OutputFormat::Png;
+    let mut response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CACHE_CONTROL, "public, max-age=86400")
+        .header(header::VARY, "Accept")
+        .header("X-Cache", cache_status);
+    for (name, value) in &logo.headers {
+        response = response.header(name, value);
+    }
+
+    Ok(response.body(Body::from(logo.body)).unwrap())
+}
+
+/// Fetch, process, and encode a logo, returning the body and its content headers.
+async fn render_logo(
+    state: &AppState,
+    league: League,
+    team_id: &str,
+    params: &LogoQuery,
+    output_format: OutputFormat,
+) -> Result<RenderedLogo, AppError> {
+    validate_params(params)?;
 
     // Fetch native 500x500 logo from ESPN CDN
     let logo_bytes = state
-        .espn_client
-        .fetch_logo(league, &team_id)
+        .provider
+        .fetch_logo(league, team_id, params.variant)
         .await?;
 
+    render_png(&logo_bytes, params, output_format)
+}
+
+/// Serve a logo from PNG bytes produced locally rather than fetched from ESPN,
+/// with the same query parameters and caching as the ESPN-backed endpoints.
+/// `key` identifies the source image in the cache.
+pub async fn serve_generated_logo(
+    state: &AppState,
+    key: &str,
+    params: LogoQuery,
+    headers: &HeaderMap,
+    generate: impl FnOnce() -> Result<Vec<u8>, AppError>,
+) -> Result<Response<Body>, AppError> {
+    let params = apply_device(state, params)?;
+    let output_format = params
+        .format
+        .unwrap_or_else(|| parse_accept_header(headers));
+    let cache_key = format!("{key}/{output_format:?}/{params:?}");
+
+    let render = async {
+        validate_params(&params)?;
+        render_png(&generate()?, &params, output_format)
+    };
+    logo_response(state, cache_key, render).await
+}
+
+/// Fill in unset parameters from the requested device profile, if any.
+fn apply_device(state: &AppState, params: LogoQuery) -> Result<LogoQuery, AppError> {
+    match params.device.clone() {
+        Some(name) => Ok(params.with_device(state.live.read().unwrap().device(&name)?)),
+        None => Ok(params),
+    }
+}
+
+/// Process and encode one source PNG.
+fn render_png(
+    logo_bytes: &[u8],
+    params: &LogoQuery,
+    output_format: OutputFormat,
+) -> Result<RenderedLogo, AppError> {
+    let processed = process_logo(logo_bytes, params, output_format)?;
+    let mut logo = encode_logo(processed.image, params, output_format, "logo")?;
+    if let Some((r, g, b)) = processed.auto_background {
+        logo.headers.push((
+            "X-Background-Color".to_string(),
+            format!("{r:02X}{g:02X}{b:02X}"),
+        ));
+    }
+    Ok(logo)
+}
+
+/// Fetch every NFL logo, process each one, and encode them as a single sheet.
+async fn render_sheet(
+    state: &AppState,
+    params: &LogoQuery,
+    output_format: OutputFormat,
+) -> Result<RenderedLogo, AppError> {
+    validate_params(params)?;
+
+    let logos = try_join_all(NFL_TEAMS.iter().map(|team| {
+        state
+            .provider
+            .fetch_logo(FootballLeague::Nfl.into(), team.abbreviation, params.variant)
+    }))
+    .await?;
+
+    let tiles = logos
+        .iter()
+        .map(|bytes| process_logo(bytes, params, output_format).map(|p| p.image))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let index = nfl_sheet_index(params);
+    let sheet = compose_sheet(&tiles, &index);
+
+    let mut logo = encode_logo(sheet, params, output_format, "logos")?;
+    for (name, value) in [
+        ("X-Sheet-Columns", index.columns),
+        ("X-Tile-Width", index.tile_width),
+        ("X-Tile-Height", index.tile_height),
+    ] {
+        logo.headers.push((name.to_string(), value.to_string()));
+    }
+    Ok(logo)
+}
+
+fn nfl_sheet_index(params: &LogoQuery) -> SpriteSheetIndex {
+    let teams: Vec<&str> = NFL_TEAMS.iter().map(|team| team.abbreviation).collect();
+    let (tile_width, tile_height) = tile_size(params);
+    sheet_index(&teams, tile_width, tile_height)
+}
+
+/// Reject invalid query parameters before doing any fetching.
+fn validate_params(params: &LogoQuery) -> Result<(), AppError> {
+    let background = params
+        .background_color
+        .as_ref()
+        .filter(|hex| hex.as_str() != AUTO_BACKGROUND);
+    for hex in [background, params.padding_color.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        parse_hex_color(hex)?;
+    }
+    if let Some(count) = params.colors
+        && !(2..=256).contains(&count)
+    {
+        return Err(AppError::InvalidColorCount(count));
+    }
+    for (name, value) in [
+        ("gamma", params.gamma),
+        ("brightness", params.brightness),
+        ("saturation", params.saturation),
+    ] {
+        if value.is_some_and(|v| !v.is_finite() || v < 0.0) {
+            return Err(AppError::InvalidColorAdjustment(name.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// A logo ready for encoding.
+struct ProcessedLogo {
+    image: RgbaImage,
+    /// Background color picked for `background_color=auto`
+    auto_background: Option<(u8, u8, u8)>,
+}
+
+/// Decode a source logo and apply everything up to the final bit-depth reduction.
+fn process_logo(
+    logo_bytes: &[u8],
+    params: &LogoQuery,
+    output_format: OutputFormat,
+) -> Result<ProcessedLogo, AppError> {
+    let auto_background = params.background_color.as_deref() == Some(AUTO_BACKGROUND);
+    let background = params
+        .background_color
+        .as_deref()
+        .filter(|_| !auto_background)
+        .map(parse_hex_color)
+        .transpose()?;
+    let padding_color = params
+        .padding_color
+        .as_deref()
+        .map(parse_hex_color)
+        .transpose()?;
+
+    let supports_transparency = matches!(output_format, OutputFormat::Png | OutputFormat::Qoi);
+
     // Decode and resize using Lanczos3 for high-quality downscaling
-    let img = decode_png(&logo_bytes)?;
-    let resized = resize_image(&img, params.width, params.height);
+    let img = decode_png(logo_bytes)?;
+    let img = match params.crop {
+        Some(Crop::Auto) => trim_transparent(&img),
+        None => img,
+    };
+
+    // Judge contrast from the logo itself, which works for every league
+    let auto_background =
+        auto_background.then(|| contrasting_background(&img.thumbnail(64, 64).to_rgba8()));
+    let background = background.or(auto_background);
+
+    // Scale into the area inside the padding, then pad out to the requested size
+    let resized = resize_image(
+        &img,
+        params.width.saturating_sub(params.pad.saturating_mul(2)).max(1),
+        params.height.saturating_sub(params.pad.saturating_mul(2)).max(1),
+        params.fit,
+        params.filter,
+        padding_color,
+    );
+    let resized = pad_image(&resized, params.width, params.height, padding_color);
+    let resized = orient(resized, params.rotate, params.flip);
 
     // Apply background blending
-    // For formats without alpha (RGB565, RGB888, PPM), always blend against black
+    // For formats without alpha (RGB565, RGB888, PPM, BMP), always blend against black
     // to prevent semi-transparent pixels from producing visible artifacts.
     let processed = if let Some(bg) = background {
         blend_with_background(&resized, bg)
@@ -77,10 +322,48 @@ async fn get_team_logo_impl(
         resized
     };
 
+    // Color correction for the display, before any bit-depth reduction
+    let mut processed = processed;
+    adjust_colors(
+        &mut processed,
+        params.gamma.unwrap_or(1.0),
+        params.brightness.unwrap_or(1.0),
+        params.saturation.unwrap_or(1.0),
+    );
+    Ok(ProcessedLogo {
+        image: processed,
+        auto_background,
+    })
+}
+
+/// Reduce and encode a processed image; `name` is the download filename stem.
+fn encode_logo(
+    processed: RgbaImage,
+    params: &LogoQuery,
+    output_format: OutputFormat,
+    name: &str,
+) -> Result<RenderedLogo, AppError> {
+    // Spread quantization error before RGB565 truncates the low bits
+    let processed = match params.dither {
+        Some(method) if output_format == OutputFormat::Rgb565 => dither_rgb565(&processed, method),
+        _ => processed,
+    };
+
+    // Reduce to a palette when asked (indexed output always needs one)
+    let palette_size = match output_format {
+        OutputFormat::Indexed => Some(params.colors.unwrap_or(256)),
+        _ => params.colors,
+    };
+    let indexed = palette_size.map(|count| quantize(&processed, count as usize));
+    let processed = indexed.as_ref().map_or(processed, |i| i.to_rgba());
+
     // Encode to output format
     let (output_bytes, content_type) = match output_format {
         OutputFormat::Png => {
-            let bytes = encode_png(&processed)?;
+            let bytes = match &indexed {
+                Some(indexed) => encode_png_indexed(indexed)?,
+                None => encode_png(&processed)?,
+            };
             (bytes, OutputFormat::Png.content_type())
         }
         OutputFormat::Ppm => {
@@ -95,27 +378,388 @@ async fn get_team_logo_impl(
             let bytes = encode_rgb565_raw(&processed);
             (bytes, OutputFormat::Rgb565.content_type())
         }
+        OutputFormat::Bmp => {
+            let bytes = encode_bmp_24(&processed, params.top_down);
+            (bytes, OutputFormat::Bmp.content_type())
+        }
+        OutputFormat::Qoi => {
+            let bytes = encode_qoi(&processed);
+            (bytes, OutputFormat::Qoi.content_type())
+        }
+        OutputFormat::Indexed => {
+            let indexed = indexed.as_ref().expect("indexed output is always quantized");
+            (encode_indexed_raw(indexed), OutputFormat::Indexed.content_type())
+        }
     };
 
-    let mut response = Response::builder()
-        .status(StatusCode::OK)
-        .header(header::CONTENT_TYPE, content_type)
-        .header(header::CACHE_CONTROL, "public, max-age=86400")
-        .header(header::VARY, "Accept");
+    // Run-length encode the pixel data, leaving any palette table in front as-is
+    let rle_pixel_size = match params.compress {
+        Some(Compression::Rle) => output_format.raw_pixel_size(),
+        None => None,
+    };
+    let output_bytes = if let Some(pixel_size) = rle_pixel_size {
+        let (width, height) = processed.dimensions();
+        let header_len = output_bytes.len() - (width * height) as usize * pixel_size;
+        let mut compressed = output_bytes[..header_len].to_vec();
+        compressed.extend(encode_rle(&output_bytes[header_len..], pixel_size));
+        compressed
+    } else {
+        output_bytes
+    };
+
+    // Raw pixels requested via ?format= are plain bytes to be copied into a framebuffer
+    let content_type = if params.format.is_some() && output_format.is_raw() {
+        "application/octet-stream"
+    } else {
+        content_type
+    };
+
+    let mut headers = vec![(header::CONTENT_TYPE.to_string(), content_type.to_string())];
+    let mut add_header = |name: &str, value: String| headers.push((name.to_string(), value));
+
+    // Raw formats carry no header, so describe the buffer layout
+    if output_format.is_raw() {
+        let (width, height) = processed.dimensions();
+        add_header("X-Image-Width", width.to_string());
+        add_header("X-Image-Height", height.to_string());
+        match output_format {
+            OutputFormat::Rgb565 => {
+                add_header("X-Pixel-Format", "rgb565".to_string());
+                add_header("X-Byte-Order", "little-endian".to_string());
+            }
+            OutputFormat::Indexed => {
+                let palette_size = indexed.as_ref().map_or(0, |i| i.palette.len());
+                add_header("X-Pixel-Format", "indexed8".to_string());
+                add_header("X-Palette-Size", palette_size.to_string());
+            }
+            _ => add_header("X-Pixel-Format", "rgb888".to_string()),
+        }
+        if rle_pixel_size.is_some() {
+            add_header("X-Compression", "rle".to_string());
+        }
+    }
 
-    if matches!(output_format, OutputFormat::Png | OutputFormat::Ppm) {
+    if matches!(
+        output_format,
+        OutputFormat::Png | OutputFormat::Ppm | OutputFormat::Bmp | OutputFormat::Qoi
+    ) {
         let ext = match output_format {
             OutputFormat::Png => "png",
             OutputFormat::Ppm => "ppm",
+            OutputFormat::Bmp => "bmp",
+            OutputFormat::Qoi => "qoi",
             _ => unreachable!(),
         };
-        response = response.header(
-            header::CONTENT_DISPOSITION,
-            format!("inline; filename=\"logo.{ext}\""),
+        add_header(
+            header::CONTENT_DISPOSITION.as_str(),
+            format!("inline; filename=\"{name}.{ext}\""),
         );
     }
 
-    Ok(response.body(Body::from(output_bytes)).unwrap())
+    Ok(RenderedLogo {
+        headers,
+        body: Bytes::from(output_bytes),
+    })
+}
+
+/// GET /api/teams/logos/sheet
+///
+/// Every NFL logo in one image, processed like the single-logo endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/teams/logos/sheet",
+    params(LogoQuery),
+    responses(
+        (status = 200, description = "Sprite sheet image; see /api/teams/logos/sheet/index for tile offsets", content(
+            ("image/png"),
+            ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("image/x-indexed"),
+            ("image/x-rgb888"),
+            ("image/x-rgb565"),
+            ("application/octet-stream")
+        )),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "football"
+)]
+pub async fn get_logo_sheet(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<LogoQuery>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, AppError> {
+    let params = apply_device(&state, params)?;
+    let output_format = params
+        .format
+        .unwrap_or_else(|| parse_accept_header(&headers));
+    let cache_key = format!("sheet/nfl/{:?}/{:?}", output_format, params);
+
+    logo_response(
+        &state,
+        cache_key,
+        render_sheet(&state, &params, output_format),
+    )
+    .await
+}
+
+/// GET /api/teams/logos/sheet/index
+///
+/// Tile offsets for the sprite sheet requested with the same query parameters.
+#[utoipa::path(
+    get,
+    path = "/api/teams/logos/sheet/index",
+    params(LogoQuery),
+    responses(
+        (status = 200, description = "Sprite sheet layout", body = SpriteSheetIndex),
+        (status = 400, description = "Unknown device", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "football"
+)]
+pub async fn get_logo_sheet_index(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<LogoQuery>,
+) -> Result<Json<SpriteSheetIndex>, AppError> {
+    let params = apply_device(&state, params)?;
+    Ok(Json(nfl_sheet_index(&params)))
+}
+
+/// GET /api/teams/{team_id}/color
+///
+/// An NFL team's primary and/or secondary color as a solid swatch. In a raw
+/// format at the default 1x1 size this is just the color's bytes, so clients can
+/// set backlight or edge LEDs without parsing JSON.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{team_id}/color",
+    params(
+        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'dal', 'nyg')"),
+        ColorSwatchQuery
+    ),
+    responses(
+        (status = 200, description = "Color swatch", content(
+            ("application/octet-stream"),
+            ("image/png"),
+            ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi")
+        )),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Team not found", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "football"
+)]
+pub async fn get_team_color_swatch(
+    _api_key: ApiKey,
+    Path(team_id): Path<String>,
+    Query(params): Query<ColorSwatchQuery>,
+) -> Result<Response<Body>, AppError> {
+    let team = find_team(&team_id).ok_or_else(|| AppError::TeamNotFound(team_id.clone()))?;
+    let colors = match params.which {
+        SwatchColors::Primary => vec![team.color],
+        SwatchColors::Secondary => vec![team.color_secondary],
+        SwatchColors::Both => vec![team.color, team.color_secondary],
+    };
+
+    let (width, height) = (params.width.clamp(1, 64), params.height.clamp(1, 64));
+    let swatch = RgbaImage::from_fn(width * colors.len() as u32, height, |x, _| {
+        let Color { r, g, b } = colors[(x / width) as usize];
+        Rgba([r, g, b, 255])
+    });
+
+    let format = params.format.unwrap_or(OutputFormat::Rgb565);
+    let (response, body) = encode_frame(&swatch, FrameEncoding::Image(format), 256)?;
+    Ok(response
+        .header(header::CACHE_CONTROL, "public, max-age=86400")
+        .header("X-Color-Count", colors.len())
+        .body(Body::from(body))
+        .unwrap())
+}
+
+/// GET /api/teams/{team_id}/colors
+///
+/// Measures the dominant and accent colors of an NFL team's logo, for when the
+/// color ESPN reports doesn't match what the logo actually looks like.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{team_id}/colors",
+    params(
+        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'dal', 'nyg')"),
+        TeamColorsQuery
+    ),
+    responses(
+        (status = 200, description = "Logo colors", body = TeamColors),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Team not found", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "football"
+)]
+pub async fn get_team_colors(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path(team_id): Path<String>,
+    Query(params): Query<TeamColorsQuery>,
+) -> Result<Json<TeamColors>, AppError> {
+    measure_team_colors(&state, &team_id, params.count).await.map(Json)
+}
+
+/// Measure the colors of an NFL team's logo, keeping up to `count` (1-8).
+pub async fn measure_team_colors(
+    state: &AppState,
+    team_id: &str,
+    count: u8,
+) -> Result<TeamColors, AppError> {
+    let logo_bytes = state
+        .provider
+        .fetch_logo(FootballLeague::Nfl.into(), team_id, LogoVariant::Default)
+        .await?;
+
+    // A small copy is plenty for a color histogram
+    let img = decode_png(&logo_bytes)?.thumbnail(64, 64).to_rgba8();
+    let palette: Vec<ColorShare> = dominant_colors(&img, count.clamp(1, 8) as usize)
+        .into_iter()
+        .map(|((r, g, b), share)| ColorShare {
+            color: Color { r, g, b },
+            share,
+        })
+        .collect();
+
+    Ok(TeamColors {
+        team: team_id.to_lowercase(),
+        dominant: palette.first().map(|c| c.color),
+        accent: palette.get(1).map(|c| c.color),
+        palette,
+    })
+}
+
+/// GET /api/football/{league}/{team_id}/celebration
+///
+/// Looping touchdown celebration: the team logo inside a border flashing
+/// between the logo's two main colors.
+#[utoipa::path(
+    get,
+    path = "/api/football/{league}/{team_id}/celebration",
+    params(
+        ("league" = String, Path, description = "Football league: nfl or ncaaf"),
+        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'dal', 'nyg'), or ESPN team ID for college teams. Game responses give each team's `logo_path`."),
+        CelebrationQuery
+    ),
+    responses(
+        (status = 200, description = "Animation", content(
+            ("image/gif"),
+            ("image/apng"),
+            ("application/octet-stream")
+        )),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "Team not found", body = ErrorResponse),
+        (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+    tag = "football"
+)]
+pub async fn get_football_celebration(
+    _api_key: ApiKey,
+    State(state): State<Arc<AppState>>,
+    Path((league, team_id)): Path<(String, String)>,
+    Query(params): Query<CelebrationQuery>,
+) -> Result<Response<Body>, AppError> {
+    let football_league = FootballLeague::from_league(&league)?;
+    let cache_key = format!(
+        "celebration/{}/{}/{:?}",
+        football_league.espn_logo_path(),
+        team_id.to_lowercase(),
+        params
+    );
+
+    logo_response(
+        &state,
+        cache_key,
+        render_celebration(&state, football_league.into(), &team_id, &params),
+    )
+    .await
+}
+
+/// Fetch a logo and encode its celebration animation.
+async fn render_celebration(
+    state: &AppState,
+    league: League,
+    team_id: &str,
+    params: &CelebrationQuery,
+) -> Result<RenderedLogo, AppError> {
+    let logo_bytes = state
+        .provider
+        .fetch_logo(league, team_id, params.variant)
+        .await?;
+    let img = decode_png(&logo_bytes)?;
+
+    // Flash between the colors the logo actually shows
+    let palette = dominant_colors(&img.thumbnail(64, 64).to_rgba8(), 2);
+    let primary = palette.first().map_or((255, 255, 255), |&(color, _)| color);
+    let accent = palette.get(1).map_or((255, 255, 255), |&(color, _)| color);
+
+    let (width, height) = (params.width.clamp(8, 128), params.height.clamp(8, 128));
+    let inset = 2 * (width.min(height) / 8 + 1);
+    let logo = resize_image(
+        &img,
+        width.saturating_sub(inset).max(1),
+        height.saturating_sub(inset).max(1),
+        Fit::Contain,
+        ResizeFilter::default(),
+        None,
+    );
+    let frame_count = params.frames.clamp(2, 32) as usize;
+    let frames = celebration_frames(&logo, width, height, [primary, accent], frame_count);
+
+    let mut headers = Vec::new();
+    let mut add_header = |name: &str, value: String| headers.push((name.to_string(), value));
+    let body = match params.format {
+        AnimationFormat::Gif => {
+            add_header(header::CONTENT_TYPE.as_str(), "image/gif".to_string());
+            encode_gif(frames, params.frame_ms)?
+        }
+        AnimationFormat::Apng => {
+            add_header(header::CONTENT_TYPE.as_str(), "image/apng".to_string());
+            encode_apng(&frames, params.frame_ms)?
+        }
+        AnimationFormat::Rgb565 | AnimationFormat::Rgb888 => {
+            // Raw frames carry no header, so describe the buffer layout
+            add_header(
+                header::CONTENT_TYPE.as_str(),
+                "application/octet-stream".to_string(),
+            );
+            add_header("X-Image-Width", width.to_string());
+            add_header("X-Image-Height", height.to_string());
+            if params.format == AnimationFormat::Rgb565 {
+                add_header("X-Pixel-Format", "rgb565".to_string());
+                add_header("X-Byte-Order", "little-endian".to_string());
+                frames.iter().flat_map(encode_rgb565_raw).collect()
+            } else {
+                add_header("X-Pixel-Format", "rgb888".to_string());
+                frames.iter().flat_map(encode_rgb888_raw).collect()
+            }
+        }
+    };
+    add_header("X-Frame-Count", frame_count.to_string());
+    add_header("X-Frame-Delay-Ms", params.frame_ms.to_string());
+
+    Ok(RenderedLogo {
+        headers,
+        body: Bytes::from(body),
+    })
 }
 
 /// GET /api/football/{league}/{team_id}/logo
@@ -126,20 +770,25 @@ async fn get_team_logo_impl(
     path = "/api/football/{league}/{team_id}/logo",
     params(
         ("league" = String, Path, description = "Football league: nfl or ncaaf"),
-        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'dal', 'nyg')"),
+        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'dal', 'nyg'), or ESPN team ID for college teams. Game responses give each team's `logo_path`."),
         LogoQuery
     ),
     responses(
         (status = 200, description = "Logo image", content(
             ("image/png"),
             ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("image/x-indexed"),
             ("image/x-rgb888"),
-            ("image/x-rgb565")
+            ("image/x-rgb565"),
+            ("application/octet-stream")
         )),
         (status = 400, description = "Invalid parameters", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
         (status = 404, description = "Team not found", body = ErrorResponse),
         (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
     ),
     security(("api_key" = [])),
     tag = "football"
@@ -152,7 +801,7 @@ pub async fn get_football_team_logo(
     headers: HeaderMap,
 ) -> Result<Response<Body>, AppError> {
     let football_league = FootballLeague::from_league(&league)?;
-    get_team_logo_impl(api_key, state, football_league, team_id, params, headers).await
+    get_team_logo_impl(api_key, state, football_league.into(), team_id, params, headers).await
 }
 
 /// GET /api/basketball/{league}/{team_id}/logo
@@ -163,20 +812,25 @@ pub async fn get_football_team_logo(
     path = "/api/basketball/{league}/{team_id}/logo",
     params(
         ("league" = String, Path, description = "Basketball league: nba or ncaab"),
-        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'lal', 'bos')"),
+        ("team_id" = String, Path, description = "Team abbreviation (e.g., 'lal', 'bos'), or ESPN team ID for college teams. Game responses give each team's `logo_path`."),
         LogoQuery
     ),
     responses(
         (status = 200, description = "Logo image", content(
             ("image/png"),
             ("image/x-portable-pixmap"),
+            ("image/bmp"),
+            ("image/qoi"),
+            ("image/x-indexed"),
             ("image/x-rgb888"),
-            ("image/x-rgb565")
+            ("image/x-rgb565"),
+            ("application/octet-stream")
         )),
         (status = 400, description = "Invalid parameters", body = ErrorResponse),
         (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
         (status = 404, description = "Team not found", body = ErrorResponse),
         (status = 502, description = "Error fetching from ESPN", body = ErrorResponse),
+        (status = 503, description = "ESPN is rate limiting requests; see Retry-After", body = ErrorResponse),
     ),
     security(("api_key" = [])),
     tag = "basketball"
@@ -189,5 +843,5 @@ pub async fn get_basketball_team_logo(
     headers: HeaderMap,
 ) -> Result<Response<Body>, AppError> {
     let basketball_league = BasketballLeague::from_league(&league)?;
-    get_team_logo_impl(api_key, state, basketball_league, team_id, params, headers).await
+    get_team_logo_impl(api_key, state, basketball_league.into(), team_id, params, headers).await
 }
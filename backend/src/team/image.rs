@@ -1,8 +1,11 @@
 use image::{imageops::FilterType, DynamicImage, ImageFormat, Rgba, RgbaImage};
+use std::collections::HashMap;
 use std::io::Cursor;
 
 use crate::error::AppError;
 
+use super::types::{Dither, Fit, Flip, ResizeFilter, Rotation};
+
 /// Parse hex RGB888 color string (without #) into RGB tuple
 pub fn parse_hex_color(hex: &str) -> Result<(u8, u8, u8), AppError> {
     if hex.len() != 6 {
@@ -25,12 +28,113 @@ pub fn parse_hex_color(hex: &str) -> Result<(u8, u8, u8), AppError> {
 /// bleeding into visible edges during interpolation (the "gray fringe" problem).
 /// CatmullRom (bicubic) produces sharper results than Triangle (bilinear)
 /// without the ringing artifacts of Lanczos3.
-pub fn resize_image(img: &DynamicImage, width: u32, height: u32) -> RgbaImage {
+///
+/// `Fit::Contain` centers the scaled image on a canvas of `padding` color
+/// (transparent if `None`); `Fit::Cover` crops the overflow evenly from both sides.
+pub fn resize_image(
+    img: &DynamicImage,
+    width: u32,
+    height: u32,
+    fit: Fit,
+    filter: ResizeFilter,
+    padding: Option<(u8, u8, u8)>,
+) -> RgbaImage {
+    let (source_width, source_height) = (img.width().max(1), img.height().max(1));
+    let width_scale = width as f64 / source_width as f64;
+    let height_scale = height as f64 / source_height as f64;
+    let (scaled_width, scaled_height) = match fit {
+        Fit::Stretch => (width, height),
+        Fit::Contain | Fit::Cover => {
+            let scale = if fit == Fit::Contain {
+                width_scale.min(height_scale)
+            } else {
+                width_scale.max(height_scale)
+            };
+            (
+                ((source_width as f64 * scale).round() as u32).max(1),
+                ((source_height as f64 * scale).round() as u32).max(1),
+            )
+        }
+    };
+
     let mut rgba = img.to_rgba8();
     premultiply_alpha(&mut rgba);
-    let mut resized = image::imageops::resize(&rgba, width, height, FilterType::CatmullRom);
+    let mut resized = image::imageops::resize(
+        &rgba,
+        scaled_width,
+        scaled_height,
+        filter_type(filter),
+    );
     unpremultiply_alpha(&mut resized);
-    resized
+
+    pad_image(&resized, width, height, padding)
+}
+
+/// Center an image on a `width` x `height` canvas of `padding` color
+/// (transparent if `None`). Anything that doesn't fit is cropped evenly.
+pub fn pad_image(
+    img: &RgbaImage,
+    width: u32,
+    height: u32,
+    padding: Option<(u8, u8, u8)>,
+) -> RgbaImage {
+    if img.dimensions() == (width, height) {
+        return img.clone();
+    }
+
+    let fill = match padding {
+        Some((r, g, b)) => Rgba([r, g, b, 255]),
+        None => Rgba([0, 0, 0, 0]),
+    };
+    let mut canvas = RgbaImage::from_pixel(width, height, fill);
+    let x = (width as i64 - img.width() as i64) / 2;
+    let y = (height as i64 - img.height() as i64) / 2;
+    image::imageops::replace(&mut canvas, img, x, y);
+    canvas
+}
+
+/// Rotate clockwise, then mirror, to match how a display is mounted.
+pub fn orient(img: RgbaImage, rotate: Option<Rotation>, flip: Option<Flip>) -> RgbaImage {
+    let img = match rotate {
+        Some(Rotation::Rotate90) => image::imageops::rotate90(&img),
+        Some(Rotation::Rotate180) => image::imageops::rotate180(&img),
+        Some(Rotation::Rotate270) => image::imageops::rotate270(&img),
+        None => img,
+    };
+
+    match flip {
+        Some(Flip::Horizontal) => image::imageops::flip_horizontal(&img),
+        Some(Flip::Vertical) => image::imageops::flip_vertical(&img),
+        None => img,
+    }
+}
+
+/// Crop away fully transparent rows and columns around the image.
+///
+/// Images with no visible pixels are returned unchanged.
+pub fn trim_transparent(img: &DynamicImage) -> DynamicImage {
+    let rgba = img.to_rgba8();
+    let visible = || rgba.enumerate_pixels().filter(|(_, _, p)| p[3] > 0);
+
+    let (Some(left), Some(right), Some(top), Some(bottom)) = (
+        visible().map(|(x, _, _)| x).min(),
+        visible().map(|(x, _, _)| x).max(),
+        visible().map(|(_, y, _)| y).min(),
+        visible().map(|(_, y, _)| y).max(),
+    ) else {
+        return img.clone();
+    };
+
+    img.crop_imm(left, top, right - left + 1, bottom - top + 1)
+}
+
+fn filter_type(filter: ResizeFilter) -> FilterType {
+    match filter {
+        ResizeFilter::Nearest => FilterType::Nearest,
+        ResizeFilter::Triangle => FilterType::Triangle,
+        ResizeFilter::CatmullRom => FilterType::CatmullRom,
+        ResizeFilter::Lanczos => FilterType::Lanczos3,
+    }
 }
 
 /// Multiply RGB channels by alpha, neutralizing hidden RGB values in transparent pixels.
@@ -55,6 +159,40 @@ fn unpremultiply_alpha(img: &mut RgbaImage) {
     }
 }
 
+/// WCAG relative luminance of an sRGB color, from 0 (black) to 1 (white).
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f32 {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// WCAG contrast ratio between two colors, from 1 (identical) to 21 (black on white).
+pub fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f32 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Black or white, whichever contrasts more with the image's dominant color.
+///
+/// The better of the two always reaches at least 4.5:1 against any color.
+pub fn contrasting_background(img: &RgbaImage) -> (u8, u8, u8) {
+    let Some(&(dominant, _)) = dominant_colors(img, 1).first() else {
+        return (0, 0, 0);
+    };
+    let (black, white) = ((0, 0, 0), (255, 255, 255));
+    if contrast_ratio(dominant, black) >= contrast_ratio(dominant, white) {
+        black
+    } else {
+        white
+    }
+}
+
 /// Blend transparent pixels with a background color.
 /// Uses standard alpha compositing: out = src * alpha + bg * (1 - alpha)
 pub fn blend_with_background(img: &RgbaImage, bg: (u8, u8, u8)) -> RgbaImage {
@@ -95,6 +233,83 @@ pub fn encode_png(img: &RgbaImage) -> Result<Vec<u8>, AppError> {
     Ok(buffer.into_inner())
 }
 
+/// Encode as QOI ("Quite OK Image", https://qoiformat.org), RGBA with sRGB color.
+///
+/// Each pixel becomes the shortest of: a run of the previous pixel, an index into
+/// a 64-entry table of recently seen pixels, a small difference from the previous
+/// pixel, or the full RGB(A) value.
+pub fn encode_qoi(img: &RgbaImage) -> Vec<u8> {
+    const OP_INDEX: u8 = 0x00;
+    const OP_DIFF: u8 = 0x40;
+    const OP_LUMA: u8 = 0x80;
+    const OP_RUN: u8 = 0xC0;
+    const OP_RGB: u8 = 0xFE;
+    const OP_RGBA: u8 = 0xFF;
+    const MAX_RUN: u8 = 62;
+
+    let (width, height) = img.dimensions();
+    let mut out = Vec::with_capacity(14 + (width * height) as usize + 8);
+    out.extend_from_slice(b"qoif");
+    out.extend_from_slice(&width.to_be_bytes());
+    out.extend_from_slice(&height.to_be_bytes());
+    out.push(4); // RGBA
+    out.push(0); // sRGB with linear alpha
+
+    let mut seen = [[0u8; 4]; 64];
+    let mut prev = [0u8, 0, 0, 255];
+    let mut run = 0u8;
+    let pixel_count = img.pixels().len();
+
+    for (i, pixel) in img.pixels().enumerate() {
+        let px = pixel.0;
+        if px == prev {
+            run += 1;
+            if run == MAX_RUN || i == pixel_count - 1 {
+                out.push(OP_RUN | (run - 1));
+                run = 0;
+            }
+            continue;
+        }
+        if run > 0 {
+            out.push(OP_RUN | (run - 1));
+            run = 0;
+        }
+
+        let [r, g, b, a] = px;
+        let hash = (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64;
+        if seen[hash] == px {
+            out.push(OP_INDEX | hash as u8);
+        } else {
+            seen[hash] = px;
+            if a == prev[3] {
+                let dr = r.wrapping_sub(prev[0]) as i8;
+                let dg = g.wrapping_sub(prev[1]) as i8;
+                let db = b.wrapping_sub(prev[2]) as i8;
+                let (dr_dg, db_dg) = (dr.wrapping_sub(dg), db.wrapping_sub(dg));
+
+                if (-2..=1).contains(&dr) && (-2..=1).contains(&dg) && (-2..=1).contains(&db) {
+                    let (dr, dg, db) = ((dr + 2) as u8, (dg + 2) as u8, (db + 2) as u8);
+                    out.push(OP_DIFF | dr << 4 | dg << 2 | db);
+                } else if (-32..=31).contains(&dg)
+                    && (-8..=7).contains(&dr_dg)
+                    && (-8..=7).contains(&db_dg)
+                {
+                    out.push(OP_LUMA | (dg + 32) as u8);
+                    out.push(((dr_dg + 8) as u8) << 4 | (db_dg + 8) as u8);
+                } else {
+                    out.extend_from_slice(&[OP_RGB, r, g, b]);
+                }
+            } else {
+                out.extend_from_slice(&[OP_RGBA, r, g, b, a]);
+            }
+        }
+        prev = px;
+    }
+
+    out.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+    out
+}
+
 /// Convert image to PPM P6 binary format (RGB888, no alpha)
 pub fn encode_ppm_p6(img: &RgbaImage) -> Vec<u8> {
     let (width, height) = img.dimensions();
@@ -120,6 +335,292 @@ pub fn encode_ppm_p6(img: &RgbaImage) -> Vec<u8> {
     output
 }
 
+/// Convert image to an uncompressed 24-bit BMP (BGR, no alpha)
+///
+/// Rows are padded to a multiple of 4 bytes as the format requires. BMP rows
+/// are normally stored bottom-up; `top_down` writes them in display order
+/// instead (signalled by a negative height in the header).
+pub fn encode_bmp_24(img: &RgbaImage, top_down: bool) -> Vec<u8> {
+    const HEADER_SIZE: u32 = 14 + 40; // BITMAPFILEHEADER + BITMAPINFOHEADER
+
+    let (width, height) = img.dimensions();
+    let row_size = (width * 3).div_ceil(4) * 4;
+    let image_size = row_size * height;
+    let file_size = HEADER_SIZE + image_size;
+    let header_height = if top_down {
+        -(height as i32)
+    } else {
+        height as i32
+    };
+
+    let mut output = Vec::with_capacity(file_size as usize);
+
+    // BITMAPFILEHEADER
+    output.extend_from_slice(b"BM");
+    output.extend_from_slice(&file_size.to_le_bytes());
+    output.extend_from_slice(&[0; 4]); // reserved
+    output.extend_from_slice(&HEADER_SIZE.to_le_bytes()); // pixel data offset
+
+    // BITMAPINFOHEADER
+    output.extend_from_slice(&40u32.to_le_bytes());
+    output.extend_from_slice(&(width as i32).to_le_bytes());
+    output.extend_from_slice(&header_height.to_le_bytes());
+    output.extend_from_slice(&1u16.to_le_bytes()); // color planes
+    output.extend_from_slice(&24u16.to_le_bytes()); // bits per pixel
+    output.extend_from_slice(&0u32.to_le_bytes()); // BI_RGB (uncompressed)
+    output.extend_from_slice(&image_size.to_le_bytes());
+    output.extend_from_slice(&2835i32.to_le_bytes()); // 72 DPI horizontal
+    output.extend_from_slice(&2835i32.to_le_bytes()); // 72 DPI vertical
+    output.extend_from_slice(&0u32.to_le_bytes()); // palette colors
+    output.extend_from_slice(&0u32.to_le_bytes()); // important colors
+
+    let padding = (row_size - width * 3) as usize;
+    let mut write_row = |y: u32| {
+        for x in 0..width {
+            let Rgba([r, g, b, _]) = *img.get_pixel(x, y);
+            output.extend_from_slice(&[b, g, r]);
+        }
+        output.extend(std::iter::repeat_n(0, padding));
+    };
+
+    if top_down {
+        (0..height).for_each(&mut write_row);
+    } else {
+        (0..height).rev().for_each(&mut write_row);
+    }
+
+    output
+}
+
+/// An image reduced to a palette of at most 256 colors.
+pub struct IndexedImage {
+    pub width: u32,
+    pub height: u32,
+    pub palette: Vec<Rgba<u8>>,
+    /// One palette index per pixel, row-major
+    pub indices: Vec<u8>,
+}
+
+impl IndexedImage {
+    /// Expand back to full color, with every pixel replaced by its palette entry.
+    pub fn to_rgba(&self) -> RgbaImage {
+        RgbaImage::from_fn(self.width, self.height, |x, y| {
+            self.palette[self.indices[(y * self.width + x) as usize] as usize]
+        })
+    }
+}
+
+/// Quantize an image to at most `max_colors` colors (clamped to 1-256) using median cut.
+///
+/// Pixels are split into boxes along their widest RGBA channel until there are
+/// `max_colors` boxes or no box can be split further. Each box becomes one
+/// palette entry holding its average color.
+pub fn quantize(img: &RgbaImage, max_colors: usize) -> IndexedImage {
+    let max_colors = max_colors.clamp(1, 256);
+    let pixels: Vec<[u8; 4]> = img.pixels().map(|p| p.0).collect();
+
+    let mut boxes: Vec<Vec<usize>> = vec![(0..pixels.len()).collect()];
+    while boxes.len() < max_colors {
+        // Split the box with the widest channel range
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| {
+                let (channel, range) = widest_channel(&pixels, b);
+                (i, channel, range)
+            })
+            .max_by_key(|&(_, _, range)| range);
+
+        let Some((index, channel, range)) = widest else {
+            break;
+        };
+        if range == 0 {
+            break;
+        }
+
+        let mut split = std::mem::take(&mut boxes[index]);
+        split.sort_unstable_by_key(|&p| pixels[p][channel]);
+
+        // Cut at the median, moved to a value boundary so equal colors stay together
+        let median = pixels[split[split.len() / 2]][channel];
+        let mut cut = split.partition_point(|&p| pixels[p][channel] < median);
+        if cut == 0 {
+            cut = split.partition_point(|&p| pixels[p][channel] <= median);
+        }
+        let upper = split.split_off(cut);
+        boxes[index] = split;
+        boxes.push(upper);
+    }
+
+    let mut palette = Vec::with_capacity(boxes.len());
+    let mut indices = vec![0u8; pixels.len()];
+    for (palette_index, members) in boxes.iter().filter(|b| !b.is_empty()).enumerate() {
+        let mut sum = [0u64; 4];
+        for &p in members {
+            for channel in 0..4 {
+                sum[channel] += pixels[p][channel] as u64;
+            }
+            indices[p] = palette_index as u8;
+        }
+        let count = members.len() as u64;
+        palette.push(Rgba(sum.map(|s| ((s + count / 2) / count) as u8)));
+    }
+
+    let (width, height) = img.dimensions();
+    IndexedImage {
+        width,
+        height,
+        palette,
+        indices,
+    }
+}
+
+/// Channel with the largest spread of values among `members`, and that spread.
+fn widest_channel(pixels: &[[u8; 4]], members: &[usize]) -> (usize, u8) {
+    (0..4)
+        .map(|channel| {
+            let values = members.iter().map(|&p| pixels[p][channel]);
+            let min = values.clone().min().unwrap_or(0);
+            let max = values.max().unwrap_or(0);
+            (channel, max - min)
+        })
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+/// Pixels with alpha below this are background, not part of the logo.
+const VISIBLE_ALPHA: u8 = 128;
+
+/// Colors closer than this (RGB distance) count as the same color.
+const DISTINCT_COLOR_DISTANCE: u32 = 64;
+
+/// Most common visibly distinct colors of an image, with the share of visible
+/// pixels each one covers, most common first.
+///
+/// Pixels are grouped into a 4-bit-per-channel histogram. Buckets are then taken
+/// in order of size; a bucket too close to an already chosen color, or found once
+/// `max_colors` are chosen, is folded into the nearest chosen color instead.
+pub fn dominant_colors(img: &RgbaImage, max_colors: usize) -> Vec<((u8, u8, u8), f32)> {
+    let mut histogram: HashMap<[u8; 3], ([u64; 3], u64)> = HashMap::new();
+    for pixel in img.pixels().filter(|p| p[3] >= VISIBLE_ALPHA) {
+        let [r, g, b, _] = pixel.0;
+        let (sum, count) = histogram.entry([r >> 4, g >> 4, b >> 4]).or_default();
+        for (total, value) in sum.iter_mut().zip([r, g, b]) {
+            *total += value as u64;
+        }
+        *count += 1;
+    }
+
+    let mut buckets: Vec<([u64; 3], u64)> = histogram.into_values().collect();
+    buckets.sort_unstable_by_key(|&(sum, count)| (std::cmp::Reverse(count), sum));
+    let visible: u64 = buckets.iter().map(|&(_, count)| count).sum();
+
+    let average = |(sum, count): ([u64; 3], u64)| sum.map(|s| (s / count.max(1)) as u8);
+    let distance_sq = |a: [u8; 3], b: [u8; 3]| -> u32 {
+        a.iter()
+            .zip(b)
+            .map(|(&x, y)| (x as i32 - y as i32).pow(2) as u32)
+            .sum()
+    };
+
+    let mut chosen: Vec<([u64; 3], u64)> = Vec::new();
+    for bucket in buckets {
+        let color = average(bucket);
+        let full = chosen.len() >= max_colors.max(1);
+        let nearest = chosen
+            .iter_mut()
+            .map(|c| (distance_sq(average(*c), color), c))
+            .min_by_key(|(d, _)| *d);
+        match nearest {
+            Some((d, c)) if full || d < DISTINCT_COLOR_DISTANCE.pow(2) => {
+                for (total, value) in c.0.iter_mut().zip(bucket.0) {
+                    *total += value;
+                }
+                c.1 += bucket.1;
+            }
+            _ => chosen.push(bucket),
+        }
+    }
+
+    chosen.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    chosen
+        .into_iter()
+        .map(|bucket| {
+            let [r, g, b] = average(bucket);
+            ((r, g, b), bucket.1 as f32 / visible as f32)
+        })
+        .collect()
+}
+
+/// Encode an indexed image as a palette PNG (8-bit indices, tRNS chunk if any alpha)
+pub fn encode_png_indexed(img: &IndexedImage) -> Result<Vec<u8>, AppError> {
+    let palette: Vec<u8> = img.palette.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
+    let alpha: Vec<u8> = img.palette.iter().map(|p| p[3]).collect();
+
+    let mut buffer = Vec::new();
+    let mut encoder = png::Encoder::new(&mut buffer, img.width, img.height);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette);
+    if alpha.iter().any(|&a| a < 255) {
+        encoder.set_trns(alpha);
+    }
+
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+    writer
+        .write_image_data(&img.indices)
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+    writer
+        .finish()
+        .map_err(|e| AppError::ImageDecode(e.to_string()))?;
+
+    Ok(buffer)
+}
+
+/// Convert an indexed image to raw palette + index bytes
+///
+/// Layout:
+/// - Palette size N: u16, little-endian
+/// - Palette: N entries of R,G,B (3 bytes each)
+/// - Pixels: one palette index byte per pixel, row-major
+///
+/// Alpha is discarded.
+pub fn encode_indexed_raw(img: &IndexedImage) -> Vec<u8> {
+    let mut output = Vec::with_capacity(2 + img.palette.len() * 3 + img.indices.len());
+
+    output.extend_from_slice(&(img.palette.len() as u16).to_le_bytes());
+    for color in &img.palette {
+        output.extend_from_slice(&[color[0], color[1], color[2]]);
+    }
+    output.extend_from_slice(&img.indices);
+
+    output
+}
+
+/// Run-length encode raw pixel data
+///
+/// Output is a sequence of runs, each a count byte (1-255) followed by one
+/// pixel of `pixel_size` bytes to repeat `count` times. A trailing partial
+/// pixel is ignored.
+pub fn encode_rle(data: &[u8], pixel_size: usize) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut pixels = data.chunks_exact(pixel_size).peekable();
+
+    while let Some(pixel) = pixels.next() {
+        let mut count: u8 = 1;
+        while count < u8::MAX && pixels.peek() == Some(&pixel) {
+            pixels.next();
+            count += 1;
+        }
+        output.push(count);
+        output.extend_from_slice(pixel);
+    }
+
+    output
+}
+
 /// Decode PNG bytes into a DynamicImage
 pub fn decode_png(bytes: &[u8]) -> Result<DynamicImage, AppError> {
     image::load_from_memory_with_format(bytes, ImageFormat::Png)
@@ -146,6 +647,151 @@ pub fn encode_rgb888_raw(img: &RgbaImage) -> Vec<u8> {
     output
 }
 
+/// Convert image to 1-bit monochrome (no header)
+///
+/// A pixel is set when its alpha is at least 128. Rows are padded to whole
+/// bytes with the leftmost pixel in the most significant bit, the layout
+/// MicroPython's `framebuf.MONO_HLSB` expects.
+pub fn encode_mono_raw(img: &RgbaImage) -> Vec<u8> {
+    pack_mono(img, |pixel| pixel[3] >= 128)
+}
+
+/// Convert image to 1-bit monochrome by brightness (no header)
+///
+/// A bit is set where the pixel's Rec. 601 luma is at least 128, so white is 1
+/// as most e-paper drivers expect. Rows are packed like `encode_mono_raw`.
+pub fn encode_mono_luma(img: &RgbaImage) -> Vec<u8> {
+    pack_mono(img, |pixel| luma(pixel) >= 128)
+}
+
+/// Pack one bit per pixel, set where `is_set`, MSB-first in byte-padded rows.
+fn pack_mono(img: &RgbaImage, is_set: impl Fn(&Rgba<u8>) -> bool) -> Vec<u8> {
+    let (width, height) = img.dimensions();
+    let stride = width.div_ceil(8) as usize;
+
+    let mut output = vec![0u8; stride * height as usize];
+
+    for (x, y, pixel) in img.enumerate_pixels() {
+        if is_set(pixel) {
+            output[y as usize * stride + x as usize / 8] |= 0x80 >> (x % 8);
+        }
+    }
+
+    output
+}
+
+/// Reduce to pure black and white by Rec. 601 luma, keeping transparency
+/// (alpha below 128 becomes fully transparent).
+pub fn threshold_black_white(img: &RgbaImage) -> RgbaImage {
+    RgbaImage::from_fn(img.width(), img.height(), |x, y| {
+        let pixel = img.get_pixel(x, y);
+        match (pixel[3] >= 128, luma(pixel) >= 128) {
+            (false, _) => Rgba([0, 0, 0, 0]),
+            (true, true) => Rgba([255, 255, 255, 255]),
+            (true, false) => Rgba([0, 0, 0, 255]),
+        }
+    })
+}
+
+fn luma(pixel: &Rgba<u8>) -> u32 {
+    (299 * pixel[0] as u32 + 587 * pixel[1] as u32 + 114 * pixel[2] as u32) / 1000
+}
+
+/// Apply saturation, brightness, and gamma (in that order) to the RGB channels.
+///
+/// Saturation blends each pixel with its Rec. 601 luma; gamma maps each
+/// channel through `(v / 255) ^ gamma`. Alpha is untouched.
+pub fn adjust_colors(img: &mut RgbaImage, gamma: f32, brightness: f32, saturation: f32) {
+    if gamma == 1.0 && brightness == 1.0 && saturation == 1.0 {
+        return;
+    }
+
+    for pixel in img.pixels_mut() {
+        let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(|c| c as f32 / 255.0);
+        let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+
+        for (channel, value) in [r, g, b].into_iter().enumerate() {
+            let value = (luma + (value - luma) * saturation) * brightness;
+            let value = value.clamp(0.0, 1.0).powf(gamma);
+            pixel[channel] = (value * 255.0).round() as u8;
+        }
+    }
+}
+
+/// 4x4 Bayer threshold matrix (values 0-15)
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Bits kept per channel by RGB565 (red, green, blue)
+const RGB565_BITS: [u32; 3] = [5, 6, 5];
+
+/// Dither an image down to the RGB565 palette.
+///
+/// Each output channel holds its RGB565 level in the high bits, so
+/// `encode_rgb565_raw` truncates to exactly the chosen level. Alpha is kept.
+pub fn dither_rgb565(img: &RgbaImage, method: Dither) -> RgbaImage {
+    match method {
+        Dither::Bayer => dither_bayer(img),
+        Dither::FloydSteinberg => dither_floyd_steinberg(img),
+    }
+}
+
+fn dither_bayer(img: &RgbaImage) -> RgbaImage {
+    let mut output = img.clone();
+
+    for (x, y, pixel) in output.enumerate_pixels_mut() {
+        let threshold = (BAYER_4X4[(y % 4) as usize][(x % 4) as usize] as f32 + 0.5) / 16.0;
+        for (channel, bits) in RGB565_BITS.iter().enumerate() {
+            let levels = ((1u32 << bits) - 1) as f32;
+            let level = (pixel[channel] as f32 * levels / 255.0 + threshold - 0.5)
+                .round()
+                .clamp(0.0, levels);
+            pixel[channel] = (level as u8) << (8 - bits);
+        }
+    }
+
+    output
+}
+
+fn dither_floyd_steinberg(img: &RgbaImage) -> RgbaImage {
+    let (width, height) = img.dimensions();
+    let mut output = img.clone();
+
+    // Working values per channel, accumulating diffused error
+    let mut values: Vec<[f32; 3]> = img
+        .pixels()
+        .map(|p| [p[0] as f32, p[1] as f32, p[2] as f32])
+        .collect();
+
+    for y in 0..height {
+        for x in 0..width {
+            let index = (y * width + x) as usize;
+            let pixel = output.get_pixel_mut(x, y);
+
+            for (channel, bits) in RGB565_BITS.iter().enumerate() {
+                let levels = ((1u32 << bits) - 1) as f32;
+                let value = values[index][channel].clamp(0.0, 255.0);
+                let level = (value * levels / 255.0).round();
+                let error = value - level * 255.0 / levels;
+                pixel[channel] = (level as u8) << (8 - bits);
+
+                let mut spread = |dx: i64, dy: i64, weight: f32| {
+                    let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+                    if nx >= 0 && nx < width as i64 && ny < height as i64 {
+                        values[(ny as u32 * width + nx as u32) as usize][channel] +=
+                            error * weight;
+                    }
+                };
+                spread(1, 0, 7.0 / 16.0);
+                spread(-1, 1, 3.0 / 16.0);
+                spread(0, 1, 5.0 / 16.0);
+                spread(1, 1, 1.0 / 16.0);
+            }
+        }
+    }
+
+    output
+}
+
 /// Convert image to raw RGB565 bytes (2 bytes per pixel, little-endian)
 ///
 /// RGB565 format: RRRRR GGGGGG BBBBB (5 bits red, 6 bits green, 5 bits blue)
@@ -229,6 +875,36 @@ mod tests {
         assert_eq!(ppm.len() - header_end, 600);
     }
 
+    #[test]
+    fn test_bmp_header_and_padding() {
+        let img = RgbaImage::new(3, 2);
+        let bmp = encode_bmp_24(&img, false);
+
+        // Rows are 9 bytes of pixels padded to 12
+        assert_eq!(bmp.len(), 54 + 12 * 2);
+        assert_eq!(&bmp[0..2], b"BM");
+        assert_eq!(u32::from_le_bytes(bmp[2..6].try_into().unwrap()), 78);
+        assert_eq!(i32::from_le_bytes(bmp[18..22].try_into().unwrap()), 3);
+        assert_eq!(i32::from_le_bytes(bmp[22..26].try_into().unwrap()), 2);
+        assert_eq!(u16::from_le_bytes(bmp[28..30].try_into().unwrap()), 24);
+    }
+
+    #[test]
+    fn test_bmp_row_order() {
+        let mut img = RgbaImage::new(1, 2);
+        img.put_pixel(0, 0, Rgba([0x11, 0x22, 0x33, 0xFF])); // top
+        img.put_pixel(0, 1, Rgba([0x44, 0x55, 0x66, 0xFF])); // bottom
+
+        // Bottom-up: last row first, BGR with one padding byte per row
+        let bmp = encode_bmp_24(&img, false);
+        assert_eq!(&bmp[54..], &[0x66, 0x55, 0x44, 0, 0x33, 0x22, 0x11, 0]);
+
+        // Top-down: negative height, rows in display order
+        let bmp = encode_bmp_24(&img, true);
+        assert_eq!(i32::from_le_bytes(bmp[22..26].try_into().unwrap()), -2);
+        assert_eq!(&bmp[54..], &[0x33, 0x22, 0x11, 0, 0x66, 0x55, 0x44, 0]);
+    }
+
     #[test]
     fn test_blend_fully_transparent() {
         let mut img = RgbaImage::new(1, 1);
@@ -352,6 +1028,149 @@ mod tests {
         assert_eq!(raw, vec![0x00, 0x00]);
     }
 
+    #[test]
+    fn test_bayer_dither_mixes_levels() {
+        // Red 4 sits halfway between the RGB565 levels 0 and 8
+        let img = RgbaImage::from_pixel(4, 4, Rgba([4, 0, 0, 255]));
+        let dithered = dither_rgb565(&img, Dither::Bayer);
+
+        let on = dithered.pixels().filter(|p| p[0] == 8).count();
+        let off = dithered.pixels().filter(|p| p[0] == 0).count();
+        assert_eq!((on, off), (8, 8));
+    }
+
+    #[test]
+    fn test_floyd_steinberg_keeps_exact_levels() {
+        // Full and zero intensity are exact levels, so no error is diffused
+        let img = RgbaImage::from_pixel(3, 3, Rgba([255, 0, 255, 255]));
+        let dithered = dither_rgb565(&img, Dither::FloydSteinberg);
+        assert!(dithered.pixels().all(|p| *p == Rgba([0xF8, 0, 0xF8, 255])));
+    }
+
+    #[test]
+    fn test_quantize_limits_palette() {
+        let img = RgbaImage::from_fn(16, 16, |x, y| Rgba([(x * 16) as u8, (y * 16) as u8, 0, 255]));
+        let indexed = quantize(&img, 4);
+
+        assert_eq!(indexed.palette.len(), 4);
+        assert_eq!(indexed.indices.len(), 256);
+        assert!(indexed.indices.iter().all(|&i| i < 4));
+    }
+
+    #[test]
+    fn test_quantize_keeps_exact_colors() {
+        let mut img = RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255]));
+        img.put_pixel(1, 1, Rgba([0, 0, 255, 255]));
+        let indexed = quantize(&img, 16);
+
+        // Two distinct colors need only two entries
+        assert_eq!(indexed.palette.len(), 2);
+        assert_eq!(indexed.to_rgba(), img);
+
+        let raw = encode_indexed_raw(&indexed);
+        assert_eq!(&raw[0..2], &[2, 0]);
+        assert_eq!(raw.len(), 2 + 2 * 3 + 4);
+
+        let png = encode_png_indexed(&indexed).unwrap();
+        assert_eq!(decode_png(&png).unwrap().to_rgba8(), img);
+    }
+
+    #[test]
+    fn test_rle_runs() {
+        let data = [1, 2, 1, 2, 1, 2, 3, 4];
+        assert_eq!(encode_rle(&data, 2), vec![3, 1, 2, 1, 3, 4]);
+
+        // Runs longer than 255 pixels are split
+        let data = vec![7u8; 300];
+        assert_eq!(encode_rle(&data, 1), vec![255, 7, 45, 7]);
+    }
+
+    #[test]
+    fn test_resize_contain_pads() {
+        // 4x2 red source into a 4x4 box leaves a row of padding above and below
+        let img = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 2, Rgba([255, 0, 0, 255])));
+        let blue = Some((0, 0, 255));
+        let resized = resize_image(&img, 4, 4, Fit::Contain, ResizeFilter::Nearest, blue);
+
+        assert_eq!(resized.dimensions(), (4, 4));
+        assert_eq!(*resized.get_pixel(0, 0), Rgba([0, 0, 255, 255]));
+        assert_eq!(*resized.get_pixel(0, 1), Rgba([255, 0, 0, 255]));
+        assert_eq!(*resized.get_pixel(0, 3), Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_resize_cover_crops() {
+        // Left half red, right half blue; covering a 2x2 box keeps the middle two columns
+        let img = DynamicImage::ImageRgba8(RgbaImage::from_fn(4, 2, |x, _| {
+            if x < 2 {
+                Rgba([255, 0, 0, 255])
+            } else {
+                Rgba([0, 0, 255, 255])
+            }
+        }));
+        let resized = resize_image(&img, 2, 2, Fit::Cover, ResizeFilter::Nearest, None);
+
+        assert_eq!(resized.dimensions(), (2, 2));
+        assert_eq!(*resized.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(*resized.get_pixel(1, 0), Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_trim_transparent_margins() {
+        let mut img = RgbaImage::new(10, 8);
+        img.put_pixel(2, 3, Rgba([255, 0, 0, 255]));
+        img.put_pixel(6, 4, Rgba([0, 255, 0, 10]));
+
+        let trimmed = trim_transparent(&DynamicImage::ImageRgba8(img));
+        assert_eq!((trimmed.width(), trimmed.height()), (5, 2));
+
+        // Nothing visible: left as is
+        let empty = DynamicImage::ImageRgba8(RgbaImage::new(4, 4));
+        assert_eq!(trim_transparent(&empty).width(), 4);
+    }
+
+    #[test]
+    fn test_pad_image_centers() {
+        let img = RgbaImage::from_pixel(2, 2, Rgba([255, 255, 255, 255]));
+        let padded = pad_image(&img, 4, 4, None);
+
+        assert_eq!(*padded.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+        assert_eq!(*padded.get_pixel(1, 1), Rgba([255, 255, 255, 255]));
+        assert_eq!(*padded.get_pixel(2, 2), Rgba([255, 255, 255, 255]));
+        assert_eq!(*padded.get_pixel(3, 3), Rgba([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_adjust_colors() {
+        let mut img = RgbaImage::from_pixel(1, 1, Rgba([200, 100, 50, 128]));
+        adjust_colors(&mut img, 1.0, 1.0, 0.0);
+        // Fully desaturated: all channels equal the luma, alpha untouched
+        let p = *img.get_pixel(0, 0);
+        assert!(p[0] == p[1] && p[1] == p[2]);
+        assert_eq!(p[3], 128);
+
+        let mut img = RgbaImage::from_pixel(1, 1, Rgba([128, 255, 0, 255]));
+        adjust_colors(&mut img, 2.0, 0.5, 1.0);
+        // 128 * 0.5 -> ~0.25 -> squared ~0.063; 255 * 0.5 -> 0.5 -> 0.25
+        assert_eq!(*img.get_pixel(0, 0), Rgba([16, 64, 0, 255]));
+    }
+
+    #[test]
+    fn test_orient_rotates_then_flips() {
+        // 2x1: red on the left, blue on the right
+        let mut img = RgbaImage::new(2, 1);
+        img.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        img.put_pixel(1, 0, Rgba([0, 0, 255, 255]));
+
+        // Clockwise quarter turn puts red on top
+        let rotated = orient(img.clone(), Some(Rotation::Rotate90), None);
+        assert_eq!(rotated.dimensions(), (1, 2));
+        assert_eq!(rotated.get_pixel(0, 0)[0], 255);
+
+        let flipped = orient(img, Some(Rotation::Rotate90), Some(Flip::Vertical));
+        assert_eq!(flipped.get_pixel(0, 0)[2], 255);
+    }
+
     #[test]
     fn test_premultiply_opaque_unchanged() {
         let mut img = RgbaImage::new(1, 1);
@@ -385,4 +1204,113 @@ mod tests {
         assert_eq!(p[3], 128);
     }
 
+    #[test]
+    fn test_dominant_colors_ignores_transparent_and_orders_by_share() {
+        let img = RgbaImage::from_fn(10, 10, |x, _| match x {
+            0..=5 => Rgba([200, 0, 0, 255]),
+            6..=7 => Rgba([0, 0, 200, 255]),
+            _ => Rgba([0, 255, 0, 0]),
+        });
+        let colors = dominant_colors(&img, 4);
+
+        assert_eq!(colors.len(), 2);
+        assert_eq!(colors[0].0, (200, 0, 0));
+        assert!((colors[0].1 - 0.75).abs() < 1e-6);
+        assert_eq!(colors[1].0, (0, 0, 200));
+    }
+
+    #[test]
+    fn test_dominant_colors_merges_similar_shades() {
+        let img = RgbaImage::from_fn(4, 1, |x, _| match x {
+            0 | 1 => Rgba([200, 0, 0, 255]),
+            2 => Rgba([216, 8, 0, 255]),
+            _ => Rgba([255, 255, 255, 255]),
+        });
+        let colors = dominant_colors(&img, 4);
+
+        assert_eq!(colors.len(), 2);
+        assert!((colors[0].1 - 0.75).abs() < 1e-6);
+
+        let limited = dominant_colors(&img, 1);
+        assert_eq!(limited.len(), 1);
+        assert!((limited[0].1 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        assert!((contrast_ratio((0, 0, 0), (255, 255, 255)) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio((90, 20, 20), (90, 20, 20)) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_contrasting_background_picks_black_or_white() {
+        let navy = RgbaImage::from_pixel(4, 4, Rgba([0, 34, 68, 255]));
+        assert_eq!(contrasting_background(&navy), (255, 255, 255));
+
+        let gold = RgbaImage::from_pixel(4, 4, Rgba([255, 184, 28, 255]));
+        assert_eq!(contrasting_background(&gold), (0, 0, 0));
+
+        // Mid-gray is the worst case, and still clears 4.5:1
+        let gray = (118, 118, 118);
+        let img = RgbaImage::from_pixel(1, 1, Rgba([gray.0, gray.1, gray.2, 255]));
+        assert!(contrast_ratio(gray, contrasting_background(&img)) >= 4.5);
+    }
+
+    #[test]
+    fn test_encode_qoi_header_and_runs() {
+        let img = RgbaImage::from_pixel(3, 1, Rgba([200, 0, 0, 255]));
+        let qoi = encode_qoi(&img);
+
+        assert_eq!(&qoi[..4], b"qoif");
+        assert_eq!(&qoi[4..12], &[0, 0, 0, 3, 0, 0, 0, 1]);
+        assert_eq!(&qoi[12..14], &[4, 0]);
+        // Full red, then a run of two, then the end marker
+        assert_eq!(&qoi[14..19], &[0xFE, 200, 0, 0, 0xC1]);
+        assert_eq!(&qoi[19..], &[0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_encode_qoi_ops() {
+        let pixels = [
+            Rgba([1, 1, 1, 255]),    // diff from black
+            Rgba([11, 10, 12, 255]), // luma
+            Rgba([1, 1, 1, 255]),    // index
+            Rgba([1, 1, 1, 0]),      // alpha change
+        ];
+        let img = RgbaImage::from_fn(4, 1, |x, _| pixels[x as usize]);
+        let qoi = encode_qoi(&img);
+
+        let hash = ((3 + 5 + 7 + 255 * 11) % 64) as u8;
+        let diff = 0x40 | 3 << 4 | 3 << 2 | 3;
+        let luma = [0x80 | 41, 9 << 4 | 10];
+        let expected = [diff, luma[0], luma[1], hash, 0xFF, 1, 1, 1, 0];
+        assert_eq!(&qoi[14..qoi.len() - 8], &expected);
+    }
+
+    #[test]
+    fn test_encode_mono_raw_pads_rows() {
+        let img = RgbaImage::from_fn(10, 2, |x, y| {
+            let on = (y == 0 && x == 0) || (y == 1 && x == 9);
+            Rgba([255, 255, 255, if on { 255 } else { 0 }])
+        });
+        assert_eq!(encode_mono_raw(&img), vec![0x80, 0x00, 0x00, 0x40]);
+    }
+
+    #[test]
+    fn test_threshold_and_mono_luma() {
+        let pixels = [
+            Rgba([250, 240, 230, 255]),
+            Rgba([30, 40, 200, 255]),
+            Rgba([255, 255, 255, 10]),
+        ];
+        let img = RgbaImage::from_fn(3, 1, |x, _| pixels[x as usize]);
+
+        let bw = threshold_black_white(&img);
+        assert_eq!(bw.get_pixel(0, 0).0, [255, 255, 255, 255]);
+        assert_eq!(bw.get_pixel(1, 0).0, [0, 0, 0, 255]);
+        assert_eq!(bw.get_pixel(2, 0).0, [0, 0, 0, 0]);
+
+        // Light pixels are set whatever their alpha
+        assert_eq!(encode_mono_luma(&img), vec![0b1010_0000]);
+    }
 }
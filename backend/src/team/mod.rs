@@ -1,5 +1,11 @@
+pub mod animation;
+pub mod cache;
 pub mod handler;
 pub mod image;
+pub mod sheet;
 pub mod types;
 
-pub use handler::{get_basketball_team_logo, get_football_team_logo};
+pub use handler::{
+    get_basketball_team_logo, get_football_celebration, get_football_team_logo, get_logo_sheet,
+    get_logo_sheet_index, get_team_color_swatch, get_team_colors,
+};
@@ -0,0 +1,87 @@
+//! Sprite sheet of every NFL logo, so firmware can fetch the whole set at once.
+
+use image::RgbaImage;
+
+use super::types::{LogoQuery, Rotation, SpriteSheetIndex, SpriteSheetTile};
+
+/// Logos per sheet row (32 NFL teams make a 8x4 grid).
+pub const SHEET_COLUMNS: u32 = 8;
+
+/// Size of one processed logo, accounting for rotation.
+pub fn tile_size(params: &LogoQuery) -> (u32, u32) {
+    match params.rotate {
+        Some(Rotation::Rotate90 | Rotation::Rotate270) => (params.height, params.width),
+        _ => (params.width, params.height),
+    }
+}
+
+/// Lay out tiles for `teams` in order, left to right then top to bottom.
+pub fn sheet_index(teams: &[&str], tile_width: u32, tile_height: u32) -> SpriteSheetIndex {
+    let count = teams.len() as u32;
+    let columns = SHEET_COLUMNS.min(count).max(1);
+    let rows = count.div_ceil(columns);
+
+    let tiles = teams
+        .iter()
+        .enumerate()
+        .map(|(i, team)| SpriteSheetTile {
+            team: team.to_lowercase(),
+            x: (i as u32 % columns) * tile_width,
+            y: (i as u32 / columns) * tile_height,
+        })
+        .collect();
+
+    SpriteSheetIndex {
+        width: columns * tile_width,
+        height: rows * tile_height,
+        tile_width,
+        tile_height,
+        columns,
+        rows,
+        tiles,
+    }
+}
+
+/// Paste each logo at its position in `index`. Empty cells stay transparent.
+pub fn compose_sheet(logos: &[RgbaImage], index: &SpriteSheetIndex) -> RgbaImage {
+    let mut sheet = RgbaImage::new(index.width, index.height);
+    for (logo, tile) in logos.iter().zip(&index.tiles) {
+        image::imageops::replace(&mut sheet, logo, tile.x as i64, tile.y as i64);
+    }
+    sheet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_sheet_index_grid() {
+        let teams: Vec<String> = (0..32).map(|i| format!("T{i}")).collect();
+        let teams: Vec<&str> = teams.iter().map(String::as_str).collect();
+        let index = sheet_index(&teams, 32, 24);
+
+        assert_eq!((index.columns, index.rows), (8, 4));
+        assert_eq!((index.width, index.height), (256, 96));
+        assert_eq!(index.tiles[0].team, "t0");
+        assert_eq!((index.tiles[9].x, index.tiles[9].y), (32, 24));
+        assert_eq!((index.tiles[31].x, index.tiles[31].y), (224, 72));
+    }
+
+    #[test]
+    fn test_compose_sheet_places_tiles() {
+        let index = sheet_index(&["a", "b", "c"], 2, 2);
+        let logos = [
+            RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255])),
+            RgbaImage::from_pixel(2, 2, Rgba([0, 255, 0, 255])),
+            RgbaImage::from_pixel(2, 2, Rgba([0, 0, 255, 255])),
+        ];
+        let sheet = compose_sheet(&logos, &index);
+
+        assert_eq!(sheet.dimensions(), (6, 2));
+        assert_eq!(*sheet.get_pixel(1, 1), Rgba([255, 0, 0, 255]));
+        assert_eq!(*sheet.get_pixel(2, 0), Rgba([0, 255, 0, 255]));
+        assert_eq!(*sheet.get_pixel(5, 1), Rgba([0, 0, 255, 255]));
+    }
+}
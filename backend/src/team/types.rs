@@ -1,5 +1,9 @@
-use serde::Deserialize;
-use utoipa::IntoParams;
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::config::DeviceProfile;
+use crate::shared::types::Color;
 
 /// Query parameters for the logo endpoint
 #[derive(Debug, Deserialize, IntoParams)]
@@ -14,20 +18,218 @@ pub struct LogoQuery {
 
     /// Background color as hex RGB888 without # (e.g., "FFFFFF").
     /// If provided, transparent pixels are blended with this color.
+    /// `auto` picks black or white, whichever contrasts more with the logo's
+    /// dominant color, and reports the choice in `X-Background-Color`.
     pub background_color: Option<String>,
+
+    /// Store BMP rows top-down instead of the standard bottom-up order
+    /// (default: false). Only applies to `image/bmp`.
+    #[serde(default)]
+    pub top_down: bool,
+
+    /// Output format, overriding the Accept header. Raw formats (rgb565, rgb888)
+    /// selected this way are sent as `application/octet-stream`.
+    pub format: Option<OutputFormat>,
+
+    /// Dither when reducing color depth, to avoid banding in gradients.
+    /// Only applies to RGB565 output.
+    pub dither: Option<Dither>,
+
+    /// Quantize to a palette of at most this many colors (2-256). PNG output
+    /// becomes a palette PNG. Indexed output always uses a palette (default: 256).
+    pub colors: Option<u16>,
+
+    /// Compress the pixel data of raw formats (rgb565, rgb888, indexed).
+    /// See `encode_rle` for the layout.
+    pub compress: Option<Compression>,
+
+    /// How the square source logo fits the requested size (default: stretch)
+    #[serde(default)]
+    pub fit: Fit,
+
+    /// Resampling filter (default: catmullrom). `nearest` keeps edges crisp at very small sizes.
+    #[serde(default)]
+    pub filter: ResizeFilter,
+
+    /// Color of the space added by `fit=contain` and `pad`, as hex RGB888 without #.
+    /// Transparent if not specified.
+    pub padding_color: Option<String>,
+
+    /// Trim margins before resizing. `auto` removes fully transparent borders,
+    /// so logos with large empty margins fill the output like the rest.
+    pub crop: Option<Crop>,
+
+    /// Pixels of padding on every side of the logo, inside the requested size
+    /// (default: 0). Filled with `padding_color`.
+    #[serde(default)]
+    pub pad: u32,
+
+    /// Gamma exponent applied to each channel (default: 1.0). Values above 1
+    /// darken midtones, which counters the washed-out look of LED panels (try 2.2).
+    pub gamma: Option<f32>,
+
+    /// Brightness multiplier (default: 1.0)
+    pub brightness: Option<f32>,
+
+    /// Saturation multiplier; 0 is grayscale (default: 1.0)
+    pub saturation: Option<f32>,
+
+    /// Rotate clockwise by 90, 180, or 270 degrees, for panels mounted sideways.
+    /// `width` and `height` describe the logo before rotation.
+    pub rotate: Option<Rotation>,
+
+    /// Mirror horizontally (`h`) or vertically (`v`), applied after rotation
+    pub flip: Option<Flip>,
+
+    /// Which ESPN artwork to start from (default: default). `scoreboard` and `dark`
+    /// stay legible on black LED backgrounds.
+    #[serde(default)]
+    pub variant: LogoVariant,
+
+    /// Configured device profile whose format, palette size, dithering, rotation,
+    /// and gamma apply to any of those parameters not given explicitly
+    pub device: Option<String>,
+}
+
+impl LogoQuery {
+    /// Fill in the parameters this request left unset from a device profile.
+    pub fn with_device(mut self, device: &DeviceProfile) -> Self {
+        self.format = self.format.or(device.format);
+        self.colors = self.colors.or(device.colors);
+        self.dither = self.dither.or(device.dither);
+        self.rotate = self.rotate.or(device.rotate);
+        self.gamma = self.gamma.or(device.gamma);
+        self
+    }
 }
 
 fn default_size() -> u32 {
     128
 }
 
-/// Supported output formats based on Accept header
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Dithering method for low-bit-depth output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Dither {
+    /// Ordered dithering with a 4x4 Bayer matrix (stable pattern, no smearing)
+    Bayer,
+    /// Error diffusion (smoother gradients, noisier edges)
+    FloydSteinberg,
+}
+
+/// How the logo is scaled into the requested width and height
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Fit {
+    /// Scale to fit inside, padding the leftover space
+    Contain,
+    /// Scale to fill, cropping the overflow
+    Cover,
+    /// Scale each axis independently, ignoring aspect ratio
+    #[default]
+    Stretch,
+}
+
+/// Resampling filter used when resizing
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ResizeFilter {
+    Nearest,
+    Triangle,
+    #[default]
+    CatmullRom,
+    Lanczos,
+}
+
+/// Margin trimming applied to the source logo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Crop {
+    /// Trim fully transparent rows and columns from the edges
+    Auto,
+}
+
+/// ESPN logo artwork variants
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LogoVariant {
+    /// Primary logo, designed for light backgrounds
+    #[default]
+    Default,
+    /// Helmet artwork
+    Helmet,
+    /// Logo recolored for dark backgrounds
+    Dark,
+    /// Simplified logo from ESPN's scoreboards, readable small and on dark backgrounds
+    Scoreboard,
+}
+
+impl LogoVariant {
+    /// CDN directory between the league and the file name, for pro leagues.
+    pub fn cdn_path(&self) -> &'static str {
+        match self {
+            LogoVariant::Default => "500",
+            LogoVariant::Helmet => "500-helmet",
+            LogoVariant::Dark => "500-dark",
+            LogoVariant::Scoreboard => "500/scoreboard",
+        }
+    }
+
+    /// Whether a logo with these `rel` tags is this variant (college teams API).
+    pub fn matches_rel(&self, rel: &[String]) -> bool {
+        let has = |tag: &str| rel.iter().any(|r| r == tag);
+        match self {
+            LogoVariant::Default => has("default"),
+            LogoVariant::Helmet => has("helmet"),
+            LogoVariant::Dark => has("dark") && !has("scoreboard"),
+            LogoVariant::Scoreboard => has("scoreboard") && !has("dark"),
+        }
+    }
+}
+
+/// Clockwise rotation for mounted displays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum Rotation {
+    #[serde(rename = "90")]
+    Rotate90,
+    #[serde(rename = "180")]
+    Rotate180,
+    #[serde(rename = "270")]
+    Rotate270,
+}
+
+/// Mirroring for mounted displays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+pub enum Flip {
+    /// Mirror left-to-right
+    #[serde(rename = "h")]
+    Horizontal,
+    /// Mirror top-to-bottom
+    #[serde(rename = "v")]
+    Vertical,
+}
+
+/// Compression for raw pixel data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    /// Run-length encoding of whole pixels
+    Rle,
+}
+
+/// Supported output formats based on Accept header or `?format=`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     Png,
     Ppm,
     Rgb888,
     Rgb565,
+    Bmp,
+    /// Palette table followed by one palette index per pixel
+    Indexed,
+    /// "Quite OK Image" format: compressed RGBA that decodes in a few lines of code
+    Qoi,
 }
 
 impl OutputFormat {
@@ -37,6 +239,178 @@ impl OutputFormat {
             OutputFormat::Ppm => "image/x-portable-pixmap",
             OutputFormat::Rgb888 => "image/x-rgb888",
             OutputFormat::Rgb565 => "image/x-rgb565",
+            OutputFormat::Bmp => "image/bmp",
+            OutputFormat::Indexed => "image/x-indexed",
+            OutputFormat::Qoi => "image/qoi",
         }
     }
+
+    /// Headerless pixel data, which needs its dimensions sent alongside.
+    pub fn is_raw(&self) -> bool {
+        self.raw_pixel_size().is_some()
+    }
+
+    /// Bytes per pixel for raw formats.
+    pub fn raw_pixel_size(&self) -> Option<usize> {
+        match self {
+            OutputFormat::Rgb888 => Some(3),
+            OutputFormat::Rgb565 => Some(2),
+            OutputFormat::Indexed => Some(1),
+            _ => None,
+        }
+    }
+}
+
+/// Where each logo sits in the sprite sheet.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SpriteSheetIndex {
+    /// Sheet width in pixels
+    pub width: u32,
+    /// Sheet height in pixels
+    pub height: u32,
+    /// Width of one logo tile, after rotation
+    pub tile_width: u32,
+    /// Height of one logo tile, after rotation
+    pub tile_height: u32,
+    pub columns: u32,
+    pub rows: u32,
+    /// Tiles in sheet order, left to right then top to bottom
+    pub tiles: Vec<SpriteSheetTile>,
+}
+
+/// One logo's position in the sprite sheet.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SpriteSheetTile {
+    /// Team abbreviation, lowercase (e.g., "dal")
+    pub team: String,
+    /// Left edge in pixels
+    pub x: u32,
+    /// Top edge in pixels
+    pub y: u32,
+}
+
+/// Query parameters for the team colors endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TeamColorsQuery {
+    /// Maximum number of palette colors, clamped to 1-8 (default: 4)
+    #[serde(default = "default_color_count")]
+    pub count: u8,
+}
+
+fn default_color_count() -> u8 {
+    4
+}
+
+/// Colors measured from a team's logo image.
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+pub struct TeamColors {
+    /// Team abbreviation, lowercase (e.g., "dal")
+    pub team: String,
+    /// Most common color in the logo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dominant: Option<Color>,
+    /// Most common color clearly different from `dominant`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent: Option<Color>,
+    /// Distinct logo colors, most common first
+    pub palette: Vec<ColorShare>,
+}
+
+/// A logo color and how much of the logo it covers.
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+pub struct ColorShare {
+    pub color: Color,
+    /// Fraction of visible logo pixels (0-1)
+    pub share: f32,
+}
+
+/// Query parameters for the celebration animation endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CelebrationQuery {
+    /// Width in pixels, clamped to 8-128 (default: 64)
+    #[serde(default = "default_animation_size")]
+    pub width: u32,
+
+    /// Height in pixels, clamped to 8-128 (default: 64)
+    #[serde(default = "default_animation_size")]
+    pub height: u32,
+
+    /// Number of frames in the loop, clamped to 2-32 (default: 8)
+    #[serde(default = "default_frame_count")]
+    pub frames: u8,
+
+    /// Time each frame is shown, in milliseconds (default: 125)
+    #[serde(default = "default_frame_ms")]
+    pub frame_ms: u16,
+
+    /// Output format (default: gif)
+    #[serde(default)]
+    pub format: AnimationFormat,
+
+    /// Which ESPN artwork to animate (default: default)
+    #[serde(default)]
+    pub variant: LogoVariant,
+}
+
+fn default_animation_size() -> u32 {
+    64
+}
+
+fn default_frame_count() -> u8 {
+    8
+}
+
+fn default_frame_ms() -> u16 {
+    125
+}
+
+/// Output formats for animations
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AnimationFormat {
+    /// Looping animated GIF
+    #[default]
+    Gif,
+    /// Looping animated PNG
+    Apng,
+    /// Frames of raw RGB565 (little-endian) back to back
+    Rgb565,
+    /// Frames of raw RGB888 back to back
+    Rgb888,
+}
+
+/// Query parameters for the team color swatch endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ColorSwatchQuery {
+    /// Which team color to return (default: primary). `both` puts the secondary
+    /// color to the right of the primary.
+    #[serde(default)]
+    pub which: SwatchColors,
+
+    /// Output format (default: rgb565). A 1x1 rgb565 swatch is just the 2-byte
+    /// little-endian color.
+    pub format: Option<OutputFormat>,
+
+    /// Width of each color's block in pixels, clamped to 1-64 (default: 1)
+    #[serde(default = "default_swatch_size")]
+    pub width: u32,
+
+    /// Height in pixels, clamped to 1-64 (default: 1)
+    #[serde(default = "default_swatch_size")]
+    pub height: u32,
+}
+
+fn default_swatch_size() -> u32 {
+    1
+}
+
+/// Team colors included in a swatch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SwatchColors {
+    #[default]
+    Primary,
+    Secondary,
+    /// Primary then secondary
+    Both,
 }
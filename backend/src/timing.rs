@@ -0,0 +1,74 @@
+//! Where a request's time went, for the latency budget warnings in
+//! `request_log`.
+//!
+//! The request log middleware gives each request an empty breakdown; handlers
+//! time their ESPN fetch, transform, and serialize steps with `espn`,
+//! `transform`, and `Timed`. Time outside those steps (auth, mock games,
+//! rendering) shows up as the difference from the total.
+
+use axum::response::{IntoResponse, Response};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// Time spent per step of a request
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Breakdown {
+    pub espn: Duration,
+    pub transform: Duration,
+    pub serialize: Duration,
+}
+
+impl Breakdown {
+    /// Whether any step was timed.
+    pub fn is_empty(&self) -> bool {
+        self.espn.is_zero() && self.transform.is_zero() && self.serialize.is_zero()
+    }
+}
+
+tokio::task_local! {
+    static BREAKDOWN: RefCell<Breakdown>;
+}
+
+/// Run `request` with an empty breakdown for the steps below to fill in,
+/// returning its output and the breakdown.
+pub async fn measure<F: Future>(request: F) -> (F::Output, Breakdown) {
+    BREAKDOWN
+        .scope(RefCell::new(Breakdown::default()), async {
+            let output = request.await;
+            (output, BREAKDOWN.with(|b| *b.borrow()))
+        })
+        .await
+}
+
+/// Add `elapsed` to a step, if the request is being measured.
+fn record(step: fn(&mut Breakdown) -> &mut Duration, elapsed: Duration) {
+    let _ = BREAKDOWN.try_with(|b| *step(&mut b.borrow_mut()) += elapsed);
+}
+
+/// Time an ESPN fetch.
+pub async fn espn<F: Future>(fetch: F) -> F::Output {
+    let started = Instant::now();
+    let output = fetch.await;
+    record(|b| &mut b.espn, started.elapsed());
+    output
+}
+
+/// Time turning ESPN data into our response types.
+pub fn transform<T>(f: impl FnOnce() -> T) -> T {
+    let started = Instant::now();
+    let output = f();
+    record(|b| &mut b.transform, started.elapsed());
+    output
+}
+
+/// A response whose conversion (for `Json`, serialization) is timed.
+pub struct Timed<T>(pub T);
+
+impl<T: IntoResponse> IntoResponse for Timed<T> {
+    fn into_response(self) -> Response {
+        let started = Instant::now();
+        let response = self.0.into_response();
+        record(|b| &mut b.serialize, started.elapsed());
+        response
+    }
+}
@@ -0,0 +1,138 @@
+//! API versions. `/api/v1` (and the unversioned `/api` deployed firmware
+//! already calls) keep today's response shapes frozen; `/api/v2` is where
+//! payloads improve.
+//!
+//! Handlers produce v1 JSON, and v2 rewrites it on the way out, so a v2 change
+//! is one rule in `upgrade` rather than a second set of handlers. So far v2
+//! differs in one way: game clocks are numbers of seconds (`112` rather than
+//! `"1:52"`, `45.3` rather than `"45.3"`), or null when there's no clock to
+//! show (e.g., `""` at halftime).
+//!
+//! Streamed JSON (`?stream=true`) is buffered in v2; server-sent events pass
+//! through unchanged.
+
+use axum::body::{to_bytes, Body};
+use axum::extract::Request;
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+
+use crate::shared::transform::parse_clock;
+
+/// Largest v1 body v2 will rewrite; full scoreboards are well under this
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Keys whose string values are game clocks
+const CLOCK_KEYS: &[&str] = &["clock", "start_clock"];
+
+/// Version prefixes `api_routes` is nested under besides plain `/api`
+const VERSION_PREFIXES: &[&str] = &["/api/v1/", "/api/v2/"];
+
+/// A route template without its version, as latency budgets are keyed:
+/// "/api/v2/football/{league}/games" is "/api/football/{league}/games".
+pub fn unversioned(route: &str) -> String {
+    VERSION_PREFIXES
+        .iter()
+        .find_map(|prefix| route.strip_prefix(prefix))
+        .map_or_else(|| route.to_string(), |rest| format!("/api/{rest}"))
+}
+
+/// A clock string as seconds: "MM:SS" as a whole number, or ESPN's
+/// under-a-minute "45.3" as a decimal one.
+fn clock_seconds(clock: &str) -> Option<Value> {
+    if let Some(seconds) = parse_clock(clock) {
+        return Some(seconds.into());
+    }
+    let seconds: f64 = clock.parse().ok()?;
+    serde_json::Number::from_f64(seconds).map(Value::Number)
+}
+
+/// Rewrite a v1 JSON document into its v2 shape.
+fn upgrade(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if CLOCK_KEYS.contains(&key.as_str())
+                    && let Some(clock) = field.as_str()
+                {
+                    *field = clock_seconds(clock).unwrap_or(Value::Null);
+                    continue;
+                }
+                upgrade(field);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(upgrade),
+        _ => {}
+    }
+}
+
+/// Middleware for `/api/v2`: upgrade successful JSON responses.
+pub async fn v2_shim(request: Request, next: Next) -> Response {
+    let response = next.run(request).await;
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    if !response.status().is_success() || !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!(error = %e, "Couldn't read response body for v2");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let Ok(mut value) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    upgrade(&mut value);
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_upgrade_makes_clocks_numeric() {
+        let mut games = json!([
+            {"state": "live", "clock": "1:52", "situation": {"ball_on": "KC 35"}},
+            {"state": "live", "clock": "45.3"},
+            {"state": "pregame", "start_time": 1700000000},
+            {"plays": [{"start_clock": "15:00", "text": "Kickoff"}]},
+        ]);
+        upgrade(&mut games);
+        assert_eq!(
+            games,
+            json!([
+                {"state": "live", "clock": 112, "situation": {"ball_on": "KC 35"}},
+                {"state": "live", "clock": 45.3},
+                {"state": "pregame", "start_time": 1700000000},
+                {"plays": [{"start_clock": 900, "text": "Kickoff"}]},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_upgrade_nulls_unparseable_clocks() {
+        let mut game = json!({"clock": "", "period": "halftime"});
+        upgrade(&mut game);
+        assert_eq!(game, json!({"clock": null, "period": "halftime"}));
+    }
+
+    #[test]
+    fn test_unversioned_routes() {
+        let games = "/api/football/{league}/games";
+        assert_eq!(unversioned("/api/v1/football/{league}/games"), games);
+        assert_eq!(unversioned("/api/v2/football/{league}/games"), games);
+        assert_eq!(unversioned(games), games);
+        assert_eq!(unversioned("/health/live"), "/health/live");
+    }
+}